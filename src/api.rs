@@ -0,0 +1,19 @@
+//! The public API of Cairo lint that external consumers are meant to depend on.
+//!
+//! Everything re-exported here is kept backwards compatible within the same [`API_VERSION`].
+//! The other public modules of this crate expose internals, which can change at any time.
+
+/// Version of the API exposed by this module.
+/// It's bumped whenever a breaking change is made to any of the re-exported items.
+pub const API_VERSION: u32 = 1;
+
+pub use crate::context::LintGroup;
+pub use crate::diagnostics::{format_diagnostic, format_diagnostic_with_name};
+pub use crate::fixer::{DiagnosticFixSuggestion, Suggestion};
+pub use crate::plugin::cairo_lint_allow_plugin_suite;
+pub use crate::{
+    CAIRO_LINT_TOOL_NAME, CairoLintSeverities, CairoLintToolMetadata, FixSummary, LintSeverity,
+    LinterAnalysisDatabase, LinterAnalysisDatabaseBuilder, LinterDiagnosticParams, LinterGroup,
+    apply_file_fixes, apply_file_fixes_with_summary, apply_single_fix, compute_fixed_content,
+    fix_for_diagnostic, get_fixes, get_separated_fixes, lint_string, preview_fixes,
+};
@@ -1,6 +1,9 @@
+use crate::LinterDiagnosticParams;
 use crate::fixer::InternalFix;
 use crate::lints::assert_on_const::AssertOnConst;
 use crate::lints::assert_on_const::check_assert_on_const;
+use crate::lints::assign_op_pattern::AssignOpPattern;
+use crate::lints::assign_op_pattern::check_assign_op_pattern;
 use crate::lints::bitwise_for_parity_check::BitwiseForParity;
 use crate::lints::bitwise_for_parity_check::check_bitwise_for_parity;
 use crate::lints::bool_comparison::BoolComparison;
@@ -10,17 +13,25 @@ use crate::lints::breaks::check_break;
 use crate::lints::clone_on_copy::{CloneOnCopy, check_clone_on_copy};
 use crate::lints::collapsible_match::CollapsibleMatch;
 use crate::lints::collapsible_match::check_collapsible_match;
+use crate::lints::comparison_chain::ComparisonChain;
+use crate::lints::comparison_chain::check_comparison_chain;
 use crate::lints::double_comparison::ContradictoryComparison;
 use crate::lints::double_comparison::ImpossibleComparison;
 use crate::lints::double_comparison::RedundantComparison;
 use crate::lints::double_comparison::SimplifiableComparison;
 use crate::lints::double_comparison::check_double_comparison;
+use crate::lints::double_negation::DoubleNegation;
+use crate::lints::double_negation::check_double_negation;
 use crate::lints::double_parens::DoubleParens;
 use crate::lints::double_parens::check_double_parens;
 use crate::lints::duplicate_underscore_args::DuplicateUnderscoreArgs;
 use crate::lints::duplicate_underscore_args::check_duplicate_underscore_args;
 use crate::lints::empty_enum_brackets_variant::EmptyEnumBracketsVariant;
 use crate::lints::empty_enum_brackets_variant::check_empty_enum_brackets_variant;
+use crate::lints::empty_match::UselessMatch;
+use crate::lints::empty_match::check_useless_match;
+use crate::lints::empty_string_comparison::EmptyStringComparison;
+use crate::lints::empty_string_comparison::check_empty_string_comparison;
 use crate::lints::enum_variant_names::EnumVariantNames;
 use crate::lints::enum_variant_names::check_enum_variant_names;
 use crate::lints::eq_op::BitwiseEqualityOperation;
@@ -32,6 +43,15 @@ use crate::lints::eq_op::NotEqualComparisonOperation;
 use crate::lints::eq_op::check_eq_op;
 use crate::lints::erasing_op::ErasingOperation;
 use crate::lints::erasing_op::check_erasing_operation;
+use crate::lints::excessive_nesting::ExcessiveNesting;
+use crate::lints::excessive_nesting::check_excessive_nesting;
+use crate::lints::extreme_comparisons::AbsurdExtremeComparison;
+use crate::lints::extreme_comparisons::check_extreme_comparisons;
+use crate::lints::format_args::FormatLiteralArgument;
+use crate::lints::format_args::UselessFormat;
+use crate::lints::format_args::check_format_args;
+use crate::lints::ifs::branches_sharing_code::BranchesSharingCode;
+use crate::lints::ifs::branches_sharing_code::check_branches_sharing_code;
 use crate::lints::ifs::collapsible_if::CollapsibleIf;
 use crate::lints::ifs::collapsible_if::check_collapsible_if;
 use crate::lints::ifs::collapsible_if_else::CollapsibleIfElse;
@@ -40,72 +60,169 @@ use crate::lints::ifs::equatable_if_let::EquatableIfLet;
 use crate::lints::ifs::equatable_if_let::check_equatable_if_let;
 use crate::lints::ifs::ifs_same_cond::DuplicateIfCondition;
 use crate::lints::ifs::ifs_same_cond::check_duplicate_if_condition;
+use crate::lints::ifs::manual_bool_logic::ManualBoolLogic;
+use crate::lints::ifs::manual_bool_logic::check_manual_bool_logic;
+use crate::lints::ifs::manual_flatten::ManualFlatten;
+use crate::lints::ifs::manual_flatten::check_manual_flatten;
+use crate::lints::ifs::redundant_else::RedundantElse;
+use crate::lints::ifs::redundant_else::check_redundant_else;
 use crate::lints::int_op_one::IntegerGreaterEqualMinusOne;
 use crate::lints::int_op_one::IntegerGreaterEqualPlusOne;
 use crate::lints::int_op_one::IntegerLessEqualMinusOne;
 use crate::lints::int_op_one::IntegerLessEqualPlusOne;
 use crate::lints::int_op_one::check_int_op_one;
+use crate::lints::large_enum_variant::LargeEnumVariant;
+use crate::lints::large_enum_variant::check_large_enum_variant;
+use crate::lints::len_zero::LenZero;
+use crate::lints::len_zero::check_len_zero;
+use crate::lints::let_and_return::LetAndReturn;
+use crate::lints::let_and_return::check_let_and_return;
+use crate::lints::loops::explicit_counter_loop::ExplicitCounterLoop;
+use crate::lints::loops::explicit_counter_loop::check_explicit_counter_loop;
 use crate::lints::loops::loop_for_while::LoopForWhile;
 use crate::lints::loops::loop_for_while::check_loop_for_while;
 use crate::lints::loops::loop_match_pop_front::LoopMatchPopFront;
 use crate::lints::loops::loop_match_pop_front::check_loop_match_pop_front;
+use crate::lints::loops::manual_all_any::ManualAll;
+use crate::lints::loops::manual_all_any::ManualAny;
+use crate::lints::loops::manual_all_any::check_manual_all_any;
+use crate::lints::loops::manual_array_fill::ManualArrayFill;
+use crate::lints::loops::manual_array_fill::check_manual_array_fill;
+use crate::lints::loops::manual_contains::ManualContains;
+use crate::lints::loops::manual_contains::check_manual_contains;
+use crate::lints::loops::manual_retain::ManualRetain;
+use crate::lints::loops::manual_retain::check_manual_retain;
 use crate::lints::manual::manual_assert::ManualAssert;
 use crate::lints::manual::manual_assert::check_manual_assert;
+use crate::lints::manual::manual_checked_conversion::ManualCheckedConversion;
+use crate::lints::manual::manual_checked_conversion::check_manual_checked_conversion;
 use crate::lints::manual::manual_err::ManualErr;
 use crate::lints::manual::manual_err::check_manual_err;
 use crate::lints::manual::manual_expect::ManualExpect;
 use crate::lints::manual::manual_expect::check_manual_expect;
 use crate::lints::manual::manual_expect_err::ManualExpectErr;
 use crate::lints::manual::manual_expect_err::check_manual_expect_err;
+use crate::lints::manual::manual_filter::ManualFilter;
+use crate::lints::manual::manual_filter::check_manual_filter;
 use crate::lints::manual::manual_is::ManualIsErr;
 use crate::lints::manual::manual_is::ManualIsNone;
 use crate::lints::manual::manual_is::ManualIsOk;
 use crate::lints::manual::manual_is::ManualIsSome;
 use crate::lints::manual::manual_is::check_manual_is;
 use crate::lints::manual::manual_is_empty::{ManualIsEmpty, check_manual_is_empty};
+use crate::lints::manual::manual_is_some_and::ManualIsSomeAnd;
+use crate::lints::manual::manual_is_some_and::check_manual_is_some_and;
+use crate::lints::manual::manual_is_variant::ManualIsVariant;
+use crate::lints::manual::manual_is_variant::check_manual_is_variant;
+use crate::lints::manual::manual_map_or::ManualMapOr;
+use crate::lints::manual::manual_map_or::check_manual_map_or;
 use crate::lints::manual::manual_ok::ManualOk;
 use crate::lints::manual::manual_ok::check_manual_ok;
 use crate::lints::manual::manual_ok_or::ManualOkOr;
 use crate::lints::manual::manual_ok_or::check_manual_ok_or;
+use crate::lints::manual::manual_ok_or_default::ManualOkOrDefault;
+use crate::lints::manual::manual_ok_or_default::check_manual_ok_or_default;
+use crate::lints::manual::manual_partition::ManualPartition;
+use crate::lints::manual::manual_partition::check_manual_partition;
+use crate::lints::manual::manual_repeat::ManualRepeat;
+use crate::lints::manual::manual_repeat::check_manual_repeat;
 use crate::lints::manual::manual_unwrap_or::ManualUnwrapOr;
 use crate::lints::manual::manual_unwrap_or::check_manual_unwrap_or;
 use crate::lints::manual::manual_unwrap_or_default::ManualUnwrapOrDefault;
 use crate::lints::manual::manual_unwrap_or_default::check_manual_unwrap_or_default;
 use crate::lints::manual::manual_unwrap_or_else::ManualUnwrapOrElse;
 use crate::lints::manual::manual_unwrap_or_else::check_manual_unwrap_or_else;
+use crate::lints::manual::needless_match::NeedlessMatch;
+use crate::lints::manual::needless_match::check_needless_match;
+use crate::lints::manual_clamp::ManualClamp;
+use crate::lints::manual_clamp::check_manual_clamp;
+use crate::lints::manual_div_ceil::ManualDivCeil;
+use crate::lints::manual_div_ceil::check_manual_div_ceil;
+use crate::lints::manual_min_max::ManualMax;
+use crate::lints::manual_min_max::ManualMin;
+use crate::lints::manual_min_max::check_manual_min_max;
+use crate::lints::manual_pow::ManualPow;
+use crate::lints::manual_pow::check_manual_pow;
+use crate::lints::manual_saturating::ManualSaturating;
+use crate::lints::manual_saturating::check_manual_saturating;
+use crate::lints::manual_swap::ManualSwap;
+use crate::lints::manual_swap::check_manual_swap;
+use crate::lints::manual_take::ManualTake;
+use crate::lints::manual_take::check_manual_take;
+use crate::lints::match_bool::MatchBool;
+use crate::lints::match_bool::check_match_bool;
+use crate::lints::modulo_one::ModuloOne;
+use crate::lints::modulo_one::check_modulo_one;
+use crate::lints::needless_question_mark::NeedlessQuestionMark;
+use crate::lints::needless_question_mark::check_needless_question_mark;
+use crate::lints::needless_return::NeedlessReturn;
+use crate::lints::needless_return::check_needless_return;
+use crate::lints::needless_semicolon::NeedlessSemicolon;
+use crate::lints::needless_semicolon::check_needless_semicolon;
 use crate::lints::panic::PanicInCode;
 use crate::lints::panic::check_panic_usage;
 use crate::lints::performance::inefficient_unwrap_or::InefficientUnwrapOr;
 use crate::lints::performance::inefficient_unwrap_or::check_inefficient_unwrap_or;
 use crate::lints::performance::inefficient_while_comp::InefficientWhileComparison;
 use crate::lints::performance::inefficient_while_comp::check_inefficient_while_comp;
+use crate::lints::performance::manual_set_insert::ManualSetInsert;
+use crate::lints::performance::manual_set_insert::check_manual_set_insert;
+use crate::lints::redundant_block::RedundantBlock;
+use crate::lints::redundant_block::check_redundant_block;
 use crate::lints::redundant_brackets_in_enum_call::RedundantBracketsInEnumCall;
 use crate::lints::redundant_brackets_in_enum_call::check_redundant_brackets_in_enum_call;
 use crate::lints::redundant_into::RedundantInto;
 use crate::lints::redundant_into::check_redundant_into;
 use crate::lints::redundant_op::RedundantOperation;
 use crate::lints::redundant_op::check_redundant_operation;
+use crate::lints::self_assignment::SelfAssignment;
+use crate::lints::self_assignment::check_self_assignment;
 use crate::lints::single_match::DestructMatch;
 use crate::lints::single_match::EqualityMatch;
 use crate::lints::single_match::check_single_matches;
+use crate::lints::struct_reassign::FieldReassignWithDefault;
+use crate::lints::struct_reassign::check_field_reassign_with_default;
+use crate::lints::suspicious_comparison::SuspiciousOperationGrouping;
+use crate::lints::suspicious_comparison::check_suspicious_operation_groupings;
+use crate::lints::too_many_arguments::TooManyArguments;
+use crate::lints::too_many_arguments::check_too_many_arguments;
 use crate::lints::unit_return_type::UnitReturnType;
 use crate::lints::unit_return_type::check_unit_return_type;
+use crate::lints::unnecessary_array::UnnecessaryArray;
+use crate::lints::unnecessary_array::check_unnecessary_array;
+use crate::lints::unnecessary_cast_chain::UnnecessaryCastChain;
+use crate::lints::unnecessary_cast_chain::check_unnecessary_cast_chain;
+use crate::lints::unsigned_comparison::ImpossibleUnsignedComparison;
+use crate::lints::unsigned_comparison::RedundantUnsignedComparison;
+use crate::lints::unsigned_comparison::check_unsigned_comparison;
+use crate::lints::unused_allow::UnusedAllow;
+use crate::lints::unused_variant::UnusedEnumVariant;
+use crate::lints::unused_variant::check_unused_enum_variant;
 use crate::lints::unwrap_syscall::UnwrapSyscall;
 use crate::lints::unwrap_syscall::check_unwrap_syscall;
+use crate::lints::wildcard_import::WildcardImport;
+use crate::lints::wildcard_import::check_wildcard_import;
+use crate::lints::zero_division::ZeroDividedBy;
+use crate::lints::zero_division::check_zero_divided_by;
 use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
 use cairo_lang_syntax::node::SyntaxNode;
 use itertools::Itertools;
 use salsa::Database;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::LazyLock;
 use std::vec;
 
 /// Type describing a linter group's rule checking function.
-type CheckingFunction =
-    for<'db> fn(&'db dyn Database, &ModuleItemId<'db>, &mut Vec<PluginDiagnostic<'db>>);
+type CheckingFunction = for<'db> fn(
+    &'db dyn Database,
+    &ModuleItemId<'db>,
+    &LinterDiagnosticParams,
+    &mut Vec<PluginDiagnostic<'db>>,
+);
 
 /// Enum representing the kind of a linter. Some lint rules might have the same kind.
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub enum CairoLintKind {
     DestructMatch,
     MatchForEquality,
@@ -154,16 +271,125 @@ pub enum CairoLintKind {
     RedundantInto,
     InefficientUnwrapOr,
     ManualUnwrapOrElse,
+    RedundantElse,
+    ExcessiveNesting,
+    AssignOpPattern,
+    ManualRepeat,
+    ManualPartition,
+    RedundantBlock,
+    ManualMin,
+    ManualMax,
+    ManualSetInsert,
+    ManualContains,
+    ManualAllAny,
+    NeedlessMatch,
+    ManualMapOr,
+    ManualCheckedConversion,
+    UnusedAllow,
+    UselessMatch,
+    ModuloOne,
+    DoubleNegation,
+    EmptyStringComparison,
+    SelfAssignment,
+    ExplicitCounterLoop,
+    ManualSwap,
+    ManualFlatten,
+    ManualDivCeil,
+    NeedlessReturn,
+    FieldReassignWithDefault,
+    UnnecessaryCastChain,
+    ManualOkOrDefault,
+    ManualFilter,
+    TooManyArguments,
+    LargeEnumVariant,
+    MatchBool,
+    BranchesSharingCode,
+    WildcardImport,
+    ImpossibleUnsignedComparison,
+    RedundantUnsignedComparison,
+    ManualIsSomeAnd,
+    UnnecessaryArray,
+    ComparisonChain,
+    ManualArrayFill,
+    LetAndReturn,
+    AbsurdExtremeComparison,
+    ManualSaturating,
+    ManualRetain,
+    ManualBoolLogic,
+    UselessFormat,
+    PrintLiteral,
+    ManualTake,
+    ManualClamp,
+    SuspiciousOperationGrouping,
+    UnusedEnumVariant,
+    ManualIsVariant,
+    NeedlessQuestionMark,
+    ZeroDividedBy,
+    ManualPow,
+    NeedlessSemicolon,
+    LenZero,
+}
+
+/// Category of a lint rule. Allowing a group, either with `#[allow(cairo_lint::<group>)]`
+/// or in the tool metadata, affects all of its lints.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum LintGroup {
+    /// Code that is most likely wrong or useless.
+    Correctness,
+    /// Code that should be written in a more idiomatic way.
+    Style,
+    /// Code that does something simple in a complex way.
+    Complexity,
+    /// Code that can be written to run faster.
+    Performance,
+}
+
+impl LintGroup {
+    /// Name of the group, as used in the tool metadata.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintGroup::Correctness => "correctness",
+            LintGroup::Style => "style",
+            LintGroup::Complexity => "complexity",
+            LintGroup::Performance => "performance",
+        }
+    }
+
+    /// Name of the group, as used in the `allow` attribute.
+    pub fn allowed_name(&self) -> &'static str {
+        match self {
+            LintGroup::Correctness => "cairo_lint::correctness",
+            LintGroup::Style => "cairo_lint::style",
+            LintGroup::Complexity => "cairo_lint::complexity",
+            LintGroup::Performance => "cairo_lint::performance",
+        }
+    }
+
+    /// All the lint groups.
+    pub fn all() -> [LintGroup; 4] {
+        [
+            LintGroup::Correctness,
+            LintGroup::Style,
+            LintGroup::Complexity,
+            LintGroup::Performance,
+        ]
+    }
 }
 
 pub trait Lint: Sync + Send {
     /// A name that is going to be registered by the compiler as an allowed lint to be ignored.
     /// Some multiple lint rules might have the same allowed name. This way all of the will be ignored with only one allow attribute.
     fn allowed_name(&self) -> &'static str;
+    /// A unique code of the lint rule, e.g. `CL0012`, which tools can use to reference the rule
+    /// without relying on its message. Codes are stable across versions: a code is never changed
+    /// nor reused once assigned, and new rules take the next free one.
+    fn code(&self) -> &'static str;
     /// A predefined message that is going to appear in the compiler's diagnostic output. It should be the same as the one in the lint check function.
     fn diagnostic_message(&self) -> &'static str;
     /// The kind of the lint rule. Some lint rules might have the same kind.
     fn kind(&self) -> CairoLintKind;
+    /// The group the lint rule belongs to. All lints of a group can be allowed at once.
+    fn group(&self) -> LintGroup;
 
     /// Checks if the lint rule is enabled.
     /// By default all of the rules are enabled.
@@ -206,6 +432,23 @@ pub trait Lint: Sync + Send {
     }
 }
 
+/// Description of a lint rule, for tools which list the available lints (e.g. documentation or
+/// settings UIs).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LintMetadata {
+    /// The name used to allow the lint and to configure it in the tool metadata.
+    pub name: &'static str,
+    /// The unique code of the lint rule, e.g. `CL0012`.
+    pub code: &'static str,
+    pub kind: CairoLintKind,
+    pub group: LintGroup,
+    /// Whether the lint is enabled when it's not configured in the tool metadata.
+    pub enabled_by_default: bool,
+    pub has_fixer: bool,
+    /// The message describing the fix, `None` if the lint has no fixer.
+    pub fix_message: Option<&'static str>,
+}
+
 /// A group of lint rules.
 ///
 /// We want to group lint rules because some lint rules can share an allowed name for compiler or the checking function.
@@ -214,13 +457,18 @@ pub struct LintRuleGroup {
     lints: Vec<Box<dyn Lint>>,
     /// A Function which will be fired during linter plugin analysis.
     /// This one should emit certain diagnostics in order to later identify (and maybe fix) the linting problem.
-    check_function: CheckingFunction,
+    /// `None` for the lints reported outside of the checking functions.
+    check_function: Option<CheckingFunction>,
 }
 
 /// A global Linter context. It contains all the lint rules.
 struct LintContext {
     lint_groups: Vec<LintRuleGroup>,
     diagnostic_to_lint_kind_map: HashMap<&'static str, CairoLintKind>,
+    /// Unique checking functions of all the lint rule groups, computed once with the context.
+    checking_functions: Vec<CheckingFunction>,
+    /// Names of the lints emitted by each of the `checking_functions`, at the same index.
+    checking_functions_lint_names: Vec<Vec<&'static str>>,
 }
 
 impl LintContext {
@@ -229,11 +477,11 @@ impl LintContext {
         vec![
             LintRuleGroup {
                 lints: vec![Box::new(DestructMatch), Box::new(EqualityMatch)],
-                check_function: check_single_matches,
+                check_function: Some(check_single_matches),
             },
             LintRuleGroup {
                 lints: vec![Box::new(DoubleParens)],
-                check_function: check_double_parens,
+                check_function: Some(check_double_parens),
             },
             LintRuleGroup {
                 lints: vec![
@@ -242,71 +490,71 @@ impl LintContext {
                     Box::new(RedundantComparison),
                     Box::new(ContradictoryComparison),
                 ],
-                check_function: check_double_comparison,
+                check_function: Some(check_double_comparison),
             },
             LintRuleGroup {
                 lints: vec![Box::new(EquatableIfLet)],
-                check_function: check_equatable_if_let,
+                check_function: Some(check_equatable_if_let),
             },
             LintRuleGroup {
                 lints: vec![Box::new(BreakUnit)],
-                check_function: check_break,
+                check_function: Some(check_break),
             },
             LintRuleGroup {
                 lints: vec![Box::new(BoolComparison)],
-                check_function: check_bool_comparison,
+                check_function: Some(check_bool_comparison),
             },
             LintRuleGroup {
                 lints: vec![Box::new(CollapsibleIfElse)],
-                check_function: check_collapsible_if_else,
+                check_function: Some(check_collapsible_if_else),
             },
             LintRuleGroup {
                 lints: vec![Box::new(CollapsibleIf)],
-                check_function: check_collapsible_if,
+                check_function: Some(check_collapsible_if),
             },
             LintRuleGroup {
                 lints: vec![Box::new(DuplicateUnderscoreArgs)],
-                check_function: check_duplicate_underscore_args,
+                check_function: Some(check_duplicate_underscore_args),
             },
             LintRuleGroup {
                 lints: vec![Box::new(LoopMatchPopFront)],
-                check_function: check_loop_match_pop_front,
+                check_function: Some(check_loop_match_pop_front),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualUnwrapOrDefault)],
-                check_function: check_manual_unwrap_or_default,
+                check_function: Some(check_manual_unwrap_or_default),
             },
             LintRuleGroup {
                 lints: vec![Box::new(BitwiseForParity)],
-                check_function: check_bitwise_for_parity,
+                check_function: Some(check_bitwise_for_parity),
             },
             LintRuleGroup {
                 lints: vec![Box::new(LoopForWhile)],
-                check_function: check_loop_for_while,
+                check_function: Some(check_loop_for_while),
             },
             LintRuleGroup {
                 lints: vec![Box::new(PanicInCode)],
-                check_function: check_panic_usage,
+                check_function: Some(check_panic_usage),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ErasingOperation)],
-                check_function: check_erasing_operation,
+                check_function: Some(check_erasing_operation),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualOkOr)],
-                check_function: check_manual_ok_or,
+                check_function: Some(check_manual_ok_or),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualIsEmpty)],
-                check_function: check_manual_is_empty,
+                check_function: Some(check_manual_is_empty),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualOk)],
-                check_function: check_manual_ok,
+                check_function: Some(check_manual_ok),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualErr)],
-                check_function: check_manual_err,
+                check_function: Some(check_manual_err),
             },
             LintRuleGroup {
                 lints: vec![
@@ -315,19 +563,19 @@ impl LintContext {
                     Box::new(ManualIsOk),
                     Box::new(ManualIsErr),
                 ],
-                check_function: check_manual_is,
+                check_function: Some(check_manual_is),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualExpect)],
-                check_function: check_manual_expect,
+                check_function: Some(check_manual_expect),
             },
             LintRuleGroup {
                 lints: vec![Box::new(DuplicateIfCondition)],
-                check_function: check_duplicate_if_condition,
+                check_function: Some(check_duplicate_if_condition),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualExpectErr)],
-                check_function: check_manual_expect_err,
+                check_function: Some(check_manual_expect_err),
             },
             LintRuleGroup {
                 lints: vec![
@@ -336,7 +584,7 @@ impl LintContext {
                     Box::new(IntegerLessEqualPlusOne),
                     Box::new(IntegerLessEqualMinusOne),
                 ],
-                check_function: check_int_op_one,
+                check_function: Some(check_int_op_one),
             },
             LintRuleGroup {
                 lints: vec![
@@ -347,67 +595,288 @@ impl LintContext {
                     Box::new(BitwiseEqualityOperation),
                     Box::new(LogicalEqualityOperation),
                 ],
-                check_function: check_eq_op,
+                check_function: Some(check_eq_op),
             },
             LintRuleGroup {
                 lints: vec![Box::new(InefficientWhileComparison)],
-                check_function: check_inefficient_while_comp,
+                check_function: Some(check_inefficient_while_comp),
             },
             LintRuleGroup {
                 lints: vec![Box::new(RedundantOperation)],
-                check_function: check_redundant_operation,
+                check_function: Some(check_redundant_operation),
             },
             LintRuleGroup {
                 lints: vec![Box::new(EnumVariantNames)],
-                check_function: check_enum_variant_names,
+                check_function: Some(check_enum_variant_names),
             },
             LintRuleGroup {
                 lints: vec![Box::new(CloneOnCopy)],
-                check_function: check_clone_on_copy,
+                check_function: Some(check_clone_on_copy),
             },
             LintRuleGroup {
                 lints: vec![Box::new(EmptyEnumBracketsVariant)],
-                check_function: check_empty_enum_brackets_variant,
+                check_function: Some(check_empty_enum_brackets_variant),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualAssert)],
-                check_function: check_manual_assert,
+                check_function: Some(check_manual_assert),
             },
             LintRuleGroup {
                 lints: vec![Box::new(RedundantBracketsInEnumCall)],
-                check_function: check_redundant_brackets_in_enum_call,
+                check_function: Some(check_redundant_brackets_in_enum_call),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualUnwrapOr)],
-                check_function: check_manual_unwrap_or,
+                check_function: Some(check_manual_unwrap_or),
             },
             LintRuleGroup {
                 lints: vec![Box::new(UnitReturnType)],
-                check_function: check_unit_return_type,
+                check_function: Some(check_unit_return_type),
             },
             LintRuleGroup {
                 lints: vec![Box::new(UnwrapSyscall)],
-                check_function: check_unwrap_syscall,
+                check_function: Some(check_unwrap_syscall),
             },
             LintRuleGroup {
                 lints: vec![Box::new(RedundantInto)],
-                check_function: check_redundant_into,
+                check_function: Some(check_redundant_into),
             },
             LintRuleGroup {
                 lints: vec![Box::new(CollapsibleMatch)],
-                check_function: check_collapsible_match,
+                check_function: Some(check_collapsible_match),
             },
             LintRuleGroup {
                 lints: vec![Box::new(InefficientUnwrapOr)],
-                check_function: check_inefficient_unwrap_or,
+                check_function: Some(check_inefficient_unwrap_or),
             },
             LintRuleGroup {
                 lints: vec![Box::new(ManualUnwrapOrElse)],
-                check_function: check_manual_unwrap_or_else,
+                check_function: Some(check_manual_unwrap_or_else),
             },
             LintRuleGroup {
                 lints: vec![Box::new(AssertOnConst)],
-                check_function: check_assert_on_const,
+                check_function: Some(check_assert_on_const),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(RedundantElse)],
+                check_function: Some(check_redundant_else),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ExcessiveNesting)],
+                check_function: Some(check_excessive_nesting),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(AssignOpPattern)],
+                check_function: Some(check_assign_op_pattern),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualRepeat)],
+                check_function: Some(check_manual_repeat),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualPartition)],
+                check_function: Some(check_manual_partition),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(RedundantBlock)],
+                check_function: Some(check_redundant_block),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualMin), Box::new(ManualMax)],
+                check_function: Some(check_manual_min_max),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualSetInsert)],
+                check_function: Some(check_manual_set_insert),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualContains)],
+                check_function: Some(check_manual_contains),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualAll), Box::new(ManualAny)],
+                check_function: Some(check_manual_all_any),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(NeedlessMatch)],
+                check_function: Some(check_needless_match),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualMapOr)],
+                check_function: Some(check_manual_map_or),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualCheckedConversion)],
+                check_function: Some(check_manual_checked_conversion),
+            },
+            // Unused `allow` attributes are found only after all the other lints have been
+            // checked, in `linter_diagnostics`.
+            LintRuleGroup {
+                lints: vec![Box::new(UnusedAllow)],
+                check_function: None,
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(UselessMatch)],
+                check_function: Some(check_useless_match),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ModuloOne)],
+                check_function: Some(check_modulo_one),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(DoubleNegation)],
+                check_function: Some(check_double_negation),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(EmptyStringComparison)],
+                check_function: Some(check_empty_string_comparison),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(SelfAssignment)],
+                check_function: Some(check_self_assignment),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ExplicitCounterLoop)],
+                check_function: Some(check_explicit_counter_loop),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualSwap)],
+                check_function: Some(check_manual_swap),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualFlatten)],
+                check_function: Some(check_manual_flatten),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualDivCeil)],
+                check_function: Some(check_manual_div_ceil),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(NeedlessReturn)],
+                check_function: Some(check_needless_return),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(FieldReassignWithDefault)],
+                check_function: Some(check_field_reassign_with_default),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(UnnecessaryCastChain)],
+                check_function: Some(check_unnecessary_cast_chain),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualOkOrDefault)],
+                check_function: Some(check_manual_ok_or_default),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualFilter)],
+                check_function: Some(check_manual_filter),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(TooManyArguments)],
+                check_function: Some(check_too_many_arguments),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(LargeEnumVariant)],
+                check_function: Some(check_large_enum_variant),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(MatchBool)],
+                check_function: Some(check_match_bool),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(BranchesSharingCode)],
+                check_function: Some(check_branches_sharing_code),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(WildcardImport)],
+                check_function: Some(check_wildcard_import),
+            },
+            LintRuleGroup {
+                lints: vec![
+                    Box::new(ImpossibleUnsignedComparison),
+                    Box::new(RedundantUnsignedComparison),
+                ],
+                check_function: Some(check_unsigned_comparison),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualIsSomeAnd)],
+                check_function: Some(check_manual_is_some_and),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(UnnecessaryArray)],
+                check_function: Some(check_unnecessary_array),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ComparisonChain)],
+                check_function: Some(check_comparison_chain),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualArrayFill)],
+                check_function: Some(check_manual_array_fill),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(LetAndReturn)],
+                check_function: Some(check_let_and_return),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(AbsurdExtremeComparison)],
+                check_function: Some(check_extreme_comparisons),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualSaturating)],
+                check_function: Some(check_manual_saturating),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualRetain)],
+                check_function: Some(check_manual_retain),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualBoolLogic)],
+                check_function: Some(check_manual_bool_logic),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(UselessFormat), Box::new(FormatLiteralArgument)],
+                check_function: Some(check_format_args),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualTake)],
+                check_function: Some(check_manual_take),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualClamp)],
+                check_function: Some(check_manual_clamp),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(SuspiciousOperationGrouping)],
+                check_function: Some(check_suspicious_operation_groupings),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(UnusedEnumVariant)],
+                check_function: Some(check_unused_enum_variant),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualIsVariant)],
+                check_function: Some(check_manual_is_variant),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(NeedlessQuestionMark)],
+                check_function: Some(check_needless_question_mark),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ZeroDividedBy)],
+                check_function: Some(check_zero_divided_by),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(ManualPow)],
+                check_function: Some(check_manual_pow),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(NeedlessSemicolon)],
+                check_function: Some(check_needless_semicolon),
+            },
+            LintRuleGroup {
+                lints: vec![Box::new(LenZero)],
+                check_function: Some(check_len_zero),
             },
         ]
     }
@@ -423,12 +892,39 @@ impl LintContext {
         self
     }
 
+    fn precompute_checking_functions(mut self) -> Self {
+        self.checking_functions = self
+            .lint_groups
+            .iter()
+            .filter_map(|rule_group| rule_group.check_function)
+            .unique()
+            .collect();
+        let mut lint_names: HashMap<CheckingFunction, Vec<&'static str>> = HashMap::new();
+        for rule_group in self.lint_groups.iter() {
+            if let Some(checking_function) = rule_group.check_function {
+                lint_names
+                    .entry(checking_function)
+                    .or_default()
+                    .extend(rule_group.lints.iter().map(|rule| rule.allowed_name()));
+            }
+        }
+        self.checking_functions_lint_names = self
+            .checking_functions
+            .iter()
+            .map(|checking_function| lint_names.remove(checking_function).unwrap_or_default())
+            .collect();
+        self
+    }
+
     fn new() -> Self {
         let new = Self {
             lint_groups: Self::get_all_lints(),
             diagnostic_to_lint_kind_map: Default::default(),
+            checking_functions: Default::default(),
+            checking_functions_lint_names: Default::default(),
         };
         new.precompute_diagnostic_to_lint_kind_map()
+            .precompute_checking_functions()
     }
 
     fn get_lint_type_from_diagnostic_message(&self, message: &str) -> CairoLintKind {
@@ -439,6 +935,16 @@ impl LintContext {
     }
 }
 
+/// Pairs of lints known to report on the same constructs. Enabling both lints of a pair usually
+/// results in duplicated diagnostics (and fixes) for a single piece of code.
+const CONFLICTING_LINTS: [(&str, &str); 5] = [
+    ("destruct_match", "collapsible_match"),
+    ("manual_unwrap_or", "manual_unwrap_or_default"),
+    ("manual_unwrap_or", "manual_unwrap_or_else"),
+    ("collapsible_if_else", "redundant_else"),
+    ("match_bool", "equality_match"),
+];
+
 /// A singleton instance of the `LintContext`. It should be the only instance of the `LintContext`.
 static LINT_CONTEXT: LazyLock<LintContext> = LazyLock::new(LintContext::new);
 
@@ -473,12 +979,29 @@ pub fn get_unique_allowed_names() -> Vec<&'static str> {
 }
 
 /// Get all the checking functions that exist for each `LintRuleGroup`.
-pub fn get_all_checking_functions() -> impl Iterator<Item = &'static CheckingFunction> {
+/// The list is built only once, together with the lint context.
+pub fn get_all_checking_functions() -> &'static [CheckingFunction] {
+    &LINT_CONTEXT.checking_functions
+}
+
+/// Get the unique checking functions emitting at least one of the lints with the given names, or
+/// all of them if no names are given. The precomputed list is only filtered, so it's cheap to do
+/// for every linted item.
+pub fn get_checking_functions_for_lints(
+    lint_names: Option<&BTreeSet<String>>,
+) -> impl Iterator<Item = CheckingFunction> + '_ {
     LINT_CONTEXT
-        .lint_groups
+        .checking_functions
         .iter()
-        .unique_by(|rule| rule.check_function)
-        .map(|rule_group| &rule_group.check_function)
+        .zip(&LINT_CONTEXT.checking_functions_lint_names)
+        .filter(move |(_, checking_function_lint_names)| {
+            lint_names.is_none_or(|lint_names| {
+                checking_function_lint_names
+                    .iter()
+                    .any(|name| lint_names.contains(*name))
+            })
+        })
+        .map(|(checking_function, _)| *checking_function)
 }
 
 /// Get lint name based on the diagnostic message.
@@ -491,6 +1014,28 @@ pub fn get_name_for_diagnostic_message(message: &str) -> Option<&'static str> {
         .map(|rule| rule.allowed_name())
 }
 
+/// Get the lint group based on the diagnostic message.
+pub fn get_group_for_diagnostic_message(message: &str) -> Option<LintGroup> {
+    LINT_CONTEXT
+        .lint_groups
+        .iter()
+        .flat_map(|group| group.lints.iter())
+        .find(|rule| rule.diagnostic_message() == message)
+        .map(|rule| rule.group())
+}
+
+/// Get the unique names of all the lints belonging to the given group.
+pub fn get_lints_in_group(group: LintGroup) -> Vec<&'static str> {
+    LINT_CONTEXT
+        .lint_groups
+        .iter()
+        .flat_map(|rule_group| rule_group.lints.iter())
+        .filter(|rule| rule.group() == group)
+        .map(|rule| rule.allowed_name())
+        .unique()
+        .collect()
+}
+
 /// Checks if the lint related to the diagnostic message is enabled by default.
 pub fn is_lint_enabled_by_default(message: &str) -> Option<bool> {
     LINT_CONTEXT
@@ -501,6 +1046,35 @@ pub fn is_lint_enabled_by_default(message: &str) -> Option<bool> {
         .map(|rule| rule.is_enabled())
 }
 
+/// Get the codes of all the lint rules, in the order the rules are registered.
+pub fn get_all_lint_codes() -> Vec<&'static str> {
+    LINT_CONTEXT
+        .lint_groups
+        .iter()
+        .flat_map(|rule_group| rule_group.lints.iter().map(|rule| rule.code()))
+        .collect()
+}
+
+#[allow(clippy::borrowed_box)]
+/// Finds the lint by its code, e.g. `CL0012`.
+pub fn get_lint_by_code(code: &str) -> Option<&Box<dyn Lint>> {
+    LINT_CONTEXT
+        .lint_groups
+        .iter()
+        .flat_map(|group| group.lints.iter())
+        .find(|rule| rule.code() == code)
+}
+
+/// Get lint code based on the diagnostic message.
+pub fn code_for_diagnostic_message(message: &str) -> Option<&'static str> {
+    LINT_CONTEXT
+        .lint_groups
+        .iter()
+        .flat_map(|group| group.lints.iter())
+        .find(|rule| rule.diagnostic_message() == message)
+        .map(|rule| rule.code())
+}
+
 #[allow(clippy::borrowed_box)]
 /// Finds the lint by it's struct's name.
 /// By struct name we mean the last part of the path of the lint rule.
@@ -542,3 +1116,38 @@ pub fn get_all_fix_messages() -> Vec<Option<&'static str>> {
         })
         .collect()
 }
+
+/// Get the metadata of all the lint rules, in the order the rules are registered.
+pub fn all_lint_metadata() -> Vec<LintMetadata> {
+    LINT_CONTEXT
+        .lint_groups
+        .iter()
+        .flat_map(|rule_group| rule_group.lints.iter())
+        .map(|rule| LintMetadata {
+            name: rule.allowed_name(),
+            code: rule.code(),
+            kind: rule.kind(),
+            group: rule.group(),
+            enabled_by_default: rule.is_enabled(),
+            has_fixer: rule.has_fixer(),
+            fix_message: rule.has_fixer().then(|| rule.fix_message()).flatten(),
+        })
+        .collect()
+}
+
+/// Get the names of the lints that conflict (overlap on the same constructs) with the given lint.
+/// The relation is symmetric: if `a` conflicts with `b`, then `b` conflicts with `a`.
+pub fn conflicting_lints(name: &str) -> Vec<&'static str> {
+    CONFLICTING_LINTS
+        .iter()
+        .filter_map(|&(first, second)| {
+            if first == name {
+                Some(second)
+            } else if second == name {
+                Some(first)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -7,7 +7,6 @@ use cairo_lang_defs::{
     },
 };
 use cairo_lang_filesystem::ids::CrateId;
-use cairo_lang_semantic::helper::ModuleHelper;
 use cairo_lang_semantic::items::imp::ImplSemantic;
 use cairo_lang_semantic::items::trt::TraitSemantic;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
@@ -19,28 +18,68 @@ pub const PANIC_WITH_BYTE_ARRAY_PATH: &str = "core::panics::panic_with_byte_arra
 pub const T_COPY_CLONE_PATH: &str = "core::clone::TCopyClone";
 pub const PARTIAL_ORD_LE_PATH: &str = "core::traits::PartialOrd::le";
 pub const PARTIAL_ORD_GE_PATH: &str = "core::traits::PartialOrd::ge";
+pub const PARTIAL_ORD_LT_PATH: &str = "core::traits::PartialOrd::lt";
+pub const PARTIAL_ORD_GT_PATH: &str = "core::traits::PartialOrd::gt";
+pub const PARTIAL_ORD_CLAMP_PATH: &str = "core::traits::PartialOrd::clamp";
 pub const ADD_TRAIT_FUNCTION_PATH: &str = "core::traits::Add::add";
 pub const SUB_TRAIT_FUNCTION_PATH: &str = "core::traits::Sub::sub";
+pub const MUL_TRAIT_FUNCTION_PATH: &str = "core::traits::Mul::mul";
+pub const DIV_TRAIT_FUNCTION_PATH: &str = "core::traits::Div::div";
+pub const REM_TRAIT_FUNCTION_PATH: &str = "core::traits::Rem::rem";
+pub const POW_TRAIT_FUNCTION_PATH: &str = "core::num::traits::ops::pow::Pow::pow";
 pub const INTEGER_MODULE_PATH: &str = "core::integer";
 pub const INTO_TRAIT_FUNCTION_PATH: &str = "core::traits::Into::into";
 pub const TRY_INTO_TRAIT_FUNCTION_PATH: &str = "core::traits::TryInto::try_into";
 pub const OPTION_TYPE_PATH: &str = "core::option::Option";
+pub const OPTION_TRAIT_PATH: &str = "core::option::OptionTrait";
+pub const OPTION_TAKE_TRAIT_FUNCTION_PATH: &str = "core::option::OptionTrait::take";
+pub const RESULT_TRAIT_PATH: &str = "core::result::ResultTrait";
 
-static CORELIB_ITEM_PATHS: [&str; 12] = [
+const CORELIB_ITEM_PATHS: [&str; 22] = [
     BOOL_PARTIAL_EQ_PATH,
     PANIC_PATH,
     PANIC_WITH_BYTE_ARRAY_PATH,
     T_COPY_CLONE_PATH,
     PARTIAL_ORD_LE_PATH,
     PARTIAL_ORD_GE_PATH,
+    PARTIAL_ORD_LT_PATH,
+    PARTIAL_ORD_GT_PATH,
+    PARTIAL_ORD_CLAMP_PATH,
     ADD_TRAIT_FUNCTION_PATH,
     SUB_TRAIT_FUNCTION_PATH,
+    MUL_TRAIT_FUNCTION_PATH,
+    DIV_TRAIT_FUNCTION_PATH,
+    REM_TRAIT_FUNCTION_PATH,
+    POW_TRAIT_FUNCTION_PATH,
     INTEGER_MODULE_PATH,
     OPTION_TYPE_PATH,
     INTO_TRAIT_FUNCTION_PATH,
     TRY_INTO_TRAIT_FUNCTION_PATH,
+    OPTION_TRAIT_PATH,
+    OPTION_TAKE_TRAIT_FUNCTION_PATH,
+    RESULT_TRAIT_PATH,
 ];
 
+/// Generates a getter on [`CorelibContext`] for each of the given corelib items.
+/// A getter returns `None` if the item is not defined in the corelib or is of an unexpected kind.
+macro_rules! corelib_getters {
+    ($($name:ident: $path:ident => $ty:ident, $lookup_variant:ident($item_kind:ident::$item_variant:ident);)*) => {
+        impl<'db> CorelibContext<'db> {
+            $(
+                pub fn $name(&self) -> Option<$ty<'db>> {
+                    match self.get_item($path)? {
+                        LookupItemId::$lookup_variant($item_kind::$item_variant(id)) => Some(id),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+
+        /// Paths of the corelib items which have a getter on [`CorelibContext`].
+        const CORELIB_GETTER_PATHS: &[&str] = &[$($path),*];
+    };
+}
+
 #[derive(PartialEq, Eq, Hash, Debug, Clone, SalsaValue)]
 pub struct CorelibContext<'db> {
     corelib_items: OrderedHashMap<String, Option<LookupItemId<'db>>>,
@@ -67,161 +106,67 @@ impl<'db> CorelibContext<'db> {
         }
     }
 
-    // TODO (https://github.com/software-mansion/cairo-lint/issues/398): Write a macro for these getters to avoid boilerplate.
-    pub fn get_bool_partial_eq_impl_id(&self) -> ImplDefId<'db> {
-        let item = self
-            .corelib_items
-            .get(BOOL_PARTIAL_EQ_PATH)
-            .expect("Expected BoolPartialEq to be present in corelib items")
-            .expect("Expected BoolPartialEq to be defined in the corelib");
-        match item {
-            LookupItemId::ModuleItem(ModuleItemId::Impl(id)) => id,
-            _ => unreachable!("Expected BoolPartialEq to be an ImplDefId"),
-        }
-    }
-
-    pub fn get_panic_function_id(&self) -> ExternFunctionId<'db> {
-        let item = self
-            .corelib_items
-            .get(PANIC_PATH)
-            .expect("Expected panic to be present in corelib items")
-            .expect("Expected panic to be defined in the corelib");
-        match item {
-            LookupItemId::ModuleItem(ModuleItemId::ExternFunction(id)) => id,
-            _ => unreachable!("Expected panic to be a ExternFunction"),
-        }
-    }
-
-    pub fn get_panic_with_byte_array_function_id(&self) -> FreeFunctionId<'db> {
-        let item = self
-            .corelib_items
-            .get(PANIC_WITH_BYTE_ARRAY_PATH)
-            .expect("Expected panic_with_byte_array to be present in corelib items")
-            .expect("Expected panic_with_byte_array to be defined in the corelib");
-        match item {
-            LookupItemId::ModuleItem(ModuleItemId::FreeFunction(id)) => id,
-            _ => unreachable!("Expected panic_with_byte_array to be a FreeFunction"),
-        }
-    }
-
-    pub fn get_t_copy_clone_impl_id(&self) -> ImplDefId<'db> {
-        let item = self
-            .corelib_items
-            .get(T_COPY_CLONE_PATH)
-            .expect("Expected TCopyClone to be present in corelib items")
-            .expect("Expected TCopyClone to be defined in the corelib");
-        match item {
-            LookupItemId::ModuleItem(ModuleItemId::Impl(id)) => id,
-            _ => unreachable!("Expected TCopyClone to be an ImplDefId"),
-        }
-    }
-
-    pub fn get_partial_ord_le_trait_function_id(&self) -> TraitFunctionId<'db> {
-        let item = self
-            .corelib_items
-            .get(PARTIAL_ORD_LE_PATH)
-            .expect("Expected PartialOrd::le to be present in corelib items")
-            .expect("Expected PartialOrd::le to be defined in the corelib");
-        match item {
-            LookupItemId::TraitItem(TraitItemId::Function(id)) => id,
-            _ => unreachable!("Expected PartialOrd::le to be a TraitFunctionId"),
-        }
-    }
-
-    pub fn get_partial_ord_ge_trait_function_id(&self) -> TraitFunctionId<'db> {
-        let item = self
-            .corelib_items
-            .get(PARTIAL_ORD_GE_PATH)
-            .expect("Expected PartialOrd::ge to be present in corelib items")
-            .expect("Expected PartialOrd::ge to be defined in the corelib");
-        match item {
-            LookupItemId::TraitItem(TraitItemId::Function(id)) => id,
-            _ => unreachable!("Expected PartialOrd::ge to be a TraitFunctionId"),
-        }
-    }
-
-    pub fn get_add_trait_function_id(&self) -> TraitFunctionId<'db> {
-        let item = self
-            .corelib_items
-            .get(ADD_TRAIT_FUNCTION_PATH)
-            .expect("Expected Add::add to be present in corelib items")
-            .expect("Expected Add::add to be defined in the corelib");
-        match item {
-            LookupItemId::TraitItem(TraitItemId::Function(id)) => id,
-            _ => unreachable!("Expected Add::add to be a TraitFunctionId"),
-        }
+    /// Returns the item with the given path, if it's defined in the corelib.
+    /// The corelib in use might be non-standard or outdated, so the lints depending on its items
+    /// should not fire if an item is missing, rather than panic.
+    fn get_item(&self, path: &str) -> Option<LookupItemId<'db>> {
+        self.corelib_items.get(path).copied().flatten()
     }
+}
 
-    pub fn get_sub_trait_function_id(&self) -> TraitFunctionId<'db> {
-        let item = self
-            .corelib_items
-            .get(SUB_TRAIT_FUNCTION_PATH)
-            .expect("Expected Sub::sub to be present in corelib items")
-            .expect("Expected Sub::sub to be defined in the corelib");
-        match item {
-            LookupItemId::TraitItem(TraitItemId::Function(id)) => id,
-            _ => unreachable!("Expected Sub::sub to be a TraitFunctionId"),
-        }
-    }
+corelib_getters! {
+    get_bool_partial_eq_impl_id: BOOL_PARTIAL_EQ_PATH => ImplDefId, ModuleItem(ModuleItemId::Impl);
+    get_panic_function_id: PANIC_PATH => ExternFunctionId, ModuleItem(ModuleItemId::ExternFunction);
+    get_panic_with_byte_array_function_id: PANIC_WITH_BYTE_ARRAY_PATH => FreeFunctionId, ModuleItem(ModuleItemId::FreeFunction);
+    get_t_copy_clone_impl_id: T_COPY_CLONE_PATH => ImplDefId, ModuleItem(ModuleItemId::Impl);
+    get_partial_ord_le_trait_function_id: PARTIAL_ORD_LE_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_partial_ord_ge_trait_function_id: PARTIAL_ORD_GE_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_partial_ord_lt_trait_function_id: PARTIAL_ORD_LT_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_partial_ord_gt_trait_function_id: PARTIAL_ORD_GT_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_partial_ord_clamp_trait_function_id: PARTIAL_ORD_CLAMP_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_add_trait_function_id: ADD_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_sub_trait_function_id: SUB_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_mul_trait_function_id: MUL_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_div_trait_function_id: DIV_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_rem_trait_function_id: REM_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_pow_trait_function_id: POW_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_integer_module_id: INTEGER_MODULE_PATH => SubmoduleId, ModuleItem(ModuleItemId::Submodule);
+    get_into_trait_function_id: INTO_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_try_into_trait_function_id: TRY_INTO_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_option_enum_id: OPTION_TYPE_PATH => EnumId, ModuleItem(ModuleItemId::Enum);
+    get_option_trait_id: OPTION_TRAIT_PATH => TraitId, ModuleItem(ModuleItemId::Trait);
+    get_option_take_trait_function_id: OPTION_TAKE_TRAIT_FUNCTION_PATH => TraitFunctionId, TraitItem(TraitItemId::Function);
+    get_result_trait_id: RESULT_TRAIT_PATH => TraitId, ModuleItem(ModuleItemId::Trait);
+}
 
-    pub fn get_integer_module_id(&self) -> SubmoduleId<'db> {
-        let item = self
-            .corelib_items
-            .get(INTEGER_MODULE_PATH)
-            .expect("Expected integer module to be present in corelib items")
-            .expect("Expected integer module to be defined in the corelib");
-        match item {
-            LookupItemId::ModuleItem(ModuleItemId::Submodule(id)) => id,
-            _ => unreachable!("Expected integer module to be a Submodule"),
-        }
-    }
-
-    pub fn get_into_trait_function_id(&self) -> TraitFunctionId<'db> {
-        let item = self
-            .corelib_items
-            .get(INTO_TRAIT_FUNCTION_PATH)
-            .expect("Expected Into::into to be present in corelib items")
-            .expect("Expected Into::into to be defined in the corelib");
-        match item {
-            LookupItemId::TraitItem(TraitItemId::Function(id)) => id,
-            _ => unreachable!("Expected Into::into to be a TraitFunctionId"),
-        }
+// Every corelib item looked up by the context must be accessible with a getter.
+const _: () = {
+    let mut i = 0;
+    while i < CORELIB_ITEM_PATHS.len() {
+        assert!(
+            contains_path(CORELIB_GETTER_PATHS, CORELIB_ITEM_PATHS[i]),
+            "Every corelib item path must have a getter in `corelib_getters!`"
+        );
+        i += 1;
     }
+};
 
-    pub fn get_try_into_trait_function_id(&self) -> TraitFunctionId<'db> {
-        let item = self
-            .corelib_items
-            .get(TRY_INTO_TRAIT_FUNCTION_PATH)
-            .expect("Expected TryInto::try_into to be present in corelib items")
-            .expect("Expected TryInto::try_into to be defined in the corelib");
-        match item {
-            LookupItemId::TraitItem(TraitItemId::Function(id)) => id,
-            _ => unreachable!("Expected TryInto::try_into to be a TraitFunctionId"),
-        }
-    }
-    pub fn get_option_enum_id(&self) -> EnumId<'db> {
-        let item = self
-            .corelib_items
-            .get(OPTION_TYPE_PATH)
-            .expect("Expected Option to be present in corelib items")
-            .expect("Expected Option to be defined in the corelib");
-        match item {
-            LookupItemId::ModuleItem(ModuleItemId::Enum(id)) => id,
-            _ => unreachable!("Expected Option to be a EnumId"),
+const fn contains_path(paths: &[&str], path: &str) -> bool {
+    let mut i = 0;
+    while i < paths.len() {
+        if paths[i].len() == path.len() {
+            let (lhs, rhs) = (paths[i].as_bytes(), path.as_bytes());
+            let mut j = 0;
+            while j < lhs.len() && lhs[j] == rhs[j] {
+                j += 1;
+            }
+            if j == lhs.len() {
+                return true;
+            }
         }
+        i += 1;
     }
-
-    pub fn get_option_trait(&self, db: &'db dyn Database) -> TraitId<'db> {
-        ModuleHelper::core(db)
-            .submodule("option")
-            .trait_id("OptionTrait")
-    }
-
-    pub fn get_result_trait(&self, db: &'db dyn Database) -> TraitId<'db> {
-        ModuleHelper::core(db)
-            .submodule("result")
-            .trait_id("ResultTrait")
-    }
+    false
 }
 
 fn find_item_with_path<'db>(
@@ -0,0 +1,113 @@
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_semantic::SemanticDiagnostic;
+use itertools::Itertools;
+use salsa::Database;
+use serde::{Deserialize, Serialize};
+
+use crate::context::get_name_for_diagnostic_message;
+
+/// Maximum number of lines a known diagnostic can move and still be matched with its baseline entry.
+pub const BASELINE_LINE_TOLERANCE: usize = 10;
+
+/// A set of known linter diagnostics. Diagnostics matching an entry of the baseline are considered
+/// already reported, which allows adopting new lints without fixing all the existing code at once.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// A single known diagnostic, identified by the lint name and a location fingerprint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// The name of the lint, as used in `#[allow(...)]`.
+    pub lint: String,
+    pub file: String,
+    /// 1-based line of the diagnostic start, used only as a hint when matching diagnostics.
+    pub line: usize,
+    /// The code pointed by the diagnostic with normalized whitespace.
+    pub fingerprint: String,
+}
+
+impl BaselineEntry {
+    /// Creates the entry for a diagnostic. Returns `None` for non-linter diagnostics,
+    /// as these can't be suppressed.
+    pub fn new(diagnostic: &SemanticDiagnostic, db: &dyn Database) -> Option<Self> {
+        let lint = get_name_for_diagnostic_message(&diagnostic.format(db))?;
+        let location = diagnostic.location(db);
+        let line = location
+            .span
+            .position_in_file(db, location.file_id)
+            .map_or(0, |position| position.start.line + 1);
+        let fingerprint = diagnostic
+            .stable_location
+            .syntax_node(db)
+            .get_text_without_trivia(db)
+            .long(db)
+            .split_whitespace()
+            .join(" ");
+
+        Some(Self {
+            lint: lint.to_string(),
+            file: location.file_id.full_path(db),
+            line,
+            fingerprint,
+        })
+    }
+
+    fn matches(&self, other: &BaselineEntry) -> bool {
+        self.lint == other.lint
+            && self.file == other.file
+            && self.fingerprint == other.fingerprint
+            && self.line.abs_diff(other.line) <= BASELINE_LINE_TOLERANCE
+    }
+}
+
+/// Creates a baseline containing all the linter diagnostics.
+pub fn generate_baseline(diagnostics: &[SemanticDiagnostic], db: &dyn Database) -> Baseline {
+    Baseline {
+        entries: diagnostics
+            .iter()
+            .filter_map(|diagnostic| BaselineEntry::new(diagnostic, db))
+            .collect(),
+    }
+}
+
+/// Removes the diagnostics already present in the baseline, leaving only the new ones.
+///
+/// Each baseline entry suppresses at most one diagnostic. If several diagnostics match the same
+/// entry, the one closest to the recorded line is suppressed. Non-linter diagnostics are never
+/// filtered out.
+pub fn filter_baseline_diagnostics<'db>(
+    diagnostics: Vec<SemanticDiagnostic<'db>>,
+    baseline: &Baseline,
+    db: &'db dyn Database,
+) -> Vec<SemanticDiagnostic<'db>> {
+    let entries = diagnostics
+        .iter()
+        .map(|diagnostic| BaselineEntry::new(diagnostic, db))
+        .collect_vec();
+    let mut suppressed = vec![false; diagnostics.len()];
+
+    for known in &baseline.entries {
+        let closest = entries
+            .iter()
+            .enumerate()
+            .filter(|(index, entry)| {
+                !suppressed[*index] && entry.as_ref().is_some_and(|entry| known.matches(entry))
+            })
+            .min_by_key(|(_, entry)| {
+                entry
+                    .as_ref()
+                    .map_or(usize::MAX, |entry| known.line.abs_diff(entry.line))
+            });
+        if let Some((index, _)) = closest {
+            suppressed[index] = true;
+        }
+    }
+
+    diagnostics
+        .into_iter()
+        .zip(suppressed)
+        .filter_map(|(diagnostic, suppressed)| (!suppressed).then_some(diagnostic))
+        .collect()
+}
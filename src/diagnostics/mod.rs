@@ -0,0 +1,156 @@
+pub mod baseline;
+pub mod sarif;
+
+use std::collections::HashMap;
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_diagnostics::format_diagnostics as cairo_format_diagnostics;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_semantic::SemanticDiagnostic;
+use salsa::Database;
+use serde::Serialize;
+
+use crate::context::{
+    CairoLintKind, code_for_diagnostic_message, get_fix_for_diagnostic_message,
+    get_lint_type_from_diagnostic_message, get_name_for_diagnostic_message,
+};
+
+pub fn format_diagnostic(diagnostic: &SemanticDiagnostic, db: &dyn Database) -> String {
+    cairo_format_diagnostics(db, &diagnostic.format(db), diagnostic.location(db))
+}
+
+/// Same as [`format_diagnostic`], but the header also names the lint which emitted the diagnostic
+/// along with its code, e.g. `[double_parens, CL0003]`, so it's clear what to `#[allow(...)]`.
+/// Diagnostics which don't come from cairo-lint are formatted as in [`format_diagnostic`].
+pub fn format_diagnostic_with_name(diagnostic: &SemanticDiagnostic, db: &dyn Database) -> String {
+    let message = diagnostic.format(db);
+    let header = match (
+        get_name_for_diagnostic_message(&message),
+        code_for_diagnostic_message(&message),
+    ) {
+        (Some(name), Some(code)) => format!("{message} [{name}, {code}]"),
+        _ => message,
+    };
+    cairo_format_diagnostics(db, &header, diagnostic.location(db))
+}
+
+/// Returns the name of the lint which emitted the diagnostic, as used in `#[allow(...)]`.
+/// `None` if the diagnostic doesn't come from cairo-lint.
+pub fn lint_name_of(diagnostic: &PluginDiagnostic) -> Option<&'static str> {
+    get_name_for_diagnostic_message(&diagnostic.message)
+}
+
+/// Returns the kind of the lint which emitted the diagnostic.
+/// `CairoLintKind::Unknown` if the diagnostic doesn't come from cairo-lint.
+pub fn kind_of(diagnostic: &PluginDiagnostic) -> CairoLintKind {
+    get_lint_type_from_diagnostic_message(&diagnostic.message)
+}
+
+/// Groups the diagnostics by the file they are reported in. The diagnostics of each file are sorted
+/// by their start offset and then by the name of the lint which emitted them, which is the order
+/// they are rendered in. Diagnostics which don't come from cairo-lint go first among the
+/// diagnostics starting at the same offset.
+pub fn sort_and_group<'db>(
+    diagnostics: Vec<SemanticDiagnostic<'db>>,
+    db: &'db dyn Database,
+) -> HashMap<FileId<'db>, Vec<SemanticDiagnostic<'db>>> {
+    let mut grouped: HashMap<FileId<'db>, Vec<SemanticDiagnostic<'db>>> = HashMap::new();
+    for diagnostic in diagnostics {
+        grouped
+            .entry(diagnostic.location(db).file_id)
+            .or_default()
+            .push(diagnostic);
+    }
+    for file_diagnostics in grouped.values_mut() {
+        file_diagnostics.sort_by_cached_key(|diagnostic| {
+            (
+                diagnostic.location(db).span.start,
+                get_name_for_diagnostic_message(&diagnostic.format(db)),
+            )
+        });
+    }
+    grouped
+}
+
+/// Machine-readable form of a single diagnostic, used for CI integrations.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonDiagnostic {
+    /// The name of the lint, as used in `#[allow(...)]`. `None` for non-linter diagnostics.
+    pub lint: Option<&'static str>,
+    pub kind: CairoLintKind,
+    pub file: String,
+    pub start: JsonPosition,
+    pub end: JsonPosition,
+    pub message: String,
+    pub fix_available: bool,
+}
+
+/// 1-based line and column of a diagnostic boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct JsonPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl JsonDiagnostic {
+    pub fn new(diagnostic: &SemanticDiagnostic, db: &dyn Database) -> Self {
+        let message = diagnostic.format(db);
+        let location = diagnostic.location(db);
+        let (start, end) = location
+            .span
+            .position_in_file(db, location.file_id)
+            .map(|position| {
+                (
+                    JsonPosition {
+                        line: position.start.line + 1,
+                        column: position.start.col + 1,
+                    },
+                    JsonPosition {
+                        line: position.end.line + 1,
+                        column: position.end.col + 1,
+                    },
+                )
+            })
+            .unwrap_or((
+                JsonPosition { line: 0, column: 0 },
+                JsonPosition { line: 0, column: 0 },
+            ));
+        let fix_available = get_fix_for_diagnostic_message(
+            db,
+            diagnostic.stable_location.syntax_node(db),
+            &message,
+        )
+        .is_some();
+
+        Self {
+            lint: get_name_for_diagnostic_message(&message),
+            kind: get_lint_type_from_diagnostic_message(&message),
+            file: location.file_id.full_path(db),
+            start,
+            end,
+            message,
+            fix_available,
+        }
+    }
+}
+
+/// Serializes the diagnostic into a JSON object. The text form produced by [`format_diagnostic`]
+/// is left untouched.
+pub fn diagnostic_to_json(diagnostic: &SemanticDiagnostic, db: &dyn Database) -> serde_json::Value {
+    serde_json::to_value(JsonDiagnostic::new(diagnostic, db))
+        .expect("Diagnostic should always be serializable")
+}
+
+/// Serializes all the diagnostics into a JSON array.
+pub fn diagnostics_to_json(
+    diagnostics: &[SemanticDiagnostic],
+    db: &dyn Database,
+) -> serde_json::Value {
+    serde_json::Value::Array(
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic_to_json(diagnostic, db))
+            .collect(),
+    )
+}
@@ -0,0 +1,226 @@
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::SemanticDiagnostic;
+use salsa::Database;
+use serde::Serialize;
+
+use crate::context::get_name_for_diagnostic_message;
+use crate::fix_for_diagnostic;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "cairo-lint";
+const TOOL_INFORMATION_URI: &str = "https://github.com/software-mansion/cairo-lint";
+
+/// The top level object of a SARIF 2.1.0 report.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub version: &'static str,
+    pub runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub information_uri: &'static str,
+    pub rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: &'static str,
+    pub short_description: SarifMessage,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: &'static str,
+    pub rule_index: usize,
+    pub level: &'static str,
+    pub message: SarifMessage,
+    pub locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    pub region: SarifRegion,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+}
+
+/// A region of a file with 1-based lines and columns. The end column is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifFix {
+    pub description: SarifMessage,
+    pub artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactChange {
+    pub artifact_location: SarifArtifactLocation,
+    pub replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifReplacement {
+    pub deleted_region: SarifRegion,
+    pub inserted_content: SarifArtifactContent,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifArtifactContent {
+    pub text: String,
+}
+
+/// Converts the linter diagnostics into a SARIF 2.1.0 log.
+/// Diagnostics that don't come from cairo-lint are skipped.
+///
+/// Every distinct lint is registered once in `tool.driver.rules`, and the available fixes
+/// are attached to the results they originate from.
+pub fn to_sarif<'db>(diagnostics: &[SemanticDiagnostic<'db>], db: &'db dyn Database) -> SarifLog {
+    let mut rules: Vec<SarifRule> = Vec::new();
+    let mut results = Vec::new();
+    for diagnostic in diagnostics {
+        let message = diagnostic.format(db);
+        let Some(rule_id) = get_name_for_diagnostic_message(&message) else {
+            continue;
+        };
+
+        let rule_index = match rules.iter().position(|rule| rule.id == rule_id) {
+            Some(index) => index,
+            None => {
+                // Linter diagnostics always use the lint's diagnostic message.
+                rules.push(SarifRule {
+                    id: rule_id,
+                    short_description: SarifMessage {
+                        text: message.clone(),
+                    },
+                });
+                rules.len() - 1
+            }
+        };
+
+        let location = diagnostic.location(db);
+        let uri = location.file_id.full_path(db);
+        // Overlapping fixes are not resolved, as each fix is reported separately for its own result.
+        let result_fixes = fix_for_diagnostic(db, diagnostic)
+            .into_iter()
+            .map(|fix| SarifFix {
+                description: SarifMessage {
+                    text: fix.description.clone(),
+                },
+                artifact_changes: vec![SarifArtifactChange {
+                    artifact_location: SarifArtifactLocation { uri: uri.clone() },
+                    replacements: fix
+                        .suggestions
+                        .iter()
+                        .map(|suggestion| SarifReplacement {
+                            deleted_region: region(db, location.file_id, suggestion.span),
+                            inserted_content: SarifArtifactContent {
+                                text: suggestion.code.clone(),
+                            },
+                        })
+                        .collect(),
+                }],
+            })
+            .collect();
+
+        results.push(SarifResult {
+            rule_id,
+            rule_index,
+            level: match diagnostic.severity() {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            },
+            message: SarifMessage { text: message },
+            locations: vec![SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation { uri },
+                    region: region(db, location.file_id, location.span),
+                },
+            }],
+            fixes: result_fixes,
+        });
+    }
+
+    SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: TOOL_NAME,
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: TOOL_INFORMATION_URI,
+                    rules,
+                },
+            },
+            results,
+        }],
+    }
+}
+
+fn region(db: &dyn Database, file_id: FileId<'_>, span: TextSpan) -> SarifRegion {
+    match span.position_in_file(db, file_id) {
+        Some(position) => SarifRegion {
+            start_line: position.start.line + 1,
+            start_column: position.start.col + 1,
+            end_line: position.end.line + 1,
+            end_column: position.end.col + 1,
+        },
+        None => SarifRegion {
+            start_line: 1,
+            start_column: 1,
+            end_line: 1,
+            end_column: 1,
+        },
+    }
+}
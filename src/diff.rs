@@ -0,0 +1,151 @@
+use std::fmt::Write;
+
+/// Number of unchanged lines shown around each change.
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineChange {
+    Unchanged,
+    Removed,
+    Added,
+}
+
+/// Produces a unified diff between two versions of the file at `path`.
+/// Returns an empty string if the contents are equal.
+pub fn unified_diff(path: &str, original: &str, modified: &str) -> String {
+    let original_lines = original.split_inclusive('\n').collect::<Vec<_>>();
+    let modified_lines = modified.split_inclusive('\n').collect::<Vec<_>>();
+    let changes = diff_lines(&original_lines, &modified_lines);
+    if changes
+        .iter()
+        .all(|(change, _)| *change == LineChange::Unchanged)
+    {
+        return String::new();
+    }
+
+    let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+    for hunk in hunks(&changes) {
+        write_hunk(&mut diff, &changes, hunk);
+    }
+    diff
+}
+
+/// Computes the line changes turning `original` into `modified`, using the longest common
+/// subsequence of lines. The common prefix and suffix are skipped, as fixes are usually local.
+fn diff_lines<'a>(original: &[&'a str], modified: &[&'a str]) -> Vec<(LineChange, &'a str)> {
+    let prefix = original
+        .iter()
+        .zip(modified)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = original[prefix..]
+        .iter()
+        .rev()
+        .zip(modified[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let original_middle = &original[prefix..original.len() - suffix];
+    let modified_middle = &modified[prefix..modified.len() - suffix];
+
+    // `lcs[i][j]` is the length of the longest common subsequence of
+    // `original_middle[i..]` and `modified_middle[j..]`.
+    let mut lcs = vec![vec![0_usize; modified_middle.len() + 1]; original_middle.len() + 1];
+    for i in (0..original_middle.len()).rev() {
+        for j in (0..modified_middle.len()).rev() {
+            lcs[i][j] = if original_middle[i] == modified_middle[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = original[..prefix]
+        .iter()
+        .map(|line| (LineChange::Unchanged, *line))
+        .collect::<Vec<_>>();
+    let (mut i, mut j) = (0, 0);
+    while i < original_middle.len() || j < modified_middle.len() {
+        if i < original_middle.len()
+            && j < modified_middle.len()
+            && original_middle[i] == modified_middle[j]
+        {
+            changes.push((LineChange::Unchanged, original_middle[i]));
+            i += 1;
+            j += 1;
+        } else if j == modified_middle.len()
+            || (i < original_middle.len() && lcs[i + 1][j] >= lcs[i][j + 1])
+        {
+            changes.push((LineChange::Removed, original_middle[i]));
+            i += 1;
+        } else {
+            changes.push((LineChange::Added, modified_middle[j]));
+            j += 1;
+        }
+    }
+    changes.extend(
+        original[original.len() - suffix..]
+            .iter()
+            .map(|line| (LineChange::Unchanged, *line)),
+    );
+    changes
+}
+
+/// Groups the changes into ranges of `changes` that are shown together, each with up to
+/// [`CONTEXT_LINES`] unchanged lines around it.
+fn hunks(changes: &[(LineChange, &str)]) -> Vec<(usize, usize)> {
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (index, (change, _)) in changes.iter().enumerate() {
+        if *change == LineChange::Unchanged {
+            continue;
+        }
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + 1 + CONTEXT_LINES).min(changes.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+    hunks
+}
+
+fn write_hunk(diff: &mut String, changes: &[(LineChange, &str)], (start, end): (usize, usize)) {
+    let count_before = |predicate: fn(LineChange) -> bool| {
+        changes[..start]
+            .iter()
+            .filter(|(change, _)| predicate(*change))
+            .count()
+    };
+    let count_in_hunk = |predicate: fn(LineChange) -> bool| {
+        changes[start..end]
+            .iter()
+            .filter(|(change, _)| predicate(*change))
+            .count()
+    };
+    let in_original = |change: LineChange| change != LineChange::Added;
+    let in_modified = |change: LineChange| change != LineChange::Removed;
+
+    let original_len = count_in_hunk(in_original);
+    let modified_len = count_in_hunk(in_modified);
+    // Empty ranges point at the line preceding them.
+    let original_start = count_before(in_original) + usize::from(original_len > 0);
+    let modified_start = count_before(in_modified) + usize::from(modified_len > 0);
+    writeln!(
+        diff,
+        "@@ -{original_start},{original_len} +{modified_start},{modified_len} @@"
+    )
+    .unwrap();
+
+    for (change, line) in &changes[start..end] {
+        let marker = match change {
+            LineChange::Unchanged => ' ',
+            LineChange::Removed => '-',
+            LineChange::Added => '+',
+        };
+        diff.push(marker);
+        diff.push_str(line);
+        if !line.ends_with('\n') {
+            diff.push_str("\n\\ No newline at end of file\n");
+        }
+    }
+}
@@ -1,20 +1,27 @@
+use std::sync::Arc;
+
 use cairo_lang_defs::db::{defs_group_input, init_external_files};
 use cairo_lang_filesystem::db::files_group_input;
+use cairo_lang_filesystem::ids::FileInput;
 use cairo_lang_lowering::{db::init_lowering_group, optimizations::config::Optimizations};
 use cairo_lang_semantic::db::semantic_group_input;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use salsa::{Database, Setter};
 
 #[salsa::db]
 #[derive(Clone)]
 pub struct FixerDatabase {
     storage: salsa::Storage<Self>,
+    /// File overrides of the database this one was created from, restored by
+    /// [`FixerDatabase::reset_overrides`].
+    source_file_overrides: Option<OrderedHashMap<FileInput, Arc<str>>>,
 }
 
 impl salsa::Database for FixerDatabase {}
 
 impl FixerDatabase {
     pub fn new_from(db: &dyn Database) -> Self {
-        let mut new_db = Self::new();
+        let mut new_db = Self::new(files_group_input(db).file_overrides(db).clone());
 
         init_lowering_group(
             &mut new_db,
@@ -58,7 +65,7 @@ impl FixerDatabase {
             .to(files_group_input(db).crate_configs(db).clone());
         files_group_input(&new_db)
             .set_file_overrides(&mut new_db)
-            .to(files_group_input(db).file_overrides(db).clone());
+            .to(new_db.source_file_overrides.clone());
         files_group_input(&new_db)
             .set_flags(&mut new_db)
             .to(files_group_input(db).flags(db).clone());
@@ -74,9 +81,19 @@ impl FixerDatabase {
         new_db
     }
 
-    fn new() -> Self {
+    /// Restores the file overrides to the ones of the source database, dropping the contents of
+    /// the files modified while applying the fixes.
+    pub fn reset_overrides(&mut self) {
+        let source_file_overrides = self.source_file_overrides.clone();
+        files_group_input(self)
+            .set_file_overrides(self)
+            .to(source_file_overrides);
+    }
+
+    fn new(source_file_overrides: Option<OrderedHashMap<FileInput, Arc<str>>>) -> Self {
         Self {
             storage: Default::default(),
+            source_file_overrides,
         }
     }
 }
@@ -11,7 +11,7 @@
 //! items are removed while preserving the structure of the import statements.
 
 use std::cmp::Reverse;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::UseId;
@@ -28,8 +28,9 @@ use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use cairo_lang_utils::Intern;
 use itertools::Itertools;
 use log::debug;
+use tracing::warn;
 
-use crate::context::get_fix_for_diagnostic_message;
+use crate::context::{get_fix_for_diagnostic_message, get_name_for_diagnostic_message};
 use crate::{LinterDiagnosticParams, LinterGroup};
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_filesystem::ids::FileInput;
@@ -47,6 +48,12 @@ pub struct Suggestion {
     pub code: String,
 }
 
+/// Default maximum number of times the file is re-linted while merging overlapping fixes.
+pub const DEFAULT_MAX_FIX_MERGE_ITERATIONS: usize = 10;
+
+/// Name reported for the fixes of unused imports, which don't come from a lint.
+pub const UNUSED_IMPORTS_FIX_NAME: &str = "unused_imports";
+
 /// Represents a fix for a diagnostic, containing the span of diagnosed code,
 /// the suggested replacements, and a short description of the fix.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -54,6 +61,9 @@ pub struct DiagnosticFixSuggestion {
     pub diagnostic_span: TextSpan,
     pub suggestions: Vec<Suggestion>,
     pub description: String,
+    /// Names of the lints the fix comes from. A fix resulting from merging overlapping fixes
+    /// lists the names of all the merged fixes.
+    pub lint_names: Vec<&'static str>,
 }
 
 /// Represents an internal fix that includes the node to be modified,
@@ -85,44 +95,9 @@ pub fn get_fixes_without_resolving_overlapping<'db>(
     });
 
     for diag in diags_without_imports {
-        if let Some(InternalFix {
-            node: fix_node,
-            suggestion: fix,
-            description,
-            import_addition_paths,
-        }) = fix_semantic_diagnostic(db, &diag)
-        // If the fix is not None, we create a DiagnosticFixSuggestion.
-        // The span of the fix is the span of the node to be replaced.
-        // The code is the suggested replacement.
-        {
-            let location = diag.location(db);
-            let mut fix = DiagnosticFixSuggestion {
-                diagnostic_span: fix_node.span(db),
-                suggestions: vec![Suggestion {
-                    span: fix_node.span(db),
-                    code: fix,
-                }],
-                description,
-            };
-
-            // If there are import addition paths, we add them as a suggestion.
-            // Even if the import is being duplicated, later cairo-lang-formatter will handle that,
-            // and leave only a single import.
-            if let Some(import_paths) = import_addition_paths {
-                let imports_suggestion = import_paths
-                    .iter()
-                    .map(|import_path| format!("use {import_path};\n"))
-                    .join("");
-                fix.suggestions.push(Suggestion {
-                    span: TextSpan {
-                        start: TextOffset::START,
-                        end: TextOffset::START,
-                    },
-                    code: imports_suggestion,
-                });
-            }
+        if let Some(fix) = fix_for_diagnostic(db, &diag) {
             fixes
-                .entry(location.file_id)
+                .entry(diag.location(db).file_id)
                 .or_insert_with(Vec::new)
                 .push(fix);
         }
@@ -130,6 +105,52 @@ pub fn get_fixes_without_resolving_overlapping<'db>(
     fixes
 }
 
+/// Computes the fix for a single semantic diagnostic, without resolving overlaps with fixes
+/// of other diagnostics. Unused imports are not handled here, as they need to be analyzed together.
+pub fn fix_for_diagnostic<'db>(
+    db: &'db dyn Database,
+    diag: &SemanticDiagnostic<'db>,
+) -> Option<DiagnosticFixSuggestion> {
+    let InternalFix {
+        node: fix_node,
+        suggestion: fix,
+        description,
+        import_addition_paths,
+    } = fix_semantic_diagnostic(db, diag)?;
+    let lint_name = get_name_for_diagnostic_message(&diag.format(db));
+
+    // The span of the fix is the span of the node to be replaced.
+    // The diagnostic span skips the trivia, so it covers only the diagnosed code.
+    // The code is the suggested replacement.
+    let mut fix = DiagnosticFixSuggestion {
+        diagnostic_span: fix_node.span_without_trivia(db),
+        suggestions: vec![Suggestion {
+            span: fix_node.span(db),
+            code: fix,
+        }],
+        description,
+        lint_names: lint_name.into_iter().collect(),
+    };
+
+    // If there are import addition paths, we add them as a suggestion.
+    // Even if the import is being duplicated, later cairo-lang-formatter will handle that,
+    // and leave only a single import.
+    if let Some(import_paths) = import_addition_paths {
+        let imports_suggestion = import_paths
+            .iter()
+            .map(|import_path| format!("use {import_path};\n"))
+            .join("");
+        fix.suggestions.push(Suggestion {
+            span: TextSpan {
+                start: TextOffset::START,
+                end: TextOffset::START,
+            },
+            code: imports_suggestion,
+        });
+    }
+    Some(fix)
+}
+
 /// Attempts to fix a semantic diagnostic.
 ///
 /// This function is the entry point for fixing semantic diagnostics. It examines the
@@ -308,6 +329,7 @@ pub fn apply_import_fixes<'db>(
                         code: String::new(),
                     }],
                     description: String::from("Remove unused import"),
+                    lint_names: vec![UNUSED_IMPORTS_FIX_NAME],
                 }]
             } else {
                 // Multi-import case
@@ -397,12 +419,13 @@ fn remove_entire_import<'db>(
         current_node = parent;
     }
     vec![DiagnosticFixSuggestion {
-        diagnostic_span: current_node.span(db),
+        diagnostic_span: current_node.span_without_trivia(db),
         suggestions: vec![Suggestion {
             span: current_node.span(db),
             code: String::new(),
         }],
         description: String::from("Remove unused import"),
+        lint_names: vec![UNUSED_IMPORTS_FIX_NAME],
     }]
 }
 
@@ -445,12 +468,13 @@ fn remove_specific_items<'db>(
     };
 
     vec![DiagnosticFixSuggestion {
-        diagnostic_span: node.span(db),
+        diagnostic_span: node.span_without_trivia(db),
         suggestions: vec![Suggestion {
             span: node.span(db),
             code: text,
         }],
         description: String::from("Remove unused import"),
+        lint_names: vec![UNUSED_IMPORTS_FIX_NAME],
     }]
 }
 
@@ -475,6 +499,10 @@ fn find_use_path_list<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> Synt
 /// If any overlapping fixes are found, fixes are merged into a single one modifying the whole file content.
 /// If no overlapping fixes are found, the original fixes are returned.
 ///
+/// The file is re-linted at most `max_fix_merge_iterations` times (see [`LinterDiagnosticParams`]),
+/// and the merging stops early if applying a fix doesn't lead to a new file content.
+/// In both cases, the fixes applied so far are kept and the overlapping rest is skipped.
+///
 /// # Arguments
 ///
 /// * `db` - A mutable reference to the FixerDatabase.
@@ -493,16 +521,44 @@ pub fn merge_overlapping_fixes(
 ) -> Vec<DiagnosticFixSuggestion> {
     let mut current_fixes: Vec<DiagnosticFixSuggestion> = fixes.clone();
     let mut were_overlapped = false;
+    // Names of the lints whose fixes were already applied while resolving the overlaps.
+    let mut merged_lint_names = Vec::new();
     let file_content = db
         .file_content(file.clone().into_file_long_id(db).intern(db))
         .unwrap()
         .to_string();
+    // Contents of the file seen so far, to detect fixes that don't converge.
+    let mut seen_contents = HashSet::from([file_content.clone()]);
+    let mut iterations = 0;
 
     while let Some(overlapping_fix) = get_first_overlapping_fix(&current_fixes) {
+        if iterations == linter_query_params.max_fix_merge_iterations {
+            warn!(
+                "Overlapping fixes not resolved after {iterations} iterations, skipping the remaining ones."
+            );
+            current_fixes = without_overlapping_fixes(current_fixes);
+            break;
+        }
+        iterations += 1;
         were_overlapped = true;
 
-        apply_suggestions_for_file(db, file.clone(), overlapping_fix.suggestions);
         let file_id = file.clone().into_file_long_id(db).intern(db);
+        let content_before_fix = db.file_content(file_id).unwrap().to_string();
+        apply_suggestions_for_file(db, file.clone(), overlapping_fix.suggestions);
+
+        // Applying the fix either didn't change anything or brought back one of the previous
+        // contents, so resolving the overlaps further would loop forever.
+        if !seen_contents.insert(db.file_content(file_id).unwrap().to_string()) {
+            warn!(
+                "Applying overlapping fixes doesn't converge, skipping the remaining overlapping ones."
+            );
+            // The current fixes were computed for the content before applying the fix, so they
+            // are applied to that content instead.
+            set_file_content(db, file.clone(), content_before_fix);
+            current_fixes = without_overlapping_fixes(current_fixes);
+            break;
+        }
+        merged_lint_names.extend(overlapping_fix.lint_names);
 
         let diags: Vec<SemanticDiagnostic> = db
             .file_modules(file_id)
@@ -546,6 +602,7 @@ pub fn merge_overlapping_fixes(
             .flat_map(|fix| fix.suggestions.iter())
             .cloned()
             .collect::<Vec<_>>();
+        merged_lint_names.extend(current_fixes.iter().flat_map(|fix| fix.lint_names.clone()));
         apply_suggestions_for_file(db, file.clone(), suggestions);
 
         let file_id = file.into_file_long_id(db).intern(db);
@@ -566,6 +623,7 @@ pub fn merge_overlapping_fixes(
                 code: file_content_after.to_string(),
             }],
             description: String::from("Fix whole"),
+            lint_names: merged_lint_names,
         }];
     }
     current_fixes
@@ -573,15 +631,30 @@ pub fn merge_overlapping_fixes(
 
 fn get_first_overlapping_fix(fixes: &[DiagnosticFixSuggestion]) -> Option<DiagnosticFixSuggestion> {
     for current_fix in fixes.iter() {
-        if fixes.iter().any(|fix| {
-            spans_intersects(fix.diagnostic_span, current_fix.diagnostic_span) && fix != current_fix
-        }) {
+        if fixes
+            .iter()
+            .any(|fix| fixes_intersect(fix, current_fix) && fix != current_fix)
+        {
             return Some(current_fix.clone());
         }
     }
     None
 }
 
+/// Drops the fixes overlapping with any of the preceding ones, so the rest can be applied together.
+fn without_overlapping_fixes(fixes: Vec<DiagnosticFixSuggestion>) -> Vec<DiagnosticFixSuggestion> {
+    let mut kept_fixes: Vec<DiagnosticFixSuggestion> = Vec::new();
+    for fix in fixes {
+        if !kept_fixes
+            .iter()
+            .any(|kept_fix| fixes_intersect(kept_fix, &fix))
+        {
+            kept_fixes.push(fix);
+        }
+    }
+    kept_fixes
+}
+
 fn apply_suggestions_for_file(
     db: &mut FixerDatabase,
     file: FileInput,
@@ -598,14 +671,37 @@ fn apply_suggestions_for_file(
         content.replace_range(suggestion.span.to_str_range(), &suggestion.code);
     }
 
+    set_file_content(db, file, content);
+}
+
+/// Overrides the content of the file in the database.
+fn set_file_content(db: &mut FixerDatabase, file: FileInput, content: String) {
     let input = files_group_input(db);
 
     let mut overrides = input.file_overrides(db).clone().unwrap();
-    overrides.insert(file.clone(), content.into());
+    overrides.insert(file, content.into());
 
     input.set_file_overrides(db).to(overrides.into());
 }
 
+/// Checks if the code replaced by the fixes intersects, including the replaced trivia.
+/// The insertions, i.e. the added imports, are skipped, as the formatter merges the duplicated ones.
+fn fixes_intersect(fix_a: &DiagnosticFixSuggestion, fix_b: &DiagnosticFixSuggestion) -> bool {
+    let replaced_spans = |fix: &DiagnosticFixSuggestion| {
+        fix.suggestions
+            .iter()
+            .map(|suggestion| suggestion.span)
+            .filter(|span| span.start != span.end)
+            .collect::<Vec<_>>()
+    };
+    let spans_b = replaced_spans(fix_b);
+    replaced_spans(fix_a).into_iter().any(|span_a| {
+        spans_b
+            .iter()
+            .any(|span_b| spans_intersects(span_a, *span_b))
+    })
+}
+
 fn spans_intersects(span_a: TextSpan, span_b: TextSpan) -> bool {
     span_a.start <= span_b.end && span_b.start <= span_a.end
 }
@@ -12,9 +12,13 @@
 //! These helper functions can be reused in various parts of the Cairo Lint codebase to maintain
 //! consistency and modularity when working with blocks and conditions.
 use cairo_lang_defs::db::DefsGroup;
-use cairo_lang_defs::ids::{ImplItemId, LookupItemId, ModuleId, ModuleItemId, TraitItemId};
+use cairo_lang_defs::ids::{
+    FreeFunctionLongId, FunctionWithBodyId, ImplFunctionLongId, ImplItemId, LookupItemId, ModuleId,
+    ModuleItemId, TraitFunctionLongId, TraitItemId,
+};
 use cairo_lang_diagnostics::DiagnosticsBuilder;
 use cairo_lang_filesystem::ids::{FileKind, FileLongId, SmolStrId, VirtualFile};
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_formatter::{FormatterConfig, get_formatted_file};
 use cairo_lang_parser::parser::Parser;
 use cairo_lang_semantic::items::imp::ImplSemantic;
@@ -359,6 +363,108 @@ pub fn find_module_containing_node<'db>(
         })
 }
 
+/// Returns the id of the function (trait, impl, or free) defined by the syntax node, if it's a
+/// function with a body.
+pub fn get_function_with_body_id<'db>(
+    db: &'db dyn Database,
+    module_id: ModuleId<'db>,
+    ancestor: SyntaxNode<'db>,
+) -> Option<FunctionWithBodyId<'db>> {
+    if let Some(trait_func) = ast::TraitItemFunction::cast(db, ancestor) {
+        let ptr = trait_func.stable_ptr(db);
+        Some(FunctionWithBodyId::Trait(
+            TraitFunctionLongId(module_id, ptr).intern(db),
+        ))
+    } else if let Some(func_with_body) = ast::FunctionWithBody::cast(db, ancestor) {
+        let ptr = func_with_body.stable_ptr(db);
+
+        let function_with_body_id = if ancestor
+            .ancestor_of_kind(db, SyntaxKind::ItemImpl)
+            .is_some()
+        {
+            FunctionWithBodyId::Impl(ImplFunctionLongId(module_id, ptr).intern(db))
+        } else {
+            FunctionWithBodyId::Free(FreeFunctionLongId(module_id, ptr).intern(db))
+        };
+
+        Some(function_with_body_id)
+    } else {
+        None
+    }
+}
+
+/// Checks if the node is inside a function with the given name.
+pub fn is_inside_function<'db>(db: &'db dyn Database, node: SyntaxNode<'db>, name: &str) -> bool {
+    node.ancestors(db)
+        .find_map(|ancestor| ast::FunctionWithBody::cast(db, ancestor))
+        .is_some_and(|function| function.declaration(db).name(db).text(db).to_string(db) == name)
+}
+
+/// Checks if evaluating the expression has no side effects, so it can be evaluated any number of
+/// times, or not at all. It's the case if it only reads variables, their members, constants and
+/// literals, possibly with unary operators. `-` is allowed only on literals, as negating a variable
+/// can overflow.
+pub fn is_side_effect_free_syntax<'db>(db: &'db dyn Database, expr: &ast::Expr<'db>) -> bool {
+    match expr {
+        ast::Expr::Path(_)
+        | ast::Expr::Literal(_)
+        | ast::Expr::ShortString(_)
+        | ast::Expr::String(_)
+        | ast::Expr::True(_)
+        | ast::Expr::False(_) => true,
+        ast::Expr::Parenthesized(parenthesized) => {
+            is_side_effect_free_syntax(db, &parenthesized.expr(db))
+        }
+        ast::Expr::Unary(unary) => match unary.op(db) {
+            ast::UnaryOperator::Minus(_) => matches!(unary.expr(db), ast::Expr::Literal(_)),
+            _ => is_side_effect_free_syntax(db, &unary.expr(db)),
+        },
+        ast::Expr::Binary(binary) if matches!(binary.op(db), ast::BinaryOperator::Dot(_)) => {
+            matches!(binary.rhs(db), ast::Expr::Path(_))
+                && is_side_effect_free_syntax(db, &binary.lhs(db))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the trivia preceding the code of the node, e.g. the indentation or the comments.
+pub fn leading_trivia<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> String {
+    node.get_text_of_span(
+        db,
+        TextSpan {
+            start: node.span(db).start,
+            end: node.span_without_trivia(db).start,
+        },
+    )
+    .to_string()
+}
+
+/// Returns the trivia following the code of the node, e.g. the comment until the end of the line.
+pub fn trailing_trivia<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> String {
+    node.get_text_of_span(
+        db,
+        TextSpan {
+            start: node.span_without_trivia(db).end,
+            end: node.span(db).end,
+        },
+    )
+    .to_string()
+}
+
+/// Returns the replacement of the node with the given code, keeping the leading and trailing
+/// trivia of the node, so that the fix doesn't remove the surrounding whitespace and comments.
+pub fn replace_preserving_trivia<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+    code: &str,
+) -> String {
+    format!(
+        "{}{code}{}",
+        leading_trivia(db, node),
+        trailing_trivia(db, node)
+    )
+}
+
 pub fn format_fixed_file(
     db: &dyn Database,
     formatter_config: FormatterConfig,
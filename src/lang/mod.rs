@@ -1,15 +1,24 @@
-use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
 use cairo_lang_filesystem::ids::{FileId, FileLongId};
 use cairo_lang_syntax::node::SyntaxNode;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use if_chain::if_chain;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 use crate::context::{
-    get_all_checking_functions, get_name_for_diagnostic_message, is_lint_enabled_by_default,
+    Lint, LintGroup, get_checking_functions_for_lints, get_group_for_diagnostic_message,
+    get_name_for_diagnostic_message, is_lint_enabled_by_default,
 };
-use crate::{CairoLintToolMetadata, CorelibContext};
+use crate::fixer::DEFAULT_MAX_FIX_MERGE_ITERATIONS;
+use crate::lints::excessive_nesting::DEFAULT_MAX_NESTING_DEPTH;
+use crate::lints::ifs::collapsible_if::DEFAULT_NESTED_IF_THRESHOLD;
+use crate::lints::large_enum_variant::DEFAULT_LARGE_ENUM_VARIANT_THRESHOLD;
+use crate::lints::manual_pow::DEFAULT_MANUAL_POW_THRESHOLD;
+use crate::lints::too_many_arguments::DEFAULT_TOO_MANY_ARGUMENTS_THRESHOLD;
+use crate::lints::unused_allow::{UnusedAllow, check_unused_allows};
+use crate::{CairoLintSeverities, CairoLintToolMetadata, CorelibContext, LintSeverity};
 
 use crate::mappings::{get_origin_module_item_as_syntax_node, get_origin_syntax_node};
 
@@ -22,6 +31,49 @@ use salsa::Database;
 pub struct LinterDiagnosticParams {
     pub only_generated_files: bool,
     pub tool_metadata: CairoLintToolMetadata,
+    /// Explicit severities of the lints. They take precedence over `tool_metadata`.
+    pub severities: CairoLintSeverities,
+    /// Maximum depth of nested blocks within a function, used by the `excessive_nesting` lint.
+    pub max_nesting_depth: usize,
+    /// Maximum number of times the file is re-linted while merging overlapping fixes.
+    pub max_fix_merge_iterations: usize,
+    /// Number of nested collapsible `if` expressions from which the `collapsible_if` lint fires.
+    pub nested_if_threshold: usize,
+    /// Number of arguments (`self` excluded) above which the `too_many_arguments` lint fires.
+    pub too_many_arguments_threshold: usize,
+    /// Size, in `felt252`s, above which an enum variant is reported by the `large_enum_variant`
+    /// lint.
+    pub large_enum_variant_threshold: usize,
+    /// Number of multiplied operands from which the `manual_pow` lint fires.
+    pub manual_pow_threshold: usize,
+    /// Whether the `unused_enum_variant` lint checks the variants of public enums too. They are
+    /// skipped by default, as they can be constructed by the crates depending on this one.
+    pub check_public_enum_variants: bool,
+    /// Whether the `panic` lint skips the code marked as test code, e.g. the `#[test]` functions,
+    /// where panicking is the expected way of failing.
+    pub allow_panic_in_tests: bool,
+    /// Names of the only lints to run. Unlike `tool_metadata`, which toggles single lints,
+    /// this is an allowlist: when set, all the lints not listed here are skipped.
+    pub enabled_lints: Option<BTreeSet<String>>,
+}
+
+impl Default for LinterDiagnosticParams {
+    fn default() -> Self {
+        Self {
+            only_generated_files: false,
+            tool_metadata: CairoLintToolMetadata::default(),
+            severities: CairoLintSeverities::default(),
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            max_fix_merge_iterations: DEFAULT_MAX_FIX_MERGE_ITERATIONS,
+            nested_if_threshold: DEFAULT_NESTED_IF_THRESHOLD,
+            too_many_arguments_threshold: DEFAULT_TOO_MANY_ARGUMENTS_THRESHOLD,
+            large_enum_variant_threshold: DEFAULT_LARGE_ENUM_VARIANT_THRESHOLD,
+            manual_pow_threshold: DEFAULT_MANUAL_POW_THRESHOLD,
+            check_public_enum_variants: false,
+            allow_panic_in_tests: true,
+            enabled_lints: None,
+        }
+    }
 }
 
 pub trait LinterGroup: Database {
@@ -33,6 +85,16 @@ pub trait LinterGroup: Database {
         linter_diagnostics(self.as_dyn_database(), params, module_id)
     }
 
+    /// Diagnostics of all the modules whose main file is `file_id`, restricted to that file.
+    /// Editors can use it to lint and cache results per file instead of per module.
+    fn file_lint_diagnostics<'db>(
+        &'db self,
+        params: LinterDiagnosticParams,
+        file_id: FileId<'db>,
+    ) -> &'db Vec<PluginDiagnostic<'db>> {
+        file_lint_diagnostics(self.as_dyn_database(), params, file_id)
+    }
+
     fn corelib_context<'db>(&'db self) -> &'db CorelibContext<'db> {
         corelib_context(self.as_dyn_database())
     }
@@ -53,10 +115,17 @@ fn linter_diagnostics<'db>(
     };
 
     let mut linted_nodes: HashSet<SyntaxNode> = HashSet::new();
+    let check_unused_allows_enabled = is_lint_selected(&params, UnusedAllow.allowed_name())
+        && lint_severity(
+            &params,
+            UnusedAllow.allowed_name(),
+            UnusedAllow.group(),
+            UnusedAllow.diagnostic_message(),
+        ) != LintSeverity::Allow;
 
+    // Items to lint, along with their origin nodes if they are generated.
+    let mut items_to_lint: Vec<(ModuleItemId, Option<SyntaxNode>)> = Vec::new();
     for item in module_data.items(db) {
-        let mut item_diagnostics = Vec::new();
-        let module_file = db.module_main_file(module_id).unwrap();
         let item_file = item.stable_location(db).file_id(db).long(db);
         let is_generated_item =
             matches!(item_file, FileLongId::Virtual(_) | FileLongId::External(_));
@@ -74,23 +143,38 @@ fn linter_diagnostics<'db>(
                 // we won't be processing it, as it might lead to unexpected behavior.
                 if node.get_text_without_trivia(db).long(db).as_str().contains(item_syntax_node.get_text_without_trivia(db).long(db).as_str());
                 then {
-                    let checking_functions = get_all_checking_functions();
-                    for checking_function in checking_functions {
-                        checking_function(db, item, &mut item_diagnostics);
-                    }
-
                     linted_nodes.insert(node);
-
-                    diags.extend(item_diagnostics.into_iter().filter_map(|mut diag| {
-                      let ptr = diag.stable_ptr;
-                      diag.stable_ptr = get_origin_syntax_node(db, &ptr)?.stable_ptr(db);
-                      Some((diag, module_file))}));
+                    items_to_lint.push((*item, Some(node)));
                 }
             }
-        } else if !is_generated_item || params.only_generated_files {
-            let checking_functions = get_all_checking_functions();
-            for checking_function in checking_functions {
-                checking_function(db, item, &mut item_diagnostics);
+        } else {
+            items_to_lint.push((*item, None));
+        }
+    }
+
+    // Run the checking functions for all the items in parallel. The results are cached by
+    // `item_linter_diagnostics`, so they can be collected below in the order of the items,
+    // which keeps the output deterministic.
+    let items: Vec<ModuleItemId> = items_to_lint.iter().map(|(item, _)| *item).collect();
+    let () = salsa::par_map(db, items, |db, item| {
+        item_linter_diagnostics(db, params.clone(), item);
+    });
+
+    let module_file = db.module_main_file(module_id).unwrap();
+    for (item, origin_node) in items_to_lint {
+        let mut item_diagnostics = item_linter_diagnostics(db, params.clone(), item).clone();
+
+        if origin_node.is_some() {
+            diags.extend(item_diagnostics.into_iter().filter_map(|mut diag| {
+                let ptr = diag.stable_ptr;
+                diag.stable_ptr = get_origin_syntax_node(db, &ptr)?.stable_ptr(db);
+                Some((diag, module_file))
+            }));
+        } else {
+            if check_unused_allows_enabled {
+                let mut unused_allows = Vec::new();
+                check_unused_allows(db, &item, &params, &item_diagnostics, &mut unused_allows);
+                item_diagnostics.extend(unused_allows);
             }
 
             diags.extend(item_diagnostics.into_iter().filter_map(|diag| {
@@ -102,22 +186,111 @@ fn linter_diagnostics<'db>(
 
     diags
         .into_iter()
-        .filter(|diag: &(PluginDiagnostic, FileId)| {
-            let diagnostic = &diag.0;
+        .filter_map(|(mut diagnostic, _): (PluginDiagnostic, FileId)| {
             let node = diagnostic.stable_ptr.lookup(db);
             let allowed_name = get_name_for_diagnostic_message(&diagnostic.message).unwrap();
-            let default_allowed = is_lint_enabled_by_default(&diagnostic.message).unwrap();
-            let is_rule_allowed_globally = *params
-                .tool_metadata
-                .get(allowed_name)
-                .unwrap_or(&default_allowed);
-            !node_has_ascendants_with_allow_name_attr(db, node, allowed_name)
-                && is_rule_allowed_globally
+            // A checking function may emit diagnostics of several lints, not all of them selected.
+            if !is_lint_selected(&params, allowed_name) {
+                return None;
+            }
+            let group = get_group_for_diagnostic_message(&diagnostic.message).unwrap();
+            if node_has_ascendants_with_allow_name_attr(db, node, allowed_name)
+                || node_has_ascendants_with_allow_name_attr(db, node, group.allowed_name())
+            {
+                return None;
+            }
+            match lint_severity(&params, allowed_name, group, &diagnostic.message) {
+                LintSeverity::Allow => None,
+                LintSeverity::Warn => Some(diagnostic),
+                LintSeverity::Deny => {
+                    diagnostic.severity = Severity::Error;
+                    Some(diagnostic)
+                }
+            }
+        })
+        .collect()
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+#[salsa::tracked(returns(ref))]
+fn file_lint_diagnostics<'db>(
+    db: &'db dyn Database,
+    params: LinterDiagnosticParams,
+    file_id: FileId<'db>,
+) -> Vec<PluginDiagnostic<'db>> {
+    let Ok(module_ids) = db.file_modules(file_id) else {
+        return Vec::default();
+    };
+    module_ids
+        .iter()
+        .flat_map(|module_id| linter_diagnostics(db, params.clone(), *module_id))
+        // Diagnostics of code generated by macros point to virtual files, so they are matched by
+        // the file of the code they originate from.
+        .filter(|diagnostic| {
+            get_origin_syntax_node(db, &diagnostic.stable_ptr)
+                .is_some_and(|node| node.stable_ptr(db).file_id(db) == file_id)
         })
-        .map(|diag| diag.0)
+        .cloned()
         .collect()
 }
 
+/// Runs all the checking functions on a single module item.
+/// It's a separate query, so that the items of a module can be linted in parallel.
+#[tracing::instrument(skip_all, level = "trace")]
+#[salsa::tracked(returns(ref))]
+fn item_linter_diagnostics<'db>(
+    db: &'db dyn Database,
+    params: LinterDiagnosticParams,
+    item: ModuleItemId<'db>,
+) -> Vec<PluginDiagnostic<'db>> {
+    let mut item_diagnostics = Vec::new();
+    for checking_function in get_checking_functions_for_lints(params.enabled_lints.as_ref()) {
+        checking_function(db, &item, &params, &mut item_diagnostics);
+    }
+    item_diagnostics
+}
+
+/// Checks if the lint is allowed to run by `params.enabled_lints`.
+fn is_lint_selected(params: &LinterDiagnosticParams, allowed_name: &str) -> bool {
+    params
+        .enabled_lints
+        .as_ref()
+        .is_none_or(|enabled_lints| enabled_lints.contains(allowed_name))
+}
+
+/// Resolves the severity of the lint. An entry for the lint name in either `params.severities`
+/// or `params.tool_metadata` takes precedence over an entry for its group, and an explicit
+/// severity wins over an enabled (a warning) or disabled (allowed) entry of the same name.
+/// Without any entry, the lint is a warning if it's enabled by default.
+fn lint_severity(
+    params: &LinterDiagnosticParams,
+    allowed_name: &str,
+    group: LintGroup,
+    diagnostic_message: &str,
+) -> LintSeverity {
+    let configured_severity = |name: &str| {
+        params.severities.get(name).copied().or_else(|| {
+            params.tool_metadata.get(name).map(|enabled| {
+                if *enabled {
+                    LintSeverity::Warn
+                } else {
+                    LintSeverity::Allow
+                }
+            })
+        })
+    };
+    if let Some(severity) =
+        configured_severity(allowed_name).or_else(|| configured_severity(group.name()))
+    {
+        return severity;
+    }
+    if is_lint_enabled_by_default(diagnostic_message).unwrap() {
+        LintSeverity::Warn
+    } else {
+        LintSeverity::Allow
+    }
+}
+
 #[salsa::tracked(returns(ref))]
 fn corelib_context<'db>(db: &'db dyn Database) -> CorelibContext<'db> {
     CorelibContext::new(db)
@@ -129,10 +302,19 @@ fn node_has_ascendants_with_allow_name_attr<'db>(
     node: SyntaxNode<'db>,
     allowed_name: &'static str,
 ) -> bool {
-    for node in node.ancestors_with_self(db) {
-        if node.has_attr_with_arg(db, "allow", allowed_name) {
-            return true;
-        }
-    }
-    false
+    node_has_ascendants_with_attr(db, node, "allow", Some(allowed_name))
+}
+
+/// Checks if the node or any of its ascendants has the attribute, with the given argument if
+/// provided.
+pub(crate) fn node_has_ascendants_with_attr<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+    attr: &str,
+    arg: Option<&str>,
+) -> bool {
+    node.ancestors_with_self(db).any(|node| match arg {
+        Some(arg) => node.has_attr_with_arg(db, attr, arg),
+        None => node.has_attr(db, attr),
+    })
 }
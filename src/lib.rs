@@ -9,11 +9,27 @@ use fixer::{
 use helper::format_fixed_file;
 use itertools::Itertools;
 
-use std::{cmp::Reverse, collections::HashMap};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    str::FromStr,
+};
 
-use anyhow::{Result, anyhow};
-use cairo_lang_filesystem::{db::FilesGroup, ids::FileId};
+use anyhow::{Result, anyhow, bail};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::db::{
+    CrateConfigurationInput, CrateSettings, Edition, ExperimentalFeaturesConfig, FilesGroup,
+    files_group_input, init_dev_corelib,
+};
+use cairo_lang_filesystem::detect::detect_corelib;
+use cairo_lang_filesystem::ids::{
+    CrateInput, DirectoryInput, FileId, FileInput, FileKind, VirtualFileInput,
+};
 use cairo_lang_semantic::{SemanticDiagnostic, db::SemanticGroup};
+use cairo_lang_test_plugin::test_plugin_suite;
+use cairo_lang_utils::Intern;
+use salsa::Setter;
 
 pub static CAIRO_LINT_TOOL_NAME: &str = "cairo-lint";
 
@@ -22,10 +38,57 @@ pub static CAIRO_LINT_TOOL_NAME: &str = "cairo-lint";
 /// as it might break the backwards compatibility.
 pub type CairoLintToolMetadata = OrderedHashMap<String, bool>;
 
+/// Severity level of a single lint.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum LintSeverity {
+    /// The lint is disabled and its diagnostics are not reported.
+    Allow,
+    /// The lint diagnostics are reported as warnings.
+    Warn,
+    /// The lint diagnostics are reported as errors.
+    Deny,
+}
+
+impl FromStr for LintSeverity {
+    type Err = anyhow::Error;
+
+    /// Parses the severity as written in the tool metadata, i.e. `allow`, `warn` or `deny`.
+    fn from_str(severity: &str) -> Result<Self> {
+        match severity {
+            "allow" => Ok(LintSeverity::Allow),
+            "warn" => Ok(LintSeverity::Warn),
+            "deny" => Ok(LintSeverity::Deny),
+            _ => Err(anyhow!(
+                "Unknown lint severity `{severity}`, expected `allow`, `warn` or `deny`."
+            )),
+        }
+    }
+}
+
+/// Per-lint severities, keyed by the lint name.
+/// Lints missing from this map fall back to [`CairoLintToolMetadata`] and the lint's defaults.
+pub type CairoLintSeverities = OrderedHashMap<String, LintSeverity>;
+
+/// Name of the crate created by [`lint_string`].
+const STRING_CRATE_NAME: &str = "lint_string";
+
+/// Experimental features of the crate created by [`lint_string`], matching the ones enabled in its
+/// settings. The edition is prepended by [`string_crate_config`].
+const STRING_CRATE_EXPERIMENTAL_FEATURES: &str = r#"
+[experimental_features]
+negative_impls = true
+coupons = true
+associated_item_constraints = true
+user_defined_inline_macros = true
+repr_ptrs = true
+"#;
+
+pub mod api;
 pub mod context;
 
 mod corelib;
 pub mod diagnostics;
+mod diff;
 mod fixer;
 mod helper;
 mod lang;
@@ -69,6 +132,8 @@ pub fn get_fixes<'db>(
     fixes
         .into_iter()
         .map(|(file_id, fixes)| {
+            // The contents of the previously fixed files must not leak into the next one.
+            new_db.reset_overrides();
             let new_fixes = merge_overlapping_fixes(
                 &mut new_db,
                 linter_params,
@@ -102,7 +167,54 @@ pub fn get_separated_fixes<'db>(
     get_fixes_without_resolving_overlapping(db, diagnostics)
 }
 
-/// Applies the fixes to the file.
+/// Gets the fix for a single compiler diagnostic (that uses Cairo lint analyzer plugin), without
+/// applying it, e.g. to offer it as a code action in an editor.
+/// It's lighter than [`get_fixes`], as it doesn't run the whole-file pipeline, so the overlapping
+/// fixes of other diagnostics are not taken into account. Unused imports are not supported.
+///
+/// # Arguments
+///
+/// * `db` - The reference to the database.
+/// * `diagnostic` - The diagnostic to compute the fix for.
+///
+/// # Returns
+///
+/// The proposed fix, including the suggestions adding the required imports, or `None` if no fix
+/// is available for the diagnostic.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_for_diagnostic<'db>(
+    db: &'db dyn Database,
+    diagnostic: &SemanticDiagnostic<'db>,
+) -> Option<DiagnosticFixSuggestion> {
+    fixer::fix_for_diagnostic(db, diagnostic)
+}
+
+/// Computes the content of the file with the fixes applied and formatted, without writing it
+/// to the disk.
+///
+/// # Arguments
+///
+/// * `file_id` - The FileId of the file that the fixes should be applied to.
+/// * `fixes` - The list of fixes that should be applied to the file.
+/// * `db` - The reference to the database that contains the file content.
+/// * `formatter_config` - The configuration of the formatter run on the fixed file.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn compute_fixed_content<'db>(
+    file_id: FileId<'db>,
+    fixes: &[DiagnosticFixSuggestion],
+    db: &'db dyn Database,
+    formatter_config: FormatterConfig,
+) -> Result<String> {
+    let mut content = db
+        .file_content(file_id)
+        .ok_or(anyhow!("{} not found", file_id.file_name(db).to_string(db)))?
+        .to_string();
+    apply_suggestions(&mut content, fixes);
+    Ok(format_fixed_file(db, formatter_config, content))
+}
+
+/// Applies the fixes to the file and writes the result to the disk.
+/// See [`compute_fixed_content`] for getting the fixed content instead.
 ///
 /// # Arguments
 ///
@@ -116,40 +228,316 @@ pub fn apply_file_fixes<'db>(
     db: &'db dyn Database,
     formatter_config: FormatterConfig,
 ) -> Result<()> {
+    apply_file_fixes_with_summary(file_id, fixes, db, formatter_config).map(|_| ())
+}
+
+/// Applies a single fix to the file and returns the new content, without writing it to the disk.
+/// All the suggestions of the fix (e.g. the added imports) are applied together.
+///
+/// # Arguments
+///
+/// * `file_id` - The FileId of the file that the fix should be applied to.
+/// * `fix` - The fix that should be applied to the file.
+/// * `db` - The reference to the database that contains the file content.
+/// * `formatter_config` - The configuration of the formatter run on the fixed file.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn apply_single_fix<'db>(
+    file_id: FileId<'db>,
+    fix: &DiagnosticFixSuggestion,
+    db: &'db dyn Database,
+    formatter_config: FormatterConfig,
+) -> Result<String> {
+    compute_fixed_content(file_id, std::slice::from_ref(fix), db, formatter_config)
+}
+
+/// Summary of the fixes applied to a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixSummary {
+    /// Number of applied fix suggestions, keyed by the name of the lint they come from.
+    pub fixes_per_lint: BTreeMap<&'static str, usize>,
+}
+
+impl FixSummary {
+    /// Creates the summary of the provided fixes.
+    pub fn from_fixes(fixes: &[DiagnosticFixSuggestion]) -> Self {
+        let mut fixes_per_lint = BTreeMap::new();
+        for lint_name in fixes.iter().flat_map(|fix| fix.lint_names.iter()) {
+            *fixes_per_lint.entry(*lint_name).or_default() += 1;
+        }
+        Self { fixes_per_lint }
+    }
+
+    /// Total number of applied fix suggestions.
+    pub fn fixes_count(&self) -> usize {
+        self.fixes_per_lint.values().sum()
+    }
+
+    /// Number of distinct lints with at least one applied fix.
+    pub fn lints_count(&self) -> usize {
+        self.fixes_per_lint.len()
+    }
+}
+
+/// Applies the fixes to the file, the same way as [`apply_file_fixes`], and returns the summary
+/// of the applied fixes.
+///
+/// # Arguments
+///
+/// * `file_id` - The FileId of the file that the fixes should be applied to.
+/// * `fixes` - The list of fixes that should be applied to the file.
+/// * `db` - The reference to the database that contains the file content.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn apply_file_fixes_with_summary<'db>(
+    file_id: FileId<'db>,
+    fixes: Vec<DiagnosticFixSuggestion>,
+    db: &'db dyn Database,
+    formatter_config: FormatterConfig,
+) -> Result<FixSummary> {
+    let fixed_content = compute_fixed_content(file_id, &fixes, db, formatter_config)?;
+
+    // Dump them in place.
+    std::fs::write(file_id.full_path(db), fixed_content)?;
+
+    Ok(FixSummary::from_fixes(&fixes))
+}
+
+/// Previews the fixes without writing anything to the disk.
+///
+/// The fixes are applied to an in-memory copy of the file, the same way as in [`apply_file_fixes`],
+/// and the result is formatted.
+///
+/// # Arguments
+///
+/// * `db` - The reference to the database that contains the file content.
+/// * `file_id` - The FileId of the file that the fixes would be applied to.
+/// * `fixes` - The list of fixes that would be applied to the file.
+/// * `formatter_config` - The configuration of the formatter run on the fixed file.
+///
+/// # Returns
+///
+/// A unified diff between the current content of the file and the fixed one.
+/// It's empty if the fixes don't change anything or the file doesn't exist.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn preview_fixes<'db>(
+    db: &'db dyn Database,
+    file_id: FileId<'db>,
+    fixes: &[DiagnosticFixSuggestion],
+    formatter_config: FormatterConfig,
+) -> String {
+    let Some(original) = db.file_content(file_id).map(|content| content.to_string()) else {
+        return String::new();
+    };
+    let Ok(fixed) = compute_fixed_content(file_id, fixes, db, formatter_config) else {
+        return String::new();
+    };
+
+    diff::unified_diff(&file_id.full_path(db), &original, &fixed)
+}
+
+/// Applies the suggestions of the fixes to the file content.
+fn apply_suggestions(content: &mut String, fixes: &[DiagnosticFixSuggestion]) {
     // Those suggestions MUST be sorted in reverse, so changes at the end of the file,
     // doesn't affect the spans of the previous file suggestions.
     let suggestions = fixes
         .iter()
         .flat_map(|fix| fix.suggestions.iter())
-        .sorted_by_key(|suggestion| Reverse(suggestion.span.start))
-        .collect::<Vec<_>>();
-
-    // Get all the files that need to be fixed
-    let mut files: HashMap<FileId, String> = HashMap::default();
-    files.insert(
-        file_id,
-        db.file_content(file_id)
-            .ok_or(anyhow!("{} not found", file_id.file_name(db).to_string(db)))?
-            .to_string(),
-    );
-
-    // Can't fail we just set the file value.
-    files.entry(file_id).and_modify(|file| {
-        for suggestion in suggestions {
-            file.replace_range(suggestion.span.to_str_range(), &suggestion.code)
-        }
-    });
+        .sorted_by_key(|suggestion| Reverse(suggestion.span.start));
 
-    // Dump them in place.
-    std::fs::write(
-        file_id.full_path(db),
-        format_fixed_file(db, formatter_config, files.get(&file_id).unwrap().clone()),
-    )?;
-
-    Ok(())
+    for suggestion in suggestions {
+        content.replace_range(suggestion.span.to_str_range(), &suggestion.code)
+    }
 }
 
 /// Checks if the diagnostic is a panic diagnostic.
 pub fn is_panic_diagnostic(diag: &PluginDiagnostic) -> bool {
     get_lint_type_from_diagnostic_message(&diag.message) == CairoLintKind::Panic
 }
+
+/// Builds the linter parameters from the `[tool.cairo-lint]` table of the manifest, as provided
+/// by Scarb metadata.
+///
+/// Every key is the name of a lint, a lint group, a threshold or an option:
+/// * a boolean enables or disables the lint or the whole group, as in [`CairoLintToolMetadata`],
+///   or sets the option with the same name as the [`LinterDiagnosticParams`] field,
+///   i.e. `check_public_enum_variants` or `allow_panic_in_tests`,
+/// * a string sets the severity of the lint or the whole group: `"allow"`, `"warn"` or `"deny"`,
+/// * a number sets the threshold with the same name as the [`LinterDiagnosticParams`] field,
+///   e.g. `max_nesting_depth` or `too_many_arguments_threshold`.
+///
+/// A table of booleans only, the format of [`CairoLintToolMetadata`], is parsed as before.
+///
+/// # Precedence
+///
+/// An entry for a single lint takes precedence over an entry for its group, which in turn takes
+/// precedence over the default of the lint. For the entries of the same lint (or group),
+/// a severity wins over a boolean.
+///
+/// # Example
+///
+/// ```toml
+/// [tool.cairo-lint]
+/// complexity = false
+/// double_parens = true
+/// panic = "deny"
+/// max_nesting_depth = 3
+/// ```
+pub fn parse_tool_metadata(value: &serde_json::Value) -> Result<LinterDiagnosticParams> {
+    let Some(table) = value.as_object() else {
+        bail!("The `{CAIRO_LINT_TOOL_NAME}` tool metadata must be a table.");
+    };
+    let mut params = LinterDiagnosticParams::default();
+    for (key, value) in table {
+        match value {
+            serde_json::Value::Bool(enabled) if key == "check_public_enum_variants" => {
+                params.check_public_enum_variants = *enabled;
+            }
+            serde_json::Value::Bool(enabled) if key == "allow_panic_in_tests" => {
+                params.allow_panic_in_tests = *enabled;
+            }
+            serde_json::Value::Bool(enabled) => {
+                params.tool_metadata.insert(key.clone(), *enabled);
+            }
+            serde_json::Value::String(severity) => {
+                params.severities.insert(key.clone(), severity.parse()?);
+            }
+            serde_json::Value::Number(number) => {
+                let threshold = match key.as_str() {
+                    "max_nesting_depth" => &mut params.max_nesting_depth,
+                    "max_fix_merge_iterations" => &mut params.max_fix_merge_iterations,
+                    "nested_if_threshold" => &mut params.nested_if_threshold,
+                    "too_many_arguments_threshold" => &mut params.too_many_arguments_threshold,
+                    "large_enum_variant_threshold" => &mut params.large_enum_variant_threshold,
+                    "manual_pow_threshold" => &mut params.manual_pow_threshold,
+                    _ => bail!("Unknown threshold `{key}`."),
+                };
+                *threshold = number
+                    .as_u64()
+                    .and_then(|number| usize::try_from(number).ok())
+                    .ok_or_else(|| anyhow!("`{key}` must be a non-negative integer."))?;
+            }
+            _ => bail!("Unsupported value of `{key}`: {value}."),
+        }
+    }
+    Ok(params)
+}
+
+/// Runs the linter on an in-memory Cairo source, treated as the `lib.cairo` file of a single crate.
+///
+/// A throwaway [`LinterAnalysisDatabase`] is built for this purpose. The corelib is loaded from
+/// `corelib_path` if provided, otherwise from the path in the `CORELIB_PATH` environment variable,
+/// and as a last resort from the detected development corelib.
+///
+/// `params.only_generated_files` is always overridden to `true`: the source lives in a virtual
+/// file, so otherwise it would be skipped as code generated by a plugin.
+///
+/// # Lifetimes
+///
+/// The [`PluginDiagnostic`]s borrow from the database they were computed with (their stable
+/// pointers are interned in it), so they cannot outlive it. As the database only lives for the
+/// duration of this call, the diagnostics are passed to `f` together with the database, which can
+/// be used to e.g. resolve their locations. Anything needed afterwards should be returned from `f`
+/// as owned data.
+///
+/// # Example
+///
+/// ```ignore
+/// let messages = lint_string(source, &LinterDiagnosticParams::default(), None, |_db, diagnostics| {
+///     diagnostics.into_iter().map(|diagnostic| diagnostic.message).collect::<Vec<_>>()
+/// })?;
+/// ```
+pub fn lint_string<R>(
+    source: &str,
+    params: &LinterDiagnosticParams,
+    corelib_path: Option<PathBuf>,
+    f: impl for<'db> FnOnce(&'db dyn Database, Vec<PluginDiagnostic<'db>>) -> R,
+) -> Result<R> {
+    let corelib_path = corelib_path
+        .or_else(|| std::env::var_os("CORELIB_PATH").map(PathBuf::from))
+        .or_else(detect_corelib)
+        .ok_or_else(|| anyhow!("Missing corelib path. Provide it or set the CORELIB_PATH env."))?;
+
+    let mut db = LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(test_plugin_suite())
+        .build()?;
+    let crate_input = setup_string_crate(&mut db, source);
+    init_dev_corelib(&mut db, corelib_path);
+
+    // The source lives in a virtual file, which would otherwise be treated as generated code.
+    // See the documentation of the function.
+    let params = LinterDiagnosticParams {
+        only_generated_files: true,
+        ..params.clone()
+    };
+
+    let db: &dyn Database = &db;
+    let crate_id = crate_input.into_crate_long_id(db).intern(db);
+    let diagnostics = db
+        .crate_modules(crate_id)
+        .iter()
+        .flat_map(|module_id| {
+            db.linter_diagnostics(params.clone(), *module_id)
+                .iter()
+                .cloned()
+        })
+        .collect();
+
+    Ok(f(db, diagnostics))
+}
+
+/// Returns the configuration of the crate created by [`lint_string`], with the same edition as its
+/// settings.
+fn string_crate_config(edition: Edition) -> String {
+    // The edition serializes to its name, which is a valid TOML string once quoted.
+    let edition = serde_json::to_string(&edition).expect("edition should be serializable");
+    format!("edition = {edition}\n{STRING_CRATE_EXPERIMENTAL_FEATURES}")
+}
+
+/// Sets up a crate consisting of a single virtual `lib.cairo` file with the given content.
+fn setup_string_crate(db: &mut LinterAnalysisDatabase, content: &str) -> CrateInput {
+    let edition = Edition::latest();
+    let settings = CrateSettings {
+        name: None,
+        edition,
+        version: None,
+        dependencies: Default::default(),
+        experimental_features: ExperimentalFeaturesConfig {
+            negative_impls: true,
+            associated_item_constraints: true,
+            coupons: true,
+            user_defined_inline_macros: true,
+            repr_ptrs: true,
+        },
+        cfg_set: Default::default(),
+    };
+    let file = FileInput::Virtual(VirtualFileInput {
+        parent: None,
+        name: "lib.cairo".into(),
+        content: content.into(),
+        code_mappings: [].into(),
+        kind: FileKind::Module,
+        original_item_removed: false,
+    });
+
+    let crate_input = CrateInput::Virtual {
+        name: STRING_CRATE_NAME.into(),
+        file_long_id: file.clone(),
+        settings: string_crate_config(edition),
+        cache_file: None,
+    };
+
+    files_group_input(db)
+        .set_crate_configs(db)
+        .to(Some(OrderedHashMap::from([(
+            crate_input.clone(),
+            CrateConfigurationInput {
+                root: DirectoryInput::Virtual {
+                    files: BTreeMap::from([("lib.cairo".to_string(), file)]),
+                    dirs: Default::default(),
+                },
+                settings,
+                cache_file: None,
+            },
+        )])));
+
+    crate_input
+}
@@ -23,8 +23,9 @@ use cairo_lang_utils::Intern;
 use itertools::Itertools;
 use salsa::Database;
 
+use crate::LinterDiagnosticParams;
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     queries::get_all_inline_macro_calls,
 };
 
@@ -57,6 +58,10 @@ impl Lint for AssertOnConst {
         "assert_on_const"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0055"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary assert on a const value detected."
     }
@@ -64,6 +69,10 @@ impl Lint for AssertOnConst {
     fn kind(&self) -> crate::context::CairoLintKind {
         CairoLintKind::RedundantOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
 }
 
 /// Checks for `assert!`s called on const boolean expressions.
@@ -96,6 +105,7 @@ impl Lint for AssertOnConst {
 pub fn check_assert_on_const<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let functions_with_body = match item {
@@ -0,0 +1,221 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprAssignment, ExprFunctionCallArg, ExprId, TypeId};
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr, ExprBinary};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use super::{
+    ADD, DIV, FELT252, MUL, REM, SIGNED_INTEGER_TYPES, SUB, UNSIGNED_INTEGER_TYPES,
+    function_trait_name_from_fn_id,
+};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::queries::{
+    get_all_assignment_expressions, get_all_function_bodies,
+    syntax_node_to_str_without_all_nested_trivia,
+};
+
+pub struct AssignOpPattern;
+
+/// ## What it does
+///
+/// Checks for assignments of a binary operation result to one of its operands,
+/// which can be written using a compound assignment operator.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let mut counter: u32 = 0;
+///     counter = counter + 1;
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main() {
+///     let mut counter: u32 = 0;
+///     counter += 1;
+/// }
+/// ```
+impl Lint for AssignOpPattern {
+    fn allowed_name(&self) -> &'static str {
+        "assign_op_pattern"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0058"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual implementation of an assign operation. Consider using a compound assignment operator."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::AssignOpPattern
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_assign_op_pattern(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Use a compound assignment operator")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_assign_op_pattern<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let assignment_exprs = get_all_assignment_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for assignment_expr in assignment_exprs.iter() {
+            check_single_assign_op_pattern(db, assignment_expr, arenas, diagnostics);
+        }
+    }
+}
+
+fn check_single_assign_op_pattern<'db>(
+    db: &'db dyn Database,
+    assignment_expr: &ExprAssignment<'db>,
+    arenas: &Arenas<'db>,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[assignment_expr.rhs] else {
+        return;
+    };
+
+    // Only the numeric operations are known to be commutative, e.g. the addition of `ByteArray`s is
+    // a concatenation, and a custom type may not implement the compound assignment at all.
+    let is_commutative = match function_trait_name_from_fn_id(db, &func_call.function).as_str() {
+        ADD | MUL => is_core_numeric_type(db, func_call.ty),
+        SUB | DIV | REM => false,
+        _ => return,
+    };
+
+    let (ExprFunctionCallArg::Value(lhs_id), ExprFunctionCallArg::Value(rhs_id)) =
+        (&func_call.args[0], &func_call.args[1])
+    else {
+        return;
+    };
+
+    // The assignment target is always a variable or a member path, so it can't contain function
+    // calls that would otherwise be evaluated twice.
+    let AstExpr::Binary(assignment) = assignment_expr.stable_ptr.lookup(db) else {
+        return;
+    };
+    let target =
+        syntax_node_to_str_without_all_nested_trivia(db, assignment.lhs(db).as_syntax_node());
+    let operand_text = |expr_id: ExprId| {
+        let operand_node = arenas.exprs[expr_id]
+            .stable_ptr()
+            .lookup(db)
+            .as_syntax_node();
+        syntax_node_to_str_without_all_nested_trivia(db, operand_node)
+    };
+
+    if operand_text(*lhs_id) == target || (is_commutative && operand_text(*rhs_id) == target) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: assignment_expr.stable_ptr.untyped(),
+            message: AssignOpPattern.diagnostic_message().to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
+
+/// Checks if the type is `felt252` or one of the corelib integer types.
+fn is_core_numeric_type(db: &dyn Database, ty: TypeId) -> bool {
+    let type_name = ty.format(db);
+    type_name == FELT252
+        || UNSIGNED_INTEGER_TYPES
+            .iter()
+            .chain(SIGNED_INTEGER_TYPES.iter())
+            .any(|(name, _)| *name == type_name)
+}
+
+/// Rewrites an assignment like `x = x + y` (or `x = y + x` for commutative operators of numeric
+/// types, as only those are reported) into a compound assignment `x += y`.
+///
+/// # Arguments
+///
+/// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+/// * `node` - The `SyntaxNode` containing the assignment expression.
+///
+/// # Returns
+///
+/// An `InternalFix` containing the compound assignment.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_assign_op_pattern<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let assignment = ExprBinary::from_syntax_node(db, node);
+    let target = assignment.lhs(db);
+    let target_text = syntax_node_to_str_without_all_nested_trivia(db, target.as_syntax_node());
+
+    let mut rhs = assignment.rhs(db);
+    while let AstExpr::Parenthesized(parenthesized) = rhs {
+        rhs = parenthesized.expr(db);
+    }
+    let AstExpr::Binary(operation) = rhs else {
+        return None;
+    };
+
+    let (compound_operator, is_commutative) = match operation.op(db) {
+        BinaryOperator::Plus(_) => ("+=", true),
+        BinaryOperator::Mul(_) => ("*=", true),
+        BinaryOperator::Minus(_) => ("-=", false),
+        BinaryOperator::Div(_) => ("/=", false),
+        BinaryOperator::Mod(_) => ("%=", false),
+        _ => return None,
+    };
+
+    let operation_lhs = operation.lhs(db).as_syntax_node();
+    let operation_rhs = operation.rhs(db).as_syntax_node();
+    let operand = if syntax_node_to_str_without_all_nested_trivia(db, operation_lhs) == target_text
+    {
+        operation_rhs
+    } else if is_commutative
+        && syntax_node_to_str_without_all_nested_trivia(db, operation_rhs) == target_text
+    {
+        operation_lhs
+    } else {
+        return None;
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            &format!(
+                "{} {compound_operator} {}",
+                target.as_syntax_node().get_text_without_trivia(db).long(db),
+                operand.get_text_without_trivia(db).long(db)
+            ),
+        ),
+        description: AssignOpPattern.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -1,24 +1,32 @@
 use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleItemId, TopLevelLanguageElementId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::function_with_body::{
+    FunctionWithBodySemantic, SemanticExprLookup,
+};
 use cairo_lang_semantic::items::imp::ImplSemantic;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
-use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_syntax::node::ast::{self, BinaryOperator};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 use num_bigint::BigInt;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
+use crate::fixer::InternalFix;
+use crate::helper::{find_module_containing_node, get_function_with_body_id};
 use crate::queries::{get_all_function_bodies, get_all_function_calls};
 
-use super::AND;
+use super::{AND, UNSIGNED_INTEGER_TYPES};
 use salsa::Database;
 
 pub struct BitwiseForParity;
 
 /// ## What it does
 ///
-/// Checks for `x & 1` which is unoptimized in cairo and could be replaced by `x % 1`.
+/// Checks for `x & 1` which is unoptimized in cairo and could be replaced by `x % 2`.
 ///
 /// ## Example
 ///
@@ -27,11 +35,23 @@ pub struct BitwiseForParity;
 ///     let _a = 200_u32 & 1;
 /// }
 /// ```
+///
+/// Can be replaced with:
+///
+/// ```cairo
+/// fn main() {
+///     let _a = 200_u32 % 2;
+/// }
+/// ```
 impl Lint for BitwiseForParity {
     fn allowed_name(&self) -> &'static str {
         "bitwise_for_parity_check"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0016"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "You seem to be trying to use `&` for parity check. Consider using `DivRem::div_rem()` instead."
     }
@@ -39,6 +59,22 @@ impl Lint for BitwiseForParity {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::BitwiseForParityCheck
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_bitwise_for_parity(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace `& 1` with `% 2`")
+    }
 }
 
 /// Checks for `x & 1` which is unoptimized in cairo and can be replaced by `x % 1`
@@ -46,6 +82,7 @@ impl Lint for BitwiseForParity {
 pub fn check_bitwise_for_parity<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -64,15 +101,32 @@ fn check_single_bitwise_for_parity<'db>(
     arenas: &Arenas<'db>,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
+    if is_bitwise_and_with_one(db, function_call_expr, arenas) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: function_call_expr.stable_ptr.untyped(),
+            message: BitwiseForParity.diagnostic_message().to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
+
+/// Checks if the function call is `x & 1`, with the literal `1` as the mask.
+fn is_bitwise_and_with_one<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
     let Ok(Some(func_id)) = function_call_expr.function.get_concrete(db).body(db) else {
-        return;
+        return false;
     };
     // Get the trait function id of the function (if there's none it means it cannot be a call to
     // `bitand`)
     let trait_fn_id = match func_id.function_with_body_id(db) {
         FunctionWithBodyId::Impl(func) => db.impl_function_trait_function(func).unwrap(),
         FunctionWithBodyId::Trait(func) => func,
-        _ => return,
+        _ => return false,
     };
 
     // From the trait function id get the trait name and check if it's the corelib `BitAnd`
@@ -83,13 +137,84 @@ fn check_single_bitwise_for_parity<'db>(
         if let Expr::Literal(lit) = &arenas.exprs[val];
         if lit.value == BigInt::from(1u8);
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: function_call_expr.stable_ptr.untyped(),
-                message: BitwiseForParity.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                inner_span: None,
-                error_code: None,
-            });
+            true
+        } else {
+            false
         }
     }
 }
+
+/// Rewrites `x & 1` into `x % 2`, if `x` is an unsigned integer.
+/// The parentheses around the expression are dropped if it's compared with `==` or `!=`,
+/// so `(x & 1) == 1` becomes `x % 2 == 1`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_bitwise_for_parity<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let expr_binary = ast::ExprBinary::cast(db, node)?;
+    let module_id = find_module_containing_node(db, node)?;
+    let (function_id, expr_id) = node.ancestors(db).find_map(|ancestor| {
+        let function_id = get_function_with_body_id(db, module_id, ancestor)?;
+        let expr_id = db
+            .lookup_expr_by_ptr(function_id, ast::ExprPtr(node.stable_ptr(db)))
+            .ok()?;
+        Some((function_id, expr_id))
+    })?;
+
+    let Expr::FunctionCall(function_call_expr) = db.expr_semantic(function_id, expr_id) else {
+        return None;
+    };
+    let arenas = &db.function_body(function_id).ok()?.arenas;
+    if !is_bitwise_and_with_one(db, &function_call_expr, arenas) {
+        return None;
+    }
+    let ExprFunctionCallArg::Value(operand) = function_call_expr.args[0] else {
+        return None;
+    };
+    let operand_type = arenas.exprs[operand].ty().format(db);
+    if !UNSIGNED_INTEGER_TYPES
+        .iter()
+        .any(|(name, _)| *name == operand_type)
+    {
+        return None;
+    }
+
+    let lhs = expr_binary.lhs(db);
+    let lhs_text = lhs.as_syntax_node().get_text_without_trivia(db);
+    let lhs_text = lhs_text.long(db);
+    // `%` binds stronger than most of the binary operators, so they need to be kept together.
+    let lhs_text = match &lhs {
+        ast::Expr::Binary(lhs_binary) if !matches!(lhs_binary.op(db), BinaryOperator::Dot(_)) => {
+            format!("({lhs_text})")
+        }
+        _ => lhs_text.to_string(),
+    };
+
+    let replaced_node = node
+        .parent(db)
+        .filter(|parent| parent.kind(db) == SyntaxKind::ExprParenthesized)
+        .filter(|parent| is_equality_operand(db, *parent))
+        .unwrap_or(node);
+
+    Some(InternalFix {
+        node: replaced_node,
+        suggestion: format!("{lhs_text} % 2"),
+        description: BitwiseForParity.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Checks if the node is an operand of `==` or `!=`.
+fn is_equality_operand<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> bool {
+    let Some(parent) = node.parent(db) else {
+        return false;
+    };
+    let Some(binary) = ast::ExprBinary::cast(db, parent) else {
+        return false;
+    };
+    matches!(
+        binary.op(db),
+        BinaryOperator::EqEq(_) | BinaryOperator::Neq(_)
+    )
+}
@@ -5,13 +5,16 @@ use cairo_lang_semantic::items::functions::{GenericFunctionId, ImplGenericFuncti
 use cairo_lang_semantic::items::imp::ImplHead;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
 use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr, ExprBinary};
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast::ExprBinary};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
+use crate::LinterDiagnosticParams;
 use crate::LinterGroup;
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, Lint, LintGroup};
 use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
 use crate::queries::{get_all_function_bodies, get_all_function_calls};
 use salsa::Database;
 
@@ -47,6 +50,10 @@ impl Lint for BoolComparison {
         "bool_comparison"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0010"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary comparison with a boolean value. Use the variable directly."
     }
@@ -55,6 +62,10 @@ impl Lint for BoolComparison {
         CairoLintKind::BoolComparison
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -73,6 +84,7 @@ impl Lint for BoolComparison {
 pub fn check_bool_comparison<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -99,7 +111,7 @@ fn check_single_bool_comparison<'db>(
     {
         GenericFunctionId::Impl(ImplGenericFunctionId { impl_id, .. }) => {
             if let Some(ImplHead::Concrete(impl_def_id)) = impl_id.head(db) {
-                if impl_def_id != db.corelib_context().get_bool_partial_eq_impl_id() {
+                if Some(impl_def_id) != db.corelib_context().get_bool_partial_eq_impl_id() {
                     return;
                 }
             } else {
@@ -131,49 +143,82 @@ fn check_single_bool_comparison<'db>(
 }
 
 /// Rewrites a bool comparison to a simple bool. Ex: `some_bool == false` would be rewritten to
-/// `!some_bool`
+/// `!some_bool`.
+///
+/// Comparisons nested in other expressions (e.g. `(x == true) && (y == false)`, or inside the
+/// arguments of `assert!`) are rewritten as well. If the comparison is a parenthesized operand of
+/// `&&` or `||`, the parentheses are dropped along with it, so the example becomes `x && !y`.
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn fix_bool_comparison<'db>(
     db: &'db dyn Database,
     node: SyntaxNode<'db>,
 ) -> Option<InternalFix<'db>> {
-    let node = ExprBinary::from_syntax_node(db, node);
-    let lhs = node.lhs(db).as_syntax_node().get_text(db);
-    let rhs = node.rhs(db).as_syntax_node().get_text(db);
+    let binary = ExprBinary::cast(db, node)?;
+    let simplified = simplified_comparison(db, &binary)?;
+
+    let replaced_node = node
+        .parent(db)
+        .filter(|parent| parent.kind(db) == SyntaxKind::ExprParenthesized)
+        .filter(|parent| is_logical_operand(db, *parent))
+        .unwrap_or(node);
 
-    let result = generate_fixed_text_for_comparison(db, lhs, rhs, node.clone());
     Some(InternalFix {
-        node: node.as_syntax_node(),
-        suggestion: result,
+        node: replaced_node,
+        suggestion: replace_preserving_trivia(db, replaced_node, &simplified),
         description: BoolComparison.fix_message().unwrap().to_string(),
         import_addition_paths: None,
     })
 }
 
-/// Generates the fixed boolean for a boolean comparison. It will transform `x == false` to `!x`
-fn generate_fixed_text_for_comparison<'db>(
-    db: &'db dyn Database,
-    lhs: &str,
-    rhs: &str,
-    node: ExprBinary<'db>,
-) -> String {
-    let op_kind = node.op(db).as_syntax_node().kind(db);
-    let lhs = lhs.trim();
-    let rhs = rhs.trim();
-
-    match (lhs, rhs, op_kind) {
-        // lhs
-        ("false", _, SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => format!("!{rhs} "),
-        ("true", _, SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => format!("{rhs} "),
-        ("false", _, SyntaxKind::TerminalNeq) => format!("{rhs} "),
-        ("true", _, SyntaxKind::TerminalNeq) => format!("!{rhs} "),
-
-        // rhs
-        (_, "false", SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => format!("!{lhs} "),
-        (_, "true", SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => format!("{lhs} "),
-        (_, "false", SyntaxKind::TerminalNeq) => format!("{lhs} "),
-        (_, "true", SyntaxKind::TerminalNeq) => format!("!{lhs} "),
-
-        _ => node.as_syntax_node().get_text(db).to_string(),
-    }
+/// Simplifies a comparison with a boolean literal. It will transform `x == false` to `!x`.
+fn simplified_comparison<'db>(db: &'db dyn Database, binary: &ExprBinary<'db>) -> Option<String> {
+    let literal_value = |expr: &AstExpr<'db>| match expr {
+        AstExpr::True(_) => Some(true),
+        AstExpr::False(_) => Some(false),
+        _ => None,
+    };
+    let lhs = binary.lhs(db);
+    let rhs = binary.rhs(db);
+    let (operand, literal) = match (literal_value(&lhs), literal_value(&rhs)) {
+        (_, Some(literal)) => (lhs, literal),
+        (Some(literal), None) => (rhs, literal),
+        (None, None) => return None,
+    };
+    let negate = match binary.op(db) {
+        BinaryOperator::EqEq(_) => !literal,
+        BinaryOperator::Neq(_) => literal,
+        _ => return None,
+    };
+
+    let operand_text = operand.as_syntax_node().get_text_without_trivia(db);
+    let operand_text = operand_text.long(db);
+    // The operand is kept together, so the result can be used in place of any other operand.
+    let operand_text = match &operand {
+        AstExpr::Binary(operand_binary)
+            if !matches!(operand_binary.op(db), BinaryOperator::Dot(_)) =>
+        {
+            format!("({operand_text})")
+        }
+        _ => operand_text.to_string(),
+    };
+
+    Some(if negate {
+        format!("!{operand_text}")
+    } else {
+        operand_text
+    })
+}
+
+/// Checks if the node is an operand of `&&` or `||`.
+fn is_logical_operand<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> bool {
+    let Some(parent) = node.parent(db) else {
+        return false;
+    };
+    let Some(binary) = ExprBinary::cast(db, parent) else {
+        return false;
+    };
+    matches!(
+        binary.op(db),
+        BinaryOperator::AndAnd(_) | BinaryOperator::OrOr(_)
+    )
 }
@@ -6,7 +6,8 @@ use cairo_lang_semantic::{Arenas, StatementBreak};
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
 use if_chain::if_chain;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::queries::{get_all_break_statements, get_all_function_bodies};
@@ -42,6 +43,10 @@ impl Lint for BreakUnit {
         "break_unit"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0009"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "unnecessary double parentheses found after break. Consider removing them."
     }
@@ -50,6 +55,10 @@ impl Lint for BreakUnit {
         CairoLintKind::BreakUnit
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -67,6 +76,7 @@ impl Lint for BreakUnit {
 pub fn check_break<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
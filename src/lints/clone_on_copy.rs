@@ -1,13 +1,11 @@
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::LinterGroup;
 use crate::fixer::InternalFix;
-use crate::helper::find_module_containing_node;
+use crate::helper::{find_module_containing_node, get_function_with_body_id};
 use crate::queries::{get_all_function_bodies, get_all_function_calls};
-use cairo_lang_defs::ids::{
-    FreeFunctionLongId, FunctionWithBodyId, ImplFunctionLongId, ModuleId, ModuleItemId,
-    TraitFunctionLongId,
-};
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::items::function_with_body::{
@@ -17,9 +15,7 @@ use cairo_lang_semantic::items::functions::{GenericFunctionId, ImplGenericFuncti
 use cairo_lang_semantic::items::imp::ImplHead;
 use cairo_lang_semantic::types::peel_snapshots;
 use cairo_lang_semantic::{Expr, ExprFunctionCall};
-use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode, ast};
-use cairo_lang_utils::Intern;
 use salsa::Database;
 
 pub struct CloneOnCopy;
@@ -39,6 +35,10 @@ impl Lint for CloneOnCopy {
         "clone_on_copy"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0044"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "using `clone` on type which implements `Copy` trait"
     }
@@ -47,6 +47,10 @@ impl Lint for CloneOnCopy {
         CairoLintKind::CloneOnCopy
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -64,6 +68,7 @@ impl Lint for CloneOnCopy {
 pub fn check_clone_on_copy<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -85,7 +90,7 @@ fn check_clone_usage<'db>(
         .get_concrete(db)
         .generic_function
         && let Some(ImplHead::Concrete(impl_def_id)) = impl_id.head(db)
-        && impl_def_id == db.corelib_context().get_t_copy_clone_impl_id()
+        && Some(impl_def_id) == db.corelib_context().get_t_copy_clone_impl_id()
     {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: function_call_expr.stable_ptr.untyped(),
@@ -172,31 +177,3 @@ fn get_expr_semantic<'db>(
                 .map(|id| db.expr_semantic(function_id, id))
         })
 }
-
-fn get_function_with_body_id<'db>(
-    db: &'db dyn Database,
-    module_id: ModuleId<'db>,
-    ancestor: SyntaxNode<'db>,
-) -> Option<FunctionWithBodyId<'db>> {
-    if let Some(trait_func) = ast::TraitItemFunction::cast(db, ancestor) {
-        let ptr = trait_func.stable_ptr(db);
-        Some(FunctionWithBodyId::Trait(
-            TraitFunctionLongId(module_id, ptr).intern(db),
-        ))
-    } else if let Some(func_with_body) = ast::FunctionWithBody::cast(db, ancestor) {
-        let ptr = func_with_body.stable_ptr(db);
-
-        let function_with_body_id = if ancestor
-            .ancestor_of_kind(db, SyntaxKind::ItemImpl)
-            .is_some()
-        {
-            FunctionWithBodyId::Impl(ImplFunctionLongId(module_id, ptr).intern(db))
-        } else {
-            FunctionWithBodyId::Free(FreeFunctionLongId(module_id, ptr).intern(db))
-        };
-
-        Some(function_with_body_id)
-    } else {
-        None
-    }
-}
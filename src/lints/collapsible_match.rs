@@ -5,9 +5,11 @@ use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode, ast};
 use indoc::formatdoc;
 use salsa::Database;
 
+use crate::LinterDiagnosticParams;
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     fixer::InternalFix,
+    lints::ifs::manual_flatten::is_manual_flatten,
     lints::manual::helpers::extract_pattern_variable,
     queries::{get_all_function_bodies, get_all_match_expressions},
 };
@@ -47,6 +49,10 @@ impl Lint for CollapsibleMatch {
         "collapsible_match"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0052"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Nested `match` statements can be collapsed into a single `match` statement."
     }
@@ -55,6 +61,10 @@ impl Lint for CollapsibleMatch {
         CairoLintKind::CollapsibleMatch
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -72,6 +82,7 @@ impl Lint for CollapsibleMatch {
 pub fn check_collapsible_match<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -96,6 +107,11 @@ fn check_single_collapsible_match<'db>(
         return;
     }
 
+    // Matches which only flatten a nested `Option` are reported by `manual_flatten`.
+    if is_manual_flatten(db, match_expr, arenas) {
+        return;
+    }
+
     let first_arm = &arms[0];
     let second_arm = &arms[1];
 
@@ -243,7 +259,7 @@ fn get_inner_arm_with_same_expression_as_outer_match_arm<'db>(
 }
 
 /// Gets the inner match expression from a match arm if only the inner match is the only expression.
-fn get_inner_match_expression_if_single_one<'db>(
+pub fn get_inner_match_expression_if_single_one<'db>(
     match_arm: &'db MatchArm,
     arenas: &'db Arenas<'db>,
 ) -> Option<&'db ExprMatch<'db>> {
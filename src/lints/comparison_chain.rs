@@ -0,0 +1,177 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprIf};
+use cairo_lang_syntax::node::ast::Expr as AstExpr;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::helper::is_side_effect_free_syntax;
+use crate::lints::{EQ, GT, LT, function_trait_name_from_fn_id};
+use crate::queries::{
+    get_all_function_bodies, get_all_if_expressions, syntax_node_to_str_without_all_nested_trivia,
+};
+
+pub struct ComparisonChain;
+
+/// ## What it does
+///
+/// Checks for `if`/`else if`/`else` chains where every condition compares the same two operands
+/// with `<`, `>` or `==`, each branch handling a different ordering of them.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(a: u32, b: u32) -> felt252 {
+///     if a > b {
+///         'greater'
+///     } else if a < b {
+///         'less'
+///     } else {
+///         'equal'
+///     }
+/// }
+/// ```
+impl Lint for ComparisonChain {
+    fn allowed_name(&self) -> &'static str {
+        "comparison_chain"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0095"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "`if` chain comparing the same operands detected. Consider matching on the ordering of the operands instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ComparisonChain
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+/// How the left-hand side relates to the right-hand side when a condition holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ordering {
+    Less,
+    Equal,
+    Greater,
+}
+
+impl Ordering {
+    fn reverse(self) -> Self {
+        match self {
+            Ordering::Less => Ordering::Greater,
+            Ordering::Equal => Ordering::Equal,
+            Ordering::Greater => Ordering::Less,
+        }
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_comparison_chain<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for if_expr in get_all_if_expressions(function_body) {
+            // An `else if` is checked as a part of the chain starting at the outermost `if`.
+            let is_else_if = if_expr
+                .stable_ptr
+                .lookup(db)
+                .as_syntax_node()
+                .parent(db)
+                .is_some_and(|parent| parent.kind(db) == SyntaxKind::ElseClause);
+            if is_else_if || !is_comparison_chain(db, &if_expr, arenas) {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: if_expr.stable_ptr.untyped(),
+                message: ComparisonChain.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Checks if the `if` expression starts a chain of at least two conditions ending with an `else`
+/// block, where every condition compares the same operands for a different ordering.
+fn is_comparison_chain<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let mut operands: Option<(String, String)> = None;
+    let mut orderings = vec![];
+    let mut current_if = if_expr;
+    loop {
+        let Some((lhs, rhs, ordering)) = comparison_of_condition(db, current_if, arenas) else {
+            return false;
+        };
+        let ordering = match &operands {
+            None => ordering,
+            Some((first_lhs, first_rhs)) if *first_lhs == lhs && *first_rhs == rhs => ordering,
+            Some((first_lhs, first_rhs)) if *first_lhs == rhs && *first_rhs == lhs => {
+                ordering.reverse()
+            }
+            Some(_) => return false,
+        };
+        if orderings.contains(&ordering) {
+            return false;
+        }
+        orderings.push(ordering);
+        operands.get_or_insert((lhs, rhs));
+
+        let Some(else_block) = current_if.else_block else {
+            return false;
+        };
+        match &arenas.exprs[else_block] {
+            Expr::If(else_if) => current_if = else_if,
+            _ => return orderings.len() >= 2,
+        }
+    }
+}
+
+/// Returns the operands of the `<`, `>` or `==` comparison being the only condition of the `if`,
+/// along with the ordering it checks for. Both operands have to be free of side effects.
+fn comparison_of_condition<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<(String, String, Ordering)> {
+    let [Condition::BoolExpr(condition)] = &if_expr.conditions[..] else {
+        return None;
+    };
+    let Expr::FunctionCall(func_call) = &arenas.exprs[*condition] else {
+        return None;
+    };
+    let ordering = match function_trait_name_from_fn_id(db, &func_call.function).as_str() {
+        LT => Ordering::Less,
+        GT => Ordering::Greater,
+        EQ => Ordering::Equal,
+        _ => return None,
+    };
+    let AstExpr::Binary(comparison) = func_call.stable_ptr.lookup(db) else {
+        return None;
+    };
+    let (lhs, rhs) = (comparison.lhs(db), comparison.rhs(db));
+    if !is_side_effect_free_syntax(db, &lhs) || !is_side_effect_free_syntax(db, &rhs) {
+        return None;
+    }
+    let lhs = syntax_node_to_str_without_all_nested_trivia(db, lhs.as_syntax_node());
+    let rhs = syntax_node_to_str_without_all_nested_trivia(db, rhs.as_syntax_node());
+    (lhs != rhs).then_some((lhs, rhs, ordering))
+}
@@ -11,7 +11,8 @@ use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr};
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 
 use super::function_trait_name_from_fn_id;
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::lints::{EQ, GE, GT, LE, LT};
@@ -41,6 +42,10 @@ impl Lint for ImpossibleComparison {
         "impossible_comparison"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0004"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Impossible condition, always false"
     }
@@ -48,6 +53,10 @@ impl Lint for ImpossibleComparison {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::ImpossibleComparison
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
 }
 
 pub struct SimplifiableComparison;
@@ -89,6 +98,10 @@ impl Lint for SimplifiableComparison {
         "simplifiable_comparison"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0005"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This double comparison can be simplified."
     }
@@ -97,6 +110,10 @@ impl Lint for SimplifiableComparison {
         CairoLintKind::DoubleComparison
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -144,6 +161,10 @@ impl Lint for RedundantComparison {
         "redundant_comparison"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0006"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Redundant double comparison found. Consider simplifying to a single comparison."
     }
@@ -152,6 +173,10 @@ impl Lint for RedundantComparison {
         CairoLintKind::DoubleComparison
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -199,6 +224,10 @@ impl Lint for ContradictoryComparison {
         "contradictory_comparison"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0007"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This double comparison is contradictory and always false."
     }
@@ -207,6 +236,10 @@ impl Lint for ContradictoryComparison {
         CairoLintKind::DoubleComparison
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -224,6 +257,7 @@ impl Lint for ContradictoryComparison {
 pub fn check_double_comparison<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
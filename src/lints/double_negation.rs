@@ -0,0 +1,171 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_syntax::node::ast::{Expr, ExprUnary, UnaryOperator};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{leading_trivia, replace_preserving_trivia};
+use crate::queries::get_all_unary_expressions;
+
+pub struct DoubleNegation;
+
+/// ## What it does
+///
+/// Checks for double negations, such as `!!b` or `--x`, which cancel each other out.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let b = true;
+///     let _c = !!b;
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main() {
+///     let b = true;
+///     let _c = b;
+/// }
+/// ```
+impl Lint for DoubleNegation {
+    fn allowed_name(&self) -> &'static str {
+        "double_negation"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0074"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Double negation has no effect. Consider removing both operators."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::DoubleNegation
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_double_negation(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Remove the double negation")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_double_negation<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    for unary_expr in get_all_unary_expressions(db, item) {
+        // In a chain like `---x`, only the outermost pair is reported.
+        let is_negated_by_parent = unary_expr
+            .as_syntax_node()
+            .parent(db)
+            .and_then(|parent| ExprUnary::cast(db, parent))
+            .is_some_and(|parent| is_double_negation(db, &parent));
+
+        if is_double_negation(db, &unary_expr) && !is_negated_by_parent {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: unary_expr.stable_ptr(db).untyped(),
+                message: DoubleNegation.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Checks if the operand of the `!` or `-` unary expression is negated by the same operator.
+fn is_double_negation<'db>(db: &'db dyn Database, unary_expr: &ExprUnary<'db>) -> bool {
+    let Expr::Unary(inner_expr) = unary_expr.expr(db) else {
+        return false;
+    };
+    matches!(
+        (unary_expr.op(db), inner_expr.op(db)),
+        (UnaryOperator::Not(_), UnaryOperator::Not(_))
+            | (UnaryOperator::Minus(_), UnaryOperator::Minus(_))
+    )
+}
+
+/// Replaces the double negation with the negated expression.
+/// The parentheses around the expression are kept only if it's a part of another operation.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_double_negation<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let unary_expr = ExprUnary::from_syntax_node(db, node);
+    let Expr::Unary(inner_expr) = unary_expr.expr(db) else {
+        return None;
+    };
+    let mut expr = inner_expr.expr(db);
+
+    let is_operand = node.parent(db).is_some_and(|parent| {
+        matches!(
+            parent.kind(db),
+            SyntaxKind::ExprBinary | SyntaxKind::ExprUnary
+        )
+    });
+    if !is_operand && let Expr::Parenthesized(parenthesized_expr) = &expr {
+        expr = parenthesized_expr.expr(db);
+    }
+
+    let expr_text = expr.as_syntax_node().get_text_without_trivia(db);
+    let expr_text = expr_text.long(db);
+    // E.g. in `a----5` the remaining `-5` is kept apart from the preceding `-`.
+    let separator = if leading_trivia(db, node).is_empty()
+        && let Some(first_char @ ('-' | '!')) = expr_text.chars().next()
+        && preceding_char(db, node) == Some(first_char)
+    {
+        " "
+    } else {
+        ""
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &format!("{separator}{expr_text}")),
+        description: DoubleNegation.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Returns the character directly preceding the node in the file.
+fn preceding_char<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<char> {
+    let start = node.span(db).start;
+    let ancestor = node
+        .ancestors(db)
+        .find(|ancestor| ancestor.span(db).start < start)?;
+    ancestor
+        .get_text_of_span(
+            db,
+            TextSpan {
+                start: ancestor.span(db).start,
+                end: start,
+            },
+        )
+        .chars()
+        .last()
+}
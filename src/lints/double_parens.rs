@@ -1,4 +1,5 @@
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::helper::indent_snippet;
@@ -38,6 +39,10 @@ impl Lint for DoubleParens {
         "double_parens"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0003"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "unnecessary double parentheses found. Consider removing them."
     }
@@ -46,6 +51,10 @@ impl Lint for DoubleParens {
         CairoLintKind::DoubleParens
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -63,6 +72,7 @@ impl Lint for DoubleParens {
 pub fn check_double_parens<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let parenthesized_exprs = get_all_parenthesized_expressions(db, item);
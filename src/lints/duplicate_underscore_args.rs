@@ -1,9 +1,15 @@
 use std::collections::HashSet;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
 use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
 use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::items::function_with_body::FunctionWithBodySemantic;
+use cairo_lang_syntax::node::ast::{BinaryOperator, ExprBinary, ExprPath, Param, ParamList};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
 
 use crate::queries::get_all_checkable_functions;
 use salsa::Database;
@@ -21,11 +27,21 @@ pub struct DuplicateUnderscoreArgs;
 /// ```cairo
 /// fn foo(test: u32, _test: u32) {}
 /// ```
+///
+/// The fixer renames the underscore-prefixed argument:
+///
+/// ```cairo
+/// fn foo(test: u32, _test2: u32) {}
+/// ```
 impl Lint for DuplicateUnderscoreArgs {
     fn allowed_name(&self) -> &'static str {
         "duplicate_underscore_args"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0013"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "duplicate arguments, having another argument having almost the same name \
                                              makes code comprehension and documentation more difficult"
@@ -34,12 +50,29 @@ impl Lint for DuplicateUnderscoreArgs {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::DuplicateUnderscoreArgs
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_duplicate_underscore_args(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Rename the underscore-prefixed argument")
+    }
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn check_duplicate_underscore_args<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let functions = get_all_checkable_functions(db, item);
@@ -68,3 +101,124 @@ pub fn check_duplicate_underscore_args<'db>(
         }
     }
 }
+
+/// Renames the underscore-prefixed argument of the colliding pair by appending a numeric suffix
+/// to it, and updates all of its uses within the function body.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_duplicate_underscore_args<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let param_list_node = node.ancestor_of_kind(db, SyntaxKind::ParamList)?;
+    let params: Vec<Param> = ParamList::from_syntax_node(db, param_list_node)
+        .elements(db)
+        .collect();
+    let diagnosed_name = node.get_text_without_trivia(db).long(db).to_string();
+
+    // The diagnostic can point at either argument of the pair, but only the one prefixed with an
+    // underscore gets renamed.
+    let old_name = if diagnosed_name.starts_with('_') {
+        diagnosed_name
+    } else {
+        format!("_{diagnosed_name}")
+    };
+    let renamed_param = params
+        .iter()
+        .find(|param| param.name(db).text(db).to_string(db) == old_name)?
+        .name(db)
+        .as_syntax_node();
+
+    let function_node = param_list_node.ancestors(db).find(|ancestor| {
+        matches!(
+            ancestor.kind(db),
+            SyntaxKind::FunctionWithBody | SyntaxKind::TraitItemFunction
+        )
+    })?;
+
+    let identifiers: Vec<SyntaxNode> = function_node
+        .descendants(db)
+        .filter(|descendant| descendant.kind(db) == SyntaxKind::TerminalIdentifier)
+        .collect();
+
+    let mut nodes_to_rename = Vec::new();
+    for identifier in identifiers.iter() {
+        if identifier.get_text_without_trivia(db).long(db).as_str() != old_name {
+            continue;
+        }
+        if *identifier == renamed_param || is_variable_reference(db, *identifier) {
+            nodes_to_rename.push(*identifier);
+        } else if identifier
+            .ancestor_of_kind(db, SyntaxKind::ExprBlock)
+            .is_some()
+        {
+            // The name is rebound or used in a way that can't be renamed safely, e.g. shadowed
+            // by a `let` binding.
+            return None;
+        }
+    }
+
+    let new_name = (2..)
+        .map(|suffix| format!("{old_name}{suffix}"))
+        .find(|candidate| {
+            identifiers.iter().all(|identifier| {
+                identifier.get_text_without_trivia(db).long(db).as_str() != candidate.as_str()
+            })
+        })?;
+
+    let span = function_node.span(db);
+    let mut suggestion = String::new();
+    let mut position = span.start;
+    for identifier in nodes_to_rename {
+        let identifier_span = identifier.span_without_trivia(db);
+        suggestion.push_str(&function_node.get_text_of_span(
+            db,
+            TextSpan {
+                start: position,
+                end: identifier_span.start,
+            },
+        ));
+        suggestion.push_str(&new_name);
+        position = identifier_span.end;
+    }
+    suggestion.push_str(&function_node.get_text_of_span(
+        db,
+        TextSpan {
+            start: position,
+            end: span.end,
+        },
+    ));
+
+    Some(InternalFix {
+        node: function_node,
+        suggestion,
+        description: DuplicateUnderscoreArgs.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Checks if the identifier is a single-segment path referring to a variable, as opposed to e.g.
+/// a member access.
+fn is_variable_reference<'db>(db: &'db dyn Database, identifier: SyntaxNode<'db>) -> bool {
+    let Some(path_node) = identifier.ancestor_of_kind(db, SyntaxKind::ExprPath) else {
+        return false;
+    };
+    if identifier.parent(db).map(|parent| parent.kind(db)) != Some(SyntaxKind::PathSegmentSimple) {
+        return false;
+    }
+    if ExprPath::from_syntax_node(db, path_node)
+        .segments(db)
+        .elements(db)
+        .len()
+        != 1
+    {
+        return false;
+    }
+    match path_node.parent(db) {
+        Some(parent) if parent.kind(db) == SyntaxKind::ExprBinary => {
+            let binary = ExprBinary::from_syntax_node(db, parent);
+            !(matches!(binary.op(db), BinaryOperator::Dot(_))
+                && binary.rhs(db).as_syntax_node() == path_node)
+        }
+        _ => true,
+    }
+}
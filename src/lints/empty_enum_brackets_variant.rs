@@ -1,13 +1,15 @@
 use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
 use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::items::enm::EnumSemantic;
 use cairo_lang_syntax::node::{
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
     ast::{self, OptionTypeClause},
 };
 
+use crate::LinterDiagnosticParams;
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     fixer::InternalFix,
 };
 use salsa::Database;
@@ -40,6 +42,10 @@ impl Lint for EmptyEnumBracketsVariant {
         "empty_enum_brackets_variant"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0045"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "redundant parentheses in enum variant definition"
     }
@@ -48,6 +54,10 @@ impl Lint for EmptyEnumBracketsVariant {
         CairoLintKind::EnumEmptyVariantBrackets
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -65,6 +75,7 @@ impl Lint for EmptyEnumBracketsVariant {
 pub fn check_empty_enum_brackets_variant<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let ModuleItemId::Enum(enum_id) = item else {
@@ -98,25 +109,38 @@ pub fn check_empty_enum_brackets_variant<'db>(
     }
 }
 
+/// Removes the type clause from the variant declaration, e.g. `Empty: ()` becomes `Empty`.
+/// The comments around the variant are kept.
 #[tracing::instrument(skip_all, level = "trace")]
 fn fix_empty_enum_brackets_variant<'db>(
     db: &'db dyn Database,
     node: SyntaxNode<'db>,
 ) -> Option<InternalFix<'db>> {
     let ast_variant = ast::Variant::from_syntax_node(db, node);
+    let OptionTypeClause::TypeClause(type_clause) = ast_variant.type_clause(db) else {
+        return None;
+    };
 
-    // Extract a clean type definition, to remove
-    let type_clause = ast_variant
-        .type_clause(db)
-        .as_syntax_node()
-        .get_text_without_trivia(db);
-
-    let variant_text = node.get_text(db);
-    let fixed = variant_text.replace(type_clause.long(db).as_str(), "");
+    let variant_span = node.span(db);
+    let type_clause_span = type_clause.as_syntax_node().span_without_trivia(db);
+    let before_type_clause = node.get_text_of_span(
+        db,
+        TextSpan {
+            start: variant_span.start,
+            end: type_clause_span.start,
+        },
+    );
+    let after_type_clause = node.get_text_of_span(
+        db,
+        TextSpan {
+            start: type_clause_span.end,
+            end: variant_span.end,
+        },
+    );
 
     Some(InternalFix {
         node,
-        suggestion: fixed,
+        suggestion: format!("{}{after_type_clause}", before_type_clause.trim_end()),
         description: EmptyEnumBracketsVariant.fix_message().unwrap().to_string(),
         import_addition_paths: None,
     })
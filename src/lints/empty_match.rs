@@ -0,0 +1,159 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, ExprMatch, Pattern};
+use cairo_lang_syntax::node::ast::ExprMatch as AstExprMatch;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{is_side_effect_free_syntax, leading_trivia, replace_preserving_trivia};
+use crate::lints::single_match::is_expr_unit;
+use crate::queries::{get_all_function_bodies, get_all_match_expressions};
+
+pub struct UselessMatch;
+
+/// ## What it does
+///
+/// Checks for `match` expressions without arms, or with a single wildcard arm that does nothing.
+/// Unlike `destruct_match` and `equality_match`, which look for a single meaningful arm, such a
+/// `match` doesn't do anything at all.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let x: Option<u32> = Option::Some(1);
+///     match x {
+///         _ => (),
+///     }
+/// }
+/// ```
+///
+/// Can be removed, keeping the matched expression only if it can have side effects:
+///
+/// ```cairo
+/// fn main() {
+///     let x: Option<u32> = Option::Some(1);
+/// }
+/// ```
+impl Lint for UselessMatch {
+    fn allowed_name(&self) -> &'static str {
+        "empty_match"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0072"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "This `match` does nothing. Consider removing it."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UselessMatch
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_useless_match(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Remove the useless `match`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_useless_match<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies {
+        let match_exprs = get_all_match_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for match_expr in match_exprs.iter() {
+            if is_useless_match(db, match_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: match_expr.stable_ptr.untyped(),
+                    message: UselessMatch.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+fn is_useless_match<'db>(
+    db: &'db dyn Database,
+    match_expr: &ExprMatch<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    match &match_expr.arms[..] {
+        [] => true,
+        [arm] => {
+            matches!(
+                &arm.patterns[..],
+                [pattern] if matches!(arenas.patterns[*pattern], Pattern::Otherwise(_))
+            ) && is_expr_unit(arenas.exprs[arm.expression].stable_ptr().lookup(db), db)
+        }
+        _ => false,
+    }
+}
+
+/// Removes the `match` statement. If the matched expression can have side effects, it's kept as
+/// a statement on its own.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_useless_match<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let matched_expr = AstExprMatch::from_syntax_node(db, node).expr(db);
+    // Only a `match` used as a statement can be removed, as otherwise its value is used.
+    let statement_node = node.parent(db)?;
+    if statement_node.kind(db) != SyntaxKind::StatementExpr {
+        return None;
+    }
+
+    let suggestion = if !is_side_effect_free_syntax(db, &matched_expr) {
+        replace_preserving_trivia(
+            db,
+            statement_node,
+            &format!(
+                "{};",
+                matched_expr
+                    .as_syntax_node()
+                    .get_text_without_trivia(db)
+                    .long(db),
+            ),
+        )
+    } else {
+        // Drop the whole line of the statement, but keep the comments preceding it.
+        leading_trivia(db, statement_node)
+            .rsplit_once('\n')
+            .map(|(comments, _indentation)| format!("{comments}\n"))
+            .unwrap_or_default()
+    };
+
+    Some(InternalFix {
+        node: statement_node,
+        suggestion,
+        description: UselessMatch.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -0,0 +1,175 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
+use cairo_lang_syntax::node::ast::{self, BinaryOperator};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::lints::{BYTE_ARRAY, EQ, NE, function_trait_name_from_fn_id};
+use crate::queries::{get_all_function_bodies, get_all_function_calls};
+
+pub struct EmptyStringComparison;
+
+/// ## What it does
+///
+/// Checks for comparisons of a `ByteArray` with an empty string literal.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let s: ByteArray = "abc";
+///     if s == "" {
+///         println!("empty");
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main() {
+///     let s: ByteArray = "abc";
+///     if s.len() == 0 {
+///         println!("empty");
+///     }
+/// }
+/// ```
+impl Lint for EmptyStringComparison {
+    fn allowed_name(&self) -> &'static str {
+        "empty_string_comparison"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0075"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Comparison with an empty string. Consider checking the length of the string instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::EmptyStringComparison
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_empty_string_comparison(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Compare the length of the string with 0")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_empty_string_comparison<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let function_call_exprs = get_all_function_calls(function_body);
+        let arenas = &function_body.arenas;
+        for function_call_expr in function_call_exprs {
+            if is_empty_string_comparison(db, &function_call_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: function_call_expr.stable_ptr.untyped(),
+                    message: EmptyStringComparison.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the function call is `==` or `!=` on `ByteArray`s, with one of the operands being
+/// the empty string literal.
+fn is_empty_string_comparison<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let function_name = function_trait_name_from_fn_id(db, &function_call_expr.function);
+    if ![EQ, NE].contains(&function_name.as_str()) {
+        return false;
+    }
+
+    function_call_expr.args.iter().any(|arg| {
+        let ExprFunctionCallArg::Value(expr_id) = arg else {
+            return false;
+        };
+        let expr = match &arenas.exprs[*expr_id] {
+            Expr::Snapshot(snapshot) => &arenas.exprs[snapshot.inner],
+            expr => expr,
+        };
+        // Short strings, such as `''`, are `felt252` literals, not string literals.
+        matches!(expr, Expr::StringLiteral(literal) if literal.value.is_empty())
+            && expr.ty().format(db) == BYTE_ARRAY
+    })
+}
+
+/// Rewrites `s == ""` to `s.len() == 0` and `s != ""` to `s.len() != 0`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_empty_string_comparison<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let expr_binary = ast::ExprBinary::cast(db, node)?;
+    let operator = match expr_binary.op(db) {
+        BinaryOperator::EqEq(_) => "==",
+        BinaryOperator::Neq(_) => "!=",
+        _ => return None,
+    };
+
+    let is_empty_string = |expr: &ast::Expr<'db>| {
+        matches!(expr, ast::Expr::String(_))
+            && expr
+                .as_syntax_node()
+                .get_text_without_trivia(db)
+                .long(db)
+                .as_str()
+                == "\"\""
+    };
+    let (lhs, rhs) = (expr_binary.lhs(db), expr_binary.rhs(db));
+    let string_expr = if is_empty_string(&rhs) {
+        lhs
+    } else if is_empty_string(&lhs) {
+        rhs
+    } else {
+        return None;
+    };
+
+    let string_text = string_expr.as_syntax_node().get_text_without_trivia(db);
+    let string_text = string_text.long(db);
+    // The method call binds stronger than the other operators.
+    let string_text = match &string_expr {
+        ast::Expr::Binary(binary) if !matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+            format!("({string_text})")
+        }
+        ast::Expr::Unary(_) => format!("({string_text})"),
+        _ => string_text.to_string(),
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: format!("{string_text}.len() {operator} 0"),
+        description: EmptyStringComparison.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
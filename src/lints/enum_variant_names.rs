@@ -1,14 +1,22 @@
-use crate::context::{CairoLintKind, Lint};
+use std::collections::{HashMap, HashSet};
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::items::enm::EnumSemantic;
 
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{
-    SyntaxNode, Terminal, TypedSyntaxNode, ast::ItemEnum as AstEnumItem,
+    SyntaxNode, Terminal, TypedSyntaxNode,
+    ast::{ExprPath, ItemEnum as AstEnumItem, Visibility},
 };
+use itertools::Itertools;
 use salsa::Database;
 
 pub struct EnumVariantNames;
@@ -36,11 +44,20 @@ pub struct EnumVariantNames;
 ///     Battenberg,
 /// }
 /// ```
+///
+/// The fix renames the variants along with the paths to them, rewriting the code from the enum
+/// to its last use at once. Other fixes within that code are applied after re-linting the file.
+/// No fix is proposed for public enums, nor for variants imported with `use`, as their uses
+/// can't all be renamed.
 impl Lint for EnumVariantNames {
     fn allowed_name(&self) -> &'static str {
         "enum_variant_names"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0043"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "All enum variants are prefixed or suffixed by the same characters."
     }
@@ -49,6 +66,10 @@ impl Lint for EnumVariantNames {
         CairoLintKind::EnumVariantNames
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn is_enabled(&self) -> bool {
         false
     }
@@ -70,6 +91,7 @@ impl Lint for EnumVariantNames {
 pub fn check_enum_variant_names<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let ModuleItemId::Enum(enum_id) = item else {
@@ -93,50 +115,142 @@ pub fn check_enum_variant_names<'db>(
     }
 }
 
+/// Strips the common prefix and suffix from the variants of the enum, and renames the paths to the
+/// variants in the file accordingly.
+///
+/// No fix is proposed if two variants would end up with the same name, or if the variants could
+/// be referenced in a way that can't be renamed safely: from another file (the enum is public) or
+/// through a `use` import.
 #[tracing::instrument(skip_all, level = "trace")]
 fn fix_enum_variant_names<'db>(
     db: &'db dyn Database,
     node: SyntaxNode<'db>,
 ) -> Option<InternalFix<'db>> {
     let enum_item = AstEnumItem::from_syntax_node(db, node);
-
-    let source = enum_item.as_syntax_node().get_text(db);
-    let variants = enum_item.variants(db).elements(db);
-
+    if !matches!(enum_item.visibility(db), Visibility::Default(_)) {
+        return None;
+    }
+    let enum_name = enum_item.name(db).text(db).to_string(db);
+    let variants: Vec<_> = enum_item.variants(db).elements(db).collect();
     let variant_names: Vec<String> = variants
+        .iter()
         .map(|v| v.name(db).text(db).to_string(db))
         .collect();
 
     let (prefixes, suffixes) = get_prefix_and_suffix(&variant_names);
+    let new_names: HashMap<&str, String> = variant_names
+        .iter()
+        .map(|name| (name.as_str(), strip_affixes(name, &prefixes, &suffixes)))
+        .collect();
+    let unique_new_names: HashSet<&String> = new_names.values().collect();
+    if unique_new_names.len() != variant_names.len()
+        || new_names
+            .values()
+            .any(|name| !name.starts_with(|c: char| c.is_alphabetic()))
+    {
+        return None;
+    }
 
-    let mut fixed_enum = source.to_string();
-
-    for variant in &variant_names {
-        let mut fixed_name = variant.clone();
-
-        for prefix in &prefixes {
-            if let Some(stripped) = fixed_name.strip_prefix(prefix) {
-                fixed_name = stripped.to_string();
+    let file_node = node.ancestors(db).last()?;
+    let mut identifiers_to_rename: Vec<(SyntaxNode, &str)> = variants
+        .iter()
+        .zip(&variant_names)
+        .map(|(variant, name)| {
+            (
+                variant.name(db).as_syntax_node(),
+                new_names[name.as_str()].as_str(),
+            )
+        })
+        .collect();
+    // Paths to the variants, e.g. `Cake::BlackForestCake`.
+    for path_node in file_node
+        .descendants(db)
+        .filter(|descendant| descendant.kind(db) == SyntaxKind::ExprPath)
+    {
+        let segments: Vec<_> = ExprPath::from_syntax_node(db, path_node)
+            .segments(db)
+            .elements(db)
+            .collect();
+        for (enum_segment, variant_segment) in segments.iter().tuple_windows() {
+            if enum_segment.identifier(db).long(db).as_str() != enum_name {
+                continue;
             }
-        }
-
-        for suffix in &suffixes {
-            if let Some(stripped) = fixed_name.strip_suffix(suffix) {
-                fixed_name = stripped.to_string();
+            if let Some(new_name) = new_names.get(variant_segment.identifier(db).long(db).as_str())
+            {
+                identifiers_to_rename.push((
+                    variant_segment.identifier_ast(db).as_syntax_node(),
+                    new_name.as_str(),
+                ));
             }
         }
+    }
+    // Any other use of the old names, e.g. an imported variant, can't be renamed safely.
+    let old_names_count = file_node
+        .descendants(db)
+        .filter(|descendant| {
+            descendant.kind(db) == SyntaxKind::TerminalIdentifier
+                && new_names.contains_key(descendant.get_text_without_trivia(db).long(db).as_str())
+        })
+        .count();
+    if old_names_count != identifiers_to_rename.len() {
+        return None;
+    }
 
-        fixed_enum = fixed_enum.replace(variant, &fixed_name);
+    identifiers_to_rename.sort_by_key(|(identifier, _)| identifier.span_without_trivia(db).start);
+    // Only the code spanning the renamed identifiers is replaced, so that the fix doesn't overlap
+    // with the fixes of the rest of the file.
+    let (first_identifier, _) = identifiers_to_rename.first()?;
+    let (last_identifier, _) = identifiers_to_rename.last()?;
+    let last_end = last_identifier.span_without_trivia(db).end;
+    let fix_node = first_identifier
+        .ancestors(db)
+        .find(|ancestor| ancestor.span(db).end >= last_end)?;
+    let span = fix_node.span(db);
+    let mut suggestion = String::new();
+    let mut position = span.start;
+    for (identifier, new_name) in identifiers_to_rename {
+        let identifier_span = identifier.span_without_trivia(db);
+        suggestion.push_str(&fix_node.get_text_of_span(
+            db,
+            TextSpan {
+                start: position,
+                end: identifier_span.start,
+            },
+        ));
+        suggestion.push_str(new_name);
+        position = identifier_span.end;
     }
+    suggestion.push_str(&fix_node.get_text_of_span(
+        db,
+        TextSpan {
+            start: position,
+            end: span.end,
+        },
+    ));
 
     Some(InternalFix {
-        node,
-        suggestion: fixed_enum,
+        node: fix_node,
+        suggestion,
         description: EnumVariantNames.fix_message().unwrap().to_string(),
         import_addition_paths: None,
     })
 }
 
+/// Strips the prefix and suffix words from the variant name, along with the `_` separating them
+/// from the rest of the name.
+fn strip_affixes(name: &str, prefixes: &[String], suffixes: &[String]) -> String {
+    let mut stripped = name;
+    for prefix in prefixes {
+        stripped = stripped.trim_start_matches('_');
+        stripped = stripped.strip_prefix(prefix.as_str()).unwrap_or(stripped);
+    }
+    for suffix in suffixes {
+        stripped = stripped.trim_end_matches('_');
+        stripped = stripped.strip_suffix(suffix.as_str()).unwrap_or(stripped);
+    }
+    stripped.trim_matches('_').to_string()
+}
+
 fn get_prefix_and_suffix(variant_names: &[String]) -> (Vec<String>, Vec<String>) {
     let Some(first) = variant_names.first() else {
         return (vec![], vec![]);
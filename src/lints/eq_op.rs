@@ -3,11 +3,16 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
 
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr};
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
+use crate::fixer::InternalFix;
+use crate::helper::is_side_effect_free_syntax;
+use crate::helper::replace_preserving_trivia;
 use crate::queries::{get_all_function_bodies, get_all_function_calls};
 
 use super::{AND, DIV, EQ, GE, GT, LE, LT, NE, NOT, OR, SUB, XOR, function_trait_name_from_fn_id};
@@ -40,6 +45,10 @@ impl Lint for DivisionEqualityOperation {
         "div_eq_op"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0035"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Division with identical operands, this operation always results in one (except for zero) and \
                          may indicate a logic error"
@@ -48,6 +57,10 @@ impl Lint for DivisionEqualityOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
 }
 
 pub struct EqualComparisonOperation;
@@ -76,6 +89,10 @@ impl Lint for EqualComparisonOperation {
         "eq_comp_op"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0036"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Comparison with identical operands, this operation always results in true and may indicate a logic error"
     }
@@ -83,6 +100,10 @@ impl Lint for EqualComparisonOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
 }
 
 pub struct NotEqualComparisonOperation;
@@ -115,6 +136,10 @@ impl Lint for NotEqualComparisonOperation {
         "neq_comp_op"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0037"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Comparison with identical operands, this operation always results in false and may indicate a logic error"
     }
@@ -122,6 +147,10 @@ impl Lint for NotEqualComparisonOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
 }
 
 pub struct DifferenceEqualityOperation;
@@ -150,6 +179,10 @@ impl Lint for DifferenceEqualityOperation {
         "eq_diff_op"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0038"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Subtraction with identical operands, this operation always results in zero and may indicate a logic error"
     }
@@ -157,6 +190,10 @@ impl Lint for DifferenceEqualityOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
 }
 
 pub struct BitwiseEqualityOperation;
@@ -185,6 +222,10 @@ impl Lint for BitwiseEqualityOperation {
         "eq_bitwise_op"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0039"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Bitwise operation with identical operands, this operation always results in the same \
                              value and may indicate a logic error"
@@ -193,6 +234,10 @@ impl Lint for BitwiseEqualityOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
 }
 
 pub struct LogicalEqualityOperation;
@@ -221,6 +266,10 @@ impl Lint for LogicalEqualityOperation {
         "eq_logical_op"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0040"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Logical operation with identical operands, this operation always results in the same \
                              value and may indicate a logic error"
@@ -229,12 +278,29 @@ impl Lint for LogicalEqualityOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::EqualityOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_logical_eq_op(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the operation with its operand")
+    }
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn check_eq_op<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -339,3 +405,34 @@ fn get_diagnostic_message(op: &str) -> Option<&'static str> {
         _ => None,
     }
 }
+
+/// Replaces `x & x` and `x | x` with `x`. Operands that could have side effects are not fixed.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_logical_eq_op<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let AstExpr::Binary(binary) = AstExpr::from_syntax_node(db, node) else {
+        return None;
+    };
+    if !matches!(
+        binary.op(db),
+        BinaryOperator::And(_) | BinaryOperator::Or(_)
+    ) {
+        return None;
+    }
+    let lhs = binary.lhs(db);
+    if !are_operands_equal(db, lhs.as_syntax_node(), binary.rhs(db).as_syntax_node())
+        || !is_side_effect_free_syntax(db, &lhs)
+    {
+        return None;
+    }
+    let operand = lhs.as_syntax_node().get_text_without_trivia(db).long(db);
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, operand),
+        description: LogicalEqualityOperation.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
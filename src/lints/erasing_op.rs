@@ -5,7 +5,8 @@ use cairo_lang_semantic::{Arenas, ExprFunctionCall};
 use cairo_lang_syntax::node::TypedStablePtr;
 
 use super::{AND, function_trait_name_from_fn_id};
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::helper::is_zero;
 use crate::lints::{DIV, MUL};
@@ -44,6 +45,10 @@ impl Lint for ErasingOperation {
         "erasing_op"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0019"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This operation results in the value being erased (e.g., multiplication by 0). \
                                      Consider replacing the entire expression with 0."
@@ -52,12 +57,17 @@ impl Lint for ErasingOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::ErasingOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn check_erasing_operation<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
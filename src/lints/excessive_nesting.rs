@@ -0,0 +1,145 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+
+/// Default maximum depth of nested `if`/`match`/loop blocks within a single function.
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 5;
+
+pub struct ExcessiveNesting;
+
+/// ## What it does
+///
+/// Checks for functions in which `if`, `match` and loop blocks are nested deeper than
+/// the configured threshold (5 by default). `else if` chains do not add a level of nesting.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(a: u32, b: u32, c: u32) {
+///     if a > 0 {
+///         if b > 0 {
+///             loop {
+///                 match c {
+///                     0 => {
+///                         while a > b {
+///                             if c > 1 {
+///                                 break;
+///                             }
+///                         }
+///                     },
+///                     _ => {},
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+impl Lint for ExcessiveNesting {
+    fn allowed_name(&self) -> &'static str {
+        "excessive_nesting"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0057"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Blocks are nested too deeply. Consider extracting some logic into separate functions or using early returns."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ExcessiveNesting
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_excessive_nesting<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let item_node = match item {
+        ModuleItemId::FreeFunction(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::Impl(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        // Trait can have a default function impl.
+        ModuleItemId::Trait(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        _ => return,
+    };
+    check_functions_nesting(db, item_node, params.max_nesting_depth, diagnostics);
+}
+
+/// Finds all the functions under the given node and reports the deepest nested block
+/// of each function exceeding `max_depth`.
+fn check_functions_nesting<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+    max_depth: usize,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    if matches!(
+        node.kind(db),
+        SyntaxKind::FunctionWithBody | SyntaxKind::TraitItemFunction
+    ) {
+        if let Some((depth, deepest_node)) = find_deepest_nested_block(db, node, 0)
+            && depth > max_depth
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: deepest_node.stable_ptr(db),
+                message: ExcessiveNesting.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+        return;
+    }
+
+    for child in node.get_children(db) {
+        check_functions_nesting(db, *child, max_depth, diagnostics);
+    }
+}
+
+/// Returns the nesting depth and the node of the first most nested block under the given node.
+fn find_deepest_nested_block<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+    depth: usize,
+) -> Option<(usize, SyntaxNode<'db>)> {
+    let is_nesting = is_nesting_block(db, node);
+    let depth = if is_nesting { depth + 1 } else { depth };
+
+    let mut deepest = is_nesting.then_some((depth, node));
+    for child in node.get_children(db) {
+        if let Some(child_deepest) = find_deepest_nested_block(db, *child, depth)
+            && deepest.is_none_or(|(deepest_depth, _)| child_deepest.0 > deepest_depth)
+        {
+            deepest = Some(child_deepest);
+        }
+    }
+    deepest
+}
+
+fn is_nesting_block<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> bool {
+    match node.kind(db) {
+        // `else if` continues the chain of the parent `if`, so it doesn't add a nesting level.
+        SyntaxKind::ExprIf => node
+            .parent(db)
+            .is_none_or(|parent| parent.kind(db) != SyntaxKind::ElseClause),
+        SyntaxKind::ExprMatch
+        | SyntaxKind::ExprLoop
+        | SyntaxKind::ExprWhile
+        | SyntaxKind::ExprFor => true,
+        _ => false,
+    }
+}
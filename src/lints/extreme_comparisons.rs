@@ -0,0 +1,166 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::constant::ConstValue;
+use cairo_lang_semantic::items::imp::ImplSemantic;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, TypeId};
+use cairo_lang_syntax::node::TypedStablePtr;
+use num_bigint::BigInt;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::{
+    GE, GT, LE, LT, SIGNED_INTEGER_TYPES, UNSIGNED_INTEGER_TYPES, function_trait_name_from_fn_id,
+};
+use crate::queries::{get_all_function_bodies, get_all_function_calls};
+
+pub struct AbsurdExtremeComparison;
+
+/// ## What it does
+///
+/// Checks for comparisons of an integer with the minimum or maximum value of its type, whose
+/// result is constant: either they can never hold, or they always hold.
+///
+/// ## Example
+///
+/// ```cairo
+/// use core::num::traits::Bounded;
+///
+/// fn main(x: u8) {
+///     if x > Bounded::MAX {
+///         // impossible to reach
+///     }
+///     if x <= 255 {
+///         // always reached
+///     }
+/// }
+/// ```
+impl Lint for AbsurdExtremeComparison {
+    fn allowed_name(&self) -> &'static str {
+        "absurd_extreme_comparisons"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0098"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Absurd condition, an integer never exceeds the bounds of its type. The result is always \
+         the same"
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::AbsurdExtremeComparison
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_extreme_comparisons<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for function_call_expr in get_all_function_calls(function_body) {
+            if extreme_comparison_result(db, &function_call_expr, arenas).is_none() {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: function_call_expr.stable_ptr.untyped(),
+                message: AbsurdExtremeComparison.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Returns the constant result of a comparison between an integer and one of the bounds of its
+/// type, i.e. `false` for `x < MIN` and `x > MAX`, `true` for `x >= MIN` and `x <= MAX`.
+fn extreme_comparison_result<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<bool> {
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = function_call_expr.args[..]
+    else {
+        return None;
+    };
+    let (min, max) = integer_bounds(db, arenas.exprs[lhs].ty())?;
+    let lhs_value = constant_value(db, arenas, lhs);
+    let rhs_value = constant_value(db, arenas, rhs);
+
+    let (bound, bound_expr, always_true) =
+        match function_trait_name_from_fn_id(db, &function_call_expr.function).as_str() {
+            LT if rhs_value == Some(min.clone()) => (min, rhs, false),
+            GT if lhs_value == Some(min.clone()) => (min, lhs, false),
+            GE if rhs_value == Some(min.clone()) => (min, rhs, true),
+            LE if lhs_value == Some(min.clone()) => (min, lhs, true),
+            GT if rhs_value == Some(max.clone()) => (max, rhs, false),
+            LT if lhs_value == Some(max.clone()) => (max, lhs, false),
+            LE if rhs_value == Some(max.clone()) => (max, rhs, true),
+            GE if lhs_value == Some(max.clone()) => (max, lhs, true),
+            _ => return None,
+        };
+    // Comparisons of unsigned integers with a literal zero are reported by
+    // `impossible_unsigned_comparison` and `redundant_unsigned_comparison`.
+    if bound == BigInt::ZERO && matches!(arenas.exprs[bound_expr], Expr::Literal(_)) {
+        return None;
+    }
+    Some(always_true)
+}
+
+/// Returns the minimum and maximum values of the type, if it's one of the corelib integer types.
+pub(crate) fn integer_bounds(db: &dyn Database, ty: TypeId) -> Option<(BigInt, BigInt)> {
+    let type_name = ty.format(db);
+    if let Some((_, bits)) = UNSIGNED_INTEGER_TYPES
+        .iter()
+        .find(|(name, _)| *name == type_name)
+    {
+        return Some((BigInt::ZERO, (BigInt::from(1) << *bits) - 1));
+    }
+    SIGNED_INTEGER_TYPES
+        .iter()
+        .find(|(name, _)| *name == type_name)
+        .map(|(_, bits)| {
+            let half = BigInt::from(1) << (*bits - 1);
+            (-half.clone(), half - 1)
+        })
+}
+
+/// Returns the value of the expression if it's an integer literal or a constant, including the
+/// constants of concrete impls such as `Bounded::<u8>::MAX`.
+pub(crate) fn constant_value<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+) -> Option<BigInt> {
+    match &arenas.exprs[expr_id] {
+        Expr::Literal(literal) => Some(literal.value.clone()),
+        Expr::Constant(constant) => {
+            let const_value_id = match constant.const_value_id.long(db) {
+                ConstValue::ImplConstant(impl_constant_id) => db
+                    .impl_constant_concrete_implized_value(*impl_constant_id)
+                    .ok()?,
+                _ => constant.const_value_id,
+            };
+            match const_value_id.long(db) {
+                ConstValue::Int(value, _) => Some(value.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
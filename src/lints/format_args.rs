@@ -0,0 +1,193 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
+use cairo_lang_syntax::node::ast::{ArgClause, Expr as AstExpr, ExprInlineMacro, WrappedArgList};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::queries::get_all_inline_macro_calls;
+
+const FORMAT_MACRO: &str = "format";
+const PRINTING_MACROS: [&str; 2] = ["print", "println"];
+
+pub struct UselessFormat;
+
+/// ## What it does
+///
+/// Checks for `format!` calls whose format string is a single `{}` placeholder.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(name: ByteArray) -> ByteArray {
+///     format!("{}", name)
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(name: ByteArray) -> ByteArray {
+///     name
+/// }
+/// ```
+impl Lint for UselessFormat {
+    fn allowed_name(&self) -> &'static str {
+        "useless_format"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0103"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Useless `format!` of a single value. Consider converting the value to a `ByteArray` directly."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UselessFormat
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+pub struct FormatLiteralArgument;
+
+/// ## What it does
+///
+/// Checks for string literals passed as arguments to `format!`, `print!` or `println!`, which can
+/// be a part of the format string instead.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     println!("{}, {}!", "Hello", "world");
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main() {
+///     println!("Hello, world!");
+/// }
+/// ```
+impl Lint for FormatLiteralArgument {
+    fn allowed_name(&self) -> &'static str {
+        "print_literal"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0104"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "String literal used as a format argument. Consider inlining it into the format string."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::PrintLiteral
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+/// Checks the arguments of the formatting macros. The macros are checked at the call site, which
+/// is also where the calls expanded from other plugins are mapped to.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_format_args<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    for inline_macro in get_all_inline_macro_calls(db, item) {
+        let Some(message) = useless_format_message(db, &inline_macro) else {
+            continue;
+        };
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: inline_macro.stable_ptr(db).untyped(),
+            message: message.to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
+
+fn useless_format_message<'db>(
+    db: &'db dyn Database,
+    inline_macro: &ExprInlineMacro<'db>,
+) -> Option<&'static str> {
+    let name = inline_macro
+        .path(db)
+        .as_syntax_node()
+        .get_text_without_trivia(db);
+    let name = name.long(db).as_str();
+    if name != FORMAT_MACRO && !PRINTING_MACROS.contains(&name) {
+        return None;
+    }
+    let WrappedArgList::ParenthesizedArgList(arg_list) =
+        inline_macro.as_legacy_inline_macro(db)?.arguments(db)
+    else {
+        return None;
+    };
+    let mut arguments = arg_list
+        .arguments(db)
+        .elements(db)
+        .map(|arg| match arg.arg_clause(db) {
+            ArgClause::Unnamed(clause) => Some(clause.value(db)),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?
+        .into_iter();
+    let Some(AstExpr::String(format_string)) = arguments.next() else {
+        return None;
+    };
+    let format_string = format_string.string_value(db)?;
+    let arguments = arguments.collect::<Vec<_>>();
+    // Named and formatted placeholders, e.g. `{x}` or `{:?}`, can't be simplified this way.
+    if plain_placeholders_count(&format_string)? != arguments.len() {
+        return None;
+    }
+
+    if name == FORMAT_MACRO && format_string == "{}" {
+        Some(UselessFormat.diagnostic_message())
+    } else if arguments
+        .iter()
+        .any(|argument| matches!(argument, AstExpr::String(_)))
+    {
+        Some(FormatLiteralArgument.diagnostic_message())
+    } else {
+        None
+    }
+}
+
+/// Returns the number of `{}` placeholders in the format string, or `None` if any of the
+/// placeholders isn't empty.
+fn plain_placeholders_count(format_string: &str) -> Option<usize> {
+    let mut count = 0;
+    let mut chars = format_string.chars().peekable();
+    while let Some(char) = chars.next() {
+        match (char, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+            }
+            ('{', Some('}')) => {
+                chars.next();
+                count += 1;
+            }
+            ('{', _) | ('}', _) => return None,
+            _ => {}
+        }
+    }
+    Some(count)
+}
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprIf, Statement, StatementId};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::ifs::collapsible_if_else::is_only_statement_if;
+use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+
+pub struct BranchesSharingCode;
+
+/// ## What it does
+///
+/// Checks for `if`/`else` expressions whose branches start or end with the same statements.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(flag: bool) {
+///     if flag {
+///         println!("start");
+///         println!("flag is set");
+///     } else {
+///         println!("start");
+///         println!("flag is not set");
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(flag: bool) {
+///     println!("start");
+///     if flag {
+///         println!("flag is set");
+///     } else {
+///         println!("flag is not set");
+///     }
+/// }
+/// ```
+impl Lint for BranchesSharingCode {
+    fn allowed_name(&self) -> &'static str {
+        "branches_sharing_code"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0089"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "All branches of this `if` start or end with the same statements. Consider moving them out of the `if`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::BranchesSharingCode
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_branches_sharing_code<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let if_exprs = get_all_if_expressions(function_body);
+        let arenas = &function_body.arenas;
+        // The statements of an `else if` can't be moved out of it without moving them out of the
+        // whole chain, so chains aren't checked.
+        let else_ifs = if_exprs
+            .iter()
+            .filter_map(|if_expr| if_expr.else_block)
+            .filter(|else_block| matches!(arenas.exprs[*else_block], Expr::If(_)))
+            .map(|else_block| arenas.exprs[else_block].stable_ptr())
+            .collect::<HashSet<_>>();
+        for if_expr in if_exprs.iter() {
+            if !else_ifs.contains(&if_expr.stable_ptr) {
+                check_single_branches_sharing_code(db, if_expr, arenas, diagnostics);
+            }
+        }
+    }
+}
+
+fn check_single_branches_sharing_code<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    // Statements of an `if let` branch may refer to the variables bound by the pattern.
+    if if_expr
+        .conditions
+        .iter()
+        .any(|condition| matches!(condition, Condition::Let(..)))
+    {
+        return;
+    }
+    let Some(else_block) = if_expr.else_block else {
+        return;
+    };
+    let (Expr::Block(if_block), Expr::Block(else_block)) =
+        (&arenas.exprs[if_expr.if_block], &arenas.exprs[else_block])
+    else {
+        return;
+    };
+    // Such an `else` is reported by `collapsible_if_else`.
+    if is_only_statement_if(db, else_block, arenas) {
+        return;
+    }
+
+    let text = |statement_id: &StatementId| {
+        arenas.statements[*statement_id]
+            .stable_ptr()
+            .lookup(db)
+            .as_syntax_node()
+            .get_text_without_trivia(db)
+    };
+    let shared_prefix = if_block
+        .statements
+        .iter()
+        .zip(&else_block.statements)
+        .take_while(|(if_statement, else_statement)| text(if_statement) == text(else_statement))
+        .count();
+
+    let if_rest = &if_block.statements[shared_prefix..];
+    let else_rest = &else_block.statements[shared_prefix..];
+    // Trailing statements can be moved after the `if` only if no tail expression follows them
+    // and they can't refer to variables bound in the branches.
+    let binds_variables = |statements: &[StatementId]| {
+        statements
+            .iter()
+            .any(|statement_id| matches!(arenas.statements[*statement_id], Statement::Let(_)))
+    };
+    let shared_suffix = if if_block.tail.is_none()
+        && else_block.tail.is_none()
+        && !binds_variables(if_rest)
+        && !binds_variables(else_rest)
+    {
+        if_rest
+            .iter()
+            .rev()
+            .zip(else_rest.iter().rev())
+            .take_while(|(if_statement, else_statement)| text(if_statement) == text(else_statement))
+            .count()
+    } else {
+        0
+    };
+
+    // The diagnostics point at the first shared statement of the `if` branch.
+    let shared_starts = [
+        (shared_prefix > 0).then(|| if_block.statements[0]),
+        (shared_suffix > 0).then(|| if_rest[if_rest.len() - shared_suffix]),
+    ];
+    for statement_id in shared_starts.into_iter().flatten() {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: arenas.statements[statement_id].stable_ptr().untyped(),
+            message: BranchesSharingCode.diagnostic_message().to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
@@ -9,7 +9,8 @@ use cairo_lang_syntax::node::{
 };
 use if_chain::if_chain;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::helper::indent_snippet;
@@ -55,6 +56,10 @@ impl Lint for CollapsibleIf {
         "collapsible_if"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0012"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Each `if`-statement adds one level of nesting, which makes code look more complex than it really is."
     }
@@ -63,6 +68,10 @@ impl Lint for CollapsibleIf {
         CairoLintKind::CollapsibleIf
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -76,10 +85,14 @@ impl Lint for CollapsibleIf {
     }
 }
 
+/// Default number of nested collapsible `if` expressions from which the `collapsible_if` lint fires.
+pub const DEFAULT_NESTED_IF_THRESHOLD: usize = 2;
+
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn check_collapsible_if<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -87,7 +100,13 @@ pub fn check_collapsible_if<'db>(
         let if_exprs = get_all_if_expressions(function_body);
         let arenas = &function_body.arenas;
         for if_expr in if_exprs.iter() {
-            check_single_collapsible_if(db, if_expr, arenas, diagnostics);
+            check_single_collapsible_if(
+                db,
+                if_expr,
+                arenas,
+                params.nested_if_threshold,
+                diagnostics,
+            );
         }
     }
 }
@@ -96,10 +115,37 @@ fn check_single_collapsible_if<'db>(
     db: &'db dyn Database,
     if_expr: &ExprIf<'db>,
     arenas: &Arenas<'db>,
+    nested_if_threshold: usize,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
+    // The outer `if` counts as the first level of nesting.
+    let mut depth = 1;
+    let mut current_if = if_expr;
+    while let Some(inner_if_expr) = get_collapsible_inner_if(db, current_if, arenas) {
+        depth += 1;
+        if depth >= nested_if_threshold {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: if_expr.stable_ptr.untyped(),
+                message: CollapsibleIf.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                error_code: None,
+                inner_span: None,
+            });
+            return;
+        }
+        current_if = inner_if_expr;
+    }
+}
+
+/// Returns the inner `if` expression if it's the only content of the `if` block and can be
+/// collapsed into the outer one.
+fn get_collapsible_inner_if<'a, 'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &'a Arenas<'db>,
+) -> Option<&'a ExprIf<'db>> {
     let Expr::Block(ref if_block) = arenas.exprs[if_expr.if_block] else {
-        return;
+        return None;
     };
 
     // TODO: Check if if block can contain only 1 statement without tail
@@ -124,15 +170,7 @@ fn check_single_collapsible_if<'db>(
         // Check if any of the ifs (outer and inner) have an else block, if it's the case, don't return any diagnostics.
         if inner_if_expr.else_block.is_none() && if_expr.else_block.is_none();
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: if_expr.stable_ptr.untyped(),
-                message: CollapsibleIf.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                error_code: None,
-                inner_span: None
-            });
-
-            return;
+            return Some(inner_if_expr);
         }
     }
 
@@ -150,15 +188,11 @@ fn check_single_collapsible_if<'db>(
         // Check if any of the ifs (outer and inner) have an else block, if it's the case, don't return any diagnostics.
         if if_expr.else_block.is_none() && inner_if_expr.else_block.is_none();
         then {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: if_expr.stable_ptr.untyped(),
-                message: CollapsibleIf.diagnostic_message().to_string(),
-                severity: Severity::Warning,
-                error_code: None,
-                inner_span: None,
-            });
+            return Some(inner_if_expr);
         }
     }
+
+    None
 }
 
 /// Attempts to fix a collapsible if-statement by combining its conditions.
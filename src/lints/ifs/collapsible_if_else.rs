@@ -1,6 +1,7 @@
 use cairo_lang_defs::ids::ModuleItemId;
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::{Arenas, Expr, ExprBlock, ExprIf, Statement};
 
 use cairo_lang_syntax::node::{
@@ -12,8 +13,9 @@ use cairo_lang_syntax::node::{
 };
 use if_chain::if_chain;
 
+use crate::LinterDiagnosticParams;
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     queries::is_assert_macro_call,
 };
 
@@ -60,6 +62,10 @@ impl Lint for CollapsibleIfElse {
         "collapsible_if_else"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0011"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Consider using else if instead of else { if ... }"
     }
@@ -68,6 +74,10 @@ impl Lint for CollapsibleIfElse {
         CairoLintKind::CollapsibleIfElse
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -103,6 +113,7 @@ impl Lint for CollapsibleIfElse {
 pub fn check_collapsible_if_else<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -143,7 +154,12 @@ fn check_single_collapsible_if_else<'db>(
     }
 }
 
-fn is_only_statement_if(db: &dyn Database, block_expr: &ExprBlock, arenas: &Arenas) -> bool {
+/// Checks if the block contains nothing but an `if` expression.
+pub(crate) fn is_only_statement_if(
+    db: &dyn Database,
+    block_expr: &ExprBlock,
+    arenas: &Arenas,
+) -> bool {
     if block_expr.statements.len() == 1 && block_expr.tail.is_none() {
         if_chain! {
             if let Statement::Expr(statement_expr) = &arenas.statements[block_expr.statements[0]];
@@ -205,9 +221,17 @@ pub fn fix_collapsible_if_else<'db>(
             .take_while(|c| c.is_whitespace())
             .collect::<String>();
 
+        // Comments placed before the inner `if` are moved before the `else if`, in separate lines.
+        let comments = leading_comments(db, statement_expr.as_syntax_node());
+        let prefix = if comments.is_empty() {
+            original_indent
+        } else {
+            format!("\n{}\n", comments.join("\n"))
+        };
+
         return Some(InternalFix {
             node: else_clause.as_syntax_node(),
-            suggestion: format!("{original_indent}else if {condition} {if_body} {else_body}"),
+            suggestion: format!("{prefix}else if {condition} {if_body} {else_body}"),
             description: CollapsibleIfElse.fix_message().unwrap().to_string(),
             import_addition_paths: None,
         });
@@ -216,3 +240,20 @@ pub fn fix_collapsible_if_else<'db>(
     // If we can't transform it, return the original text
     None
 }
+
+/// Returns the comment lines of the leading trivia of the node.
+fn leading_comments<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> Vec<String> {
+    let leading_trivia = node.get_text_of_span(
+        db,
+        TextSpan {
+            start: node.span(db).start,
+            end: node.span_without_trivia(db).start,
+        },
+    );
+    leading_trivia
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("//"))
+        .map(str::to_string)
+        .collect()
+}
@@ -8,7 +8,8 @@ use cairo_lang_syntax::node::{
     ast::{Condition as AstCondition, ExprIf as AstExprIf},
 };
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::queries::{get_all_function_bodies, get_all_if_expressions};
@@ -40,6 +41,10 @@ impl Lint for EquatableIfLet {
         "equatable_if_let"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0008"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "`if let` pattern used for equatable value. Consider using a simple comparison `==` instead"
     }
@@ -48,6 +53,10 @@ impl Lint for EquatableIfLet {
         CairoLintKind::EquatableIfLet
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -65,6 +74,7 @@ impl Lint for EquatableIfLet {
 pub fn check_equatable_if_let<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -5,7 +5,8 @@ use cairo_lang_semantic::{Arenas, Condition, Expr, ExprFunctionCall, ExprFunctio
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::queries::{get_all_function_bodies, get_all_if_expressions};
 use salsa::Database;
@@ -46,6 +47,10 @@ impl Lint for DuplicateIfCondition {
         "ifs_same_cond"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0029"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Consecutive `if` with the same condition found."
     }
@@ -53,12 +58,17 @@ impl Lint for DuplicateIfCondition {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::DuplicateIfCondition
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn check_duplicate_if_condition<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -0,0 +1,257 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprId, ExprIf};
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, BlockOrIf, Condition as AstCondition, Expr as AstExpr, ExprBlock,
+    ExprIf as AstExprIf, OptionElseClause, OptionTerminalSemicolon, Statement as AstStatement,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::{BOOL, FALSE, TRUE};
+use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+
+pub struct ManualBoolLogic;
+
+/// ## What it does
+///
+/// Checks for `if` expressions returning a boolean literal in one branch and a boolean expression
+/// in the other, which are the same as the `&&` or `||` operators.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(a: bool, b: bool) -> bool {
+///     if a {
+///         b
+///     } else {
+///         false
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(a: bool, b: bool) -> bool {
+///     a && b
+/// }
+/// ```
+impl Lint for ManualBoolLogic {
+    fn allowed_name(&self) -> &'static str {
+        "manual_bool_logic"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0102"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual implementation of a boolean operator. Consider using `&&` or `||` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualBoolLogic
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_bool_logic(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace with `&&` or `||`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_bool_logic<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for if_expr in get_all_if_expressions(function_body) {
+            if is_manual_bool_logic(db, &if_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: ManualBoolLogic.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the `if` is either `if a { b } else { false }` or `if a { true } else { b }`, where
+/// `b` isn't a boolean literal.
+fn is_manual_bool_logic<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let [Condition::BoolExpr(_)] = &if_expr.conditions[..] else {
+        return false;
+    };
+    let Some(else_block) = if_expr.else_block else {
+        return false;
+    };
+    if if_expr.ty.format(db) != BOOL {
+        return false;
+    }
+    let (Some(if_value), Some(else_value)) = (
+        block_tail(arenas, if_expr.if_block),
+        block_tail(arenas, else_block),
+    ) else {
+        return false;
+    };
+    // Both branches being literals is a different pattern, e.g. `if a { true } else { false }`.
+    match (
+        bool_literal_value(db, arenas, if_value),
+        bool_literal_value(db, arenas, else_value),
+    ) {
+        (None, Some(false)) | (Some(true), None) => true,
+        _ => false,
+    }
+}
+
+/// Returns the tail expression of the block, if the block has no statements.
+fn block_tail(arenas: &Arenas, block_id: ExprId) -> Option<ExprId> {
+    match &arenas.exprs[block_id] {
+        Expr::Block(block) if block.statements.is_empty() => block.tail,
+        _ => None,
+    }
+}
+
+fn bool_literal_value<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+) -> Option<bool> {
+    let Expr::EnumVariantCtor(variant_ctor) = &arenas.exprs[expr_id] else {
+        return None;
+    };
+    match variant_ctor.variant.id.full_path(db).as_str() {
+        TRUE => Some(true),
+        FALSE => Some(false),
+        _ => None,
+    }
+}
+
+/// Rewrites the `if` to `a && b` or `a || b`, adding parentheses around the operands and the
+/// resulting expression where the precedence of the operators requires it.
+///
+/// The fix isn't available if there are comments in the `if`, which would be lost.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_bool_logic<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let if_expr = AstExprIf::cast(db, node)?;
+    if node.get_text_without_trivia(db).long(db).contains("//") {
+        return None;
+    }
+    let mut conditions = if_expr.conditions(db).elements(db);
+    let (Some(AstCondition::Expr(condition)), None) = (conditions.next(), conditions.next()) else {
+        return None;
+    };
+    let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+        return None;
+    };
+    let BlockOrIf::Block(else_block) = else_clause.else_block_or_if(db) else {
+        return None;
+    };
+    let if_value = ast_block_tail(db, &if_expr.if_block(db))?;
+    let else_value = ast_block_tail(db, &else_block)?;
+
+    let literal_value = |expr: &AstExpr<'db>| match expr {
+        AstExpr::True(_) => Some(true),
+        AstExpr::False(_) => Some(false),
+        _ => None,
+    };
+    let (operator, operand) = match (literal_value(&if_value), literal_value(&else_value)) {
+        (None, Some(false)) => ("&&", if_value),
+        (Some(true), None) => ("||", else_value),
+        _ => return None,
+    };
+    let is_and = operator == "&&";
+    let mut fixed = format!(
+        "{} {operator} {}",
+        operand_text(db, &condition.expr(db), is_and),
+        operand_text(db, &operand, is_and)
+    );
+    if node.parent(db).is_some_and(|parent| {
+        matches!(
+            parent.kind(db),
+            SyntaxKind::ExprBinary | SyntaxKind::ExprUnary
+        )
+    }) {
+        fixed = format!("({fixed})");
+    }
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &fixed),
+        description: ManualBoolLogic.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Returns the tail expression of the block, if the block consists only of it.
+fn ast_block_tail<'db>(db: &'db dyn Database, block: &ExprBlock<'db>) -> Option<AstExpr<'db>> {
+    let mut statements = block.statements(db).elements(db);
+    let (Some(AstStatement::Expr(statement_expr)), None) = (statements.next(), statements.next())
+    else {
+        return None;
+    };
+    matches!(
+        statement_expr.semicolon(db),
+        OptionTerminalSemicolon::Empty(_)
+    )
+    .then(|| statement_expr.expr(db))
+}
+
+/// Returns the text of the operand, in parentheses if it binds weaker than the operator.
+fn operand_text<'db>(db: &'db dyn Database, operand: &AstExpr<'db>, is_and: bool) -> String {
+    let text = syntax_text(db, operand);
+    let needs_parentheses = match operand {
+        AstExpr::Binary(binary) => is_and && matches!(binary.op(db), BinaryOperator::OrOr(_)),
+        AstExpr::If(_)
+        | AstExpr::Match(_)
+        | AstExpr::Block(_)
+        | AstExpr::Loop(_)
+        | AstExpr::While(_)
+        | AstExpr::For(_) => true,
+        _ => false,
+    };
+    if needs_parentheses {
+        format!("({text})")
+    } else {
+        text
+    }
+}
+
+fn syntax_text<'db>(db: &'db dyn Database, expr: &AstExpr<'db>) -> String {
+    expr.as_syntax_node()
+        .get_text_without_trivia(db)
+        .long(db)
+        .to_string()
+}
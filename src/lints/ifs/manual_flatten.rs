@@ -0,0 +1,263 @@
+use cairo_lang_defs::ids::{ModuleItemId, TopLevelLanguageElementId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprMatch, MatchArm, Pattern, PatternVariable, VarId};
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr, ExprMatch as AstExprMatch};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::collapsible_match::get_inner_match_expression_if_single_one;
+use crate::lints::{NONE, SOME};
+use crate::queries::{get_all_function_bodies, get_all_match_expressions};
+
+pub struct ManualFlatten;
+
+/// ## What it does
+///
+/// Checks for `match` expressions on an `Option<Option<T>>` that only re-wrap the inner value,
+/// either with nested matches or with a single collapsed one.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(opt: Option<Option<u32>>) -> Option<u32> {
+///     match opt {
+///         Some(Some(value)) => Some(value),
+///         _ => None,
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(opt: Option<Option<u32>>) -> Option<u32> {
+///     opt.flatten()
+/// }
+/// ```
+impl Lint for ManualFlatten {
+    fn allowed_name(&self) -> &'static str {
+        "manual_flatten"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0079"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual flattening of a nested `Option` detected. Consider using `.flatten()` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualFlatten
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_flatten(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace with `.flatten()`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_flatten<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let match_exprs = get_all_match_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for match_expr in match_exprs.iter() {
+            if is_manual_flatten(db, match_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: match_expr.stable_ptr.untyped(),
+                    message: ManualFlatten.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the `match` evaluates to the inner option of an `Option<Option<T>>`: a single arm
+/// re-wraps the inner value, and all the other ones evaluate to `None`.
+///
+/// The re-wrapping arm is either collapsed, e.g. `Some(Some(v)) => Some(v)`, or contains a nested
+/// `match` on the inner option, e.g. `Some(x) => match x { Some(v) => Some(v), _ => None }`.
+pub fn is_manual_flatten<'db>(
+    db: &'db dyn Database,
+    match_expr: &ExprMatch<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let mut rewrapping_arms = 0;
+    for arm in match_expr.arms.iter() {
+        let [pattern] = &arm.patterns[..] else {
+            return false;
+        };
+        let Some(inner_pattern) = some_inner_pattern(db, &arenas.patterns[*pattern], arenas) else {
+            if is_none_arm(db, arm, arenas) {
+                continue;
+            }
+            return false;
+        };
+
+        let rewraps_inner_value = match inner_pattern {
+            // `Some(Some(v)) => Some(v)`
+            Pattern::EnumVariant(_) => some_inner_pattern(db, inner_pattern, arenas)
+                .and_then(as_variable)
+                .is_some_and(|value| is_some_of(db, &arenas.exprs[arm.expression], value, arenas)),
+            // `Some(x) => match x { Some(v) => Some(v), _ => None }`
+            Pattern::Variable(inner_option) => {
+                get_inner_match_expression_if_single_one(arm, arenas).is_some_and(|inner_match| {
+                    matches!(
+                        &arenas.exprs[inner_match.matched_expr],
+                        Expr::Var(var) if var.var == VarId::Local(inner_option.var.id)
+                    ) && is_option_rewrap(db, inner_match, arenas)
+                })
+            }
+            _ => false,
+        };
+        if rewraps_inner_value {
+            rewrapping_arms += 1;
+        } else if !is_none_arm(db, arm, arenas) {
+            return false;
+        }
+    }
+    rewrapping_arms == 1
+}
+
+/// Checks if the `match` on an `Option` re-wraps its value and evaluates to `None` otherwise.
+fn is_option_rewrap<'db>(
+    db: &'db dyn Database,
+    match_expr: &ExprMatch<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let mut rewrapping_arms = 0;
+    for arm in match_expr.arms.iter() {
+        let [pattern] = &arm.patterns[..] else {
+            return false;
+        };
+        let value =
+            some_inner_pattern(db, &arenas.patterns[*pattern], arenas).and_then(as_variable);
+        if value.is_some_and(|value| is_some_of(db, &arenas.exprs[arm.expression], value, arenas)) {
+            rewrapping_arms += 1;
+        } else if !is_none_arm(db, arm, arenas) {
+            return false;
+        }
+    }
+    rewrapping_arms == 1
+}
+
+/// Returns the pattern inside `Some(...)`.
+fn some_inner_pattern<'a, 'db>(
+    db: &'db dyn Database,
+    pattern: &Pattern<'db>,
+    arenas: &'a Arenas<'db>,
+) -> Option<&'a Pattern<'db>> {
+    let Pattern::EnumVariant(enum_pattern) = pattern else {
+        return None;
+    };
+    if enum_pattern.variant.id.full_path(db) != SOME {
+        return None;
+    }
+    Some(&arenas.patterns[enum_pattern.inner_pattern?])
+}
+
+fn as_variable<'a, 'db>(pattern: &'a Pattern<'db>) -> Option<&'a PatternVariable<'db>> {
+    match pattern {
+        Pattern::Variable(variable) => Some(variable),
+        _ => None,
+    }
+}
+
+/// Checks if the expression is `Some(value)`.
+fn is_some_of<'db>(
+    db: &'db dyn Database,
+    expr: &Expr<'db>,
+    value: &PatternVariable<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let Expr::EnumVariantCtor(variant_ctor) = expr else {
+        return false;
+    };
+    variant_ctor.variant.id.full_path(db) == SOME
+        && matches!(
+            &arenas.exprs[variant_ctor.value_expr],
+            Expr::Var(var) if var.var == VarId::Local(value.var.id)
+        )
+}
+
+/// Checks if the arm evaluates to `None` and only matches values which are flattened to `None`,
+/// i.e. `None`, `Some(None)` or anything else with `_`.
+fn is_none_arm<'db>(db: &'db dyn Database, arm: &MatchArm, arenas: &Arenas<'db>) -> bool {
+    let is_none_expr = matches!(
+        &arenas.exprs[arm.expression],
+        Expr::EnumVariantCtor(variant_ctor) if variant_ctor.variant.id.full_path(db) == NONE
+    );
+    is_none_expr
+        && arm
+            .patterns
+            .iter()
+            .all(|pattern| is_none_pattern(db, &arenas.patterns[*pattern], arenas))
+}
+
+fn is_none_pattern<'db>(
+    db: &'db dyn Database,
+    pattern: &Pattern<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    match pattern {
+        Pattern::Otherwise(_) => true,
+        Pattern::EnumVariant(enum_pattern) if enum_pattern.variant.id.full_path(db) == NONE => true,
+        Pattern::EnumVariant(_) => some_inner_pattern(db, pattern, arenas)
+            .is_some_and(|inner_pattern| is_none_pattern(db, inner_pattern, arenas)),
+        _ => false,
+    }
+}
+
+/// Replaces the `match` with `.flatten()` called on the matched expression.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_flatten<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let matched_expr = AstExprMatch::cast(db, node)?.expr(db);
+    let matched_expr_text = matched_expr
+        .as_syntax_node()
+        .get_text_without_trivia(db)
+        .long(db)
+        .to_string();
+    let receiver = match &matched_expr {
+        AstExpr::Binary(binary) if !matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+            format!("({matched_expr_text})")
+        }
+        AstExpr::Unary(_) => format!("({matched_expr_text})"),
+        _ => matched_expr_text,
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &format!("{receiver}.flatten()")),
+        description: ManualFlatten.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
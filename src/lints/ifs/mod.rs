@@ -1,4 +1,8 @@
+pub mod branches_sharing_code;
 pub mod collapsible_if;
 pub mod collapsible_if_else;
 pub mod equatable_if_let;
 pub mod ifs_same_cond;
+pub mod manual_bool_logic;
+pub mod manual_flatten;
+pub mod redundant_else;
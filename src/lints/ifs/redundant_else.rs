@@ -0,0 +1,313 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::{Arenas, Expr, ExprIf, Statement};
+use cairo_lang_syntax::node::ast::{
+    BlockOrIf, ExprBlock, ExprIf as AstExprIf, OptionElseClause, OptionTerminalSemicolon,
+    PatternIdentifier, Statement as AstStatement, StatementExpr as AstStatementExpr,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::trailing_trivia;
+use crate::lints::manual::helpers::func_call_or_block_returns_never;
+use crate::queries::{get_all_function_bodies, get_all_if_expressions, is_assert_macro_call};
+use salsa::Database;
+
+pub struct RedundantElse;
+
+/// ## What it does
+///
+/// Checks for `else` blocks following an `if` block that always diverges
+/// (ends with `return`, `break`, `continue` or a panic).
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32) -> u32 {
+///     if x == 0 {
+///         return 0;
+///     } else {
+///         let y = x * 2;
+///         y + 1
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(x: u32) -> u32 {
+///     if x == 0 {
+///         return 0;
+///     }
+///     let y = x * 2;
+///     y + 1
+/// }
+/// ```
+impl Lint for RedundantElse {
+    fn allowed_name(&self) -> &'static str {
+        "redundant_else"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0056"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Redundant `else` block. The `if` block always diverges, so the `else` block can be removed."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::RedundantElse
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_redundant_else(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Remove the `else` block and move its content to the outer scope")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_redundant_else<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let if_exprs = get_all_if_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for if_expr in if_exprs.iter() {
+            check_single_redundant_else(db, if_expr, arenas, diagnostics);
+        }
+    }
+}
+
+fn check_single_redundant_else<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let Some(else_block) = if_expr.else_block else {
+        return;
+    };
+
+    // `else if` chains are not flagged, only plain `else` blocks.
+    if !matches!(arenas.exprs[else_block], Expr::Block(_)) {
+        return;
+    }
+
+    if is_assert_macro_call(db, arenas, if_expr)
+        || !block_diverges(db, &arenas.exprs[if_expr.if_block], arenas)
+    {
+        return;
+    }
+
+    // The `if` must be used as a statement, otherwise its value might be consumed elsewhere.
+    let is_statement = if_expr
+        .stable_ptr
+        .lookup(db)
+        .as_syntax_node()
+        .parent(db)
+        .is_some_and(|parent| parent.kind(db) == SyntaxKind::StatementExpr);
+
+    if is_statement {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: if_expr.stable_ptr.untyped(),
+            message: RedundantElse.diagnostic_message().to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
+
+/// Checks if any of the names bound by the `let` statements of the `else` block is used in the
+/// statements following the `if` statement in the enclosing block.
+fn else_bindings_used_after_statement<'db>(
+    db: &'db dyn Database,
+    else_block: &ExprBlock<'db>,
+    statement_node: SyntaxNode<'db>,
+) -> Option<bool> {
+    let bound_names = else_block
+        .statements(db)
+        .elements(db)
+        .filter_map(|statement| match statement {
+            AstStatement::Let(let_statement) => Some(let_statement.pattern(db).as_syntax_node()),
+            _ => None,
+        })
+        .flat_map(|pattern| pattern.descendants(db))
+        .filter_map(|node| PatternIdentifier::cast(db, node))
+        .map(|identifier| identifier.name(db).text(db).to_string(db))
+        .collect::<HashSet<_>>();
+    if bound_names.is_empty() {
+        return Some(false);
+    }
+
+    let statement_list = statement_node.parent(db)?;
+    Some(
+        statement_list
+            .get_children(db)
+            .iter()
+            .skip_while(|sibling| **sibling != statement_node)
+            .skip(1)
+            .flat_map(|sibling| sibling.descendants(db))
+            .filter(|node| node.kind(db) == SyntaxKind::TerminalIdentifier)
+            .any(|identifier| {
+                bound_names.contains(identifier.get_text_without_trivia(db).long(db).as_str())
+            }),
+    )
+}
+
+/// Checks if the block always diverges, i.e. its last statement is a `return`, `break` or `continue`,
+/// or its tail (or last statement) is a call to a function returning never (e.g. `panic!`).
+fn block_diverges<'db>(db: &'db dyn Database, expr: &Expr<'db>, arenas: &Arenas<'db>) -> bool {
+    let Expr::Block(block) = expr else {
+        return false;
+    };
+
+    if let Some(tail) = block.tail {
+        return func_call_or_block_returns_never(expr, db, arenas)
+            || func_call_or_block_returns_never(&arenas.exprs[tail], db, arenas);
+    }
+
+    match block
+        .statements
+        .last()
+        .map(|statement| &arenas.statements[*statement])
+    {
+        Some(Statement::Return(_) | Statement::Break(_) | Statement::Continue(_)) => true,
+        Some(Statement::Expr(statement_expr)) => {
+            func_call_or_block_returns_never(&arenas.exprs[statement_expr.expr], db, arenas)
+        }
+        _ => false,
+    }
+}
+
+/// Removes the redundant `else` block, moving its content to the outer scope.
+///
+/// The content of the `else` block (including comments) is de-indented by one level
+/// and placed right after the `if` block. No fix is proposed if the variables declared in the
+/// `else` block are named the same as the ones used after the `if` statement, as they would
+/// shadow them.
+///
+/// # Arguments
+///
+/// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+/// * `node` - The `SyntaxNode` containing the `if` expression.
+///
+/// # Returns
+///
+/// An `InternalFix` replacing the whole `if` statement.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_redundant_else<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let if_expr = AstExprIf::from_syntax_node(db, node);
+    let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+        return None;
+    };
+    let BlockOrIf::Block(else_block) = else_clause.else_block_or_if(db) else {
+        return None;
+    };
+    let statement_node = node.parent(db)?;
+    let statement = statement_node.cast::<AstStatementExpr>(db)?;
+    if else_bindings_used_after_statement(db, &else_block, statement_node)? {
+        return None;
+    }
+
+    let statement_span = statement_node.span(db);
+
+    let if_part = statement_node.get_text_of_span(
+        db,
+        TextSpan {
+            start: statement_span.start,
+            end: if_expr
+                .if_block(db)
+                .as_syntax_node()
+                .span_without_trivia(db)
+                .end,
+        },
+    );
+
+    // Take everything between the braces, so the comments are preserved as well.
+    let else_content = statement_node.get_text_of_span(
+        db,
+        TextSpan {
+            start: else_block
+                .lbrace(db)
+                .as_syntax_node()
+                .span_without_trivia(db)
+                .end,
+            end: else_block
+                .rbrace(db)
+                .as_syntax_node()
+                .span_start_without_trivia(db),
+        },
+    );
+
+    // De-indent the content of the `else` block by one level.
+    let mut else_lines = else_content
+        .lines()
+        .map(|line| {
+            let line = line.trim_end();
+            line.strip_prefix("    ").unwrap_or(line.trim_start())
+        })
+        .skip_while(|line| line.is_empty())
+        .collect::<Vec<_>>();
+    while else_lines.last().is_some_and(|line| line.is_empty()) {
+        else_lines.pop();
+    }
+    let mut unwrapped_else = else_lines.join("\n");
+
+    // If the whole `if` statement ended with a semicolon, the tail of the `else` block needs one as well.
+    let else_has_tail = matches!(
+        else_block.statements(db).elements(db).last(),
+        Some(AstStatement::Expr(statement_expr))
+            if matches!(statement_expr.semicolon(db), OptionTerminalSemicolon::Empty(_))
+    );
+    if else_has_tail
+        && matches!(
+            statement.semicolon(db),
+            OptionTerminalSemicolon::TerminalSemicolon(_)
+        )
+    {
+        unwrapped_else.push(';');
+    }
+
+    let trailing_trivia = trailing_trivia(db, statement_node);
+
+    let suggestion = if unwrapped_else.is_empty() {
+        format!("{if_part}{trailing_trivia}")
+    } else {
+        format!("{if_part}\n{unwrapped_else}{trailing_trivia}")
+    };
+
+    Some(InternalFix {
+        node: statement_node,
+        suggestion,
+        description: RedundantElse.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -9,7 +9,8 @@ use cairo_lang_syntax::node::ast::{Expr as AstExpr, ExprBinary};
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::LinterGroup;
 use crate::fixer::InternalFix;
@@ -47,6 +48,10 @@ impl Lint for IntegerGreaterEqualPlusOne {
         "int_ge_plus_one"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0031"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary add operation in integer >= comparison. Use simplified comparison."
     }
@@ -55,6 +60,10 @@ impl Lint for IntegerGreaterEqualPlusOne {
         CairoLintKind::IntGePlusOne
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -98,6 +107,10 @@ impl Lint for IntegerGreaterEqualMinusOne {
         "int_ge_min_one"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0032"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary sub operation in integer >= comparison. Use simplified comparison."
     }
@@ -106,6 +119,10 @@ impl Lint for IntegerGreaterEqualMinusOne {
         CairoLintKind::IntGeMinOne
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -149,6 +166,10 @@ impl Lint for IntegerLessEqualPlusOne {
         "int_le_plus_one"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0033"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary add operation in integer <= comparison. Use simplified comparison."
     }
@@ -157,6 +178,10 @@ impl Lint for IntegerLessEqualPlusOne {
         CairoLintKind::IntLePlusOne
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -200,6 +225,10 @@ impl Lint for IntegerLessEqualMinusOne {
         "int_le_min_one"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0034"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Unnecessary sub operation in integer <= comparison. Use simplified comparison."
     }
@@ -208,6 +237,10 @@ impl Lint for IntegerLessEqualMinusOne {
         CairoLintKind::IntLeMinOne
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -225,6 +258,7 @@ impl Lint for IntegerLessEqualMinusOne {
 pub fn check_int_op_one<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -253,10 +287,26 @@ fn check_single_int_op_one<'db>(
     };
 
     let corelib_context = db.corelib_context();
+    let (
+        Some(integer_module_id),
+        Some(add_trait_function_id),
+        Some(sub_trait_function_id),
+        Some(partial_ord_ge_trait_function_id),
+        Some(partial_ord_le_trait_function_id),
+    ) = (
+        corelib_context.get_integer_module_id(),
+        corelib_context.get_add_trait_function_id(),
+        corelib_context.get_sub_trait_function_id(),
+        corelib_context.get_partial_ord_ge_trait_function_id(),
+        corelib_context.get_partial_ord_le_trait_function_id(),
+    )
+    else {
+        return;
+    };
 
     // Check if the function call is the bool greater or equal (>=) or lower or equal (<=).
-    if impl_generic_func_id.function != corelib_context.get_partial_ord_ge_trait_function_id()
-        && impl_generic_func_id.function != corelib_context.get_partial_ord_le_trait_function_id()
+    if impl_generic_func_id.function != partial_ord_ge_trait_function_id
+        && impl_generic_func_id.function != partial_ord_le_trait_function_id
     {
         return;
     }
@@ -267,7 +317,7 @@ fn check_single_int_op_one<'db>(
             is_item_ancestor_of_module(
                 db,
                 &LookupItemId::ModuleItem(ModuleItemId::Impl(impl_def_id)),
-                ModuleId::Submodule(corelib_context.get_integer_module_id()),
+                ModuleId::Submodule(integer_module_id),
             )
         } else {
             false
@@ -280,11 +330,6 @@ fn check_single_int_op_one<'db>(
     let lhs = &function_call_expr.args[0];
     let rhs = &function_call_expr.args[1];
 
-    let add_trait_function_id = corelib_context.get_add_trait_function_id();
-    let sub_trait_function_id = corelib_context.get_sub_trait_function_id();
-    let partial_ord_ge_trait_function_id = corelib_context.get_partial_ord_ge_trait_function_id();
-    let partial_ord_le_trait_function_id = corelib_context.get_partial_ord_le_trait_function_id();
-
     // x >= y + 1
     if check_is_variable(lhs, arenas)
         && check_is_add_or_sub_one(
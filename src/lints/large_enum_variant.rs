@@ -0,0 +1,147 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::constant::ConstValue;
+use cairo_lang_semantic::items::enm::EnumSemantic;
+use cairo_lang_semantic::items::structure::StructSemantic;
+use cairo_lang_semantic::{ConcreteTypeId, TypeId, TypeLongId};
+use cairo_lang_syntax::node::TypedStablePtr;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+
+/// Default size, in `felt252`s, above which a variant payload is considered large.
+pub const DEFAULT_LARGE_ENUM_VARIANT_THRESHOLD: usize = 16;
+
+/// How many times a large variant has to outsize the second largest one to be reported.
+const SIZE_RATIO: usize = 2;
+
+pub struct LargeEnumVariant;
+
+/// ## What it does
+///
+/// Checks for enum variants whose payload is much larger than the payloads of the other variants.
+/// Every value of the enum takes as much space as its largest variant, so such a variant makes
+/// all the other ones needlessly big.
+///
+/// A variant is reported when its payload takes more than the configured threshold (16 `felt252`s
+/// by default) and at least twice as much as the second largest payload. The size of a type is
+/// approximated in `felt252`s: the members of structs and tuples are summed up, a fixed-size array
+/// takes its size times the size of its element, an enum takes one for the variant selector plus
+/// the size of its largest variant, and any other type (e.g. integers, `Box` or `Array`) takes one.
+///
+/// ## Example
+///
+/// ```cairo
+/// enum Message {
+///     Quit,
+///     Data: [felt252; 32],
+/// }
+/// ```
+///
+/// Can be rewritten as:
+///
+/// ```cairo
+/// enum Message {
+///     Quit,
+///     Data: Box<[felt252; 32]>,
+/// }
+/// ```
+impl Lint for LargeEnumVariant {
+    fn allowed_name(&self) -> &'static str {
+        "large_enum_variant"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0087"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "This enum variant is much larger than the other ones. Consider boxing its payload with `Box<T>`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::LargeEnumVariant
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Performance
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_large_enum_variant<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let ModuleItemId::Enum(enum_id) = item else {
+        return;
+    };
+    let Ok(variants) = db.enum_variants(*enum_id) else {
+        return;
+    };
+
+    let mut variant_sizes = Vec::new();
+    for variant in variants.values() {
+        let Ok(semantic_variant) = db.variant_semantic(*enum_id, *variant) else {
+            return;
+        };
+        variant_sizes.push((*variant, type_size(db, semantic_variant.ty)));
+    }
+    variant_sizes.sort_by_key(|(_, size)| std::cmp::Reverse(*size));
+
+    let [
+        (largest_variant, largest_size),
+        (_, second_largest_size),
+        ..,
+    ] = variant_sizes[..]
+    else {
+        return;
+    };
+    if largest_size > params.large_enum_variant_threshold
+        && largest_size >= SIZE_RATIO * second_largest_size
+    {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: largest_variant.stable_ptr(db).untyped(),
+            message: LargeEnumVariant.diagnostic_message().to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
+
+/// Approximates the size of a value of the type, in `felt252`s.
+fn type_size<'db>(db: &'db dyn Database, ty: TypeId<'db>) -> usize {
+    match ty.long(db) {
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => db
+            .concrete_struct_members(*concrete_struct_id)
+            .map_or(1, |members| {
+                members
+                    .values()
+                    .map(|member| type_size(db, member.ty))
+                    .sum()
+            }),
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => db
+            .concrete_enum_variants(*concrete_enum_id)
+            .map_or(1, |variants| {
+                1 + variants
+                    .iter()
+                    .map(|variant| type_size(db, variant.ty))
+                    .max()
+                    .unwrap_or(0)
+            }),
+        TypeLongId::Tuple(types) => types.iter().map(|ty| type_size(db, *ty)).sum(),
+        TypeLongId::Snapshot(ty) => type_size(db, *ty),
+        TypeLongId::FixedSizeArray { type_id, size } => match size.long(db) {
+            ConstValue::Int(size, _) => usize::try_from(size)
+                .unwrap_or(usize::MAX)
+                .saturating_mul(type_size(db, *type_id)),
+            _ => 1,
+        },
+        _ => 1,
+    }
+}
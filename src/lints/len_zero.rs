@@ -0,0 +1,290 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{
+    FunctionWithBodyId, ModuleItemId, TopLevelLanguageElementId, TraitFunctionId,
+};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::function_with_body::{
+    FunctionWithBodySemantic, SemanticExprLookup,
+};
+use cairo_lang_semantic::items::imp::ImplSemantic;
+use cairo_lang_semantic::items::trt::TraitSemantic;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId};
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr, ExprPtr};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use num_bigint::BigInt;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{
+    find_module_containing_node, get_function_with_body_id, replace_preserving_trivia,
+};
+use crate::lints::manual::manual_is_empty::check_if_comparison_args_are_incorrect;
+use crate::lints::{EQ, GT, LT, NE, function_trait_name_from_fn_id};
+use crate::queries::{
+    get_all_conditions, get_all_function_bodies_with_ids,
+    syntax_node_to_str_without_all_nested_trivia,
+};
+
+pub struct LenZero;
+
+/// ## What it does
+///
+/// Checks for comparisons of the result of a `len` method with zero, i.e. `x.len() == 0`,
+/// `x.len() != 0` and `x.len() > 0`, for any collection, e.g. arrays, spans and byte arrays.
+/// Checking for emptiness with `is_empty()` states the intent more clearly. The `==` comparisons
+/// of arrays and spans used as `if` and `while` conditions are reported by `manual_is_empty`
+/// instead.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(values: Span<u32>) -> bool {
+///     values.len() > 0
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(values: Span<u32>) -> bool {
+///     !values.is_empty()
+/// }
+/// ```
+impl Lint for LenZero {
+    fn allowed_name(&self) -> &'static str {
+        "len_zero"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0115"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::LenZero
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_len_zero(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the comparison with `is_empty()`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_len_zero<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies_with_ids(db, item);
+    for (function_id, function_body) in function_bodies {
+        let arenas = &function_body.arenas;
+        let conditions: HashSet<ExprId> = get_all_conditions(function_body)
+            .into_iter()
+            .filter_map(|condition| match condition {
+                Condition::BoolExpr(expr_id) => Some(expr_id),
+                _ => None,
+            })
+            .collect();
+
+        for (expr_id, expr) in arenas.exprs.iter() {
+            let Expr::FunctionCall(function_call) = expr else {
+                continue;
+            };
+            let Some(comparison) = len_zero_comparison(db, arenas, function_call) else {
+                continue;
+            };
+            // Already reported by `manual_is_empty`.
+            if !comparison.non_empty
+                && conditions.contains(&expr_id)
+                && check_if_comparison_args_are_incorrect(db, function_call, arenas)
+            {
+                continue;
+            }
+            // The comparison is the usual implementation of `is_empty` itself.
+            if is_is_empty_of_len_impl(db, arenas, function_id, comparison.len_call) {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: function_call.stable_ptr.untyped(),
+                message: LenZero.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// A comparison of the result of a `len` method with zero.
+struct LenZeroComparison<'db> {
+    /// The call of the `len` method.
+    len_call: ExprId,
+    /// The trait function of the `len` method.
+    len_function: TraitFunctionId<'db>,
+    /// Whether the comparison checks that the collection is not empty, i.e. `!=` or `>`.
+    non_empty: bool,
+}
+
+fn len_zero_comparison<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    function_call: &ExprFunctionCall<'db>,
+) -> Option<LenZeroComparison<'db>> {
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = &function_call.args[..]
+    else {
+        return None;
+    };
+    let (lhs, rhs) = (peel_snapshot(arenas, *lhs), peel_snapshot(arenas, *rhs));
+    let function_name = function_trait_name_from_fn_id(db, &function_call.function);
+    let (len_call, non_empty) = match function_name.as_str() {
+        EQ | NE if is_zero_literal(arenas, rhs) => (lhs, function_name == NE),
+        EQ | NE if is_zero_literal(arenas, lhs) => (rhs, function_name == NE),
+        GT if is_zero_literal(arenas, rhs) => (lhs, true),
+        LT if is_zero_literal(arenas, lhs) => (rhs, true),
+        _ => return None,
+    };
+    Some(LenZeroComparison {
+        len_call,
+        len_function: len_trait_function(db, arenas, len_call)?,
+        non_empty,
+    })
+}
+
+fn peel_snapshot(arenas: &Arenas, expr_id: ExprId) -> ExprId {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(snapshot) => snapshot.inner,
+        _ => expr_id,
+    }
+}
+
+fn is_zero_literal(arenas: &Arenas, expr_id: ExprId) -> bool {
+    matches!(&arenas.exprs[expr_id], Expr::Literal(literal) if literal.value == BigInt::ZERO)
+}
+
+/// Returns the trait function called by the expression, if it's a `len` method.
+fn len_trait_function<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+) -> Option<TraitFunctionId<'db>> {
+    let Expr::FunctionCall(function_call) = &arenas.exprs[expr_id] else {
+        return None;
+    };
+    if function_call.args.len() != 1 {
+        return None;
+    }
+    let function_with_body = function_call.function.get_concrete(db).body(db).ok()??;
+    let trait_function = match function_with_body.function_with_body_id(db) {
+        FunctionWithBodyId::Impl(function) => db.impl_function_trait_function(function).ok()?,
+        FunctionWithBodyId::Trait(function) => function,
+        _ => return None,
+    };
+    trait_function
+        .full_path(db)
+        .ends_with("::len")
+        .then_some(trait_function)
+}
+
+/// Checks if the function is the `is_empty` method of the same impl as the called `len` method.
+fn is_is_empty_of_len_impl<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    function_id: FunctionWithBodyId<'db>,
+    len_call: ExprId,
+) -> bool {
+    let FunctionWithBodyId::Impl(function) = function_id else {
+        return false;
+    };
+    if function.name(db).long(db).as_str() != "is_empty" {
+        return false;
+    }
+    let Expr::FunctionCall(len_call) = &arenas.exprs[len_call] else {
+        return false;
+    };
+    let Ok(Some(len_function)) = len_call.function.get_concrete(db).body(db) else {
+        return false;
+    };
+    matches!(
+        len_function.function_with_body_id(db),
+        FunctionWithBodyId::Impl(len_function)
+            if len_function.impl_def_id(db) == function.impl_def_id(db)
+    )
+}
+
+/// Checks if the trait defining the `len` method defines `is_empty` too.
+fn has_is_empty_method<'db>(db: &'db dyn Database, len_function: TraitFunctionId<'db>) -> bool {
+    db.trait_functions(len_function.trait_id(db))
+        .is_ok_and(|functions| {
+            functions
+                .values()
+                .any(|function| function.full_path(db).ends_with("::is_empty"))
+        })
+}
+
+/// Rewrites `x.len() == 0` to `x.is_empty()`, and `x.len() != 0` or `x.len() > 0` to
+/// `!x.is_empty()`.
+///
+/// The fix is only available if the trait defining the `len` method defines `is_empty` too, and
+/// `len` is called with the method syntax.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_len_zero<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+    let module_id = find_module_containing_node(db, node)?;
+    let function_id = node
+        .ancestors(db)
+        .find_map(|ancestor| get_function_with_body_id(db, module_id, ancestor))?;
+    let arenas = &db.function_body(function_id).ok()?.arenas;
+    let expr_id = db
+        .lookup_expr_by_ptr(function_id, ExprPtr(node.stable_ptr(db)))
+        .ok()?;
+    let Expr::FunctionCall(function_call) = &arenas.exprs[expr_id] else {
+        return None;
+    };
+    let comparison = len_zero_comparison(db, arenas, function_call)?;
+    if !has_is_empty_method(db, comparison.len_function) {
+        return None;
+    }
+    let AstExpr::Binary(method_call) = arenas.exprs[comparison.len_call].stable_ptr().lookup(db)
+    else {
+        return None;
+    };
+    if !matches!(method_call.op(db), BinaryOperator::Dot(_)) {
+        return None;
+    }
+    let receiver =
+        syntax_node_to_str_without_all_nested_trivia(db, method_call.lhs(db).as_syntax_node());
+    let negation = if comparison.non_empty { "!" } else { "" };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            &format!("{negation}{receiver}.is_empty()"),
+        ),
+        description: LenZero.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -0,0 +1,259 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprId, Pattern, Statement, StatementId, VarId};
+use cairo_lang_syntax::node::ast::{
+    FunctionWithBody, OptionReturnTypeClause, OptionTerminalSemicolon, OptionTypeClause,
+    Statement as AstStatement, StatementList, TraitItemFunction,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{leading_trivia, trailing_trivia};
+use crate::queries::{get_all_function_bodies, syntax_node_to_str_without_all_nested_trivia};
+
+pub struct LetAndReturn;
+
+/// ## What it does
+///
+/// Checks for `let` bindings which are immediately returned, either as the tail expression of the
+/// block or with a `return` statement, and not used anywhere else.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(a: u32, b: u32) -> u32 {
+///     let sum = a + b;
+///     sum
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(a: u32, b: u32) -> u32 {
+///     a + b
+/// }
+/// ```
+impl Lint for LetAndReturn {
+    fn allowed_name(&self) -> &'static str {
+        "let_and_return"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0097"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Returning a variable right after binding it. Consider returning the expression directly."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::LetAndReturn
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_let_and_return(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Return the expression directly")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_let_and_return<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for (_expression_id, expression) in arenas.exprs.iter() {
+            let Expr::Block(block) = expression else {
+                continue;
+            };
+            // Either `let x = ...; x` or `let x = ...; return x;`.
+            let (let_statement, returned) = match (&block.statements[..], block.tail) {
+                ([.., let_statement], Some(tail)) => (*let_statement, tail),
+                ([.., let_statement, return_statement], None) => {
+                    let Statement::Return(return_statement) = &arenas.statements[*return_statement]
+                    else {
+                        continue;
+                    };
+                    let Some(returned) = return_statement.expr_option else {
+                        continue;
+                    };
+                    (*let_statement, returned)
+                }
+                _ => continue,
+            };
+            if is_let_and_return(arenas, let_statement, returned) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: arenas.statements[let_statement].stable_ptr().untyped(),
+                    message: LetAndReturn.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the statement binds a single variable, which is the returned expression and isn't
+/// used anywhere else.
+fn is_let_and_return(arenas: &Arenas, let_statement: StatementId, returned: ExprId) -> bool {
+    let Statement::Let(let_statement) = &arenas.statements[let_statement] else {
+        return false;
+    };
+    let Pattern::Variable(binding) = &arenas.patterns[let_statement.pattern] else {
+        return false;
+    };
+    let binding = VarId::Local(binding.var.id);
+
+    matches!(&arenas.exprs[returned], Expr::Var(var) if var.var == binding)
+        && arenas
+            .exprs
+            .iter()
+            .filter(|(_expression_id, expression)| {
+                matches!(expression, Expr::Var(var) if var.var == binding)
+            })
+            .count()
+            == 1
+}
+
+/// Removes the `let` statement and returns its expression in place of the variable.
+///
+/// The fix isn't available if there are attributes on the binding or comments between the two
+/// statements, which would be lost, or if the binding has a type annotation that may be needed to
+/// infer the type of the expression, i.e. it isn't the same as the return type of the function the
+/// value is returned from.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_let_and_return<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    if node.kind(db) != SyntaxKind::StatementLet {
+        return None;
+    }
+    let statement_list_node = node.parent(db)?;
+    let statement_list = StatementList::cast(db, statement_list_node)?;
+    let statements = statement_list.elements(db).collect::<Vec<_>>();
+    let index = statements
+        .iter()
+        .position(|statement| statement.as_syntax_node() == node)?;
+    let [AstStatement::Let(let_statement), returning_statement] = &statements[index..] else {
+        return None;
+    };
+    // The attributes of the binding would be lost.
+    if let_statement.attributes(db).elements(db).next().is_some() {
+        return None;
+    }
+    let returning_node = returning_statement.as_syntax_node();
+    if trailing_trivia(db, node).contains("//") || leading_trivia(db, returning_node).contains("//")
+    {
+        return None;
+    }
+
+    let is_tail = match returning_statement {
+        AstStatement::Expr(statement_expr) => {
+            matches!(
+                statement_expr.semicolon(db),
+                OptionTerminalSemicolon::Empty(_)
+            )
+        }
+        AstStatement::Return(_) => false,
+        _ => return None,
+    };
+    if let OptionTypeClause::TypeClause(type_clause) = let_statement.type_clause(db) {
+        let binding_type =
+            syntax_node_to_str_without_all_nested_trivia(db, type_clause.ty(db).as_syntax_node());
+        if returned_function_type(db, statement_list_node, is_tail) != Some(binding_type) {
+            return None;
+        }
+    }
+
+    let value = let_statement
+        .rhs(db)
+        .as_syntax_node()
+        .get_text_without_trivia(db);
+    let value = value.long(db);
+    let returned_value = if is_tail {
+        value.to_string()
+    } else {
+        format!("return {value};")
+    };
+    let suggestion = statements[..index]
+        .iter()
+        .map(|statement| statement.as_syntax_node().get_text(db).to_string())
+        .chain([format!(
+            "{}{returned_value}{}",
+            leading_trivia(db, node),
+            trailing_trivia(db, returning_node)
+        )])
+        .collect::<String>();
+
+    Some(InternalFix {
+        node: statement_list_node,
+        suggestion,
+        description: LetAndReturn.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Returns the return type of the function the value is returned from, i.e. the function whose
+/// body is the block for a tail expression, or the innermost function for a `return` statement.
+/// `None` if it can't be determined or the function doesn't declare a return type.
+fn returned_function_type<'db>(
+    db: &'db dyn Database,
+    statement_list: SyntaxNode<'db>,
+    is_tail: bool,
+) -> Option<String> {
+    let function = if is_tail {
+        let block = statement_list.parent(db)?;
+        if block.kind(db) != SyntaxKind::ExprBlock {
+            return None;
+        }
+        block.parent(db)?
+    } else {
+        statement_list.ancestors(db).find(|node| {
+            matches!(
+                node.kind(db),
+                SyntaxKind::FunctionWithBody
+                    | SyntaxKind::TraitItemFunction
+                    | SyntaxKind::ExprClosure
+            )
+        })?
+    };
+    let signature = match function.kind(db) {
+        SyntaxKind::FunctionWithBody => FunctionWithBody::from_syntax_node(db, function)
+            .declaration(db)
+            .signature(db),
+        SyntaxKind::TraitItemFunction => TraitItemFunction::from_syntax_node(db, function)
+            .declaration(db)
+            .signature(db),
+        _ => return None,
+    };
+    let OptionReturnTypeClause::ReturnTypeClause(return_type) = signature.ret_ty(db) else {
+        return None;
+    };
+    Some(syntax_node_to_str_without_all_nested_trivia(
+        db,
+        return_type.ty(db).as_syntax_node(),
+    ))
+}
@@ -0,0 +1,182 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprFor, ExprFunctionCallArg, ExprId, Pattern, Statement, StatementId, VarId,
+};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::helper::is_zero;
+use crate::lints::loops::helpers::{expr_span, trailing_increment};
+use crate::queries::get_all_function_bodies;
+
+pub struct ExplicitCounterLoop;
+
+/// ## What it does
+///
+/// Checks for `for` loops that keep track of the iteration index in a separate mutable variable,
+/// initialized to zero before the loop and incremented by one at the end of every iteration.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(values: Span<felt252>) {
+///     let mut i: usize = 0;
+///     for value in values {
+///         println!("{i}: {value}");
+///         i += 1;
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(values: Span<felt252>) {
+///     for (i, value) in values.into_iter().enumerate() {
+///         println!("{i}: {value}");
+///     }
+/// }
+/// ```
+impl Lint for ExplicitCounterLoop {
+    fn allowed_name(&self) -> &'static str {
+        "explicit_counter_loop"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0077"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "The loop counter is only used as an index. Consider using `.enumerate()` on the iterator instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ExplicitCounterLoop
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_explicit_counter_loop<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for (_expression_id, expression) in arenas.exprs.iter() {
+            let Expr::Block(block) = expression else {
+                continue;
+            };
+            // The counter has to be declared in the same block as the loop, before it.
+            let statement_exprs = block
+                .statements
+                .iter()
+                .map(|statement| match &arenas.statements[*statement] {
+                    Statement::Expr(statement_expr) => Some(statement_expr.expr),
+                    _ => None,
+                })
+                .chain([block.tail]);
+            for (position, expr_id) in statement_exprs.enumerate() {
+                let Some(Expr::For(for_expr)) = expr_id.map(|expr_id| &arenas.exprs[expr_id])
+                else {
+                    continue;
+                };
+                if is_explicit_counter_loop(db, arenas, &block.statements[..position], for_expr) {
+                    diagnostics.push(PluginDiagnostic {
+                        stable_ptr: for_expr.stable_ptr.untyped(),
+                        message: ExplicitCounterLoop.diagnostic_message().to_string(),
+                        severity: Severity::Warning,
+                        inner_span: None,
+                        error_code: None,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Checks if the loop increments a counter declared in one of the preceding statements, and the
+/// counter isn't used anywhere but inside the loop body.
+fn is_explicit_counter_loop<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    preceding_statements: &[StatementId],
+    for_expr: &ExprFor<'db>,
+) -> bool {
+    let Some(counter) = trailing_increment(db, arenas, for_expr.body) else {
+        return false;
+    };
+    preceding_statements
+        .iter()
+        .any(|statement| is_zero_initialized_counter(arenas, *statement, counter))
+        && is_used_only_in_body(db, arenas, counter, for_expr.body)
+}
+
+/// Checks if the statement is `let mut counter = 0;`.
+fn is_zero_initialized_counter(arenas: &Arenas, statement: StatementId, counter: VarId) -> bool {
+    let Statement::Let(let_statement) = &arenas.statements[statement] else {
+        return false;
+    };
+    let Pattern::Variable(pattern) = &arenas.patterns[let_statement.pattern] else {
+        return false;
+    };
+    pattern.var.is_mut
+        && VarId::Local(pattern.var.id) == counter
+        && is_zero(&ExprFunctionCallArg::Value(let_statement.expr), arenas)
+}
+
+/// Checks if the counter is read only inside the loop body, and the increment is its only mutation.
+/// A `continue` in the body would skip the increment, so the counter wouldn't match the index.
+fn is_used_only_in_body<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    counter: VarId<'db>,
+    body: ExprId,
+) -> bool {
+    let body_span = expr_span(db, arenas, body);
+
+    let mut mutations = 0;
+    for (expression_id, expression) in arenas.exprs.iter() {
+        match expression {
+            Expr::Var(var) if var.var == counter => {
+                if !body_span.contains(expr_span(db, arenas, expression_id)) {
+                    return false;
+                }
+            }
+            Expr::Assignment(assignment) if assignment.ref_arg.base_var() == counter => {
+                mutations += 1;
+            }
+            Expr::FunctionCall(func_call) => {
+                mutations += func_call
+                    .args
+                    .iter()
+                    .filter(|arg| {
+                        matches!(
+                            arg,
+                            ExprFunctionCallArg::Reference(ref_arg)
+                                if ref_arg.base_var() == counter
+                        )
+                    })
+                    .count();
+            }
+            _ => {}
+        }
+    }
+
+    let has_continue = arenas.statements.iter().any(|(_statement_id, statement)| {
+        matches!(statement, Statement::Continue(_))
+            && body_span.contains(statement.stable_ptr().lookup(db).as_syntax_node().span(db))
+    });
+
+    mutations == 1 && !has_continue
+}
@@ -0,0 +1,160 @@
+use cairo_lang_defs::ids::TopLevelLanguageElementId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprFor, ExprFunctionCallArg, ExprId, ExprVarMemberPath, Pattern,
+    PatternVariable, Statement, TypeLongId, VarId,
+};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::helper::is_one;
+use crate::lints::{ADD, ADD_ASSIGN, ARRAY, EQ, FALSE, SPAN, TRUE, function_trait_name_from_fn_id};
+
+/// Returns the variable bound to each element, if the `for` loop iterates an array or a span
+/// and binds the elements to a single variable.
+pub fn array_for_loop_element<'a, 'db>(
+    db: &'db dyn Database,
+    for_expr: &ExprFor<'db>,
+    arenas: &'a Arenas<'db>,
+) -> Option<&'a PatternVariable<'db>> {
+    if !is_array_or_span(db, arenas.exprs[for_expr.expr_id].ty().long(db)) {
+        return None;
+    }
+    match &arenas.patterns[for_expr.pattern] {
+        Pattern::Variable(element) => Some(element),
+        _ => None,
+    }
+}
+
+/// Returns the only expression of the block, whether it's a tail or a statement.
+pub fn single_expr_of_block(arenas: &Arenas, block_id: ExprId) -> Option<ExprId> {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return None;
+    };
+    match (&block.statements[..], block.tail) {
+        ([], Some(tail)) => Some(tail),
+        ([statement], None) => match &arenas.statements[*statement] {
+            Statement::Expr(statement_expr) => Some(statement_expr.expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Checks if the condition is an equality check between the loop element and another value.
+pub fn is_element_comparison<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    condition: ExprId,
+    element: VarId<'db>,
+) -> bool {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[condition] else {
+        return false;
+    };
+    if function_trait_name_from_fn_id(db, &func_call.function) != EQ {
+        return false;
+    }
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = &func_call.args[..]
+    else {
+        return false;
+    };
+    let lhs = variable_of(arenas, *lhs);
+    let rhs = variable_of(arenas, *rhs);
+    (lhs == Some(element)) != (rhs == Some(element))
+}
+
+/// Returns the value of the expression if it's a `true` or `false` literal.
+pub fn bool_literal_value<'db>(db: &'db dyn Database, expr: &Expr<'db>) -> Option<bool> {
+    let Expr::EnumVariantCtor(variant_ctor) = expr else {
+        return None;
+    };
+    match variant_ctor.variant.id.full_path(db).as_str() {
+        TRUE => Some(true),
+        FALSE => Some(false),
+        _ => None,
+    }
+}
+
+/// Returns the variable incremented by one in the last statement of the block.
+pub fn trailing_increment<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    block_id: ExprId,
+) -> Option<VarId<'db>> {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return None;
+    };
+    let last_expr = match (block.statements.last(), block.tail) {
+        (_, Some(tail)) => tail,
+        (Some(statement), None) => match &arenas.statements[*statement] {
+            Statement::Expr(statement_expr) => statement_expr.expr,
+            _ => return None,
+        },
+        (None, None) => return None,
+    };
+    match &arenas.exprs[last_expr] {
+        // `i += 1`
+        Expr::FunctionCall(func_call)
+            if function_trait_name_from_fn_id(db, &func_call.function) == ADD_ASSIGN =>
+        {
+            let [
+                ExprFunctionCallArg::Reference(ExprVarMemberPath::Var(counter)),
+                one,
+            ] = &func_call.args[..]
+            else {
+                return None;
+            };
+            is_one(one, arenas).then_some(counter.var)
+        }
+        // `i = i + 1`
+        Expr::Assignment(assignment) => {
+            let ExprVarMemberPath::Var(counter) = &assignment.ref_arg else {
+                return None;
+            };
+            let Expr::FunctionCall(func_call) = &arenas.exprs[assignment.rhs] else {
+                return None;
+            };
+            if function_trait_name_from_fn_id(db, &func_call.function) != ADD {
+                return None;
+            }
+            let [ExprFunctionCallArg::Value(lhs), rhs] = &func_call.args[..] else {
+                return None;
+            };
+            (variable_of(arenas, *lhs) == Some(counter.var) && is_one(rhs, arenas))
+                .then_some(counter.var)
+        }
+        _ => None,
+    }
+}
+
+pub fn variable_of<'db>(arenas: &Arenas<'db>, expr_id: ExprId) -> Option<VarId<'db>> {
+    match &arenas.exprs[expr_id] {
+        Expr::Var(var) => Some(var.var),
+        Expr::Snapshot(snapshot) => variable_of(arenas, snapshot.inner),
+        Expr::Desnap(desnap) => variable_of(arenas, desnap.inner),
+        _ => None,
+    }
+}
+
+/// Returns the span of the expression in its file.
+pub fn expr_span(db: &dyn Database, arenas: &Arenas, expr_id: ExprId) -> TextSpan {
+    arenas.exprs[expr_id]
+        .stable_ptr()
+        .lookup(db)
+        .as_syntax_node()
+        .span(db)
+}
+
+fn is_array_or_span(db: &dyn Database, type_long_id: &TypeLongId) -> bool {
+    match type_long_id {
+        TypeLongId::Snapshot(type_id) => is_array_or_span(db, type_id.long(db)),
+        TypeLongId::Concrete(concrete_type_id) => {
+            let generic_type_name = concrete_type_id.generic_type(db).format(db);
+            [ARRAY, SPAN].contains(&generic_type_name.as_str())
+        }
+        _ => false,
+    }
+}
@@ -9,7 +9,8 @@ use cairo_lang_syntax::node::{
 };
 use if_chain::if_chain;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::helper::{invert_condition, remove_break_from_block, remove_break_from_else_clause};
@@ -52,6 +53,10 @@ impl Lint for LoopForWhile {
         "loop_for_while"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0017"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "you seem to be trying to use `loop`. Consider replacing this `loop` with a `while` \
                                   loop for clarity and conciseness"
@@ -61,6 +66,10 @@ impl Lint for LoopForWhile {
         CairoLintKind::LoopForWhile
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -93,6 +102,7 @@ impl Lint for LoopForWhile {
 pub fn check_loop_for_while<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -15,7 +15,8 @@ use cairo_lang_syntax::node::{
 };
 use if_chain::if_chain;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::helper::indent_snippet;
@@ -56,6 +57,10 @@ impl Lint for LoopMatchPopFront {
         "loop_match_pop_front"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0014"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "you seem to be trying to use `loop` for iterating over a span. Consider using `for in`"
     }
@@ -64,6 +69,10 @@ impl Lint for LoopMatchPopFront {
         CairoLintKind::LoopMatchPopFront
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -81,6 +90,7 @@ impl Lint for LoopMatchPopFront {
 pub fn check_loop_match_pop_front<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
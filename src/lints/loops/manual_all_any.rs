@@ -0,0 +1,245 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFor, ExprId, ExprVarMemberPath, Statement, VarId,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::loops::helpers::{
+    array_for_loop_element, bool_literal_value, is_element_comparison, single_expr_of_block,
+};
+use crate::queries::{get_all_for_expressions, get_all_function_bodies};
+
+pub struct ManualAll;
+
+/// ## What it does
+///
+/// Checks for `for` loops over an array or a span which exit early with `false` on the first
+/// element failing a condition, reimplementing `all`.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(values: Span<u32>) -> bool {
+///     let mut result = true;
+///     for value in values {
+///         if *value == 0 {
+///             result = false;
+///             break;
+///         }
+///     }
+///     result
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(values: Span<u32>) -> bool {
+///     values.into_iter().all(|value| *value != 0)
+/// }
+/// ```
+impl Lint for ManualAll {
+    fn allowed_name(&self) -> &'static str {
+        "manual_all"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0066"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual implementation of `all` detected. Consider using `.all()` on the iterator instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualAllAny
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+pub struct ManualAny;
+
+/// ## What it does
+///
+/// Checks for `for` loops over an array or a span which exit early with `true` on the first
+/// element satisfying a condition, reimplementing `any`.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(values: Span<u32>) -> bool {
+///     for value in values {
+///         if *value > 10 {
+///             return true;
+///         }
+///     }
+///     false
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(values: Span<u32>) -> bool {
+///     values.into_iter().any(|value| *value > 10)
+/// }
+/// ```
+impl Lint for ManualAny {
+    fn allowed_name(&self) -> &'static str {
+        "manual_any"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0067"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual implementation of `any` detected. Consider using `.any()` on the iterator instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualAllAny
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_all_any<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for for_expr in get_all_for_expressions(function_body) {
+            let message = match early_exit_value(db, &for_expr, arenas) {
+                Some(false) => ManualAll.diagnostic_message(),
+                Some(true) => ManualAny.diagnostic_message(),
+                None => continue,
+            };
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: for_expr.stable_ptr.untyped(),
+                message: message.to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Checks if the body of the `for` loop consists only of `if condition { ... }`, where the
+/// condition depends on the loop element and the `if` block exits the loop with a boolean,
+/// either by returning it or by assigning it to a flag and breaking.
+/// Returns the boolean the loop exits with, `false` meaning `all` and `true` meaning `any`.
+fn early_exit_value<'db>(
+    db: &'db dyn Database,
+    for_expr: &ExprFor<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<bool> {
+    let element = array_for_loop_element(db, for_expr, arenas)?;
+    let element_var = VarId::Local(element.var.id);
+
+    let Expr::If(if_expr) = &arenas.exprs[single_expr_of_block(arenas, for_expr.body)?] else {
+        return None;
+    };
+    if if_expr.else_block.is_some() {
+        return None;
+    }
+    let [Condition::BoolExpr(condition)] = &if_expr.conditions[..] else {
+        return None;
+    };
+    if !condition_uses_element(db, arenas, *condition, for_expr) {
+        return None;
+    }
+
+    let value = exit_value_of_block(db, arenas, if_expr.if_block, element_var)?;
+    // Equality checks exiting with `true` are reported by `manual_contains`.
+    if value && is_element_comparison(db, arenas, *condition, element_var) {
+        return None;
+    }
+    Some(value)
+}
+
+/// Returns the boolean exiting the loop, if the block consists only of `return <bool>;`
+/// or `<flag> = <bool>; break;`.
+fn exit_value_of_block<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    block_id: ExprId,
+    element: VarId<'db>,
+) -> Option<bool> {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return None;
+    };
+    if block.tail.is_some() {
+        return None;
+    }
+    match &block.statements[..] {
+        [statement] => {
+            let Statement::Return(return_stmt) = &arenas.statements[*statement] else {
+                return None;
+            };
+            bool_literal_value(db, &arenas.exprs[return_stmt.expr_option?])
+        }
+        [statement, break_statement] => {
+            let Statement::Break(break_stmt) = &arenas.statements[*break_statement] else {
+                return None;
+            };
+            if break_stmt.expr_option.is_some() {
+                return None;
+            }
+            let Statement::Expr(statement_expr) = &arenas.statements[*statement] else {
+                return None;
+            };
+            let Expr::Assignment(assignment) = &arenas.exprs[statement_expr.expr] else {
+                return None;
+            };
+            let ExprVarMemberPath::Var(flag) = &assignment.ref_arg else {
+                return None;
+            };
+            if flag.var == element {
+                return None;
+            }
+            bool_literal_value(db, &arenas.exprs[assignment.rhs])
+        }
+        _ => None,
+    }
+}
+
+/// Checks if the loop element is referenced in the condition.
+fn condition_uses_element<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    condition: ExprId,
+    for_expr: &ExprFor<'db>,
+) -> bool {
+    let element_name = arenas.patterns[for_expr.pattern]
+        .stable_ptr()
+        .lookup(db)
+        .as_syntax_node()
+        .get_text_without_trivia(db);
+    arenas.exprs[condition]
+        .stable_ptr()
+        .lookup(db)
+        .as_syntax_node()
+        .descendants(db)
+        .any(|node| {
+            node.kind(db) == SyntaxKind::TerminalIdentifier
+                && node.get_text_without_trivia(db) == element_name
+        })
+}
@@ -0,0 +1,237 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, Pattern, Statement, TypeLongId, VarId,
+};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::loops::helpers::{
+    expr_span, single_expr_of_block, trailing_increment, variable_of,
+};
+use crate::lints::{ARRAY_APPEND, LT, NE, function_trait_name_from_fn_id};
+use crate::queries::{get_all_for_expressions, get_all_function_bodies, get_all_while_expressions};
+
+const RANGE: &str = "core::ops::range::Range";
+
+pub struct ManualArrayFill;
+
+/// ## What it does
+///
+/// Checks for loops which only append the same value to an array a fixed number of times,
+/// either iterating over a range or incrementing a counter up to a bound.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(n: u32) -> Array<felt252> {
+///     let mut arr = array![];
+///     for _ in 0..n {
+///         arr.append(0);
+///     }
+///     arr
+/// }
+/// ```
+impl Lint for ManualArrayFill {
+    fn allowed_name(&self) -> &'static str {
+        "manual_array_fill"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0096"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Loop appends the same value to an array on every iteration. Consider filling the array with a repeat helper instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualArrayFill
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_array_fill<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        // `for _ in 0..n { arr.append(value); }`
+        let for_fills = get_all_for_expressions(function_body)
+            .into_iter()
+            .filter(|for_expr| {
+                is_range(db, arenas.exprs[for_expr.expr_id].ty().long(db))
+                    && single_expr_of_block(arenas, for_expr.body).is_some_and(|append| {
+                        let loop_span = for_expr.stable_ptr.lookup(db).as_syntax_node().span(db);
+                        is_loop_invariant_append(db, arenas, append, loop_span)
+                    })
+            })
+            .map(|for_expr| for_expr.stable_ptr);
+        // `while i < n { arr.append(value); i += 1; }`
+        let while_fills = get_all_while_expressions(function_body)
+            .into_iter()
+            .filter(|while_expr| {
+                let loop_span = while_expr.stable_ptr.lookup(db).as_syntax_node().span(db);
+                is_counter_bound(db, arenas, &while_expr.condition, while_expr.body)
+                    && leading_expr_of_two(arenas, while_expr.body).is_some_and(|append| {
+                        is_loop_invariant_append(db, arenas, append, loop_span)
+                    })
+            })
+            .map(|while_expr| while_expr.stable_ptr);
+
+        for stable_ptr in for_fills.chain(while_fills) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: stable_ptr.untyped(),
+                message: ManualArrayFill.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+fn is_range(db: &dyn Database, type_long_id: &TypeLongId) -> bool {
+    match type_long_id {
+        TypeLongId::Concrete(concrete_type_id) => {
+            concrete_type_id.generic_type(db).format(db) == RANGE
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the condition is `counter < bound` or `counter != bound`, where the counter is
+/// incremented by one at the end of the loop body.
+fn is_counter_bound<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    condition: &Condition,
+    body: ExprId,
+) -> bool {
+    let Condition::BoolExpr(condition) = condition else {
+        return false;
+    };
+    let Expr::FunctionCall(func_call) = &arenas.exprs[*condition] else {
+        return false;
+    };
+    if ![LT, NE].contains(&function_trait_name_from_fn_id(db, &func_call.function).as_str()) {
+        return false;
+    }
+    let [ExprFunctionCallArg::Value(counter), _bound] = &func_call.args[..] else {
+        return false;
+    };
+    variable_of(arenas, *counter).is_some()
+        && variable_of(arenas, *counter) == trailing_increment(db, arenas, body)
+}
+
+/// Returns the first expression of the block, if the block consists of exactly two expressions.
+fn leading_expr_of_two(arenas: &Arenas, block_id: ExprId) -> Option<ExprId> {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return None;
+    };
+    let statement_exprs = block
+        .statements
+        .iter()
+        .map(|statement| match &arenas.statements[*statement] {
+            Statement::Expr(statement_expr) => Some(statement_expr.expr),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    match (&statement_exprs[..], block.tail) {
+        ([first, _], None) | ([first], Some(_)) => Some(*first),
+        _ => None,
+    }
+}
+
+/// Checks if the expression is `arr.append(value)`, where the value is the same in every
+/// iteration of the loop.
+fn is_loop_invariant_append<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+    loop_span: TextSpan,
+) -> bool {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[expr_id] else {
+        return false;
+    };
+    if function_trait_name_from_fn_id(db, &func_call.function) != ARRAY_APPEND {
+        return false;
+    }
+    let [
+        ExprFunctionCallArg::Reference(_array),
+        ExprFunctionCallArg::Value(value),
+    ] = &func_call.args[..]
+    else {
+        return false;
+    };
+    is_loop_invariant(db, arenas, *value, loop_span)
+}
+
+/// Checks if the expression is a literal, a constant or a variable which is neither bound nor
+/// mutated inside the loop.
+fn is_loop_invariant<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+    loop_span: TextSpan,
+) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Literal(_) | Expr::StringLiteral(_) | Expr::Constant(_) => true,
+        Expr::Snapshot(snapshot) => is_loop_invariant(db, arenas, snapshot.inner, loop_span),
+        Expr::Desnap(desnap) => is_loop_invariant(db, arenas, desnap.inner, loop_span),
+        Expr::Var(var) => {
+            !is_bound_in_loop(db, arenas, var.var, loop_span)
+                && !is_mutated_in_loop(db, arenas, var.var, loop_span)
+        }
+        _ => false,
+    }
+}
+
+fn is_bound_in_loop<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    var: VarId<'db>,
+    loop_span: TextSpan,
+) -> bool {
+    arenas.patterns.iter().any(|(_pattern_id, pattern)| {
+        matches!(
+            pattern,
+            Pattern::Variable(pattern)
+                if VarId::Local(pattern.var.id) == var
+                    && loop_span.contains(pattern.stable_ptr.lookup(db).as_syntax_node().span(db))
+        )
+    })
+}
+
+fn is_mutated_in_loop<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    var: VarId<'db>,
+    loop_span: TextSpan,
+) -> bool {
+    arenas.exprs.iter().any(|(expression_id, expression)| {
+        let is_mutation = match expression {
+            Expr::Assignment(assignment) => assignment.ref_arg.base_var() == var,
+            Expr::FunctionCall(func_call) => func_call.args.iter().any(|arg| {
+                matches!(
+                    arg,
+                    ExprFunctionCallArg::Reference(ref_arg) if ref_arg.base_var() == var
+                )
+            }),
+            _ => false,
+        };
+        is_mutation && loop_span.contains(expr_span(db, arenas, expression_id))
+    })
+}
@@ -0,0 +1,183 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFor, ExprId, ExprVarMemberPath, Statement, StatementId, VarId,
+};
+use cairo_lang_syntax::node::TypedStablePtr;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::loops::helpers::{
+    array_for_loop_element, bool_literal_value, is_element_comparison, single_expr_of_block,
+};
+use crate::queries::{get_all_for_expressions, get_all_function_bodies};
+
+pub struct ManualContains;
+
+/// ## What it does
+///
+/// Checks for `for` loops over an array or a span whose only purpose is to check whether
+/// one of the elements is equal to a value, either by returning `true` early or by setting a flag.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(values: Span<felt252>, needle: felt252) -> bool {
+///     for value in values {
+///         if *value == needle {
+///             return true;
+///         }
+///     }
+///     false
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(values: Span<felt252>, needle: felt252) -> bool {
+///     values.into_iter().any(|value| *value == needle)
+/// }
+/// ```
+impl Lint for ManualContains {
+    fn allowed_name(&self) -> &'static str {
+        "manual_contains"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0065"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual membership check detected. Consider using `.contains()` or `.any()` on the iterator instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualContains
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_contains<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for for_expr in get_all_for_expressions(function_body) {
+            if is_manual_contains(db, &for_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: for_expr.stable_ptr.untyped(),
+                    message: ManualContains.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the `for` loop iterates an array or a span and its body consists only of
+/// `if element == needle { ... }` where the `if` block returns `true` or sets a flag to `true`.
+fn is_manual_contains<'db>(
+    db: &'db dyn Database,
+    for_expr: &ExprFor<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let Some(element) = array_for_loop_element(db, for_expr, arenas) else {
+        return false;
+    };
+    let element = VarId::Local(element.var.id);
+
+    let Some(if_expr_id) = single_expr_of_block(arenas, for_expr.body) else {
+        return false;
+    };
+    let Expr::If(if_expr) = &arenas.exprs[if_expr_id] else {
+        return false;
+    };
+    if if_expr.else_block.is_some() {
+        return false;
+    }
+    let [Condition::BoolExpr(condition)] = &if_expr.conditions[..] else {
+        return false;
+    };
+    is_element_comparison(db, arenas, *condition, element)
+        && is_found_block(db, arenas, if_expr.if_block, element)
+}
+
+/// Checks if the block only returns `true`, or sets a flag to `true` and optionally breaks.
+fn is_found_block<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    block_id: ExprId,
+    element: VarId<'db>,
+) -> bool {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return false;
+    };
+    match (&block.statements[..], block.tail) {
+        ([statement], None) => {
+            is_return_true(db, arenas, *statement)
+                || is_flag_set_statement(db, arenas, *statement, element)
+        }
+        ([], Some(tail)) => is_flag_set(db, arenas, tail, element),
+        ([statement, break_statement], None) => {
+            matches!(
+                &arenas.statements[*break_statement],
+                Statement::Break(break_stmt) if break_stmt.expr_option.is_none()
+            ) && is_flag_set_statement(db, arenas, *statement, element)
+        }
+        _ => false,
+    }
+}
+
+fn is_return_true<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    statement: StatementId,
+) -> bool {
+    let Statement::Return(return_stmt) = &arenas.statements[statement] else {
+        return false;
+    };
+    return_stmt
+        .expr_option
+        .is_some_and(|expr| bool_literal_value(db, &arenas.exprs[expr]) == Some(true))
+}
+
+fn is_flag_set_statement<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    statement: StatementId,
+    element: VarId<'db>,
+) -> bool {
+    let Statement::Expr(statement_expr) = &arenas.statements[statement] else {
+        return false;
+    };
+    is_flag_set(db, arenas, statement_expr.expr, element)
+}
+
+/// Checks if the expression is an assignment of `true` to a variable other than the loop element.
+fn is_flag_set<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+    element: VarId<'db>,
+) -> bool {
+    let Expr::Assignment(assignment) = &arenas.exprs[expr_id] else {
+        return false;
+    };
+    let ExprVarMemberPath::Var(flag) = &assignment.ref_arg else {
+        return false;
+    };
+    flag.var != element && bool_literal_value(db, &arenas.exprs[assignment.rhs]) == Some(true)
+}
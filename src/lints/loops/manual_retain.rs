@@ -0,0 +1,232 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFor, ExprFunctionCallArg, ExprId, ExprVarMemberPath, Pattern,
+    Statement, StatementId, VarId,
+};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::loops::helpers::{
+    array_for_loop_element, expr_span, single_expr_of_block, variable_of,
+};
+use crate::lints::manual::helpers::empty_array_binding_name;
+use crate::lints::{ARRAY_APPEND, function_trait_name_from_fn_id};
+use crate::queries::get_all_function_bodies;
+
+pub struct ManualRetain;
+
+/// ## What it does
+///
+/// Checks for loops which only append the elements matching a predicate to a new array.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(numbers: Array<u32>) -> Array<u32> {
+///     let mut evens = array![];
+///     for number in numbers {
+///         if number % 2 == 0 {
+///             evens.append(number);
+///         }
+///     }
+///     evens
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(numbers: Array<u32>) -> Array<u32> {
+///     numbers.into_iter().filter(|number| number % 2 == 0).collect()
+/// }
+/// ```
+impl Lint for ManualRetain {
+    fn allowed_name(&self) -> &'static str {
+        "manual_retain"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0101"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual filtering of a collection into a new array detected. Consider collecting a filtered iterator instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualRetain
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_retain<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for (_expression_id, expression) in arenas.exprs.iter() {
+            let Expr::Block(block) = expression else {
+                continue;
+            };
+            // The destination array has to be created in the same block as the loop, before it.
+            let statement_exprs = block
+                .statements
+                .iter()
+                .map(|statement| match &arenas.statements[*statement] {
+                    Statement::Expr(statement_expr) => Some(statement_expr.expr),
+                    _ => None,
+                })
+                .chain([block.tail]);
+            for (position, expr_id) in statement_exprs.enumerate() {
+                let Some(Expr::For(for_expr)) = expr_id.map(|expr_id| &arenas.exprs[expr_id])
+                else {
+                    continue;
+                };
+                if is_manual_retain(db, arenas, &block.statements[..position], for_expr) {
+                    diagnostics.push(PluginDiagnostic {
+                        stable_ptr: for_expr.stable_ptr.untyped(),
+                        message: ManualRetain.diagnostic_message().to_string(),
+                        severity: Severity::Warning,
+                        inner_span: None,
+                        error_code: None,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Checks if the body of the loop is a single `if` without an `else`, appending the unchanged
+/// element to an empty array created in one of the preceding statements.
+fn is_manual_retain<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    preceding_statements: &[StatementId],
+    for_expr: &ExprFor<'db>,
+) -> bool {
+    let Some(element) = array_for_loop_element(db, for_expr, arenas) else {
+        return false;
+    };
+    let element = VarId::Local(element.var.id);
+    let Some(Expr::If(if_expr)) =
+        single_expr_of_block(arenas, for_expr.body).map(|expr_id| &arenas.exprs[expr_id])
+    else {
+        return false;
+    };
+    let ([Condition::BoolExpr(condition)], None) = (&if_expr.conditions[..], if_expr.else_block)
+    else {
+        return false;
+    };
+    let Some(append) = single_expr_of_block(arenas, if_expr.if_block) else {
+        return false;
+    };
+    let Some(destination) = appended_element_destination(db, arenas, append, element) else {
+        return false;
+    };
+
+    preceding_statements
+        .iter()
+        .any(|statement| is_empty_array_binding(db, arenas, *statement, destination))
+        && depends_only_on_element(db, arenas, *condition, element)
+        && is_only_appended(db, arenas, destination, append, for_expr)
+}
+
+/// Returns the array the element is appended to, if the expression is `array.append(element)`.
+fn appended_element_destination<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+    element: VarId<'db>,
+) -> Option<VarId<'db>> {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[expr_id] else {
+        return None;
+    };
+    if function_trait_name_from_fn_id(db, &func_call.function) != ARRAY_APPEND {
+        return None;
+    }
+    let [
+        ExprFunctionCallArg::Reference(ExprVarMemberPath::Var(destination)),
+        ExprFunctionCallArg::Value(value),
+    ] = &func_call.args[..]
+    else {
+        return None;
+    };
+    (variable_of(arenas, *value) == Some(element)).then_some(destination.var)
+}
+
+/// Checks if the statement is `let mut array = array![];` or `let mut array = ArrayTrait::new();`.
+fn is_empty_array_binding<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    statement: StatementId,
+    array: VarId<'db>,
+) -> bool {
+    let Statement::Let(let_statement) = &arenas.statements[statement] else {
+        return false;
+    };
+    let Pattern::Variable(pattern) = &arenas.patterns[let_statement.pattern] else {
+        return false;
+    };
+    VarId::Local(pattern.var.id) == array
+        && empty_array_binding_name(db, &let_statement.stable_ptr.lookup(db)).is_some()
+}
+
+/// Checks if the element is the only variable the condition reads.
+fn depends_only_on_element<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    condition: ExprId,
+    element: VarId<'db>,
+) -> bool {
+    let condition_span = expr_span(db, arenas, condition);
+    let mut variables = arenas
+        .exprs
+        .iter()
+        .filter_map(|(expression_id, expression)| match expression {
+            Expr::Var(var) if condition_span.contains(expr_span(db, arenas, expression_id)) => {
+                Some(var.var)
+            }
+            _ => None,
+        })
+        .peekable();
+    variables.peek().is_some() && variables.all(|variable| variable == element)
+}
+
+/// Checks if the array isn't used anywhere before the end of the loop, except for the append.
+fn is_only_appended<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    array: VarId<'db>,
+    append: ExprId,
+    for_expr: &ExprFor<'db>,
+) -> bool {
+    let loop_end = for_expr.stable_ptr.lookup(db).as_syntax_node().span(db).end;
+    arenas.exprs.iter().all(|(expression_id, expression)| {
+        let uses_array = match expression {
+            Expr::Var(var) => var.var == array,
+            Expr::Assignment(assignment) => assignment.ref_arg.base_var() == array,
+            Expr::FunctionCall(func_call) => func_call.args.iter().any(|arg| {
+                matches!(
+                    arg,
+                    ExprFunctionCallArg::Reference(ref_arg) if ref_arg.base_var() == array
+                )
+            }),
+            _ => false,
+        };
+        !uses_array
+            || expression_id == append
+            || expr_span(db, arenas, expression_id).start >= loop_end
+    })
+}
@@ -1,2 +1,8 @@
+pub mod explicit_counter_loop;
+mod helpers;
 pub mod loop_for_while;
 pub mod loop_match_pop_front;
+pub mod manual_all_any;
+pub mod manual_array_fill;
+pub mod manual_contains;
+pub mod manual_retain;
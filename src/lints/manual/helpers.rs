@@ -1,7 +1,8 @@
 use super::is_expected_variant;
 
 use crate::helper::find_module_containing_node;
-use crate::lints::{ARRAY_NEW, DEFAULT, FALSE, NEVER, function_trait_name_from_fn_id};
+use crate::lints::{ARRAY_NEW, BOOL, DEFAULT, FALSE, NEVER, function_trait_name_from_fn_id};
+use crate::queries::syntax_node_to_str_without_all_nested_trivia;
 use cairo_lang_defs::ids::{ModuleId, ModuleItemId, TopLevelLanguageElementId};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder};
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
@@ -9,22 +10,24 @@ use cairo_lang_semantic::items::free_function::FreeFunctionSemantic;
 use cairo_lang_semantic::items::imp::ImplSemantic;
 use cairo_lang_semantic::items::trt::TraitSemantic;
 use cairo_lang_semantic::{
-    Arenas, Condition, Expr, ExprIf, FixedSizeArrayItems, LocalVariable, Pattern, PatternVariable,
-    SemanticDiagnostic, Statement, VarId,
+    Arenas, Condition, Expr, ExprFunctionCallArg, ExprIf, FixedSizeArrayItems, LocalVariable,
+    Pattern, PatternVariable, SemanticDiagnostic, Statement, VarId,
 };
 use cairo_lang_syntax::node::ast::{
-    BlockOrIf, Condition as AstCondition, Expr as AstExpr, ExprIf as AstExprIf,
-    ExprMatch as AstExprMatch, MatchArm as AstMatchArm, OptionElseClause, Pattern as AstPattern,
-    Statement as AstStatement,
+    ArgClause, BinaryOperator, BlockOrIf, Condition as AstCondition, Expr as AstExpr,
+    ExprIf as AstExprIf, ExprMatch as AstExprMatch, MatchArm as AstMatchArm, OptionElseClause,
+    Pattern as AstPattern, Statement as AstStatement,
 };
 
 use cairo_lang_syntax::node::helpers::GetIdentifier;
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 use num_bigint::BigInt;
 use salsa::Database;
 
+const EMPTY_ARRAY_CREATIONS: [&str; 2] = ["array![]", "ArrayTrait::new()"];
+
 pub struct MatchOnOption<'db> {
     pub some_arm: AstMatchArm<'db>,
     pub none_arm: AstMatchArm<'db>,
@@ -539,6 +542,115 @@ pub fn match_arm_returns_extracted_var(expr: &Expr, pattern: &Pattern, arenas: &
     pattern_check_enum_arg(pattern, &enum_destruct_var.var, arenas)
 }
 
+/// Checks if a match arm calls a function (or a method) whose only argument is the variable
+/// extracted from an enum variant, e.g. `Option::Some(x) => foo(x)` or `Option::Some(x) => x.foo()`.
+pub fn match_arm_applies_function_to_extracted_var(
+    expr: &Expr,
+    pattern: &Pattern,
+    arenas: &Arenas,
+) -> bool {
+    let Expr::FunctionCall(func_call) = expr else {
+        return false;
+    };
+    let [ExprFunctionCallArg::Value(arg)] = &func_call.args[..] else {
+        return false;
+    };
+    // Methods taking `self` by snapshot are called on the snapshot of the variable.
+    let arg = match &arenas.exprs[*arg] {
+        Expr::Snapshot(snapshot) => &arenas.exprs[snapshot.inner],
+        arg => arg,
+    };
+    let Expr::Var(arg_var) = arg else {
+        return false;
+    };
+    pattern_check_enum_arg(pattern, &arg_var.var, arenas)
+}
+
+/// Checks if the `if let` block's tail expression calls a function (or a method) whose only
+/// argument is the variable destructured in the condition.
+pub fn if_expr_pattern_applied_to_tail_function(expr: &ExprIf, arenas: &Arenas) -> bool {
+    if_chain! {
+        if let Some(Condition::Let(_condition_let, patterns)) = &expr.conditions.first();
+        if let Expr::Block(if_block) = &arenas.exprs[expr.if_block];
+        if let Some(tail_expr) = if_block.tail;
+        then {
+            return match_arm_applies_function_to_extracted_var(
+                &arenas.exprs[tail_expr],
+                &arenas.patterns[patterns[0]],
+                arenas,
+            );
+        }
+    }
+    false
+}
+
+/// Checks if a match arm returns a `bool` computed from the variable extracted from an enum
+/// variant, e.g. `Option::Some(x) => x > 5`.
+pub fn match_arm_returns_bool_using_extracted_var<'db>(
+    db: &'db dyn Database,
+    expr: &Expr<'db>,
+    pattern: &Pattern<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let Some(pattern_variable) = extract_pattern_variable(pattern, arenas) else {
+        return false;
+    };
+    expr.ty().format(db) == BOOL && !is_variable_unused(db, &pattern_variable.var)
+}
+
+/// Checks if the `if let` block's tail expression is a `bool` computed from the variable
+/// destructured in the condition.
+pub fn if_expr_tail_returns_bool_using_pattern_var<'db>(
+    db: &'db dyn Database,
+    expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    if_chain! {
+        if let Some(Condition::Let(_condition_let, patterns)) = &expr.conditions.first();
+        if let Expr::Block(if_block) = &arenas.exprs[expr.if_block];
+        if let Some(tail_expr) = if_block.tail;
+        then {
+            return match_arm_returns_bool_using_extracted_var(
+                db,
+                &arenas.exprs[tail_expr],
+                &arenas.patterns[patterns[0]],
+                arenas,
+            );
+        }
+    }
+    false
+}
+
+/// Checks if the expression is a constant value, i.e. a literal, a constant item,
+/// or an enum variant without a value.
+pub fn is_constant_expr(expr: &Expr, arenas: &Arenas) -> bool {
+    match expr {
+        Expr::Literal(_) | Expr::StringLiteral(_) | Expr::Constant(_) => true,
+        Expr::EnumVariantCtor(enum_variant) => matches!(
+            &arenas.exprs[enum_variant.value_expr],
+            Expr::Tuple(tuple) if tuple.items.is_empty()
+        ),
+        _ => false,
+    }
+}
+
+/// Checks if the expression (or the only content of a block) is a call of a function or a method,
+/// e.g. `compute()` or `x.compute()`. Operators are not considered function calls here.
+pub fn is_function_call_expr<'db>(
+    db: &'db dyn Database,
+    expr: &Expr<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let Expr::FunctionCall(function_call) = extract_tail_or_preserve_expr(expr, arenas) else {
+        return false;
+    };
+    match function_call.stable_ptr.lookup(db) {
+        AstExpr::FunctionCall(_) => true,
+        AstExpr::Binary(binary) => matches!(binary.op(db), BinaryOperator::Dot(_)),
+        _ => false,
+    }
+}
+
 /// Returns the tail expression from a block if it's the only content, otherwise returns the original expression.
 /// If the block contains statements, it returns the block itself.
 pub fn extract_tail_or_preserve_expr<'a, 'db>(
@@ -597,3 +709,63 @@ pub fn get_semantic_diagnostics<'db>(
     }
     Some(diagnostics.build())
 }
+
+/// Returns the name of the variable if the statement is `let mut <name> = array![];`
+/// or `let mut <name> = ArrayTrait::new();`.
+pub fn empty_array_binding_name<'db>(
+    db: &'db dyn Database,
+    statement: &AstStatement<'db>,
+) -> Option<String> {
+    let AstStatement::Let(let_statement) = statement else {
+        return None;
+    };
+    let AstPattern::Identifier(identifier) = let_statement.pattern(db) else {
+        return None;
+    };
+    let rhs =
+        syntax_node_to_str_without_all_nested_trivia(db, let_statement.rhs(db).as_syntax_node());
+    if !EMPTY_ARRAY_CREATIONS.contains(&rhs.as_str()) {
+        return None;
+    }
+    Some(syntax_node_to_str_without_all_nested_trivia(
+        db,
+        identifier.name(db).as_syntax_node(),
+    ))
+}
+
+/// Returns the appended value if the statement is `<array_name>.append(<value>);`.
+pub fn appended_value<'db>(
+    db: &'db dyn Database,
+    statement: &AstStatement<'db>,
+    array_name: &str,
+) -> Option<AstExpr<'db>> {
+    let AstStatement::Expr(statement_expr) = statement else {
+        return None;
+    };
+    let AstExpr::Binary(binary) = statement_expr.expr(db) else {
+        return None;
+    };
+    if !matches!(binary.op(db), BinaryOperator::Dot(_))
+        || syntax_node_to_str_without_all_nested_trivia(db, binary.lhs(db).as_syntax_node())
+            != array_name
+    {
+        return None;
+    }
+    let AstExpr::FunctionCall(func_call) = binary.rhs(db) else {
+        return None;
+    };
+    if syntax_node_to_str_without_all_nested_trivia(db, func_call.path(db).as_syntax_node())
+        != "append"
+    {
+        return None;
+    }
+
+    let mut args = func_call.arguments(db).arguments(db).elements(db);
+    if args.len() != 1 {
+        return None;
+    }
+    let ArgClause::Unnamed(unnamed_clause) = args.next()?.arg_clause(db) else {
+        return None;
+    };
+    Some(unnamed_clause.value(db))
+}
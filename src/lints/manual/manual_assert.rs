@@ -1,3 +1,4 @@
+use crate::LinterDiagnosticParams;
 use crate::{fixer::InternalFix, helper::indent_snippet};
 use cairo_lang_defs::{ids::ModuleItemId, plugin::PluginDiagnostic};
 use cairo_lang_diagnostics::Severity;
@@ -6,14 +7,15 @@ use cairo_lang_syntax::node::{
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
     ast::{
         BlockOrIf, Condition, ElseClause, Expr as AstExpr, ExprBlock as AstExprBlock,
-        ExprIf as AstExprIf, OptionElseClause, Statement as AstStatement, WrappedTokenTree,
+        ExprIf as AstExprIf, OptionElseClause, Statement as AstStatement, UnaryOperator,
+        WrappedTokenTree,
     },
 };
 use if_chain::if_chain;
 use itertools::Itertools;
 
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     helper::is_panic_expr,
     queries::{get_all_function_bodies, get_all_if_expressions},
 };
@@ -49,6 +51,10 @@ impl Lint for ManualAssert {
         "manual_assert"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0046"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual assert detected. Consider using assert!() macro instead."
     }
@@ -57,6 +63,10 @@ impl Lint for ManualAssert {
         CairoLintKind::ManualAssert
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -74,6 +84,7 @@ impl Lint for ManualAssert {
 pub fn check_manual_assert<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -184,9 +195,10 @@ pub fn fix_manual_assert<'db>(
         return None;
     };
 
-    let condition = condition_expr.expr(db).as_syntax_node().get_text(db);
+    let condition_expr = condition_expr.expr(db);
+    let condition = condition_expr.as_syntax_node().get_text(db);
     let (if_block_panic_args, else_block_panic_args) = get_panic_args_from_diagnosed_node(db, node);
-    let contrary_condition = format!("!({})", condition.trim());
+    let contrary_condition = contrary_condition(db, &condition_expr);
 
     match (if_block_panic_args, else_block_panic_args) {
         (Some(panic_args), None) => {
@@ -293,6 +305,25 @@ pub fn fix_manual_assert<'db>(
     }
 }
 
+/// Returns the negation of the condition. An already negated condition is unwrapped instead of
+/// being negated twice.
+fn contrary_condition<'db>(db: &'db dyn Database, condition: &AstExpr<'db>) -> String {
+    if let AstExpr::Unary(unary_expr) = condition
+        && matches!(unary_expr.op(db), UnaryOperator::Not(_))
+    {
+        let negated_expr = match unary_expr.expr(db) {
+            AstExpr::Parenthesized(parenthesized_expr) => parenthesized_expr.expr(db),
+            negated_expr => negated_expr,
+        };
+        return negated_expr
+            .as_syntax_node()
+            .get_text_without_trivia(db)
+            .long(db)
+            .to_string();
+    }
+    format!("!({})", condition.as_syntax_node().get_text(db).trim())
+}
+
 // Function that returns a tuple where:
 // - The first element is an iterator over the panic arguments from the `if` block.
 // - The second element is an iterator over the panic arguments from the `else` block.
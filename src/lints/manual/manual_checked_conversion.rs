@@ -0,0 +1,263 @@
+use cairo_lang_defs::ids::{ModuleItemId, TopLevelLanguageElementId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, ExprIf, TypeId, VarId,
+};
+use cairo_lang_syntax::node::TypedStablePtr;
+use num_bigint::BigInt;
+use salsa::Database;
+
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::{
+    NONE, OPTION_UNWRAP, SOME, TRY_INTO, UNSIGNED_INTEGER_TYPES, function_trait_name_from_fn_id,
+};
+use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+use crate::{LinterDiagnosticParams, LinterGroup};
+
+pub struct ManualCheckedConversion;
+
+/// ## What it does
+///
+/// Checks for manual bounds checks against the maximum value of an unsigned integer type,
+/// guarding a conversion to this type wrapped in `Option::Some`. This reimplements `try_into`.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u256) -> Option<u8> {
+///     if x <= 255 {
+///         Option::Some(x.try_into().unwrap())
+///     } else {
+///         Option::None
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(x: u256) -> Option<u8> {
+///     x.try_into()
+/// }
+/// ```
+impl Lint for ManualCheckedConversion {
+    fn allowed_name(&self) -> &'static str {
+        "manual_checked_conversion"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0070"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual checked conversion detected. Consider using `try_into()` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualCheckedConversion
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_checked_conversion<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for if_expr in get_all_if_expressions(function_body) {
+            if is_manual_checked_conversion(db, &if_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: ManualCheckedConversion.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Comparison of a variable with a literal bound, normalized so that the variable is on the
+/// left-hand side.
+enum BoundCheck {
+    /// `x <= bound`
+    Le,
+    /// `x < bound`
+    Lt,
+    /// `x >= bound`
+    Ge,
+    /// `x > bound`
+    Gt,
+}
+
+fn is_manual_checked_conversion<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let [Condition::BoolExpr(condition)] = &if_expr.conditions[..] else {
+        return false;
+    };
+    let Some(else_block) = if_expr.else_block else {
+        return false;
+    };
+    let Some((var_expr, check, bound)) = bound_check(db, arenas, *condition) else {
+        return false;
+    };
+    let Expr::Var(var) = &arenas.exprs[var_expr] else {
+        return false;
+    };
+
+    // The conversion must happen in the branch taken when the value is in the range.
+    let (in_range_block, out_of_range_block) = match check {
+        BoundCheck::Le | BoundCheck::Lt => (if_expr.if_block, else_block),
+        BoundCheck::Ge | BoundCheck::Gt => (else_block, if_expr.if_block),
+    };
+    let Some(none_expr) = block_tail(arenas, out_of_range_block) else {
+        return false;
+    };
+    if !is_variant(db, &arenas.exprs[none_expr], NONE) {
+        return false;
+    }
+    let Some(some_expr) = block_tail(arenas, in_range_block) else {
+        return false;
+    };
+    let Expr::EnumVariantCtor(some_ctor) = &arenas.exprs[some_expr] else {
+        return false;
+    };
+    if some_ctor.variant.id.full_path(db) != SOME {
+        return false;
+    }
+    let Some(target_type) = converted_variable_type(db, arenas, some_ctor.value_expr, var.var)
+    else {
+        return false;
+    };
+
+    // The source type must be unsigned as well, otherwise negative values would pass the check.
+    let Some(source_max) = unsigned_integer_max(db, var.ty) else {
+        return false;
+    };
+    let Some(target_max) = unsigned_integer_max(db, target_type) else {
+        return false;
+    };
+    if target_max >= source_max {
+        return false;
+    }
+
+    match check {
+        BoundCheck::Le | BoundCheck::Gt => bound == target_max,
+        BoundCheck::Lt | BoundCheck::Ge => bound == target_max + 1,
+    }
+}
+
+/// Returns the compared variable expression, the normalized comparison and the literal bound
+/// of the condition.
+fn bound_check<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    condition: ExprId,
+) -> Option<(ExprId, BoundCheck, BigInt)> {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[condition] else {
+        return None;
+    };
+    let GenericFunctionId::Impl(impl_generic_func_id) =
+        func_call.function.get_concrete(db).generic_function
+    else {
+        return None;
+    };
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = &func_call.args[..]
+    else {
+        return None;
+    };
+
+    let corelib_context = db.corelib_context();
+    let function = Some(impl_generic_func_id.function);
+    let (check, flipped) = if function == corelib_context.get_partial_ord_le_trait_function_id() {
+        (BoundCheck::Le, BoundCheck::Ge)
+    } else if function == corelib_context.get_partial_ord_lt_trait_function_id() {
+        (BoundCheck::Lt, BoundCheck::Gt)
+    } else if function == corelib_context.get_partial_ord_ge_trait_function_id() {
+        (BoundCheck::Ge, BoundCheck::Le)
+    } else if function == corelib_context.get_partial_ord_gt_trait_function_id() {
+        (BoundCheck::Gt, BoundCheck::Lt)
+    } else {
+        return None;
+    };
+
+    match (&arenas.exprs[*lhs], &arenas.exprs[*rhs]) {
+        (Expr::Var(_), Expr::Literal(literal)) => Some((*lhs, check, literal.value.clone())),
+        (Expr::Literal(literal), Expr::Var(_)) => Some((*rhs, flipped, literal.value.clone())),
+        _ => None,
+    }
+}
+
+/// Returns the result type if the expression is `var.try_into().unwrap()`.
+fn converted_variable_type<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+    var: VarId<'db>,
+) -> Option<TypeId<'db>> {
+    let Expr::FunctionCall(unwrap_call) = &arenas.exprs[expr_id] else {
+        return None;
+    };
+    if function_trait_name_from_fn_id(db, &unwrap_call.function) != OPTION_UNWRAP {
+        return None;
+    }
+    let [ExprFunctionCallArg::Value(option)] = &unwrap_call.args[..] else {
+        return None;
+    };
+    let Expr::FunctionCall(try_into_call) = &arenas.exprs[*option] else {
+        return None;
+    };
+    if function_trait_name_from_fn_id(db, &try_into_call.function) != TRY_INTO {
+        return None;
+    }
+    let [ExprFunctionCallArg::Value(converted)] = &try_into_call.args[..] else {
+        return None;
+    };
+    let Expr::Var(converted_var) = &arenas.exprs[*converted] else {
+        return None;
+    };
+    (converted_var.var == var).then_some(unwrap_call.ty)
+}
+
+/// Returns the maximum value of the type if it's one of the unsigned integer types of the corelib.
+fn unsigned_integer_max(db: &dyn Database, ty: TypeId) -> Option<BigInt> {
+    let type_name = ty.format(db);
+    UNSIGNED_INTEGER_TYPES
+        .iter()
+        .find(|(name, _)| *name == type_name)
+        .map(|(_, bits)| (BigInt::from(1) << *bits) - 1)
+}
+
+fn block_tail(arenas: &Arenas, block_id: ExprId) -> Option<ExprId> {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return None;
+    };
+    if !block.statements.is_empty() {
+        return None;
+    }
+    block.tail
+}
+
+fn is_variant<'db>(db: &'db dyn Database, expr: &Expr<'db>, variant: &str) -> bool {
+    let Expr::EnumVariantCtor(variant_ctor) = expr else {
+        return false;
+    };
+    variant_ctor.variant.id.full_path(db) == variant
+}
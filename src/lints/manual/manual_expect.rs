@@ -8,7 +8,8 @@ use cairo_lang_syntax::node::{
     ast::{ExprIf, ExprMatch},
 };
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::lints::manual::helpers::{
@@ -49,6 +50,10 @@ impl Lint for ManualExpect {
         "manual_expect"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0028"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for expect detected. Consider using `expect()` instead"
     }
@@ -57,6 +62,10 @@ impl Lint for ManualExpect {
         CairoLintKind::ManualExpect
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -74,6 +83,7 @@ impl Lint for ManualExpect {
 pub fn check_manual_expect<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -7,6 +7,7 @@ use cairo_lang_syntax::node::{
     kind::SyntaxKind,
 };
 
+use crate::LinterDiagnosticParams;
 use crate::{
     context::CairoLintKind,
     fixer::InternalFix,
@@ -14,7 +15,7 @@ use crate::{
     queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions},
 };
 use crate::{
-    context::Lint,
+    context::{Lint, LintGroup},
     lints::manual::{
         ManualLint, check_manual, check_manual_if, helpers::expr_if_get_var_name_and_err,
     },
@@ -54,6 +55,10 @@ impl Lint for ManualExpectErr {
         "manual_expect_err"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0030"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `expect_err` detected. Consider using `expect_err()` instead"
     }
@@ -62,6 +67,10 @@ impl Lint for ManualExpectErr {
         CairoLintKind::ManualExpectErr
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -79,6 +88,7 @@ impl Lint for ManualExpectErr {
 pub fn check_manual_expect_err<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -0,0 +1,83 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::TypedStablePtr;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::manual::{ManualLint, check_manual_if};
+use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+
+pub struct ManualFilter;
+
+/// ## What it does
+///
+/// Checks for `if` expressions wrapping a value in `Some` when a condition on that value holds,
+/// and evaluating to `None` otherwise.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32) -> Option<u32> {
+///     if x > 10 {
+///         Option::Some(x)
+///     } else {
+///         Option::None
+///     }
+/// }
+/// ```
+///
+/// Can be rewritten as:
+///
+/// ```cairo
+/// fn main(x: u32) -> Option<u32> {
+///     Option::Some(x).filter(|x| *x > 10)
+/// }
+/// ```
+impl Lint for ManualFilter {
+    fn allowed_name(&self) -> &'static str {
+        "manual_filter"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0085"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual `Option` filtering detected. Consider using `filter()` instead"
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualFilter
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_filter<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let if_exprs = get_all_if_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for if_expr in if_exprs.iter() {
+            if check_manual_if(db, if_expr, arenas, ManualLint::ManualFilter) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: ManualFilter.diagnostic_message().to_owned(),
+                    severity: Severity::Warning,
+                    error_code: None,
+                    inner_span: None,
+                });
+            }
+        }
+    }
+}
@@ -4,7 +4,8 @@ use cairo_lang_diagnostics::Severity;
 
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::lints::manual::{ManualLint, check_manual, check_manual_if};
@@ -45,6 +46,10 @@ impl Lint for ManualIsSome {
         "manual_is_some"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0024"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `is_some` detected. Consider using `is_some()` instead"
     }
@@ -53,6 +58,10 @@ impl Lint for ManualIsSome {
         CairoLintKind::ManualIsSome
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -97,6 +106,10 @@ impl Lint for ManualIsNone {
         "manual_is_none"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0025"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `is_none` detected. Consider using `is_none()` instead"
     }
@@ -105,6 +118,10 @@ impl Lint for ManualIsNone {
         CairoLintKind::ManualIsNone
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -149,6 +166,10 @@ impl Lint for ManualIsOk {
         "manual_is_ok"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0026"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `is_ok` detected. Consider using `is_ok()` instead"
     }
@@ -157,6 +178,10 @@ impl Lint for ManualIsOk {
         CairoLintKind::ManualIsOk
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -201,6 +226,10 @@ impl Lint for ManualIsErr {
         "manual_is_err"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0027"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `is_err` detected. Consider using `is_err()` instead"
     }
@@ -209,6 +238,10 @@ impl Lint for ManualIsErr {
         CairoLintKind::ManualIsErr
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -226,6 +259,7 @@ impl Lint for ManualIsErr {
 pub fn check_manual_is<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -1,4 +1,5 @@
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::lints::{ARRAY, SPAN, U32, function_trait_name_from_fn_id};
@@ -66,6 +67,10 @@ impl Lint for ManualIsEmpty {
         "manual_is_empty"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0021"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual check for `is_empty` detected. Consider using `is_empty()` instead"
     }
@@ -74,6 +79,10 @@ impl Lint for ManualIsEmpty {
         CairoLintKind::ManualIsEmpty
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -87,9 +96,11 @@ impl Lint for ManualIsEmpty {
     }
 }
 
+#[tracing::instrument(skip_all, level = "trace")]
 pub fn check_manual_is_empty<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let functions_bodies = get_all_function_bodies(db, item);
@@ -165,7 +176,7 @@ pub fn fix_manual_is_empty<'db>(
     })
 }
 
-fn check_if_comparison_args_are_incorrect<'db>(
+pub(crate) fn check_if_comparison_args_are_incorrect<'db>(
     db: &'db dyn Database,
     comparison: &ExprFunctionCall<'db>,
     arenas: &Arenas<'db>,
@@ -0,0 +1,142 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::manual::manual_map_or::{OptionMatchParts, unwrapped_expression_text};
+use crate::lints::manual::{ManualLint, check_manual, check_manual_if};
+use crate::queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions};
+
+pub struct ManualIsSomeAnd;
+
+/// ## What it does
+///
+/// Checks for manual implementations of `is_some_and`.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let a: Option<u32> = Option::Some(10);
+///     let _b = match a {
+///         Option::Some(x) => x > 5,
+///         Option::None => false,
+///     };
+/// }
+/// ```
+///
+/// Can be rewritten as:
+///
+/// ```cairo
+/// fn main() {
+///     let a: Option<u32> = Option::Some(10);
+///     let _b = a.is_some_and(|x| x > 5);
+/// }
+/// ```
+impl Lint for ManualIsSomeAnd {
+    fn allowed_name(&self) -> &'static str {
+        "manual_is_some_and"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0093"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual `is_some_and` detected. Consider using `is_some_and()` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualIsSomeAnd
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_is_some_and(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Use `is_some_and()` instead of manual pattern")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_is_some_and<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let if_exprs = get_all_if_expressions(function_body);
+        let match_exprs = get_all_match_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for match_expr in match_exprs.iter() {
+            if check_manual(db, match_expr, arenas, ManualLint::ManualIsSomeAnd) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: match_expr.stable_ptr.untyped(),
+                    message: ManualIsSomeAnd.diagnostic_message().to_owned(),
+                    severity: Severity::Warning,
+                    error_code: None,
+                    inner_span: None,
+                });
+            }
+        }
+        for if_expr in if_exprs.iter() {
+            if check_manual_if(db, if_expr, arenas, ManualLint::ManualIsSomeAnd) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: ManualIsSomeAnd.diagnostic_message().to_owned(),
+                    severity: Severity::Warning,
+                    error_code: None,
+                    inner_span: None,
+                });
+            }
+        }
+    }
+}
+
+/// Rewrites a manual implementation of `is_some_and` into `opt.is_some_and(|x| predicate)`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_is_some_and<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let OptionMatchParts {
+        matched_expr,
+        variable,
+        some_expr,
+        ..
+    } = OptionMatchParts::try_new(db, node)?;
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            &format!(
+                "{}.is_some_and(|{variable}| {})",
+                matched_expr
+                    .as_syntax_node()
+                    .get_text_without_trivia(db)
+                    .long(db),
+                unwrapped_expression_text(db, some_expr)
+            ),
+        ),
+        description: ManualIsSomeAnd.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
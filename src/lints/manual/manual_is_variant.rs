@@ -0,0 +1,389 @@
+use cairo_lang_defs::ids::{
+    EnumId, FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId,
+    TopLevelLanguageElementId, VariantId,
+};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::function_with_body::{
+    FunctionWithBodySemantic, SemanticExprLookup,
+};
+use cairo_lang_semantic::items::imp::ImplSemantic;
+use cairo_lang_semantic::types::peel_snapshots;
+use cairo_lang_semantic::{
+    Arenas, ConcreteTypeId, Condition, Expr, ExprId, ExprIf, ExprMatch, Pattern, PatternId,
+    TypeLongId,
+};
+use cairo_lang_syntax::node::ast::ExprPtr;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{
+    find_module_containing_node, get_function_with_body_id, is_inside_function,
+    replace_preserving_trivia,
+};
+use crate::lints::{BOOL, FALSE, OPTION, RESULT, TRUE};
+use crate::queries::{
+    get_all_function_bodies, get_all_if_expressions, get_all_match_expressions,
+    syntax_node_to_str_without_all_nested_trivia,
+};
+
+pub struct ManualIsVariant;
+
+/// ## What it does
+///
+/// Checks for `match` and `if let` expressions which only check whether an enum value is of a given
+/// variant, returning `true` for it and `false` otherwise.
+///
+/// `Option` and `Result` are not checked here, as they are covered by `manual_is_some`,
+/// `manual_is_none`, `manual_is_ok` and `manual_is_err`.
+///
+/// ## Example
+///
+/// ```cairo
+/// #[derive(Drop)]
+/// enum Shape {
+///     Circle: u32,
+///     Square: u32,
+/// }
+///
+/// #[generate_trait]
+/// impl ShapeImpl of ShapeTrait {
+///     fn is_circle(self: @Shape) -> bool {
+///         match self {
+///             Shape::Circle(_) => true,
+///             Shape::Square(_) => false,
+///         }
+///     }
+/// }
+///
+/// fn main(shape: Shape) -> bool {
+///     match shape {
+///         Shape::Circle(_) => true,
+///         _ => false,
+///     }
+/// }
+/// ```
+///
+/// Can be rewritten as:
+///
+/// ```cairo
+/// fn main(shape: Shape) -> bool {
+///     shape.is_circle()
+/// }
+/// ```
+impl Lint for ManualIsVariant {
+    fn allowed_name(&self) -> &'static str {
+        "manual_is_variant"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0110"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual check of an enum variant detected. Consider using an `is_*` method of the enum instead"
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualIsVariant
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_is_variant(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace with a call to the `is_*` method of the enum")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_is_variant<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        let match_checks = get_all_match_expressions(function_body)
+            .into_iter()
+            .filter_map(|match_expr| {
+                let variant_check = match_variant_check(db, arenas, &match_expr)?;
+                Some((match_expr.stable_ptr.untyped(), variant_check))
+            });
+        let if_checks = get_all_if_expressions(function_body)
+            .into_iter()
+            .filter_map(|if_expr| {
+                let variant_check = if_let_variant_check(db, arenas, &if_expr)?;
+                Some((if_expr.stable_ptr.untyped(), variant_check))
+            });
+
+        for (stable_ptr, variant_check) in match_checks.chain(if_checks) {
+            // The `is_*` method itself has to be implemented this way.
+            if is_inside_function(db, stable_ptr.lookup(db), &variant_check.method_name(db)) {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr,
+                message: ManualIsVariant.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// A check of whether an enum value is of the given variant.
+struct VariantCheck<'db> {
+    /// The expression whose variant is checked.
+    matched_expr: ExprId,
+    enum_id: EnumId<'db>,
+    variant_id: VariantId<'db>,
+    /// Whether the expression returns `false` for the variant, and `true` otherwise.
+    negated: bool,
+}
+
+impl<'db> VariantCheck<'db> {
+    /// Returns the name of the method checking the variant, e.g. `is_circle` for `Shape::Circle`.
+    fn method_name(&self, db: &'db dyn Database) -> String {
+        let variant_path = self.variant_id.full_path(db);
+        let variant_name = variant_path.rsplit("::").next().unwrap_or_default();
+        format!("is_{}", to_snake_case(variant_name))
+    }
+}
+
+/// Checks if the `match` is either `E::A(_) => true, _ => false` or, for an enum with two variants,
+/// `E::A(_) => true, E::B(_) => false`, with any order of the arms and the returned values.
+fn match_variant_check<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    match_expr: &ExprMatch<'db>,
+) -> Option<VariantCheck<'db>> {
+    let [first_arm, second_arm] = &match_expr.arms[..] else {
+        return None;
+    };
+    let ([first_pattern], [second_pattern]) = (&first_arm.patterns[..], &second_arm.patterns[..])
+    else {
+        return None;
+    };
+    let first_returns = bool_literal(db, arenas, first_arm.expression)?;
+    if bool_literal(db, arenas, second_arm.expression)? == first_returns {
+        return None;
+    }
+
+    let ((enum_id, variant_id), variant_returns) = match (
+        checked_variant(db, arenas, *first_pattern),
+        checked_variant(db, arenas, *second_pattern),
+    ) {
+        (Some(variant), None)
+            if matches!(arenas.patterns[*second_pattern], Pattern::Otherwise(_)) =>
+        {
+            (variant, first_returns)
+        }
+        // Both of the variants are listed, so the enum can't have any other ones.
+        (Some(first_variant), Some(second_variant)) => (
+            if first_returns {
+                first_variant
+            } else {
+                second_variant
+            },
+            true,
+        ),
+        _ => return None,
+    };
+
+    Some(VariantCheck {
+        matched_expr: match_expr.matched_expr,
+        enum_id,
+        variant_id,
+        negated: !variant_returns,
+    })
+}
+
+/// Checks if the `if` is `if let E::A(_) = x { true } else { false }`, with any order of the
+/// returned values.
+fn if_let_variant_check<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    if_expr: &ExprIf<'db>,
+) -> Option<VariantCheck<'db>> {
+    let [Condition::Let(matched_expr, patterns)] = &if_expr.conditions[..] else {
+        return None;
+    };
+    let [pattern] = patterns[..] else {
+        return None;
+    };
+    let (enum_id, variant_id) = checked_variant(db, arenas, pattern)?;
+    let if_returns = bool_literal(db, arenas, if_expr.if_block)?;
+    if bool_literal(db, arenas, if_expr.else_block?)? == if_returns {
+        return None;
+    }
+
+    Some(VariantCheck {
+        matched_expr: *matched_expr,
+        enum_id,
+        variant_id,
+        negated: !if_returns,
+    })
+}
+
+/// Returns the enum and the variant of the pattern if it only checks the variant, without binding
+/// its value.
+///
+/// `Option`, `Result` and `bool` are skipped, as they have dedicated lints.
+fn checked_variant<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    pattern: PatternId,
+) -> Option<(EnumId<'db>, VariantId<'db>)> {
+    let Pattern::EnumVariant(enum_pattern) = &arenas.patterns[pattern] else {
+        return None;
+    };
+    if let Some(inner_pattern) = enum_pattern.inner_pattern
+        && !matches!(arenas.patterns[inner_pattern], Pattern::Otherwise(_))
+    {
+        return None;
+    }
+    let enum_id = enum_pattern.variant.concrete_enum_id.enum_id(db);
+    if [OPTION, RESULT, BOOL].contains(&enum_id.full_path(db).as_str()) {
+        return None;
+    }
+    Some((enum_id, enum_pattern.variant.id))
+}
+
+/// Returns the value of the expression if it's a `bool` literal, possibly being the only element
+/// of a block.
+fn bool_literal<'db>(db: &'db dyn Database, arenas: &Arenas<'db>, expr_id: ExprId) -> Option<bool> {
+    match &arenas.exprs[expr_id] {
+        Expr::EnumVariantCtor(variant_ctor) => match variant_ctor.variant.id.full_path(db).as_str()
+        {
+            TRUE => Some(true),
+            FALSE => Some(false),
+            _ => None,
+        },
+        Expr::Block(block) if block.statements.is_empty() => bool_literal(db, arenas, block.tail?),
+        _ => None,
+    }
+}
+
+/// Rewrites the variant check into a call to the `is_*` method of the enum.
+///
+/// The fix is only available if a method named after the variant, e.g. `is_circle` for
+/// `Shape::Circle`, taking the enum as `self` is implemented in the module of the checked
+/// expression or in the module of the enum, e.g. with `#[generate_trait]`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_is_variant<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let module_id = find_module_containing_node(db, node)?;
+    let function_id = node
+        .ancestors(db)
+        .find_map(|ancestor| get_function_with_body_id(db, module_id, ancestor))?;
+    let arenas = &db.function_body(function_id).ok()?.arenas;
+    let expr_id = db
+        .lookup_expr_by_ptr(function_id, ExprPtr(node.stable_ptr(db)))
+        .ok()?;
+    let variant_check = match &arenas.exprs[expr_id] {
+        Expr::Match(match_expr) => match_variant_check(db, arenas, match_expr)?,
+        Expr::If(if_expr) => if_let_variant_check(db, arenas, if_expr)?,
+        _ => return None,
+    };
+
+    let method_name = variant_check.method_name(db);
+    let enum_id = variant_check.enum_id;
+    if ![module_id, enum_id.parent_module(db)]
+        .into_iter()
+        .any(|module_id| has_is_variant_method(db, module_id, enum_id, &method_name))
+    {
+        return None;
+    }
+
+    // Snapshots are taken implicitly by method calls, so they don't have to be repeated.
+    let matched_expr = match &arenas.exprs[variant_check.matched_expr] {
+        Expr::Snapshot(snapshot) => &arenas.exprs[snapshot.inner],
+        matched_expr => matched_expr,
+    };
+    let matched_expr_text = syntax_node_to_str_without_all_nested_trivia(
+        db,
+        matched_expr.stable_ptr().lookup(db).as_syntax_node(),
+    );
+    let negation = if variant_check.negated { "!" } else { "" };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            &format!("{negation}{matched_expr_text}.{method_name}()"),
+        ),
+        description: ManualIsVariant.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Checks if any impl in the module has a method with the given name, taking the enum as `self`.
+fn has_is_variant_method<'db>(
+    db: &'db dyn Database,
+    module_id: ModuleId<'db>,
+    enum_id: EnumId<'db>,
+    method_name: &str,
+) -> bool {
+    let Ok(module_data) = module_id.module_data(db) else {
+        return false;
+    };
+    module_data
+        .items(db)
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItemId::Impl(impl_id) => db.impl_functions(*impl_id).ok(),
+            _ => None,
+        })
+        .flat_map(|functions| functions.iter().map(|(_, impl_fn_id)| *impl_fn_id))
+        .filter(|impl_fn_id| {
+            impl_fn_id
+                .full_path(db)
+                .ends_with(&format!("::{method_name}"))
+        })
+        .filter_map(|impl_fn_id| {
+            db.function_with_body_signature(FunctionWithBodyId::Impl(impl_fn_id))
+                .ok()
+                .cloned()
+        })
+        .any(|signature| {
+            signature.params.first().is_some_and(|param| {
+                param.name.to_string(db) == "self"
+                    && matches!(
+                        peel_snapshots(db, param.ty).1,
+                        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id))
+                            if concrete_enum_id.enum_id(db) == enum_id
+                    )
+            })
+        })
+}
+
+/// Converts a `PascalCase` variant name to `snake_case`, e.g. `BigCircle` to `big_circle`.
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len());
+    for (index, char) in name.chars().enumerate() {
+        if char.is_uppercase() && index != 0 {
+            snake_case.push('_');
+        }
+        snake_case.extend(char.to_lowercase());
+    }
+    snake_case
+}
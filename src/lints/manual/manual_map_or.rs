@@ -0,0 +1,240 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{
+    BlockOrIf, Condition, Expr, ExprIf, ExprMatch, OptionElseClause, OptionPatternEnumInnerPattern,
+    Pattern, Statement,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::manual::helpers::MatchOnOption;
+use crate::lints::manual::{ManualLint, check_manual, check_manual_if};
+use crate::queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions};
+
+pub struct ManualMapOr;
+
+/// ## What it does
+///
+/// Checks for `match` and `if let` expressions on an `Option` which apply a function to the
+/// contained value and return a constant otherwise, reimplementing `map_or`.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let opt: Option<u32> = Option::Some(1);
+///     let _a = match opt {
+///         Option::Some(x) => x.into(),
+///         Option::None => 0,
+///     };
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main() {
+///     let opt: Option<u32> = Option::Some(1);
+///     let _a = opt.map_or(0, |x| x.into());
+/// }
+/// ```
+impl Lint for ManualMapOr {
+    fn allowed_name(&self) -> &'static str {
+        "manual_map_or"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0069"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual `map_or` detected. Consider using `map_or()` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualMapOr
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_map_or(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Use `map_or()` instead of manual pattern")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_map_or<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let if_exprs = get_all_if_expressions(function_body);
+        let match_exprs = get_all_match_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for match_expr in match_exprs.iter() {
+            if check_manual(db, match_expr, arenas, ManualLint::ManualMapOr) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: match_expr.stable_ptr.untyped(),
+                    message: ManualMapOr.diagnostic_message().to_owned(),
+                    severity: Severity::Warning,
+                    error_code: None,
+                    inner_span: None,
+                });
+            }
+        }
+        for if_expr in if_exprs.iter() {
+            if check_manual_if(db, if_expr, arenas, ManualLint::ManualMapOr) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: ManualMapOr.diagnostic_message().to_owned(),
+                    severity: Severity::Warning,
+                    error_code: None,
+                    inner_span: None,
+                });
+            }
+        }
+    }
+}
+
+/// Rewrites a manual implementation of `map_or` into `opt.map_or(default, |x| f(x))`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_map_or<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let OptionMatchParts {
+        matched_expr,
+        variable,
+        some_expr,
+        none_expr,
+    } = OptionMatchParts::try_new(db, node)?;
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            &format!(
+                "{}.map_or({}, |{}| {})",
+                matched_expr
+                    .as_syntax_node()
+                    .get_text_without_trivia(db)
+                    .long(db),
+                unwrapped_expression_text(db, none_expr),
+                variable,
+                unwrapped_expression_text(db, some_expr)
+            ),
+        ),
+        description: ManualMapOr.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// The parts of a `match` on an `Option` or of an `if let Some(..) = .. else ..` expression.
+pub(crate) struct OptionMatchParts<'db> {
+    pub matched_expr: Expr<'db>,
+    /// The name of the variable bound by the `Some` pattern.
+    pub variable: String,
+    pub some_expr: Expr<'db>,
+    pub none_expr: Expr<'db>,
+}
+
+impl<'db> OptionMatchParts<'db> {
+    pub fn try_new(db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<Self> {
+        let (matched_expr, some_pattern, some_expr, none_expr) = match node.kind(db) {
+            SyntaxKind::ExprMatch => {
+                let expr_match = ExprMatch::from_syntax_node(db, node);
+                let MatchOnOption { some_arm, none_arm } = MatchOnOption::try_new(db, &expr_match)?;
+                (
+                    expr_match.expr(db),
+                    some_arm.patterns(db).elements(db).next()?,
+                    some_arm.expression(db),
+                    none_arm.expression(db),
+                )
+            }
+            SyntaxKind::ExprIf => {
+                let expr_if = ExprIf::from_syntax_node(db, node);
+                let Condition::Let(condition_let) = expr_if.conditions(db).elements(db).next()?
+                else {
+                    return None;
+                };
+                let OptionElseClause::ElseClause(else_clause) = expr_if.else_clause(db) else {
+                    return None;
+                };
+                let BlockOrIf::Block(else_block) = else_clause.else_block_or_if(db) else {
+                    return None;
+                };
+                (
+                    condition_let.expr(db),
+                    condition_let.patterns(db).elements(db).next()?,
+                    Expr::Block(expr_if.if_block(db)),
+                    Expr::Block(else_block),
+                )
+            }
+            _ => return None,
+        };
+
+        let Pattern::Enum(some_pattern) = some_pattern else {
+            return None;
+        };
+        let OptionPatternEnumInnerPattern::PatternEnumInnerPattern(inner_pattern) =
+            some_pattern.pattern(db)
+        else {
+            return None;
+        };
+        let variable = inner_pattern
+            .pattern(db)
+            .as_syntax_node()
+            .get_text_without_trivia(db)
+            .long(db)
+            .to_string();
+
+        Some(Self {
+            matched_expr,
+            variable,
+            some_expr,
+            none_expr,
+        })
+    }
+}
+
+/// Returns the text of the expression, without the surrounding braces if it's a block containing
+/// only a tail expression.
+pub(crate) fn unwrapped_expression_text<'db>(db: &'db dyn Database, expr: Expr<'db>) -> String {
+    if let Expr::Block(block) = &expr {
+        let mut statements = block.statements(db).elements(db);
+        if statements.len() == 1
+            && let Some(Statement::Expr(statement_expr)) = statements.next()
+        {
+            return statement_expr
+                .expr(db)
+                .as_syntax_node()
+                .get_text_without_trivia(db)
+                .long(db)
+                .to_string();
+        }
+    }
+    expr.as_syntax_node()
+        .get_text_without_trivia(db)
+        .long(db)
+        .to_string()
+}
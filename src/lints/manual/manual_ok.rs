@@ -4,7 +4,8 @@ use cairo_lang_diagnostics::Severity;
 
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::fixer::InternalFix;
 use crate::lints::manual::{ManualLint, check_manual, check_manual_if};
@@ -45,6 +46,10 @@ impl Lint for ManualOk {
         "manual_ok"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0022"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for `ok` detected. Consider using `ok()` instead"
     }
@@ -53,6 +58,10 @@ impl Lint for ManualOk {
         CairoLintKind::ManualOk
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -70,6 +79,7 @@ impl Lint for ManualOk {
 pub fn check_manual_ok<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -1,20 +1,30 @@
+use std::collections::HashSet;
+
 use cairo_lang_defs::ids::ModuleItemId;
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::{
     SyntaxNode, TypedStablePtr, TypedSyntaxNode,
-    ast::{ExprIf, ExprMatch},
+    ast::{
+        ArgClause, BinaryOperator, BlockOrIf, Expr as AstExpr, ExprFunctionCall, ExprIf, ExprMatch,
+        OptionElseClause, Statement as AstStatement,
+    },
     kind::SyntaxKind,
 };
 
+use crate::LinterDiagnosticParams;
 use crate::{
     context::CairoLintKind,
     fixer::InternalFix,
     lints::manual::helpers::{MatchOnOption, MatchOnResult, extract_err},
-    queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions},
+    lints::manual::manual_ok_or_default::unwrap_or_default_receiver,
+    queries::{
+        get_all_function_bodies, get_all_function_calls, get_all_if_expressions,
+        get_all_match_expressions,
+    },
 };
 use crate::{
-    context::Lint,
+    context::{Lint, LintGroup},
     lints::manual::{
         ManualLint, check_manual, check_manual_if, helpers::expr_if_get_var_name_and_err,
     },
@@ -25,7 +35,8 @@ pub struct ManualOkOr;
 
 /// ## What it does
 ///
-/// Checks for manual implementations of ok_or.
+/// Checks for manual implementations of ok_or. If the error is computed, e.g. by a function call,
+/// the fix uses `ok_or_else` instead, so the error is evaluated only if it's needed.
 ///
 /// ## Example
 ///
@@ -52,6 +63,10 @@ impl Lint for ManualOkOr {
         "manual_ok_or"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0020"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual match for Option<T> detected. Consider using ok_or instead"
     }
@@ -60,6 +75,10 @@ impl Lint for ManualOkOr {
         CairoLintKind::ManualOkOr
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -77,6 +96,7 @@ impl Lint for ManualOkOr {
 pub fn check_manual_ok_or<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -84,7 +104,16 @@ pub fn check_manual_ok_or<'db>(
         let if_exprs = get_all_if_expressions(function_body);
         let match_exprs = get_all_match_expressions(function_body);
         let arenas = &function_body.arenas;
+        // Conversions unwrapped with `unwrap_or_default()` right away are reported by
+        // `manual_ok_or_default` instead.
+        let unwrapped_or_default = get_all_function_calls(function_body)
+            .filter_map(|function_call_expr| unwrap_or_default_receiver(db, &function_call_expr))
+            .map(|receiver| arenas.exprs[receiver].stable_ptr())
+            .collect::<HashSet<_>>();
         for match_expr in match_exprs.iter() {
+            if unwrapped_or_default.contains(&match_expr.stable_ptr) {
+                continue;
+            }
             if check_manual(db, match_expr, arenas, ManualLint::ManualOkOr) {
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: match_expr.stable_ptr.untyped(),
@@ -96,6 +125,9 @@ pub fn check_manual_ok_or<'db>(
             }
         }
         for if_expr in if_exprs.iter() {
+            if unwrapped_or_default.contains(&if_expr.stable_ptr) {
+                continue;
+            }
             if check_manual_if(db, if_expr, arenas, ManualLint::ManualOkOr) {
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: if_expr.stable_ptr.untyped(),
@@ -109,12 +141,103 @@ pub fn check_manual_ok_or<'db>(
     }
 }
 
-/// Rewrites a manual implementation of ok_or
+/// Checks if the error of the manual conversion is computed, i.e. it's a function or a method call,
+/// or a macro call, which should be evaluated lazily.
+fn has_computed_err<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> bool {
+    let Some(err) = err_expr(db, node) else {
+        return false;
+    };
+    match err {
+        AstExpr::FunctionCall(_) | AstExpr::InlineMacro(_) => true,
+        AstExpr::Binary(binary) => {
+            matches!(binary.op(db), BinaryOperator::Dot(_))
+                && matches!(binary.rhs(db), AstExpr::FunctionCall(_))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the argument of `Result::Err` of the manual conversion.
+fn err_expr<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<AstExpr<'db>> {
+    let err_ctor = match node.kind(db) {
+        SyntaxKind::ExprMatch => {
+            let expr_match = ExprMatch::from_syntax_node(db, node);
+            let arm = MatchOnOption::try_new(db, &expr_match)
+                .map(|match_on_option| match_on_option.none_arm)
+                .or_else(|| {
+                    MatchOnResult::try_new(db, &expr_match)
+                        .map(|match_on_result| match_on_result.err_arm)
+                })?;
+            match arm.expression(db) {
+                AstExpr::Block(block) => {
+                    single_function_call(db, block.statements(db).elements(db))?
+                }
+                AstExpr::FunctionCall(func_call) => func_call,
+                _ => return None,
+            }
+        }
+        SyntaxKind::ExprIf => {
+            let OptionElseClause::ElseClause(else_clause) =
+                ExprIf::from_syntax_node(db, node).else_clause(db)
+            else {
+                return None;
+            };
+            let BlockOrIf::Block(block) = else_clause.else_block_or_if(db) else {
+                return None;
+            };
+            single_function_call(db, block.statements(db).elements(db))?
+        }
+        _ => return None,
+    };
+    let ArgClause::Unnamed(arg) = err_ctor
+        .arguments(db)
+        .arguments(db)
+        .elements(db)
+        .next()?
+        .arg_clause(db)
+    else {
+        return None;
+    };
+    Some(arg.value(db))
+}
+
+fn single_function_call<'db>(
+    db: &'db dyn Database,
+    mut statements: impl Iterator<Item = AstStatement<'db>>,
+) -> Option<ExprFunctionCall<'db>> {
+    let (Some(AstStatement::Expr(statement_expr)), None) = (statements.next(), statements.next())
+    else {
+        return None;
+    };
+    match statement_expr.expr(db) {
+        AstExpr::FunctionCall(func_call) => Some(func_call),
+        _ => None,
+    }
+}
+
+/// Rewrites a manual implementation of ok_or, or of ok_or_else if the error is computed.
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn fix_manual_ok_or<'db>(
     db: &'db dyn Database,
     node: SyntaxNode<'db>,
 ) -> Option<InternalFix<'db>> {
+    let is_computed_err = has_computed_err(db, node);
+    let (method, description) = if is_computed_err {
+        (
+            "ok_or_else",
+            "Replace manual conversion with `ok_or_else()` method",
+        )
+    } else {
+        ("ok_or", ManualOkOr.fix_message().unwrap())
+    };
+    // A computed error is wrapped in a closure, so it's only evaluated when needed.
+    let lazy_err = |err: String| {
+        if is_computed_err {
+            format!("|| {err}")
+        } else {
+            err
+        }
+    };
     let fix = match node.kind(db) {
         SyntaxKind::ExprMatch => {
             let expr_match = ExprMatch::from_syntax_node(db, node);
@@ -128,22 +251,22 @@ pub fn fix_manual_ok_or<'db>(
                 })
                 .expect("Expected a match expression on either Option or Result");
 
-            let err = extract_err(db, &arm);
-            format!("{target_expr}.ok_or({err})")
+            let err = lazy_err(extract_err(db, &arm));
+            format!("{target_expr}.{method}({err})")
         }
         SyntaxKind::ExprIf => {
             let expr_if = ExprIf::from_syntax_node(db, node);
 
             let (option_var_name, err) = expr_if_get_var_name_and_err(expr_if, db);
 
-            format!("{}.ok_or({})", option_var_name.trim_end(), err)
+            format!("{}.{method}({})", option_var_name.trim_end(), lazy_err(err))
         }
         _ => panic!("SyntaxKind should be either ExprIf or ExprMatch"),
     };
     Some(InternalFix {
         node,
         suggestion: fix,
-        description: ManualOkOr.fix_message().unwrap().to_string(),
+        description: description.to_string(),
         import_addition_paths: None,
     })
 }
@@ -0,0 +1,194 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId};
+use cairo_lang_syntax::node::ast::{BinaryOperator, Condition, Expr as AstExpr};
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::manual::{ManualLint, check_manual, check_manual_if};
+use crate::lints::{OPTION_OK_OR, RESULT_UNWRAP_OR_DEFAULT, function_trait_name_from_fn_id};
+use crate::queries::{get_all_function_bodies, get_all_function_calls};
+
+pub struct ManualOkOrDefault;
+
+/// ## What it does
+///
+/// Checks for an `Option` converted into a `Result`, either with `ok_or` or manually, only to be
+/// unwrapped with `unwrap_or_default` right away.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(foo: Option<u32>) -> u32 {
+///     foo.ok_or('err').unwrap_or_default()
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(foo: Option<u32>) -> u32 {
+///     foo.unwrap_or_default()
+/// }
+/// ```
+impl Lint for ManualOkOrDefault {
+    fn allowed_name(&self) -> &'static str {
+        "manual_ok_or_default"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0084"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Option<T> converted into a Result only to call unwrap_or_default on it. Consider using unwrap_or_default on the Option directly"
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualOkOrDefault
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_ok_or_default(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Call `unwrap_or_default()` on the `Option` directly")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_ok_or_default<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for function_call_expr in get_all_function_calls(function_body) {
+            let Some(receiver) = unwrap_or_default_receiver(db, &function_call_expr) else {
+                continue;
+            };
+            if is_option_into_result(db, &arenas.exprs[receiver], arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: function_call_expr.stable_ptr.untyped(),
+                    message: ManualOkOrDefault.diagnostic_message().to_owned(),
+                    severity: Severity::Warning,
+                    error_code: None,
+                    inner_span: None,
+                });
+            }
+        }
+    }
+}
+
+/// Returns the receiver of the call if it's `Result::unwrap_or_default`.
+pub fn unwrap_or_default_receiver<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+) -> Option<ExprId> {
+    let [ExprFunctionCallArg::Value(receiver)] = function_call_expr.args[..] else {
+        return None;
+    };
+    (function_trait_name_from_fn_id(db, &function_call_expr.function) == RESULT_UNWRAP_OR_DEFAULT)
+        .then_some(receiver)
+}
+
+/// Checks if the expression converts an `Option` into a `Result`, either with `ok_or` or with a
+/// `match`/`if let` doing the same.
+fn is_option_into_result<'db>(
+    db: &'db dyn Database,
+    expr: &Expr<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    match expr {
+        Expr::FunctionCall(function_call_expr) => {
+            function_trait_name_from_fn_id(db, &function_call_expr.function) == OPTION_OK_OR
+        }
+        Expr::Match(expr_match) => {
+            check_manual(db, expr_match, arenas, ManualLint::ManualOkOrDefault)
+        }
+        Expr::If(expr_if) => check_manual_if(db, expr_if, arenas, ManualLint::ManualOkOrDefault),
+        _ => false,
+    }
+}
+
+/// Rewrites `opt.ok_or(e).unwrap_or_default()`, or its manual equivalent, to
+/// `opt.unwrap_or_default()`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_ok_or_default<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let AstExpr::Binary(unwrap_call) = AstExpr::from_syntax_node(db, node) else {
+        return None;
+    };
+    if !matches!(unwrap_call.op(db), BinaryOperator::Dot(_)) {
+        return None;
+    }
+    let option_expr = option_of_conversion(db, unwrap_call.lhs(db))?;
+    let option_text = option_expr
+        .as_syntax_node()
+        .get_text_without_trivia(db)
+        .long(db)
+        .to_string();
+    let receiver = match &option_expr {
+        AstExpr::Binary(binary) if !matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+            format!("({option_text})")
+        }
+        AstExpr::Unary(_) => format!("({option_text})"),
+        _ => option_text,
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &format!("{receiver}.unwrap_or_default()")),
+        description: ManualOkOrDefault.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Returns the `Option` converted into a `Result` by `opt.ok_or(e)`, `match opt { ... }` or
+/// `if let Some(v) = opt { ... } else { ... }`.
+fn option_of_conversion<'db>(
+    db: &'db dyn Database,
+    conversion: AstExpr<'db>,
+) -> Option<AstExpr<'db>> {
+    match conversion {
+        AstExpr::Binary(binary) if matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+            let AstExpr::FunctionCall(call) = binary.rhs(db) else {
+                return None;
+            };
+            let is_ok_or = call
+                .path(db)
+                .segments(db)
+                .elements(db)
+                .last()
+                .is_some_and(|segment| segment.identifier(db).long(db) == "ok_or");
+            is_ok_or.then(|| binary.lhs(db))
+        }
+        AstExpr::Match(expr_match) => Some(expr_match.expr(db)),
+        AstExpr::If(expr_if) => match expr_if.conditions(db).elements(db).next()? {
+            Condition::Let(condition_let) => Some(condition_let.expr(db)),
+            _ => None,
+        },
+        AstExpr::Parenthesized(parenthesized) => option_of_conversion(db, parenthesized.expr(db)),
+        _ => None,
+    }
+}
@@ -0,0 +1,167 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{
+    BlockOrIf, Expr, ExprBlock, ExprFor, OptionElseClause, Statement, StatementList,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::manual::helpers::{appended_value, empty_array_binding_name};
+use crate::queries::syntax_node_to_str_without_all_nested_trivia;
+
+pub struct ManualPartition;
+
+/// ## What it does
+///
+/// Checks for loops splitting a collection into two new arrays with a single `if`/`else`,
+/// where both branches append the same element.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let numbers = array![1_u32, 2, 3, 4];
+///     let mut even = array![];
+///     let mut odd = array![];
+///     for number in numbers {
+///         if number % 2 == 0 {
+///             even.append(number);
+///         } else {
+///             odd.append(number);
+///         }
+///     }
+/// }
+/// ```
+impl Lint for ManualPartition {
+    fn allowed_name(&self) -> &'static str {
+        "manual_partition"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0060"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual partition of a collection into two arrays detected. Consider extracting it into a dedicated partition helper."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualPartition
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_partition<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let item_node = match item {
+        ModuleItemId::FreeFunction(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::Impl(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        // Trait can have a default function impl.
+        ModuleItemId::Trait(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        _ => return,
+    };
+
+    for node in item_node.descendants(db) {
+        if node.kind(db) == SyntaxKind::StatementList {
+            check_partition_loops(db, &StatementList::from_syntax_node(db, node), diagnostics);
+        }
+    }
+}
+
+/// Reports `for` loops partitioning elements into two arrays created earlier in the same block.
+fn check_partition_loops<'db>(
+    db: &'db dyn Database,
+    statement_list: &StatementList<'db>,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let statements = statement_list.elements(db).collect::<Vec<_>>();
+    for (index, statement) in statements.iter().enumerate() {
+        let Statement::Expr(statement_expr) = statement else {
+            continue;
+        };
+        let Expr::For(for_expr) = statement_expr.expr(db) else {
+            continue;
+        };
+        let Some((first_array, second_array)) = partitioned_arrays(db, &for_expr) else {
+            continue;
+        };
+
+        let empty_arrays = statements[..index]
+            .iter()
+            .filter_map(|statement| empty_array_binding_name(db, statement))
+            .collect::<Vec<_>>();
+        if empty_arrays.contains(&first_array) && empty_arrays.contains(&second_array) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: for_expr.as_syntax_node().stable_ptr(db),
+                message: ManualPartition.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Returns the names of the two arrays if the body of the loop is a single
+/// `if <cond> { a.append(e) } else { b.append(e) }` appending the same element to different arrays.
+fn partitioned_arrays<'db>(
+    db: &'db dyn Database,
+    for_expr: &ExprFor<'db>,
+) -> Option<(String, String)> {
+    let mut body_statements = for_expr.body(db).statements(db).elements(db);
+    if body_statements.len() != 1 {
+        return None;
+    }
+    let Statement::Expr(statement_expr) = body_statements.next()? else {
+        return None;
+    };
+    let Expr::If(if_expr) = statement_expr.expr(db) else {
+        return None;
+    };
+    let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+        return None;
+    };
+    let BlockOrIf::Block(else_block) = else_clause.else_block_or_if(db) else {
+        return None;
+    };
+
+    let (first_array, first_value) = single_append(db, &if_expr.if_block(db))?;
+    let (second_array, second_value) = single_append(db, &else_block)?;
+    (first_array != second_array && first_value == second_value)
+        .then_some((first_array, second_array))
+}
+
+/// Returns the array name and the appended value text if the block is a single
+/// `<array>.append(<value>);` statement.
+fn single_append<'db>(db: &'db dyn Database, block: &ExprBlock<'db>) -> Option<(String, String)> {
+    let mut statements = block.statements(db).elements(db);
+    if statements.len() != 1 {
+        return None;
+    }
+    let statement = statements.next()?;
+    let Statement::Expr(statement_expr) = &statement else {
+        return None;
+    };
+    let Expr::Binary(binary) = statement_expr.expr(db) else {
+        return None;
+    };
+    let array_name =
+        syntax_node_to_str_without_all_nested_trivia(db, binary.lhs(db).as_syntax_node());
+    let value = appended_value(db, &statement, &array_name)?;
+    Some((
+        array_name,
+        syntax_node_to_str_without_all_nested_trivia(db, value.as_syntax_node()),
+    ))
+}
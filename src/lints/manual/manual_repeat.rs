@@ -0,0 +1,227 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{
+    Expr, ExprFixedSizeArray, OptionFixedSizeArraySize, StatementList,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{is_side_effect_free_syntax, replace_preserving_trivia};
+use crate::lints::manual::helpers::{appended_value, empty_array_binding_name};
+use crate::queries::syntax_node_to_str_without_all_nested_trivia;
+
+/// Minimal number of identical elements for the repetition to be reported.
+const MIN_REPEATED_ELEMENTS: usize = 3;
+
+pub struct ManualRepeat;
+
+/// ## What it does
+///
+/// Checks for arrays built from the same side-effect free value repeated multiple times,
+/// either by consecutive appends to a new array or by listing it in a fixed-size array.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let mut a = array![];
+///     a.append(0);
+///     a.append(0);
+///     a.append(0);
+///     let _b = [0, 0, 0];
+/// }
+/// ```
+///
+/// The fixed-size array can be simplified to:
+///
+/// ```cairo
+/// fn main() {
+///     let _b = [0; 3];
+/// }
+/// ```
+impl Lint for ManualRepeat {
+    fn allowed_name(&self) -> &'static str {
+        "manual_repeat"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0059"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Array is built from the same repeated value. Consider using the repeat syntax `[value; N]` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualRepeat
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_repeat(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace with the repeat syntax `[value; N]`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_repeat<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let item_node = match item {
+        ModuleItemId::Constant(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::FreeFunction(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::Impl(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        // Trait can have a default function impl.
+        ModuleItemId::Trait(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        _ => return,
+    };
+
+    for node in item_node.descendants(db) {
+        match node.kind(db) {
+            SyntaxKind::StatementList => {
+                check_repeated_appends(db, &StatementList::from_syntax_node(db, node), diagnostics)
+            }
+            SyntaxKind::ExprFixedSizeArray => {
+                if repeated_fixed_size_array_item(
+                    db,
+                    &ExprFixedSizeArray::from_syntax_node(db, node),
+                )
+                .is_some()
+                {
+                    diagnostics.push(PluginDiagnostic {
+                        stable_ptr: node.stable_ptr(db),
+                        message: ManualRepeat.diagnostic_message().to_string(),
+                        severity: Severity::Warning,
+                        inner_span: None,
+                        error_code: None,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reports `let` statements creating an empty array that is followed by appends of the same value.
+fn check_repeated_appends<'db>(
+    db: &'db dyn Database,
+    statement_list: &StatementList<'db>,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let statements = statement_list.elements(db).collect::<Vec<_>>();
+    for (index, statement) in statements.iter().enumerate() {
+        let Some(array_name) = empty_array_binding_name(db, statement) else {
+            continue;
+        };
+
+        let appended_values = statements[index + 1..]
+            .iter()
+            .map_while(|statement| appended_value(db, statement, &array_name))
+            .collect::<Vec<_>>();
+        let Some(first_value) = appended_values.first() else {
+            continue;
+        };
+        if !is_side_effect_free_syntax(db, first_value) {
+            continue;
+        }
+
+        let first_value_text =
+            syntax_node_to_str_without_all_nested_trivia(db, first_value.as_syntax_node());
+        let repetitions = appended_values
+            .iter()
+            .take_while(|value| {
+                syntax_node_to_str_without_all_nested_trivia(db, value.as_syntax_node())
+                    == first_value_text
+            })
+            .count();
+
+        if repetitions >= MIN_REPEATED_ELEMENTS {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: statement.as_syntax_node().stable_ptr(db),
+                message: ManualRepeat.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Returns the repeated item and the number of repetitions if all the items of the fixed-size
+/// array are the same side-effect free expression.
+fn repeated_fixed_size_array_item<'db>(
+    db: &'db dyn Database,
+    fixed_size_array: &ExprFixedSizeArray<'db>,
+) -> Option<(Expr<'db>, usize)> {
+    if !matches!(
+        fixed_size_array.size(db),
+        OptionFixedSizeArraySize::Empty(_)
+    ) {
+        return None;
+    }
+
+    let items = fixed_size_array.exprs(db).elements(db).collect::<Vec<_>>();
+    let first_item = items.first()?;
+    let first_item_text =
+        syntax_node_to_str_without_all_nested_trivia(db, first_item.as_syntax_node());
+    let all_items_equal = items.iter().all(|item| {
+        syntax_node_to_str_without_all_nested_trivia(db, item.as_syntax_node()) == first_item_text
+    });
+
+    (items.len() >= MIN_REPEATED_ELEMENTS
+        && all_items_equal
+        && is_side_effect_free_syntax(db, first_item))
+    .then(|| (first_item.clone(), items.len()))
+}
+
+/// Rewrites a fixed-size array of repeated items into the repeat syntax `[value; N]`.
+///
+/// # Arguments
+///
+/// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+/// * `node` - The `SyntaxNode` containing the fixed-size array.
+///
+/// # Returns
+///
+/// An `InternalFix` containing the repeat expression, or `None` for the repeated appends
+/// which are not fixed automatically.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_repeat<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let fixed_size_array = ExprFixedSizeArray::cast(db, node)?;
+    let (item, repetitions) = repeated_fixed_size_array_item(db, &fixed_size_array)?;
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            &format!(
+                "[{}; {repetitions}]",
+                item.as_syntax_node().get_text_without_trivia(db).long(db)
+            ),
+        ),
+        description: ManualRepeat.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
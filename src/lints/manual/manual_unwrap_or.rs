@@ -6,6 +6,7 @@ use cairo_lang_semantic::types::TypesSemantic;
 use cairo_lang_semantic::{Arenas, ExprIf, ExprMatch};
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode, ast};
 
+use crate::LinterDiagnosticParams;
 use crate::lints::manual::helpers::{MatchOnOption, MatchOnResult};
 use crate::{
     context::CairoLintKind,
@@ -15,7 +16,7 @@ use crate::{
     },
 };
 use crate::{
-    context::Lint,
+    context::{Lint, LintGroup},
     lints::manual::{ManualLint, check_manual, check_manual_if},
 };
 use salsa::Database;
@@ -47,6 +48,10 @@ impl Lint for ManualUnwrapOr {
         "manual_unwrap_or"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0048"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual `unwrap_or` detected. Consider using `unwrap_or()` instead."
     }
@@ -55,6 +60,10 @@ impl Lint for ManualUnwrapOr {
         CairoLintKind::ManualUnwrapOr
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -72,6 +81,7 @@ impl Lint for ManualUnwrapOr {
 pub fn check_manual_unwrap_or<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies_with_ids(db, item);
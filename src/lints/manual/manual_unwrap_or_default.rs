@@ -6,13 +6,14 @@ use cairo_lang_syntax::node::{
     ast::{self},
 };
 
+use crate::LinterDiagnosticParams;
 use crate::{
     context::CairoLintKind,
     fixer::InternalFix,
     queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions},
 };
 use crate::{
-    context::Lint,
+    context::{Lint, LintGroup},
     lints::manual::{ManualLint, check_manual, check_manual_if},
 };
 use salsa::Database;
@@ -49,6 +50,10 @@ impl Lint for ManualUnwrapOrDefault {
         "manual_unwrap_or_default"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0015"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This can be done in one call with `.unwrap_or_default()`"
     }
@@ -57,6 +62,10 @@ impl Lint for ManualUnwrapOrDefault {
         CairoLintKind::ManualUnwrapOrDefault
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -74,6 +83,7 @@ impl Lint for ManualUnwrapOrDefault {
 pub fn check_manual_unwrap_or_default<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -7,6 +7,7 @@ use cairo_lang_semantic::{Arenas, ExprIf, ExprMatch};
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode, ast};
 use salsa::Database;
 
+use crate::LinterDiagnosticParams;
 use crate::lints::manual::helpers::{MatchOnOption, MatchOnResult};
 use crate::{
     context::CairoLintKind,
@@ -16,7 +17,7 @@ use crate::{
     },
 };
 use crate::{
-    context::Lint,
+    context::{Lint, LintGroup},
     lints::manual::{ManualLint, check_manual, check_manual_if},
 };
 
@@ -25,7 +26,8 @@ pub struct ManualUnwrapOrElse;
 /// ## What it does
 ///
 /// Finds patterns that reimplement `Option::unwrap_or_else` or `Result::unwrap_or_else`.
-/// Triggers when the value returned upon `None` or `Err` doesn't implement `Drop`.
+/// Triggers when the value returned upon `None` or `Err` doesn't implement `Drop`, or when it is
+/// computed by a function call, which `unwrap_or` would evaluate even if it's not needed.
 ///
 /// ## Example
 ///
@@ -48,11 +50,34 @@ pub struct ManualUnwrapOrElse;
 /// let foo: Option<i32> = None;
 /// foo.unwrap_or_else(|| Struct { x: 0x0 });
 /// ```
+///
+/// ```cairo
+/// fn compute_default() -> u32 {
+///     42
+/// }
+///
+/// let foo: Option<u32> = None;
+/// match foo {
+///     Some(v) => v,
+///     None => compute_default(),
+/// };
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// let foo: Option<u32> = None;
+/// foo.unwrap_or_else(|| compute_default());
+/// ```
 impl Lint for ManualUnwrapOrElse {
     fn allowed_name(&self) -> &'static str {
         "manual_unwrap_or_else"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0054"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Manual `unwrap_or_else` detected. Consider using `unwrap_or_else()` instead."
     }
@@ -61,6 +86,10 @@ impl Lint for ManualUnwrapOrElse {
         CairoLintKind::ManualUnwrapOrElse
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -78,6 +107,7 @@ impl Lint for ManualUnwrapOrElse {
 pub fn check_manual_unwrap_or_else<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies_with_ids(db, item);
@@ -121,7 +151,10 @@ fn check_manual_unwrap_or_else_with_match<'db>(
 ) -> bool {
     let matched_expr = db.expr_semantic(function_id, match_expr.matched_expr);
     let is_droppable = db.droppable(matched_expr.ty()).is_ok();
-    !is_droppable && check_manual(db, match_expr, arenas, ManualLint::ManualUnwrapOrElse)
+    // The cases with a droppable type and a fallback which isn't a function call are reported by
+    // `manual_unwrap_or`.
+    check_manual(db, match_expr, arenas, ManualLint::ManualUnwrapOrElse)
+        && (!is_droppable || !check_manual(db, match_expr, arenas, ManualLint::ManualUnwrapOr))
 }
 
 fn check_manual_unwrap_or_else_with_if<'db>(
@@ -132,7 +165,8 @@ fn check_manual_unwrap_or_else_with_if<'db>(
 ) -> bool {
     let condition_expr = db.expr_semantic(function_id, if_expr.if_block);
     let is_droppable = db.droppable(condition_expr.ty()).is_ok();
-    !is_droppable && check_manual_if(db, if_expr, arenas, ManualLint::ManualUnwrapOrElse)
+    check_manual_if(db, if_expr, arenas, ManualLint::ManualUnwrapOrElse)
+        && (!is_droppable || !check_manual_if(db, if_expr, arenas, ManualLint::ManualUnwrapOr))
 }
 
 // Copied from `manual_unwrap_or` and adapted.
@@ -1,26 +1,37 @@
 pub mod helpers;
 pub mod manual_assert;
+pub mod manual_checked_conversion;
 pub mod manual_err;
 pub mod manual_expect;
 pub mod manual_expect_err;
+pub mod manual_filter;
 pub mod manual_is;
 pub mod manual_is_empty;
+pub mod manual_is_some_and;
+pub mod manual_is_variant;
+pub mod manual_map_or;
 pub mod manual_ok;
 pub mod manual_ok_or;
+pub mod manual_ok_or_default;
+pub mod manual_partition;
+pub mod manual_repeat;
 pub mod manual_unwrap_or;
 pub mod manual_unwrap_or_default;
 pub mod manual_unwrap_or_else;
+pub mod needless_match;
 
 use std::fmt::Debug;
 
 use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_semantic::{Arenas, Condition, Expr, ExprIf, ExprMatch, Pattern};
-use cairo_lang_syntax::node::{TypedStablePtr, ast};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
 use helpers::{
     check_is_default, func_call_or_block_returns_never,
-    if_expr_condition_and_block_match_enum_pattern, if_expr_pattern_matches_tail_var,
-    is_destructured_variable_used_and_expected_variant, is_expected_function,
-    match_arm_returns_extracted_var,
+    if_expr_condition_and_block_match_enum_pattern, if_expr_pattern_applied_to_tail_function,
+    if_expr_pattern_matches_tail_var, if_expr_tail_returns_bool_using_pattern_var,
+    is_constant_expr, is_destructured_variable_used_and_expected_variant, is_expected_function,
+    is_function_call_expr, match_arm_applies_function_to_extracted_var,
+    match_arm_returns_bool_using_extracted_var, match_arm_returns_extracted_var,
 };
 use if_chain::if_chain;
 
@@ -35,6 +46,7 @@ use salsa::Database;
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ManualLint {
     ManualOkOr,
+    ManualOkOrDefault,
     ManualIsSome,
     ManualIsNone,
     ManualExpect,
@@ -49,6 +61,10 @@ pub enum ManualLint {
     ManualUnwrapOr,
     ManualIsEmpty,
     ManualUnwrapOrElse,
+    NeedlessMatch,
+    ManualMapOr,
+    ManualFilter,
+    ManualIsSomeAnd,
 }
 
 /// Checks for all the manual lint written as `match`.
@@ -176,9 +192,18 @@ fn check_syntax_some_arm<'db>(
     manual_lint: ManualLint,
 ) -> bool {
     match manual_lint {
-        ManualLint::ManualOkOr => {
+        ManualLint::ManualOkOr | ManualLint::ManualOkOrDefault => {
             is_destructured_variable_used_and_expected_variant(expr, pattern, db, arenas, OK)
         }
+        ManualLint::NeedlessMatch => {
+            is_destructured_variable_used_and_expected_variant(expr, pattern, db, arenas, SOME)
+        }
+        ManualLint::ManualMapOr => {
+            match_arm_applies_function_to_extracted_var(expr, pattern, arenas)
+        }
+        ManualLint::ManualIsSomeAnd => {
+            match_arm_returns_bool_using_extracted_var(db, expr, pattern, arenas)
+        }
         ManualLint::ManualIsSome => is_expected_variant(expr, db, TRUE),
         ManualLint::ManualIsNone => is_expected_variant(expr, db, FALSE),
         ManualLint::ManualUnwrapOr
@@ -226,6 +251,9 @@ fn check_syntax_ok_arm<'db>(
         ManualLint::ManualOk => {
             is_destructured_variable_used_and_expected_variant(expr, pattern, db, arenas, SOME)
         }
+        ManualLint::NeedlessMatch => {
+            is_destructured_variable_used_and_expected_variant(expr, pattern, db, arenas, OK)
+        }
 
         ManualLint::ManualErr => is_expected_variant(expr, db, NONE),
         ManualLint::ManualExpectErr => {
@@ -253,8 +281,17 @@ fn check_syntax_none_arm<'db>(
     manual_lint: ManualLint,
 ) -> bool {
     match manual_lint {
-        ManualLint::ManualOkOr => is_expected_variant(expr, db, ERR),
-        ManualLint::ManualIsSome => is_expected_variant(expr, db, FALSE),
+        ManualLint::ManualOkOr | ManualLint::ManualOkOrDefault => {
+            is_expected_variant(expr, db, ERR)
+        }
+        ManualLint::NeedlessMatch => is_expected_variant(expr, db, NONE),
+        // A `map_or` with a `false` default is reported as `is_some_and`.
+        ManualLint::ManualMapOr => {
+            is_constant_expr(expr, arenas) && !is_expected_variant(expr, db, FALSE)
+        }
+        ManualLint::ManualIsSome | ManualLint::ManualIsSomeAnd => {
+            is_expected_variant(expr, db, FALSE)
+        }
         ManualLint::ManualIsNone => is_expected_variant(expr, db, TRUE),
         ManualLint::ManualOptExpect => {
             if let Expr::FunctionCall(func_call) = &expr {
@@ -265,7 +302,12 @@ fn check_syntax_none_arm<'db>(
             }
         }
         ManualLint::ManualUnwrapOrDefault => check_is_default(db, expr, arenas),
-        ManualLint::ManualUnwrapOr | ManualLint::ManualUnwrapOrElse => {
+        ManualLint::ManualUnwrapOr => {
+            !func_call_or_block_returns_never(expr, db, arenas)
+                && !check_is_default(db, expr, arenas)
+                && !is_function_call_expr(db, expr, arenas)
+        }
+        ManualLint::ManualUnwrapOrElse => {
             !func_call_or_block_returns_never(expr, db, arenas)
                 && !check_is_default(db, expr, arenas)
         }
@@ -288,6 +330,9 @@ fn check_syntax_err_arm<'db>(
         ManualLint::ManualErr => {
             is_destructured_variable_used_and_expected_variant(expr, pattern, db, arenas, SOME)
         }
+        ManualLint::NeedlessMatch => {
+            is_destructured_variable_used_and_expected_variant(expr, pattern, db, arenas, ERR)
+        }
         ManualLint::ManualResExpect => {
             if let Expr::FunctionCall(func_call) = &expr {
                 let func_name = func_call.function.full_path(db);
@@ -309,7 +354,12 @@ fn check_syntax_err_arm<'db>(
         }
         ManualLint::ManualExpectErr => match_arm_returns_extracted_var(expr, pattern, arenas),
         ManualLint::ManualUnwrapOrDefault => check_is_default(db, expr, arenas),
-        ManualLint::ManualUnwrapOr | ManualLint::ManualUnwrapOrElse => {
+        ManualLint::ManualUnwrapOr => {
+            !func_call_or_block_returns_never(expr, db, arenas)
+                && !check_is_default(db, expr, arenas)
+                && !is_function_call_expr(db, expr, arenas)
+        }
+        ManualLint::ManualUnwrapOrElse => {
             !func_call_or_block_returns_never(expr, db, arenas)
                 && !check_is_default(db, expr, arenas)
         }
@@ -331,6 +381,9 @@ pub fn check_manual_if<'db>(
     arenas: &Arenas<'db>,
     manual_lint: ManualLint,
 ) -> bool {
+    if manual_lint == ManualLint::ManualFilter {
+        return check_syntax_filter_if(expr, db, arenas);
+    }
     if_chain! {
         if let Some(Condition::Let(_condition_let, patterns)) = &expr.conditions.first();
         if let Pattern::EnumVariant(enum_pattern) = &arenas.patterns[patterns[0]];
@@ -359,6 +412,52 @@ pub fn check_manual_if<'db>(
     false
 }
 
+/// Checks for `if cond { Option::Some(x) } else { Option::None }` where the condition reads `x`.
+fn check_syntax_filter_if<'db>(
+    expr: &ExprIf<'db>,
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let [Condition::BoolExpr(condition)] = &expr.conditions[..] else {
+        return false;
+    };
+    let Some(else_block) = expr.else_block else {
+        return false;
+    };
+    let (Expr::Block(if_block), Expr::Block(else_block)) =
+        (&arenas.exprs[expr.if_block], &arenas.exprs[else_block])
+    else {
+        return false;
+    };
+    if !if_block.statements.is_empty() || !else_block.statements.is_empty() {
+        return false;
+    }
+    let (Some(if_tail), Some(else_tail)) = (if_block.tail, else_block.tail) else {
+        return false;
+    };
+    let Expr::EnumVariantCtor(some_ctor) = &arenas.exprs[if_tail] else {
+        return false;
+    };
+    let Expr::Var(wrapped_var) = &arenas.exprs[some_ctor.value_expr] else {
+        return false;
+    };
+    if some_ctor.variant.id.full_path(db) != SOME
+        || !is_expected_variant(&arenas.exprs[else_tail], db, NONE)
+    {
+        return false;
+    }
+
+    let condition_span = arenas.exprs[*condition]
+        .stable_ptr()
+        .lookup(db)
+        .as_syntax_node()
+        .span(db);
+    arenas.exprs.iter().any(|(_expression_id, expression)| {
+        matches!(expression, Expr::Var(var) if var.var == wrapped_var.var)
+            && condition_span.contains(expression.stable_ptr().lookup(db).as_syntax_node().span(db))
+    })
+}
+
 fn check_syntax_opt_if<'db>(
     expr: &ExprIf<'db>,
     db: &'db dyn Database,
@@ -376,9 +475,16 @@ fn check_syntax_opt_if<'db>(
     };
 
     match manual_lint {
-        ManualLint::ManualOkOr => {
+        ManualLint::ManualOkOr | ManualLint::ManualOkOrDefault => {
             if_expr_condition_and_block_match_enum_pattern(expr, db, arenas, OK)
         }
+        ManualLint::NeedlessMatch => {
+            if_expr_condition_and_block_match_enum_pattern(expr, db, arenas, SOME)
+        }
+        ManualLint::ManualMapOr => if_expr_pattern_applied_to_tail_function(expr, arenas),
+        ManualLint::ManualIsSomeAnd => {
+            if_expr_tail_returns_bool_using_pattern_var(db, expr, arenas)
+        }
         ManualLint::ManualIsSome => is_expected_variant(&arenas.exprs[tail_expr_id], db, TRUE),
         ManualLint::ManualIsNone => is_expected_variant(&arenas.exprs[tail_expr_id], db, FALSE),
         ManualLint::ManualOptExpect => if_expr_pattern_matches_tail_var(expr, arenas),
@@ -459,12 +565,25 @@ fn check_syntax_opt_else<'db>(
     let tail_expr = &arenas.exprs[tail_expr_id];
 
     match manual_lint {
-        ManualLint::ManualOkOr => is_expected_variant(&arenas.exprs[tail_expr_id], db, ERR),
-        ManualLint::ManualIsSome => is_expected_variant(&arenas.exprs[tail_expr_id], db, FALSE),
+        ManualLint::ManualOkOr | ManualLint::ManualOkOrDefault => {
+            is_expected_variant(&arenas.exprs[tail_expr_id], db, ERR)
+        }
+        ManualLint::NeedlessMatch => is_expected_variant(&arenas.exprs[tail_expr_id], db, NONE),
+        ManualLint::ManualMapOr => {
+            is_constant_expr(tail_expr, arenas) && !is_expected_variant(tail_expr, db, FALSE)
+        }
+        ManualLint::ManualIsSome | ManualLint::ManualIsSomeAnd => {
+            is_expected_variant(tail_expr, db, FALSE)
+        }
         ManualLint::ManualIsNone => is_expected_variant(&arenas.exprs[tail_expr_id], db, TRUE),
         ManualLint::ManualOptExpect => is_expected_function(tail_expr, db, PANIC_WITH_FELT252),
         ManualLint::ManualUnwrapOrDefault => check_is_default(db, tail_expr, arenas),
-        ManualLint::ManualUnwrapOr | ManualLint::ManualUnwrapOrElse => {
+        ManualLint::ManualUnwrapOr => {
+            !check_is_default(db, tail_expr, arenas)
+                && !func_call_or_block_returns_never(tail_expr, db, arenas)
+                && !is_function_call_expr(db, tail_expr, arenas)
+        }
+        ManualLint::ManualUnwrapOrElse => {
             !check_is_default(db, tail_expr, arenas)
                 && !func_call_or_block_returns_never(tail_expr, db, arenas)
         }
@@ -501,7 +620,12 @@ fn check_syntax_res_else<'db>(
         ManualLint::ManualIsErr => is_expected_variant(tail_expr, db, TRUE),
         ManualLint::ManualOk => is_expected_variant(tail_expr, db, NONE),
         ManualLint::ManualResExpect => is_expected_function(tail_expr, db, PANIC_WITH_FELT252),
-        ManualLint::ManualUnwrapOr | ManualLint::ManualUnwrapOrElse => {
+        ManualLint::ManualUnwrapOr => {
+            !check_is_default(db, tail_expr, arenas)
+                && !func_call_or_block_returns_never(tail_expr, db, arenas)
+                && !is_function_call_expr(db, tail_expr, arenas)
+        }
+        ManualLint::ManualUnwrapOrElse => {
             !check_is_default(db, tail_expr, arenas)
                 && !func_call_or_block_returns_never(tail_expr, db, arenas)
         }
@@ -0,0 +1,148 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{Condition, ExprIf, ExprMatch};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::manual::{ManualLint, check_manual, check_manual_if};
+use crate::queries::{get_all_function_bodies, get_all_if_expressions, get_all_match_expressions};
+
+pub struct NeedlessMatch;
+
+/// ## What it does
+///
+/// Checks for `match` and `if let` expressions which rebuild the matched `Option` or `Result`
+/// from its variants without changing it.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let opt: Option<u32> = Option::Some(1);
+///     let _a = match opt {
+///         Option::Some(x) => Option::Some(x),
+///         Option::None => Option::None,
+///     };
+/// }
+/// ```
+///
+/// Can be replaced with:
+///
+/// ```cairo
+/// fn main() {
+///     let opt: Option<u32> = Option::Some(1);
+///     let _a = opt;
+/// }
+/// ```
+impl Lint for NeedlessMatch {
+    fn allowed_name(&self) -> &'static str {
+        "needless_match"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0068"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Needless match detected, the expression rebuilds the matched value. Consider using the matched value directly."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::NeedlessMatch
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_needless_match(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace with the matched expression")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_needless_match<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let if_exprs = get_all_if_expressions(function_body);
+        let match_exprs = get_all_match_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for match_expr in match_exprs.iter() {
+            if check_manual(db, match_expr, arenas, ManualLint::NeedlessMatch) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: match_expr.stable_ptr.untyped(),
+                    message: NeedlessMatch.diagnostic_message().to_owned(),
+                    severity: Severity::Warning,
+                    error_code: None,
+                    inner_span: None,
+                });
+            }
+        }
+        for if_expr in if_exprs.iter() {
+            if check_manual_if(db, if_expr, arenas, ManualLint::NeedlessMatch) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: NeedlessMatch.diagnostic_message().to_owned(),
+                    severity: Severity::Warning,
+                    error_code: None,
+                    inner_span: None,
+                });
+            }
+        }
+    }
+}
+
+/// Replaces the `match` or `if let` expression with the matched expression.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_needless_match<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let matched_expr = match node.kind(db) {
+        SyntaxKind::ExprMatch => ExprMatch::from_syntax_node(db, node).expr(db),
+        SyntaxKind::ExprIf => {
+            let Condition::Let(condition_let) = ExprIf::from_syntax_node(db, node)
+                .conditions(db)
+                .elements(db)
+                .next()?
+            else {
+                return None;
+            };
+            condition_let.expr(db)
+        }
+        _ => return None,
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            matched_expr
+                .as_syntax_node()
+                .get_text_without_trivia(db)
+                .long(db),
+        ),
+        description: NeedlessMatch.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
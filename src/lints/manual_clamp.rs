@@ -0,0 +1,322 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, ExprIf, VarId};
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, BlockOrIf, Condition as AstCondition, Expr as AstExpr, ExprBlock,
+    ExprIf as AstExprIf, OptionElseClause, Statement as AstStatement,
+};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use num_bigint::BigInt;
+use salsa::Database;
+
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::queries::{
+    get_all_function_bodies, get_all_if_expressions, syntax_node_to_str_without_all_nested_trivia,
+};
+use crate::{LinterDiagnosticParams, LinterGroup};
+
+pub struct ManualClamp;
+
+/// ## What it does
+///
+/// Checks for `if` expressions limiting a value to a range by comparing it against a lower and an
+/// upper bound, which reimplement clamping.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32, lo: u32, hi: u32) -> u32 {
+///     if x < lo {
+///         lo
+///     } else if x > hi {
+///         hi
+///     } else {
+///         x
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(x: u32, lo: u32, hi: u32) -> u32 {
+///     x.clamp(lo, hi)
+/// }
+/// ```
+impl Lint for ManualClamp {
+    fn allowed_name(&self) -> &'static str {
+        "manual_clamp"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0106"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual implementation of `clamp` detected. Consider using a clamp helper instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualClamp
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_clamp(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace with `clamp`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_clamp<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for if_expr in get_all_if_expressions(function_body) {
+            if is_manual_clamp(db, &if_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: ManualClamp.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// A side effect free operand of a comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operand<'db> {
+    Variable(VarId<'db>),
+    Literal(BigInt),
+}
+
+/// Checks if the `if` expression is `if x < lo { lo } else if x > hi { hi } else { x }`, with any
+/// order of the bounds and of the comparison operands.
+fn is_manual_clamp<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let Some(inner_if) = if_expr
+        .else_block
+        .and_then(|else_block| else_if(arenas, else_block))
+    else {
+        return false;
+    };
+    let Some(value) = inner_if
+        .else_block
+        .and_then(|else_block| block_tail_operand(arenas, else_block))
+    else {
+        return false;
+    };
+    let Operand::Variable(_) = value else {
+        return false;
+    };
+    let (Some((outer_bound, outer_is_lower)), Some((inner_bound, inner_is_lower))) = (
+        bound_of_branch(db, arenas, if_expr, &value),
+        bound_of_branch(db, arenas, inner_if, &value),
+    ) else {
+        return false;
+    };
+    outer_is_lower != inner_is_lower && outer_bound != inner_bound
+}
+
+/// Returns the `if` expression of an `else if` branch.
+fn else_if<'db, 'a>(arenas: &'a Arenas<'db>, else_block: ExprId) -> Option<&'a ExprIf<'db>> {
+    match &arenas.exprs[else_block] {
+        Expr::If(if_expr) => Some(if_expr),
+        Expr::Block(block) if block.statements.is_empty() => match &arenas.exprs[block.tail?] {
+            Expr::If(if_expr) => Some(if_expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the bound the value is compared against in the condition of the `if`, if the branch
+/// returns that bound. Also tells whether it's the lower bound, i.e. the condition holds when the
+/// value is below it.
+fn bound_of_branch<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    if_expr: &ExprIf<'db>,
+    value: &Operand<'db>,
+) -> Option<(Operand<'db>, bool)> {
+    let [Condition::BoolExpr(condition)] = &if_expr.conditions[..] else {
+        return None;
+    };
+    let Expr::FunctionCall(func_call) = &arenas.exprs[*condition] else {
+        return None;
+    };
+    let GenericFunctionId::Impl(impl_generic_func_id) =
+        func_call.function.get_concrete(db).generic_function
+    else {
+        return None;
+    };
+
+    // Whether the condition is true when the left-hand side is the smaller value.
+    let corelib_context = db.corelib_context();
+    let function = Some(impl_generic_func_id.function);
+    let lhs_is_smaller = if function == corelib_context.get_partial_ord_lt_trait_function_id()
+        || function == corelib_context.get_partial_ord_le_trait_function_id()
+    {
+        true
+    } else if function == corelib_context.get_partial_ord_gt_trait_function_id()
+        || function == corelib_context.get_partial_ord_ge_trait_function_id()
+    {
+        false
+    } else {
+        return None;
+    };
+
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = &func_call.args[..]
+    else {
+        return None;
+    };
+    let lhs = operand_of(arenas, *lhs)?;
+    let rhs = operand_of(arenas, *rhs)?;
+    let (bound, is_lower) = if lhs == *value && rhs != *value {
+        (rhs, lhs_is_smaller)
+    } else if rhs == *value && lhs != *value {
+        (lhs, !lhs_is_smaller)
+    } else {
+        return None;
+    };
+
+    (block_tail_operand(arenas, if_expr.if_block)? == bound).then_some((bound, is_lower))
+}
+
+fn operand_of<'db>(arenas: &Arenas<'db>, expr_id: ExprId) -> Option<Operand<'db>> {
+    match &arenas.exprs[expr_id] {
+        Expr::Var(var) => Some(Operand::Variable(var.var)),
+        Expr::Literal(literal) => Some(Operand::Literal(literal.value.clone())),
+        Expr::Snapshot(snapshot) => operand_of(arenas, snapshot.inner),
+        _ => None,
+    }
+}
+
+fn block_tail_operand<'db>(arenas: &Arenas<'db>, block_id: ExprId) -> Option<Operand<'db>> {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return None;
+    };
+    if !block.statements.is_empty() {
+        return None;
+    }
+    operand_of(arenas, block.tail?)
+}
+
+/// Rewrites a clamping `if` expression into a `clamp` call.
+///
+/// The fix is only available if the corelib in use defines `clamp`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_clamp<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    db.corelib_context()
+        .get_partial_ord_clamp_trait_function_id()?;
+    let if_expr = AstExprIf::cast(db, node)?;
+    let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+        return None;
+    };
+    let BlockOrIf::If(inner_if) = else_clause.else_block_or_if(db) else {
+        return None;
+    };
+    let OptionElseClause::ElseClause(inner_else_clause) = inner_if.else_clause(db) else {
+        return None;
+    };
+    let BlockOrIf::Block(else_block) = inner_else_clause.else_block_or_if(db) else {
+        return None;
+    };
+    let value = block_tail_text(db, &else_block)?;
+    let (outer_bound, outer_is_lower) = bound_text_of_branch(db, &if_expr, &value)?;
+    let (inner_bound, inner_is_lower) = bound_text_of_branch(db, &inner_if, &value)?;
+    let (lower_bound, upper_bound) = match (outer_is_lower, inner_is_lower) {
+        (true, false) => (outer_bound, inner_bound),
+        (false, true) => (inner_bound, outer_bound),
+        _ => return None,
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            &format!("{value}.clamp({lower_bound}, {upper_bound})"),
+        ),
+        description: ManualClamp.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Returns the text of the bound the value is compared against in the condition of the `if`, and
+/// whether it's the lower bound.
+fn bound_text_of_branch<'db>(
+    db: &'db dyn Database,
+    if_expr: &AstExprIf<'db>,
+    value: &str,
+) -> Option<(String, bool)> {
+    let mut conditions = if_expr.conditions(db).elements(db);
+    let AstCondition::Expr(condition) = conditions.next()? else {
+        return None;
+    };
+    let AstExpr::Binary(comparison) = condition.expr(db) else {
+        return None;
+    };
+    let lhs_is_smaller = match comparison.op(db) {
+        BinaryOperator::LT(_) | BinaryOperator::LE(_) => true,
+        BinaryOperator::GT(_) | BinaryOperator::GE(_) => false,
+        _ => return None,
+    };
+    let lhs = syntax_node_to_str_without_all_nested_trivia(db, comparison.lhs(db).as_syntax_node());
+    let rhs = syntax_node_to_str_without_all_nested_trivia(db, comparison.rhs(db).as_syntax_node());
+    let (bound, is_lower) = if lhs == value {
+        (rhs, lhs_is_smaller)
+    } else if rhs == value {
+        (lhs, !lhs_is_smaller)
+    } else {
+        return None;
+    };
+
+    (block_tail_text(db, &if_expr.if_block(db))? == bound).then_some((bound, is_lower))
+}
+
+/// Returns the text of the tail expression if it's the only element of the block.
+fn block_tail_text<'db>(db: &'db dyn Database, block: &ExprBlock<'db>) -> Option<String> {
+    let mut statements = block.statements(db).elements(db);
+    if statements.len() != 1 {
+        return None;
+    }
+    let AstStatement::Expr(statement_expr) = statements.next()? else {
+        return None;
+    };
+    Some(syntax_node_to_str_without_all_nested_trivia(
+        db,
+        statement_expr.expr(db).as_syntax_node(),
+    ))
+}
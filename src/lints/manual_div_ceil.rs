@@ -0,0 +1,173 @@
+use cairo_lang_defs::ids::{ModuleItemId, TraitFunctionId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::LinterGroup;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::helper::{is_one, is_side_effect_free_syntax};
+use crate::lints::UNSIGNED_INTEGER_TYPES;
+use crate::queries::{get_all_function_bodies, get_all_function_calls};
+
+pub struct ManualDivCeil;
+
+/// ## What it does
+///
+/// Checks for ceiling divisions of unsigned integers computed manually, as `(a + b - 1) / b` or
+/// `(a - 1) / b + 1`.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(a: u32, b: u32) -> u32 {
+///     (a + b - 1) / b
+/// }
+/// ```
+///
+/// Such computations are easy to get wrong (e.g. `(a - 1) / b + 1` underflows for `a == 0`),
+/// so a dedicated, tested ceil-div helper should be used instead.
+impl Lint for ManualDivCeil {
+    fn allowed_name(&self) -> &'static str {
+        "manual_div_ceil"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0080"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual ceiling division detected. Consider using a dedicated ceil-div helper instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualDivCeil
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_div_ceil<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let function_call_exprs = get_all_function_calls(function_body);
+        let arenas = &function_body.arenas;
+        for function_call_expr in function_call_exprs {
+            if is_manual_div_ceil(db, &function_call_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: function_call_expr.stable_ptr.untyped(),
+                    message: ManualDivCeil.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the function call is `(a + b - 1) / b` or `(a - 1) / b + 1` on unsigned integers.
+fn is_manual_div_ceil<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let corelib_context = db.corelib_context();
+    let (Some(add_trait_function_id), Some(sub_trait_function_id), Some(div_trait_function_id)) = (
+        corelib_context.get_add_trait_function_id(),
+        corelib_context.get_sub_trait_function_id(),
+        corelib_context.get_div_trait_function_id(),
+    ) else {
+        return false;
+    };
+
+    let operands = |expr_id: ExprId, trait_function_id: TraitFunctionId<'db>| {
+        let Expr::FunctionCall(function_call_expr) = &arenas.exprs[expr_id] else {
+            return None;
+        };
+        unsigned_operands(db, function_call_expr, arenas, trait_function_id)
+    };
+
+    // `(a + b - 1) / b`
+    if let Some((numerator, divisor)) =
+        unsigned_operands(db, function_call_expr, arenas, div_trait_function_id)
+        && let Some((sum, one)) = operands(numerator, sub_trait_function_id)
+        && is_one(&ExprFunctionCallArg::Value(one), arenas)
+        && let Some((_, addend)) = operands(sum, add_trait_function_id)
+    {
+        return are_same_side_effect_free_exprs(db, addend, divisor, arenas);
+    }
+
+    // `(a - 1) / b + 1`
+    if let Some((quotient, one)) =
+        unsigned_operands(db, function_call_expr, arenas, add_trait_function_id)
+        && is_one(&ExprFunctionCallArg::Value(one), arenas)
+        && let Some((difference, _)) = operands(quotient, div_trait_function_id)
+        && let Some((_, one)) = operands(difference, sub_trait_function_id)
+    {
+        return is_one(&ExprFunctionCallArg::Value(one), arenas);
+    }
+
+    false
+}
+
+/// Returns the operands of the call if it's the given corelib operator trait function applied to
+/// unsigned integers.
+fn unsigned_operands<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+    trait_function_id: TraitFunctionId<'db>,
+) -> Option<(ExprId, ExprId)> {
+    let GenericFunctionId::Impl(impl_generic_func_id) = function_call_expr
+        .function
+        .get_concrete(db)
+        .generic_function
+    else {
+        return None;
+    };
+    if impl_generic_func_id.function != trait_function_id {
+        return None;
+    }
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = function_call_expr.args[..]
+    else {
+        return None;
+    };
+    let operand_type = arenas.exprs[lhs].ty().format(db);
+    UNSIGNED_INTEGER_TYPES
+        .iter()
+        .any(|(name, _)| *name == operand_type)
+        .then_some((lhs, rhs))
+}
+
+/// Checks if both expressions are written the same way and evaluating them has no side effects,
+/// so they always have the same value.
+fn are_same_side_effect_free_exprs<'db>(
+    db: &'db dyn Database,
+    lhs: ExprId,
+    rhs: ExprId,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let (lhs, rhs) = (
+        arenas.exprs[lhs].stable_ptr().lookup(db),
+        arenas.exprs[rhs].stable_ptr().lookup(db),
+    );
+    is_side_effect_free_syntax(db, &lhs)
+        && is_side_effect_free_syntax(db, &rhs)
+        && lhs.as_syntax_node().get_text_without_trivia(db)
+            == rhs.as_syntax_node().get_text_without_trivia(db)
+}
@@ -0,0 +1,355 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, ExprIf, VarId};
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, BlockOrIf, Condition as AstCondition, Expr as AstExpr, ExprBlock,
+    ExprIf as AstExprIf, OptionElseClause, Statement as AstStatement,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::queries::{
+    get_all_function_bodies, get_all_if_expressions, syntax_node_to_str_without_all_nested_trivia,
+};
+use crate::{LinterDiagnosticParams, LinterGroup};
+
+const MIN_PATH: &str = "core::cmp::min";
+const MAX_PATH: &str = "core::cmp::max";
+
+pub struct ManualMin;
+
+/// ## What it does
+///
+/// Checks for `if` expressions choosing the smaller of two variables,
+/// which reimplement `core::cmp::min`.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(a: u32, b: u32) -> u32 {
+///     if a < b {
+///         a
+///     } else {
+///         b
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// use core::cmp::min;
+///
+/// fn main(a: u32, b: u32) -> u32 {
+///     min(a, b)
+/// }
+/// ```
+impl Lint for ManualMin {
+    fn allowed_name(&self) -> &'static str {
+        "manual_min"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0062"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual implementation of `min` detected. Consider using `core::cmp::min` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualMin
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_min_max(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace with `core::cmp::min`")
+    }
+}
+
+pub struct ManualMax;
+
+/// ## What it does
+///
+/// Checks for `if` expressions choosing the greater of two variables,
+/// which reimplement `core::cmp::max`.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(a: u32, b: u32) -> u32 {
+///     if a > b {
+///         a
+///     } else {
+///         b
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// use core::cmp::max;
+///
+/// fn main(a: u32, b: u32) -> u32 {
+///     max(a, b)
+/// }
+/// ```
+impl Lint for ManualMax {
+    fn allowed_name(&self) -> &'static str {
+        "manual_max"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0063"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual implementation of `max` detected. Consider using `core::cmp::max` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualMax
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_min_max(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace with `core::cmp::max`")
+    }
+}
+
+/// Which of the two compared values is chosen by the `if` expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MinMax {
+    Min,
+    Max,
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_min_max<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for if_expr in get_all_if_expressions(function_body) {
+            // The last branch of an `if`-`else if` chain isn't a standalone expression, e.g. it's
+            // a part of a manual clamp.
+            if is_else_if(db, &if_expr) {
+                continue;
+            }
+            let message = match min_max_of_if(db, &if_expr, arenas) {
+                Some(MinMax::Min) => ManualMin.diagnostic_message(),
+                Some(MinMax::Max) => ManualMax.diagnostic_message(),
+                None => continue,
+            };
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: if_expr.stable_ptr.untyped(),
+                message: message.to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+fn is_else_if<'db>(db: &'db dyn Database, if_expr: &ExprIf<'db>) -> bool {
+    if_expr
+        .stable_ptr
+        .lookup(db)
+        .as_syntax_node()
+        .parent(db)
+        .is_some_and(|parent| parent.kind(db) == SyntaxKind::ElseClause)
+}
+
+/// Checks if the `if` expression compares two variables with `PartialOrd` and returns one of them
+/// in each branch, and tells whether it results in the smaller or the greater one.
+fn min_max_of_if<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<MinMax> {
+    let [Condition::BoolExpr(condition)] = &if_expr.conditions[..] else {
+        return None;
+    };
+    let Expr::FunctionCall(func_call) = &arenas.exprs[*condition] else {
+        return None;
+    };
+    let GenericFunctionId::Impl(impl_generic_func_id) =
+        func_call.function.get_concrete(db).generic_function
+    else {
+        return None;
+    };
+
+    // Whether the condition is true when the left-hand side is the smaller value.
+    let corelib_context = db.corelib_context();
+    let function = Some(impl_generic_func_id.function);
+    let lhs_is_smaller = if function == corelib_context.get_partial_ord_lt_trait_function_id()
+        || function == corelib_context.get_partial_ord_le_trait_function_id()
+    {
+        true
+    } else if function == corelib_context.get_partial_ord_gt_trait_function_id()
+        || function == corelib_context.get_partial_ord_ge_trait_function_id()
+    {
+        false
+    } else {
+        return None;
+    };
+
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = &func_call.args[..]
+    else {
+        return None;
+    };
+    let lhs = variable_of(arenas, *lhs)?;
+    let rhs = variable_of(arenas, *rhs)?;
+    if lhs == rhs {
+        return None;
+    }
+
+    let if_var = block_tail_variable(arenas, if_expr.if_block)?;
+    let else_var = block_tail_variable(arenas, if_expr.else_block?)?;
+    let if_returns_lhs = if if_var == lhs && else_var == rhs {
+        true
+    } else if if_var == rhs && else_var == lhs {
+        false
+    } else {
+        return None;
+    };
+
+    // Returning the left-hand side when it's smaller is `min`, swapping either the comparison
+    // direction or the branches turns it into `max`.
+    Some(if lhs_is_smaller == if_returns_lhs {
+        MinMax::Min
+    } else {
+        MinMax::Max
+    })
+}
+
+fn variable_of<'db>(arenas: &Arenas<'db>, expr_id: ExprId) -> Option<VarId<'db>> {
+    match &arenas.exprs[expr_id] {
+        Expr::Var(var) => Some(var.var),
+        Expr::Snapshot(snapshot) => variable_of(arenas, snapshot.inner),
+        _ => None,
+    }
+}
+
+fn block_tail_variable<'db>(arenas: &Arenas<'db>, block_id: ExprId) -> Option<VarId<'db>> {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return None;
+    };
+    if !block.statements.is_empty() {
+        return None;
+    }
+    variable_of(arenas, block.tail?)
+}
+
+/// Rewrites an `if` expression choosing one of two variables into a `min` or `max` call.
+///
+/// # Arguments
+///
+/// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+/// * `node` - The `SyntaxNode` containing the `if` expression.
+///
+/// # Returns
+///
+/// An `InternalFix` containing the `min`/`max` call along with the required import.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_min_max<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let if_expr = AstExprIf::cast(db, node)?;
+    let mut conditions = if_expr.conditions(db).elements(db);
+    let AstCondition::Expr(condition) = conditions.next()? else {
+        return None;
+    };
+    let AstExpr::Binary(comparison) = condition.expr(db) else {
+        return None;
+    };
+    let lhs_is_smaller = match comparison.op(db) {
+        BinaryOperator::LT(_) | BinaryOperator::LE(_) => true,
+        BinaryOperator::GT(_) | BinaryOperator::GE(_) => false,
+        _ => return None,
+    };
+    let lhs = syntax_node_to_str_without_all_nested_trivia(db, comparison.lhs(db).as_syntax_node());
+    let rhs = syntax_node_to_str_without_all_nested_trivia(db, comparison.rhs(db).as_syntax_node());
+
+    let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+        return None;
+    };
+    let BlockOrIf::Block(else_block) = else_clause.else_block_or_if(db) else {
+        return None;
+    };
+    let if_value = block_tail_text(db, &if_expr.if_block(db))?;
+    let else_value = block_tail_text(db, &else_block)?;
+    let if_returns_lhs = if if_value == lhs && else_value == rhs {
+        true
+    } else if if_value == rhs && else_value == lhs {
+        false
+    } else {
+        return None;
+    };
+
+    let (function_name, function_path, description) = if lhs_is_smaller == if_returns_lhs {
+        ("min", MIN_PATH, ManualMin.fix_message().unwrap())
+    } else {
+        ("max", MAX_PATH, ManualMax.fix_message().unwrap())
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &format!("{function_name}({lhs}, {rhs})")),
+        description: description.to_string(),
+        import_addition_paths: Some(vec![function_path.to_string()]),
+    })
+}
+
+/// Returns the text of the tail expression if it's the only element of the block.
+fn block_tail_text<'db>(db: &'db dyn Database, block: &ExprBlock<'db>) -> Option<String> {
+    let mut statements = block.statements(db).elements(db);
+    if statements.len() != 1 {
+        return None;
+    }
+    let AstStatement::Expr(statement_expr) = statements.next()? else {
+        return None;
+    };
+    Some(syntax_node_to_str_without_all_nested_trivia(
+        db,
+        statement_expr.expr(db).as_syntax_node(),
+    ))
+}
@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCallArg, ExprId};
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr, ExprBinary};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::is_side_effect_free_syntax;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::{MUL, function_trait_name_from_fn_id};
+use crate::queries::{get_all_function_bodies, syntax_node_to_str_without_all_nested_trivia};
+use crate::{LinterDiagnosticParams, LinterGroup};
+
+/// Default number of multiplied operands from which a multiplication chain is reported.
+pub const DEFAULT_MANUAL_POW_THRESHOLD: usize = 3;
+
+const POW_TRAIT_PATH: &str = "core::num::traits::Pow";
+
+pub struct ManualPow;
+
+/// ## What it does
+///
+/// Checks for chains of multiplications of the same value by itself, e.g. `x * x * x`, which are
+/// a manual implementation of raising the value to a power. The number of multiplied operands
+/// from which the lint fires can be changed with the `manual_pow_threshold` option.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32) -> u32 {
+///     x * x * x
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// use core::num::traits::Pow;
+///
+/// fn main(x: u32) -> u32 {
+///     x.pow(3)
+/// }
+/// ```
+impl Lint for ManualPow {
+    fn allowed_name(&self) -> &'static str {
+        "manual_pow"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0113"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Repeated multiplication of a value by itself detected. Consider using `pow` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualPow
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_pow(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the multiplications with `pow`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_pow<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        let multiplications: Vec<_> = arenas
+            .exprs
+            .iter()
+            .filter(|(_expression_id, expression)| is_multiplication(db, expression))
+            .collect();
+        // The operands being a part of a longer chain are checked together with the whole chain.
+        let chained_operands: HashSet<ExprId> = multiplications
+            .iter()
+            .flat_map(|(_expression_id, expression)| multiplication_operands(expression))
+            .filter(|operand| is_multiplication(db, &arenas.exprs[*operand]))
+            .collect();
+
+        for (expression_id, expression) in multiplications {
+            if chained_operands.contains(&expression_id) {
+                continue;
+            }
+            let mut operands = Vec::new();
+            collect_chain(db, arenas, expression_id, &mut operands);
+            if operands.len() < params.manual_pow_threshold
+                || !are_same_side_effect_free_operands(db, arenas, &operands)
+            {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: expression.stable_ptr().untyped(),
+                message: ManualPow.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+fn is_multiplication<'db>(db: &'db dyn Database, expr: &Expr<'db>) -> bool {
+    matches!(
+        expr,
+        Expr::FunctionCall(func_call)
+            if function_trait_name_from_fn_id(db, &func_call.function) == MUL
+    )
+}
+
+fn multiplication_operands(expr: &Expr) -> Vec<ExprId> {
+    let Expr::FunctionCall(func_call) = expr else {
+        return vec![];
+    };
+    func_call
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            ExprFunctionCallArg::Value(expr_id) => Some(*expr_id),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collects the operands of a chain of multiplications, from left to right.
+fn collect_chain<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+    operands: &mut Vec<ExprId>,
+) {
+    let expr = &arenas.exprs[expr_id];
+    if is_multiplication(db, expr) {
+        for operand in multiplication_operands(expr) {
+            collect_chain(db, arenas, operand, operands);
+        }
+    } else {
+        operands.push(expr_id);
+    }
+}
+
+/// Checks if all the operands are written the same way and reading them has no side effects.
+/// Literals are skipped, as multiplying them is computed at compile time anyway.
+fn are_same_side_effect_free_operands<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    operands: &[ExprId],
+) -> bool {
+    let mut operands = operands
+        .iter()
+        .map(|operand| arenas.exprs[*operand].stable_ptr().lookup(db));
+    let Some(first) = operands.next() else {
+        return false;
+    };
+    if matches!(first, AstExpr::Literal(_)) || !is_side_effect_free_syntax(db, &first) {
+        return false;
+    }
+    let first_text = first.as_syntax_node().get_text_without_trivia(db);
+    operands.all(|operand| operand.as_syntax_node().get_text_without_trivia(db) == first_text)
+}
+
+/// Rewrites the chain of multiplications into a `pow` call.
+///
+/// The fix is only available if the corelib in use defines `Pow`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_pow<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    db.corelib_context().get_pow_trait_function_id()?;
+    let binary = ExprBinary::cast(db, node)?;
+    let mut operands = Vec::new();
+    collect_syntax_chain(db, AstExpr::Binary(binary), &mut operands);
+    let operand = syntax_node_to_str_without_all_nested_trivia(db, operands[0].as_syntax_node());
+    let exponent = operands.len();
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &format!("{operand}.pow({exponent})")),
+        description: ManualPow.fix_message().unwrap().to_string(),
+        import_addition_paths: Some(vec![POW_TRAIT_PATH.to_string()]),
+    })
+}
+
+/// Collects the operands of a chain of `*` operators, also through parentheses, from left to right.
+fn collect_syntax_chain<'db>(
+    db: &'db dyn Database,
+    expr: AstExpr<'db>,
+    operands: &mut Vec<AstExpr<'db>>,
+) {
+    match &expr {
+        AstExpr::Binary(binary) if matches!(binary.op(db), BinaryOperator::Mul(_)) => {
+            collect_syntax_chain(db, binary.lhs(db), operands);
+            collect_syntax_chain(db, binary.rhs(db), operands);
+        }
+        AstExpr::Parenthesized(parenthesized)
+            if matches!(
+                parenthesized.expr(db),
+                AstExpr::Binary(binary) if matches!(binary.op(db), BinaryOperator::Mul(_))
+            ) =>
+        {
+            collect_syntax_chain(db, parenthesized.expr(db), operands);
+        }
+        _ => operands.push(expr),
+    }
+}
@@ -0,0 +1,180 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, ExprIf};
+use cairo_lang_syntax::node::TypedStablePtr;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::extreme_comparisons::{constant_value, integer_bounds};
+use crate::lints::{ADD, GE, GT, LE, LT, MUL, SUB, function_trait_name_from_fn_id};
+use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+
+pub struct ManualSaturating;
+
+/// ## What it does
+///
+/// Checks for `if` expressions guarding an arithmetic operation against the bounds of the integer
+/// type, returning the bound instead of the result when it would overflow.
+///
+/// ## Example
+///
+/// ```cairo
+/// use core::num::traits::Bounded;
+///
+/// fn main(a: u8, b: u8) -> u8 {
+///     if a > Bounded::MAX - b {
+///         Bounded::MAX
+///     } else {
+///         a + b
+///     }
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// use core::num::traits::SaturatingAdd;
+///
+/// fn main(a: u8, b: u8) -> u8 {
+///     a.saturating_add(b)
+/// }
+/// ```
+impl Lint for ManualSaturating {
+    fn allowed_name(&self) -> &'static str {
+        "manual_saturating"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0100"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual saturating arithmetic. Consider using the `SaturatingAdd`, `SaturatingSub` or `SaturatingMul` traits instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualSaturating
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_saturating<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for if_expr in get_all_if_expressions(function_body) {
+            if is_manual_saturating(db, &if_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: ManualSaturating.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if one of the branches is a bound of the integer type and the other one is an
+/// arithmetic operation, while the condition compares against a bound of the compared type.
+fn is_manual_saturating<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let [Condition::BoolExpr(condition)] = &if_expr.conditions[..] else {
+        return false;
+    };
+    let Some(else_block) = if_expr.else_block else {
+        return false;
+    };
+    let (Some(if_value), Some(else_value)) = (
+        block_tail(arenas, if_expr.if_block),
+        block_tail(arenas, else_block),
+    ) else {
+        return false;
+    };
+    let Some((min, max)) = integer_bounds(db, if_expr.ty) else {
+        return false;
+    };
+    let is_bound = |expr_id| {
+        constant_value(db, arenas, expr_id).is_some_and(|value| value == min || value == max)
+    };
+    let arithmetic = if is_bound(if_value) {
+        else_value
+    } else if is_bound(else_value) {
+        if_value
+    } else {
+        return false;
+    };
+    is_arithmetic(db, arenas, arithmetic) && is_comparison_with_bound(db, arenas, *condition)
+}
+
+/// Returns the tail expression of the block, if the block has no statements.
+fn block_tail(arenas: &Arenas, block_id: ExprId) -> Option<ExprId> {
+    match &arenas.exprs[block_id] {
+        Expr::Block(block) if block.statements.is_empty() => block.tail,
+        _ => None,
+    }
+}
+
+/// Checks if the expression is an addition, a subtraction or a multiplication.
+fn is_arithmetic(db: &dyn Database, arenas: &Arenas, expr_id: ExprId) -> bool {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[expr_id] else {
+        return false;
+    };
+    [ADD, SUB, MUL].contains(&function_trait_name_from_fn_id(db, &func_call.function).as_str())
+}
+
+/// Checks if the expression is a relational comparison where one of the operands is a bound of its
+/// type, or the bound offset by another value, e.g. `Bounded::MAX - b`.
+fn is_comparison_with_bound<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    condition: ExprId,
+) -> bool {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[condition] else {
+        return false;
+    };
+    if ![LT, GT, LE, GE].contains(&function_trait_name_from_fn_id(db, &func_call.function).as_str())
+    {
+        return false;
+    }
+    func_call.args.iter().any(|arg| match arg {
+        ExprFunctionCallArg::Value(operand) => involves_bound(db, arenas, *operand),
+        _ => false,
+    })
+}
+
+fn involves_bound<'db>(db: &'db dyn Database, arenas: &Arenas<'db>, expr_id: ExprId) -> bool {
+    let Some((min, max)) = integer_bounds(db, arenas.exprs[expr_id].ty()) else {
+        return false;
+    };
+    let is_bound = |expr_id: ExprId| {
+        constant_value(db, arenas, expr_id).is_some_and(|value| value == min || value == max)
+    };
+    match &arenas.exprs[expr_id] {
+        Expr::FunctionCall(func_call)
+            if [ADD, SUB]
+                .contains(&function_trait_name_from_fn_id(db, &func_call.function).as_str()) =>
+        {
+            func_call
+                .args
+                .iter()
+                .any(|arg| matches!(arg, ExprFunctionCallArg::Value(operand) if is_bound(*operand)))
+        }
+        _ => is_bound(expr_id),
+    }
+}
@@ -0,0 +1,338 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::{Arenas, Expr, Pattern, Statement, StatementId, VarId};
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, Expr as AstExpr, Param, Pattern as AstPattern, Statement as AstStatement,
+    StatementList,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::lints::self_assignment::is_same_place;
+use crate::queries::{get_all_function_bodies, syntax_node_to_str_without_all_nested_trivia};
+
+pub struct ManualSwap;
+
+/// ## What it does
+///
+/// Checks for two values swapped manually through a temporary variable.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let mut a = 1;
+///     let mut b = 2;
+///     let t = a;
+///     a = b;
+///     b = t;
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main() {
+///     let mut a = 1;
+///     let mut b = 2;
+///     let (mut a, mut b) = (b, a);
+/// }
+/// ```
+impl Lint for ManualSwap {
+    fn allowed_name(&self) -> &'static str {
+        "manual_swap"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0078"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual swap detected. Consider swapping the values with a tuple instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualSwap
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_manual_swap(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Swap the values with a tuple")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_swap<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for (_expression_id, expression) in arenas.exprs.iter() {
+            let Expr::Block(block) = expression else {
+                continue;
+            };
+            for statements in block.statements.windows(3) {
+                let [let_statement, first_assignment, second_assignment] = statements else {
+                    continue;
+                };
+                if !is_manual_swap(
+                    arenas,
+                    *let_statement,
+                    *first_assignment,
+                    *second_assignment,
+                ) {
+                    continue;
+                }
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: arenas.statements[*let_statement].stable_ptr().untyped(),
+                    message: ManualSwap.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the statements are `let t = a; a = b; b = t;`, where `a` and `b` are distinct
+/// variables or member paths, and `t` isn't used anywhere else.
+fn is_manual_swap(
+    arenas: &Arenas,
+    let_statement: StatementId,
+    first_assignment: StatementId,
+    second_assignment: StatementId,
+) -> bool {
+    let Statement::Let(let_statement) = &arenas.statements[let_statement] else {
+        return false;
+    };
+    let Pattern::Variable(temporary) = &arenas.patterns[let_statement.pattern] else {
+        return false;
+    };
+    if temporary.var.is_mut {
+        return false;
+    }
+    let temporary = VarId::Local(temporary.var.id);
+
+    let (Statement::Expr(first_assignment), Statement::Expr(second_assignment)) = (
+        &arenas.statements[first_assignment],
+        &arenas.statements[second_assignment],
+    ) else {
+        return false;
+    };
+    let (Expr::Assignment(first_assignment), Expr::Assignment(second_assignment)) = (
+        &arenas.exprs[first_assignment.expr],
+        &arenas.exprs[second_assignment.expr],
+    ) else {
+        return false;
+    };
+
+    let first = &arenas.exprs[let_statement.expr];
+    let second = &arenas.exprs[first_assignment.rhs];
+    let reads_temporary =
+        matches!(&arenas.exprs[second_assignment.rhs], Expr::Var(var) if var.var == temporary);
+
+    is_same_place(&first_assignment.ref_arg, first, arenas)
+        && is_same_place(&second_assignment.ref_arg, second, arenas)
+        && !is_same_place(&first_assignment.ref_arg, second, arenas)
+        && reads_temporary
+        && arenas
+            .exprs
+            .iter()
+            .filter(|(_expression_id, expression)| {
+                matches!(expression, Expr::Var(var) if var.var == temporary)
+            })
+            .count()
+            == 1
+}
+
+/// Replaces the swap with a tuple destructuring `let (a, b) = (b, a);`.
+///
+/// The variables are rebound by the `let`, so the fix is only available when both of them are
+/// plain variables declared in the same block as the swap (or parameters of the function whose
+/// body it is). Member paths can't be rebound this way.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_manual_swap<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    if node.kind(db) != SyntaxKind::StatementLet {
+        return None;
+    }
+    let statement_list_node = node.parent(db)?;
+    let statement_list = StatementList::cast(db, statement_list_node)?;
+    let statements = statement_list.elements(db).collect::<Vec<_>>();
+    let index = statements
+        .iter()
+        .position(|statement| statement.as_syntax_node() == node)?;
+    let [first_assignment, second_assignment] = statements.get(index + 1..index + 3)? else {
+        return None;
+    };
+
+    let (AstStatement::Expr(first_assignment), AstStatement::Expr(second_assignment)) =
+        (first_assignment, second_assignment)
+    else {
+        return None;
+    };
+    // Comments between the statements would be lost.
+    if [
+        first_assignment.as_syntax_node(),
+        second_assignment.as_syntax_node(),
+    ]
+    .iter()
+    .any(|statement| leading_trivia(db, *statement).contains("//"))
+    {
+        return None;
+    }
+    let AstExpr::Binary(assignment) = first_assignment.expr(db) else {
+        return None;
+    };
+    if !matches!(assignment.op(db), BinaryOperator::Eq(_)) {
+        return None;
+    }
+
+    let first = variable_name(db, &assignment.lhs(db))?;
+    let second = variable_name(db, &assignment.rhs(db))?;
+    let first_modifiers = binding_modifiers(db, statement_list_node, &statements[..index], &first)?;
+    let second_modifiers =
+        binding_modifiers(db, statement_list_node, &statements[..index], &second)?;
+
+    let second_assignment_node = second_assignment.as_syntax_node();
+    let swap = format!(
+        "{}let ({first_modifiers}{first}, {second_modifiers}{second}) = ({second}, {first});{}",
+        leading_trivia(db, node),
+        second_assignment_node.get_text_of_span(
+            db,
+            TextSpan {
+                start: second_assignment_node.span_without_trivia(db).end,
+                end: second_assignment_node.span(db).end,
+            },
+        ),
+    );
+
+    let suggestion = statements[..index]
+        .iter()
+        .map(|statement| statement.as_syntax_node().get_text(db).to_string())
+        .chain([swap])
+        .chain(
+            statements[index + 3..]
+                .iter()
+                .map(|statement| statement.as_syntax_node().get_text(db).to_string()),
+        )
+        .collect::<String>();
+
+    Some(InternalFix {
+        node: statement_list_node,
+        suggestion,
+        description: ManualSwap.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+fn leading_trivia<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> String {
+    node.get_text_of_span(
+        db,
+        TextSpan {
+            start: node.span(db).start,
+            end: node.span_without_trivia(db).start,
+        },
+    )
+    .to_string()
+}
+
+/// Returns the name of the variable if the expression is a single identifier.
+fn variable_name<'db>(db: &'db dyn Database, expr: &AstExpr<'db>) -> Option<String> {
+    let AstExpr::Path(path) = expr else {
+        return None;
+    };
+    let name = syntax_node_to_str_without_all_nested_trivia(db, path.as_syntax_node());
+    name.chars()
+        .all(|c| c.is_alphanumeric() || c == '_')
+        .then_some(name)
+}
+
+/// Returns the modifiers (e.g. `mut `) to rebind the variable with, if it's declared by one of the
+/// preceding statements of the block or is a parameter of the function the block is the body of.
+fn binding_modifiers<'db>(
+    db: &'db dyn Database,
+    statement_list: SyntaxNode<'db>,
+    preceding_statements: &[AstStatement<'db>],
+    name: &str,
+) -> Option<String> {
+    let let_binding = preceding_statements.iter().rev().find_map(|statement| {
+        let AstStatement::Let(let_statement) = statement else {
+            return None;
+        };
+        let AstPattern::Identifier(identifier) = let_statement.pattern(db) else {
+            return None;
+        };
+        let binding_name =
+            syntax_node_to_str_without_all_nested_trivia(db, identifier.name(db).as_syntax_node());
+        (binding_name == name).then(|| {
+            syntax_node_to_str_without_all_nested_trivia(
+                db,
+                identifier.modifiers(db).as_syntax_node(),
+            )
+        })
+    });
+
+    let modifiers = match let_binding {
+        Some(modifiers) => modifiers,
+        None => function_parameter_modifiers(db, statement_list, name)?,
+    };
+    match modifiers.as_str() {
+        "" => Some(String::new()),
+        "mut" => Some("mut ".to_string()),
+        // Rebinding a `ref` parameter wouldn't update the caller's value.
+        _ => None,
+    }
+}
+
+fn function_parameter_modifiers<'db>(
+    db: &'db dyn Database,
+    statement_list: SyntaxNode<'db>,
+    name: &str,
+) -> Option<String> {
+    let block = statement_list.parent(db)?;
+    let function = block.parent(db)?;
+    if block.kind(db) != SyntaxKind::ExprBlock
+        || !matches!(
+            function.kind(db),
+            SyntaxKind::FunctionWithBody | SyntaxKind::TraitItemFunction
+        )
+    {
+        return None;
+    }
+
+    function
+        .descendants(db)
+        .filter_map(|node| Param::cast(db, node))
+        .find(|param| {
+            syntax_node_to_str_without_all_nested_trivia(db, param.name(db).as_syntax_node())
+                == name
+        })
+        .map(|param| {
+            syntax_node_to_str_without_all_nested_trivia(db, param.modifiers(db).as_syntax_node())
+        })
+}
@@ -0,0 +1,183 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprId, ExprVarMemberPath, MatchArm, Pattern, Statement, StatementId, VarId,
+};
+use cairo_lang_syntax::node::TypedStablePtr;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::LinterGroup;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::NONE;
+use crate::queries::get_all_function_bodies;
+
+pub struct ManualTake;
+
+/// ## What it does
+///
+/// Checks for the value of an `Option` variable being moved out of it, followed by setting the
+/// variable to `None`, which is what `take` does.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let mut option = Option::Some(1_u32);
+///     let value = option;
+///     option = Option::None;
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main() {
+///     let mut option = Option::Some(1_u32);
+///     let value = option.take();
+/// }
+/// ```
+impl Lint for ManualTake {
+    fn allowed_name(&self) -> &'static str {
+        "manual_take"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0105"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Manual `take` of an `Option` detected. Consider using `take()` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualTake
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_take<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    // The suggestion is only valid if the corelib in use defines `OptionTrait::take`.
+    if db
+        .corelib_context()
+        .get_option_take_trait_function_id()
+        .is_none()
+    {
+        return;
+    }
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for (_expression_id, expression) in arenas.exprs.iter() {
+            let Expr::Block(block) = expression else {
+                continue;
+            };
+            for statements in block.statements.windows(2) {
+                let [let_statement, assignment] = statements else {
+                    continue;
+                };
+                if !is_manual_take(db, arenas, *let_statement, *assignment) {
+                    continue;
+                }
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: arenas.statements[*let_statement].stable_ptr().untyped(),
+                    message: ManualTake.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the statements are `let value = option; option = Option::None;`, where `option` is a
+/// mutable local variable and `value` is a simple binding.
+fn is_manual_take<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    let_statement: StatementId,
+    assignment: StatementId,
+) -> bool {
+    let Statement::Let(let_statement) = &arenas.statements[let_statement] else {
+        return false;
+    };
+    if !matches!(arenas.patterns[let_statement.pattern], Pattern::Variable(_)) {
+        return false;
+    }
+    let Statement::Expr(assignment) = &arenas.statements[assignment] else {
+        return false;
+    };
+    let Expr::Assignment(assignment) = &arenas.exprs[assignment.expr] else {
+        return false;
+    };
+    let ExprVarMemberPath::Var(option) = &assignment.ref_arg else {
+        return false;
+    };
+    let Expr::EnumVariantCtor(variant_ctor) = &arenas.exprs[assignment.rhs] else {
+        return false;
+    };
+
+    variant_ctor.variant.id.full_path(db) == NONE
+        && is_mutable_local(arenas, option.var)
+        && reads_option(arenas, let_statement.expr, option.var)
+}
+
+fn is_mutable_local(arenas: &Arenas, var: VarId) -> bool {
+    let VarId::Local(local_var_id) = var else {
+        return false;
+    };
+    arenas.patterns.iter().any(|(_pattern_id, pattern)| {
+        matches!(pattern, Pattern::Variable(binding) if binding.var.id == local_var_id && binding.var.is_mut)
+    })
+}
+
+/// Checks if the expression is the variable itself, or a `match` on it which returns the matched
+/// variant unchanged.
+fn reads_option(arenas: &Arenas, expr_id: ExprId, option: VarId) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Var(var) => var.var == option,
+        Expr::Match(match_expr) => {
+            matches!(&arenas.exprs[match_expr.matched_expr], Expr::Var(var) if var.var == option)
+                && match_expr
+                    .arms
+                    .iter()
+                    .all(|arm| is_identity_arm(arenas, arm))
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the arm is `Option::Some(x) => Option::Some(x)` or `Option::None => Option::None`.
+fn is_identity_arm(arenas: &Arenas, arm: &MatchArm) -> bool {
+    let [pattern] = arm.patterns[..] else {
+        return false;
+    };
+    let Pattern::EnumVariant(pattern) = &arenas.patterns[pattern] else {
+        return false;
+    };
+    let Expr::EnumVariantCtor(variant_ctor) = &arenas.exprs[arm.expression] else {
+        return false;
+    };
+    if pattern.variant != variant_ctor.variant {
+        return false;
+    }
+    match pattern.inner_pattern.map(|inner| &arenas.patterns[inner]) {
+        None => true,
+        Some(Pattern::Variable(binding)) => matches!(
+            &arenas.exprs[variant_ctor.value_expr],
+            Expr::Var(var) if var.var == VarId::Local(binding.var.id)
+        ),
+        _ => false,
+    }
+}
@@ -0,0 +1,188 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, ExprMatch, Pattern};
+use cairo_lang_syntax::node::ast::{
+    Expr as AstExpr, ExprMatch as AstExprMatch, Pattern as AstPattern,
+};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::{FALSE, TRUE};
+use crate::queries::{get_all_function_bodies, get_all_match_expressions};
+
+pub struct MatchBool;
+
+/// ## What it does
+///
+/// Checks for matches on a `bool` with a `true` and a `false` arm.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(flag: bool) -> u32 {
+///     match flag {
+///         true => 1,
+///         false => 0,
+///     }
+/// }
+/// ```
+///
+/// Can be rewritten as:
+///
+/// ```cairo
+/// fn main(flag: bool) -> u32 {
+///     if flag {
+///         1
+///     } else {
+///         0
+///     }
+/// }
+/// ```
+impl Lint for MatchBool {
+    fn allowed_name(&self) -> &'static str {
+        "match_bool"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0088"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Matching on a `bool`. Consider using an `if`/`else` expression instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::MatchBool
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_match_bool(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the `match` with an `if`/`else` expression")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_match_bool<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for match_expr in get_all_match_expressions(function_body) {
+            if is_match_bool(db, &match_expr, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: match_expr.stable_ptr.untyped(),
+                    message: MatchBool.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the match has exactly two arms, one matching `true` and the other matching `false`.
+fn is_match_bool<'db>(
+    db: &'db dyn Database,
+    match_expr: &ExprMatch<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    let variant_names = match_expr
+        .arms
+        .iter()
+        .map(|arm| {
+            let [pattern] = arm.patterns[..] else {
+                return None;
+            };
+            match &arenas.patterns[pattern] {
+                Pattern::EnumVariant(enum_pattern) if enum_pattern.inner_pattern.is_none() => {
+                    Some(enum_pattern.variant.id.full_path(db))
+                }
+                _ => None,
+            }
+        })
+        .collect::<Option<Vec<_>>>();
+    matches!(
+        variant_names.as_deref(),
+        Some([first, second])
+            if (first == TRUE && second == FALSE) || (first == FALSE && second == TRUE)
+    )
+}
+
+/// Rewrites `match b { true => x, false => y }` to `if b { x } else { y }`, whatever the order of
+/// the arms is.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_match_bool<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let match_expr = AstExprMatch::cast(db, node)?;
+    let mut true_arm = None;
+    let mut false_arm = None;
+    for arm in match_expr.arms(db).elements(db) {
+        let mut patterns = arm.patterns(db).elements(db);
+        let (Some(pattern), None) = (patterns.next(), patterns.next()) else {
+            return None;
+        };
+        match pattern {
+            AstPattern::True(_) => true_arm = Some(arm.expression(db)),
+            AstPattern::False(_) => false_arm = Some(arm.expression(db)),
+            _ => return None,
+        }
+    }
+
+    let condition = match_expr
+        .expr(db)
+        .as_syntax_node()
+        .get_text_without_trivia(db)
+        .long(db)
+        .to_string();
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            &format!(
+                "if {condition} {} else {}",
+                arm_block(db, true_arm?),
+                arm_block(db, false_arm?)
+            ),
+        ),
+        description: MatchBool.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Returns the arm expression as a block, wrapping it in braces unless it's a block already.
+fn arm_block<'db>(db: &'db dyn Database, expression: AstExpr<'db>) -> String {
+    let text = expression
+        .as_syntax_node()
+        .get_text_without_trivia(db)
+        .long(db)
+        .to_string();
+    match expression {
+        AstExpr::Block(_) => text,
+        _ => format!("{{ {text} }}"),
+    }
+}
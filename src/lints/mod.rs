@@ -4,30 +4,65 @@ use cairo_lang_semantic::items::imp::ImplSemantic;
 use salsa::Database;
 
 pub mod assert_on_const;
+pub mod assign_op_pattern;
 pub mod bitwise_for_parity_check;
 pub mod bool_comparison;
 pub mod breaks;
 pub mod clone_on_copy;
 pub mod collapsible_match;
+pub mod comparison_chain;
 pub mod double_comparison;
+pub mod double_negation;
 pub mod double_parens;
 pub mod duplicate_underscore_args;
 pub mod empty_enum_brackets_variant;
+pub mod empty_match;
+pub mod empty_string_comparison;
 pub mod enum_variant_names;
 pub mod eq_op;
 pub mod erasing_op;
+pub mod excessive_nesting;
+pub mod extreme_comparisons;
+pub mod format_args;
 pub mod ifs;
 pub mod int_op_one;
+pub mod large_enum_variant;
+pub mod len_zero;
+pub mod let_and_return;
 pub mod loops;
 pub mod manual;
+pub mod manual_clamp;
+pub mod manual_div_ceil;
+pub mod manual_min_max;
+pub mod manual_pow;
+pub mod manual_saturating;
+pub mod manual_swap;
+pub mod manual_take;
+pub mod match_bool;
+pub mod modulo_one;
+pub mod needless_question_mark;
+pub mod needless_return;
+pub mod needless_semicolon;
 pub mod panic;
 pub mod performance;
+pub mod redundant_block;
 pub mod redundant_brackets_in_enum_call;
 pub mod redundant_into;
 pub mod redundant_op;
+pub mod self_assignment;
 pub mod single_match;
+pub mod struct_reassign;
+pub mod suspicious_comparison;
+pub mod too_many_arguments;
 pub mod unit_return_type;
+pub mod unnecessary_array;
+pub mod unnecessary_cast_chain;
+pub mod unsigned_comparison;
+pub mod unused_allow;
+pub mod unused_variant;
 pub mod unwrap_syscall;
+pub mod wildcard_import;
+pub mod zero_division;
 
 pub(crate) const LE: &str = "core::traits::PartialOrd::le";
 pub(crate) const GE: &str = "core::traits::PartialOrd::ge";
@@ -39,10 +74,13 @@ pub(crate) const AND: &str = "core::traits::BitAnd::bitand";
 pub(crate) const OR: &str = "core::traits::BitOr::bitor";
 pub(crate) const XOR: &str = "core::traits::BitXor::bitxor";
 pub(crate) const NOT: &str = "core::traits::BitNot::bitnot";
+pub(crate) const BOOL_NOT: &str = "core::traits::Not::not";
 pub(crate) const DIV: &str = "core::traits::Div::div";
 pub(crate) const MUL: &str = "core::traits::Mul::mul";
 pub(crate) const ADD: &str = "core::traits::Add::add";
+pub(crate) const ADD_ASSIGN: &str = "core::ops::arith::AddAssign::add_assign";
 pub(crate) const SUB: &str = "core::traits::Sub::sub";
+pub(crate) const REM: &str = "core::traits::Rem::rem";
 pub(crate) const OK: &str = "core::result::Result::Ok";
 pub(crate) const ERR: &str = "core::result::Result::Err";
 pub(crate) const SOME: &str = "core::option::Option::Some";
@@ -51,11 +89,38 @@ pub(crate) const TRUE: &str = "core::bool::True";
 pub(crate) const FALSE: &str = "core::bool::False";
 pub(crate) const PANIC_WITH_FELT252: &str = "core::panic_with_felt252";
 pub(crate) const DEFAULT: &str = "core::traits::Default::default";
+pub(crate) const TRY_INTO: &str = "core::traits::TryInto::try_into";
+pub(crate) const OPTION_UNWRAP: &str = "core::option::OptionTrait::unwrap";
+pub(crate) const OPTION_OK_OR: &str = "core::option::OptionTrait::ok_or";
+pub(crate) const RESULT_UNWRAP_OR_DEFAULT: &str = "core::result::ResultTrait::unwrap_or_default";
 pub(crate) const ARRAY_NEW: &str = "core::array::ArrayTrait::new";
+pub(crate) const ARRAY_APPEND: &str = "core::array::ArrayTrait::append";
 pub(crate) const NEVER: &str = "core::never";
+pub(crate) const BOOL: &str = "core::bool";
+pub(crate) const OPTION: &str = "core::option::Option";
+pub(crate) const RESULT: &str = "core::result::Result";
 pub(crate) const SPAN: &str = "core::array::Span";
 pub(crate) const ARRAY: &str = "core::array::Array";
+pub(crate) const BYTE_ARRAY: &str = "core::byte_array::ByteArray";
 pub(crate) const U32: &str = "core::integer::u32";
+pub(crate) const FELT252: &str = "core::felt252";
+/// Unsigned integer types of the corelib `integer` module along with their sizes in bits.
+pub(crate) const UNSIGNED_INTEGER_TYPES: [(&str, u32); 6] = [
+    ("core::integer::u8", 8),
+    ("core::integer::u16", 16),
+    ("core::integer::u32", 32),
+    ("core::integer::u64", 64),
+    ("core::integer::u128", 128),
+    ("core::integer::u256", 256),
+];
+/// Signed integer types of the corelib `integer` module along with their sizes in bits.
+pub(crate) const SIGNED_INTEGER_TYPES: [(&str, u32); 5] = [
+    ("core::integer::i8", 8),
+    ("core::integer::i16", 16),
+    ("core::integer::i32", 32),
+    ("core::integer::i64", 64),
+    ("core::integer::i128", 128),
+];
 
 pub(crate) fn function_trait_name_from_fn_id<'db>(
     db: &'db dyn Database,
@@ -0,0 +1,175 @@
+use cairo_lang_defs::ids::{LookupItemId, ModuleId, ModuleItemId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::function_with_body::{
+    FunctionWithBodySemantic, SemanticExprLookup,
+};
+use cairo_lang_semantic::items::imp::ImplHead;
+use cairo_lang_semantic::{Expr, ExprFunctionCall, GenericFunctionId};
+use cairo_lang_syntax::node::ast::{self, OptionTypeClause};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::LinterGroup;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{
+    find_module_containing_node, get_function_with_body_id, is_item_ancestor_of_module, is_one,
+};
+use crate::queries::{get_all_function_bodies, get_all_function_calls};
+
+pub struct ModuloOne;
+
+/// ## What it does
+///
+/// Checks for the remainder of an integer division by 1, which is always 0.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() {
+///     let x: u32 = 5;
+///     let _y = x % 1;
+/// }
+/// ```
+///
+/// Can be replaced with:
+///
+/// ```cairo
+/// fn main() {
+///     let x: u32 = 5;
+///     let _y = 0_u32;
+/// }
+/// ```
+impl Lint for ModuloOne {
+    fn allowed_name(&self) -> &'static str {
+        "modulo_one"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0073"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Any integer modulo 1 is 0. Consider replacing the expression with 0."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ModuloOne
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_modulo_one(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the expression with 0")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_modulo_one<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let function_call_exprs = get_all_function_calls(function_body);
+        let arenas = &function_body.arenas;
+        for function_call_expr in function_call_exprs {
+            if is_modulo_one(db, &function_call_expr) && is_one(&function_call_expr.args[1], arenas)
+            {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: function_call_expr.stable_ptr.untyped(),
+                    message: ModuloOne.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the function call is the `%` operator of one of the corelib integer types.
+fn is_modulo_one<'db>(db: &'db dyn Database, function_call_expr: &ExprFunctionCall<'db>) -> bool {
+    let GenericFunctionId::Impl(impl_generic_func_id) = function_call_expr
+        .function
+        .get_concrete(db)
+        .generic_function
+    else {
+        return false;
+    };
+    let corelib_context = db.corelib_context();
+    let (Some(rem_trait_function_id), Some(integer_module_id)) = (
+        corelib_context.get_rem_trait_function_id(),
+        corelib_context.get_integer_module_id(),
+    ) else {
+        return false;
+    };
+    if impl_generic_func_id.function != rem_trait_function_id || function_call_expr.args.len() != 2
+    {
+        return false;
+    }
+
+    // The `Rem` trait can be implemented for user types as well, where `% 1` can mean anything.
+    let Some(ImplHead::Concrete(impl_def_id)) = impl_generic_func_id.impl_id.head(db) else {
+        return false;
+    };
+    is_item_ancestor_of_module(
+        db,
+        &LookupItemId::ModuleItem(ModuleItemId::Impl(impl_def_id)),
+        ModuleId::Submodule(integer_module_id),
+    )
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_modulo_one<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    // A variable bound without a type annotation would otherwise be inferred as `felt252`.
+    let needs_type_suffix = node
+        .parent(db)
+        .and_then(|parent| ast::StatementLet::cast(db, parent))
+        .is_some_and(|statement_let| {
+            matches!(statement_let.type_clause(db), OptionTypeClause::Empty(_))
+        });
+
+    let suggestion = if needs_type_suffix {
+        let module_id = find_module_containing_node(db, node)?;
+        let (function_id, expr_id) = node.ancestors(db).find_map(|ancestor| {
+            let function_id = get_function_with_body_id(db, module_id, ancestor)?;
+            let expr_id = db
+                .lookup_expr_by_ptr(function_id, ast::ExprPtr(node.stable_ptr(db)))
+                .ok()?;
+            Some((function_id, expr_id))
+        })?;
+        let Expr::FunctionCall(function_call_expr) = db.expr_semantic(function_id, expr_id) else {
+            return None;
+        };
+        let type_name = function_call_expr.ty.format(db);
+        let type_name = type_name.rsplit("::").next()?;
+        format!("0_{type_name}")
+    } else {
+        "0".to_string()
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion,
+        description: ModuloOne.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -0,0 +1,177 @@
+use cairo_lang_defs::ids::{ModuleItemId, TopLevelLanguageElementId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprId, Statement};
+use cairo_lang_syntax::node::ast::{ArgClause, Expr as AstExpr, ExprFunctionCall};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::{OK, SOME};
+use crate::queries::{get_all_function_bodies, syntax_node_to_str_without_all_nested_trivia};
+
+pub struct NeedlessQuestionMark;
+
+/// ## What it does
+///
+/// Checks for `Option::Some(x?)` and `Result::Ok(x?)` returned from a function, where `x` is
+/// already of the returned type, so `x` can be returned directly.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(value: Option<u32>) -> Option<u32> {
+///     Option::Some(value?)
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(value: Option<u32>) -> Option<u32> {
+///     value
+/// }
+/// ```
+impl Lint for NeedlessQuestionMark {
+    fn allowed_name(&self) -> &'static str {
+        "needless_question_mark"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0111"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Needless `?` wrapped in the same variant it propagates. Consider returning the inner expression directly."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::NeedlessQuestionMark
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_needless_question_mark(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Remove the wrapping variant and the `?`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_needless_question_mark<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        let mut returned_exprs = Vec::new();
+        collect_tail_exprs(arenas, function_body.body_expr, &mut returned_exprs);
+        for (_statement_id, statement) in arenas.statements.iter() {
+            if let Statement::Return(return_statement) = statement
+                && let Some(expr) = return_statement.expr_option
+            {
+                collect_tail_exprs(arenas, expr, &mut returned_exprs);
+            }
+        }
+
+        for expr_id in returned_exprs {
+            if !is_needless_question_mark(db, arenas, expr_id) {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: arenas.exprs[expr_id].stable_ptr().untyped(),
+                message: NeedlessQuestionMark.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Collects the expressions whose value is the value of the given expression, descending into the
+/// tails of blocks and the branches of `if` and `match` expressions.
+fn collect_tail_exprs(arenas: &Arenas, expr_id: ExprId, tail_exprs: &mut Vec<ExprId>) {
+    match &arenas.exprs[expr_id] {
+        Expr::Block(block) => {
+            if let Some(tail) = block.tail {
+                collect_tail_exprs(arenas, tail, tail_exprs);
+            }
+        }
+        Expr::If(if_expr) => {
+            collect_tail_exprs(arenas, if_expr.if_block, tail_exprs);
+            if let Some(else_block) = if_expr.else_block {
+                collect_tail_exprs(arenas, else_block, tail_exprs);
+            }
+        }
+        Expr::Match(match_expr) => {
+            for arm in &match_expr.arms {
+                collect_tail_exprs(arenas, arm.expression, tail_exprs);
+            }
+        }
+        _ => tail_exprs.push(expr_id),
+    }
+}
+
+/// Checks if the expression is `Option::Some(x?)` or `Result::Ok(x?)`, where `x` is of the same
+/// type as the whole expression.
+fn is_needless_question_mark<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+) -> bool {
+    let Expr::EnumVariantCtor(variant_ctor) = &arenas.exprs[expr_id] else {
+        return false;
+    };
+    let variant_path = variant_ctor.variant.id.full_path(db);
+    if variant_path != SOME && variant_path != OK {
+        return false;
+    }
+    let Expr::PropagateError(propagate_error) = &arenas.exprs[variant_ctor.value_expr] else {
+        return false;
+    };
+    propagate_error.ok_variant.id == variant_ctor.variant.id
+        && arenas.exprs[propagate_error.inner].ty() == variant_ctor.ty
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_needless_question_mark<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let function_call = ExprFunctionCall::cast(db, node)?;
+    let mut arguments = function_call.arguments(db).arguments(db).elements(db);
+    if arguments.len() != 1 {
+        return None;
+    }
+    let ArgClause::Unnamed(argument) = arguments.next()?.arg_clause(db) else {
+        return None;
+    };
+    let AstExpr::ErrorPropagate(error_propagate) = argument.value(db) else {
+        return None;
+    };
+    let inner_expr =
+        syntax_node_to_str_without_all_nested_trivia(db, error_propagate.expr(db).as_syntax_node());
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &inner_expr),
+        description: NeedlessQuestionMark.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -0,0 +1,134 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Expr, Statement};
+use cairo_lang_syntax::node::ast::{OptionExprClause, StatementReturn};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{leading_trivia, replace_preserving_trivia};
+use crate::queries::get_all_function_bodies;
+
+pub struct NeedlessReturn;
+
+/// ## What it does
+///
+/// Checks for a `return` statement at the end of a function body, where the tail expression
+/// would evaluate to the same value.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32) -> u32 {
+///     let y = x * 2;
+///     return y + 1;
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(x: u32) -> u32 {
+///     let y = x * 2;
+///     y + 1
+/// }
+/// ```
+impl Lint for NeedlessReturn {
+    fn allowed_name(&self) -> &'static str {
+        "needless_return"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0081"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Unnecessary `return` at the end of the function body. Use a tail expression instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::NeedlessReturn
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_needless_return(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the `return` statement with a tail expression")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_needless_return<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        // Only the function body block is checked, as a `return` at the end of a nested block
+        // (e.g. an `if` branch) is an early return.
+        let Expr::Block(body) = &arenas.exprs[function_body.body_expr] else {
+            continue;
+        };
+        if body.tail.is_some() {
+            continue;
+        }
+        let Some(Statement::Return(return_statement)) = body
+            .statements
+            .last()
+            .map(|statement| &arenas.statements[*statement])
+        else {
+            continue;
+        };
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: return_statement.stable_ptr.untyped(),
+            message: NeedlessReturn.diagnostic_message().to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
+
+/// Turns `return expr;` into the tail expression `expr`, and removes `return;` altogether.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_needless_return<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let return_statement = StatementReturn::cast(db, node)?;
+
+    let suggestion = match return_statement.expr_clause(db) {
+        OptionExprClause::ExprClause(expr_clause) => {
+            let expr = expr_clause.expr(db).as_syntax_node();
+            let expr_text = expr.get_text_without_trivia(db);
+            replace_preserving_trivia(db, node, expr_text.long(db))
+        }
+        // Only the comments preceding the statement are kept, so no empty line is left behind.
+        OptionExprClause::Empty(_) => leading_trivia(db, node)
+            .trim_end_matches([' ', '\t'])
+            .to_string(),
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion,
+        description: NeedlessReturn.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -0,0 +1,189 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::{Expr, Statement};
+use cairo_lang_syntax::node::ast::{
+    OptionTerminalSemicolon, Statement as AstStatement, StatementExpr, StatementList,
+};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::queries::get_all_function_bodies;
+
+pub struct NeedlessSemicolon;
+
+/// ## What it does
+///
+/// Checks for semicolons after block-like expressions, i.e. `if`, `match`, `loop`, `while`, `for`
+/// and blocks, used as statements, when the expression evaluates to the unit type anyway.
+///
+/// The lint is disabled by default, as the semicolons are harmless and commonly used.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32) {
+///     if x > 10 {
+///         println!("big");
+///     };
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(x: u32) {
+///     if x > 10 {
+///         println!("big");
+///     }
+/// }
+/// ```
+impl Lint for NeedlessSemicolon {
+    fn allowed_name(&self) -> &'static str {
+        "needless_semicolon"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0114"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Unnecessary semicolon after a block-like expression of the unit type."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::NeedlessSemicolon
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_needless_semicolon(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Remove the semicolon")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_needless_semicolon<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for (_statement_id, statement) in arenas.statements.iter() {
+            let Statement::Expr(statement_expr) = statement else {
+                continue;
+            };
+            let expr = &arenas.exprs[statement_expr.expr];
+            // A semicolon is needed to discard a value of any other type.
+            if !is_block_like(expr) || !expr.ty().is_unit(db) {
+                continue;
+            }
+            let AstStatement::Expr(ast_statement) = statement_expr.stable_ptr.lookup(db) else {
+                continue;
+            };
+            if matches!(
+                ast_statement.semicolon(db),
+                OptionTerminalSemicolon::Empty(_)
+            ) || is_semicolon_required(db, &ast_statement)
+            {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: statement_expr.stable_ptr.untyped(),
+                message: NeedlessSemicolon.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+fn is_block_like(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::If(_)
+            | Expr::Match(_)
+            | Expr::Loop(_)
+            | Expr::While(_)
+            | Expr::For(_)
+            | Expr::Block(_)
+    )
+}
+
+/// Checks if the statement following the given one starts with a token that would continue the
+/// expression if the semicolon was removed, e.g. `-x` or `(a, b)`.
+fn is_semicolon_required<'db>(db: &'db dyn Database, statement: &StatementExpr<'db>) -> bool {
+    let node = statement.as_syntax_node();
+    let Some(statement_list) = node
+        .parent(db)
+        .and_then(|parent| StatementList::cast(db, parent))
+    else {
+        return true;
+    };
+    let mut statements = statement_list
+        .elements(db)
+        .skip_while(|statement| statement.as_syntax_node() != node);
+    statements.next();
+    let Some(next_statement) = statements.next() else {
+        return false;
+    };
+    let text = next_statement.as_syntax_node().get_text_without_trivia(db);
+    text.long(db).starts_with(['-', '*', '(', '[', '&', '|'])
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_needless_semicolon<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let statement = StatementExpr::cast(db, node)?;
+    let OptionTerminalSemicolon::TerminalSemicolon(semicolon) = statement.semicolon(db) else {
+        return None;
+    };
+
+    let span = node.span(db);
+    let semicolon_span = semicolon.as_syntax_node().span_without_trivia(db);
+    let before_semicolon = node.get_text_of_span(
+        db,
+        TextSpan {
+            start: span.start,
+            end: semicolon_span.start,
+        },
+    );
+    let after_semicolon = node.get_text_of_span(
+        db,
+        TextSpan {
+            start: semicolon_span.end,
+            end: span.end,
+        },
+    );
+
+    Some(InternalFix {
+        node,
+        suggestion: format!("{before_semicolon}{after_semicolon}"),
+        description: NeedlessSemicolon.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -6,23 +6,29 @@ use cairo_lang_filesystem::db::get_originating_location;
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_semantic::ExprFunctionCall;
 use cairo_lang_semantic::items::functions::GenericFunctionId;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 use itertools::Itertools;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::LinterGroup;
 use crate::helper::ASSERT_FORMATTER_NAME;
+use crate::lang::node_has_ascendants_with_attr;
 use crate::queries::{get_all_function_bodies, get_all_function_calls};
 use cairo_lang_filesystem::ids::SpanInFile;
 use salsa::Database;
 
+/// Attributes, with their optional arguments, marking the code compiled for tests only.
+const TEST_ATTRIBUTES: [(&str, Option<&str>); 2] = [("test", None), ("cfg", Some("test"))];
+
 pub struct PanicInCode;
 
 /// ## What it does
 ///
-/// Checks for panic usages.
+/// Checks for panic usages. Panics in the test code, i.e. in the items marked with `#[test]` or
+/// `#[cfg(test)]`, are skipped unless the `allow_panic_in_tests` option is disabled.
 ///
 /// ## Example
 ///
@@ -36,6 +42,10 @@ impl Lint for PanicInCode {
         "panic"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0018"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Leaving `panic` in the code is discouraged."
     }
@@ -44,6 +54,10 @@ impl Lint for PanicInCode {
         CairoLintKind::Panic
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
     fn is_enabled(&self) -> bool {
         false
     }
@@ -53,13 +67,14 @@ impl Lint for PanicInCode {
 pub fn check_panic_usage<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
     for function_body in function_bodies.iter() {
         let function_call_exprs = get_all_function_calls(function_body);
         for function_call_expr in function_call_exprs.unique() {
-            check_single_panic_usage(db, &function_call_expr, diagnostics);
+            check_single_panic_usage(db, &function_call_expr, params, diagnostics);
         }
     }
 }
@@ -67,6 +82,7 @@ pub fn check_panic_usage<'db>(
 fn check_single_panic_usage<'db>(
     db: &'db dyn Database,
     function_call_expr: &ExprFunctionCall<'db>,
+    params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let init_node = function_call_expr.stable_ptr.lookup(db).as_syntax_node();
@@ -80,7 +96,7 @@ fn check_single_panic_usage<'db>(
 
     // If the function is the panic function from the corelib.
     let is_panic = if let GenericFunctionId::Extern(id) = concrete_function_id
-        && id == corelib_context.get_panic_function_id()
+        && Some(id) == corelib_context.get_panic_function_id()
     {
         true
     } else {
@@ -89,7 +105,7 @@ fn check_single_panic_usage<'db>(
 
     // If the function is the panic_with_byte_array function from the corelib.
     let is_panic_with_byte_array = if let GenericFunctionId::Free(id) = concrete_function_id
-        && id == corelib_context.get_panic_with_byte_array_function_id()
+        && Some(id) == corelib_context.get_panic_with_byte_array_function_id()
     {
         true
     } else {
@@ -121,19 +137,13 @@ fn check_single_panic_usage<'db>(
                 .stable_ptr
                 .lookup(db)
                 .as_syntax_node()
-                .span(db),
+                .span_without_trivia(db),
         },
         None,
     );
     // If the panic comes from a real file (macros generate code in new virtual files)
-    if initial_file_id == file_id {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: init_node.stable_ptr(db),
-            message: PanicInCode.diagnostic_message().to_owned(),
-            severity: Severity::Warning,
-            inner_span: None,
-            error_code: None,
-        });
+    let panic_node = if initial_file_id == file_id {
+        init_node
     } else {
         // If the originating location is a different file get the syntax node that generated the
         // code that contains a panic.
@@ -141,15 +151,26 @@ fn check_single_panic_usage<'db>(
             if let Some(text_position) = span.position_in_file(db, file_id);
             if let Ok(file_node) = db.file_syntax(file_id);
             then {
-                let syntax_node = file_node.lookup_position(db, text_position.start);
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: syntax_node.stable_ptr(db),
-                    message: PanicInCode.diagnostic_message().to_owned(),
-                    severity: Severity::Warning,
-                    inner_span: None,
-                    error_code: None,
-                });
+                file_node.lookup_position(db, text_position.start)
+            } else {
+                return;
             }
         }
+    };
+    if params.allow_panic_in_tests && is_in_test_code(db, panic_node) {
+        return;
     }
+    diagnostics.push(PluginDiagnostic {
+        stable_ptr: panic_node.stable_ptr(db),
+        message: PanicInCode.diagnostic_message().to_owned(),
+        severity: Severity::Warning,
+        inner_span: None,
+        error_code: None,
+    });
+}
+
+fn is_in_test_code<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> bool {
+    TEST_ATTRIBUTES
+        .iter()
+        .any(|(attr, arg)| node_has_ascendants_with_attr(db, node, attr, *arg))
 }
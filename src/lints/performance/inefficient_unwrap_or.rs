@@ -13,9 +13,10 @@ use cairo_lang_semantic::{
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode, ast};
 use salsa::Database;
 
+use crate::LinterDiagnosticParams;
 use crate::{
     LinterGroup,
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     fixer::InternalFix,
     queries::{get_all_function_bodies_with_ids, get_all_function_calls},
 };
@@ -49,6 +50,10 @@ impl Lint for InefficientUnwrapOr {
         "inefficient_unwrap_or"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0053"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Inefficient `unwrap_or` detected. Consider using `unwrap_or_else()` instead."
     }
@@ -57,6 +62,10 @@ impl Lint for InefficientUnwrapOr {
         CairoLintKind::InefficientUnwrapOr
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Performance
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -74,6 +83,7 @@ impl Lint for InefficientUnwrapOr {
 pub fn check_inefficient_unwrap_or<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies_with_ids(db, item);
@@ -105,8 +115,8 @@ fn get_all_unwrap_or_calls<'db>(
     db: &'db dyn Database,
     function_body: &'db FunctionBody<'db>,
 ) -> impl Iterator<Item = ExprFunctionCall<'db>> {
-    let option_trait = db.corelib_context().get_option_trait(db).long(db);
-    let result_trait = db.corelib_context().get_result_trait(db).long(db);
+    let option_trait = db.corelib_context().get_option_trait_id();
+    let result_trait = db.corelib_context().get_result_trait_id();
 
     get_all_function_calls(function_body).filter(move |function_call| {
         let generic_function = function_call.function.get_concrete(db).generic_function;
@@ -120,7 +130,7 @@ fn get_all_unwrap_or_calls<'db>(
         let function_name = impl_function.function.name(db).long(db);
         let is_unwrap_or = function_name == "unwrap_or";
 
-        let trait_id = concrete_trait.trait_id(db).long(db);
+        let trait_id = Some(concrete_trait.trait_id(db));
         let is_from_option_trait = trait_id == option_trait;
         let is_from_result_trait = trait_id == result_trait;
 
@@ -3,7 +3,8 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::{Arenas, Condition, Expr, ExprWhile};
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
 use crate::queries::{get_all_function_bodies, get_all_while_expressions};
 use salsa::Database;
@@ -40,6 +41,10 @@ impl Lint for InefficientWhileComparison {
         "inefficient_while_comp"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0041"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider \
                                               switching to `!=` or using ArrayTrait::multi_pop_front."
@@ -49,6 +54,10 @@ impl Lint for InefficientWhileComparison {
         CairoLintKind::Performance
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Performance
+    }
+
     fn is_enabled(&self) -> bool {
         false
     }
@@ -66,6 +75,7 @@ const PARTIAL_ORD_PATTERNS: [&str; 4] = [
 pub fn check_inefficient_while_comp<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
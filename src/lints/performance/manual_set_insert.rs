@@ -0,0 +1,211 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprIf, Statement,
+    TypeLongId, VarId,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::{ARRAY, ARRAY_APPEND, BOOL_NOT, SPAN, function_trait_name_from_fn_id};
+use crate::queries::{get_all_function_bodies, get_all_if_expressions};
+
+pub struct ManualSetInsert;
+
+/// ## What it does
+///
+/// Checks for appends to an array guarded by a `contains` check on the same array inside a loop.
+/// Such deduplication has O(n²) complexity, a set-like structure (e.g. `Felt252Dict`) should be
+/// used instead.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(values: Array<felt252>) -> Array<felt252> {
+///     let mut unique = array![];
+///     for value in values {
+///         if !unique.contains(@value) {
+///             unique.append(value);
+///         }
+///     }
+///     unique
+/// }
+/// ```
+impl Lint for ManualSetInsert {
+    fn allowed_name(&self) -> &'static str {
+        "manual_set_insert"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0064"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Appending to an array only if it doesn't contain the value yet has O(n²) complexity in a loop. Consider using a set-like structure such as `Felt252Dict` instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualSetInsert
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Performance
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_manual_set_insert<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for if_expr in get_all_if_expressions(function_body) {
+            if is_contains_guarded_append(db, &if_expr, arenas) && is_inside_loop(db, &if_expr) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: if_expr.stable_ptr.untyped(),
+                    message: ManualSetInsert.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the expression is `if !arr.contains(x) { arr.append(x) }` without an `else` block.
+fn is_contains_guarded_append<'db>(
+    db: &'db dyn Database,
+    if_expr: &ExprIf<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    if if_expr.else_block.is_some() {
+        return false;
+    }
+    let [Condition::BoolExpr(condition)] = &if_expr.conditions[..] else {
+        return false;
+    };
+    let Expr::FunctionCall(not_call) = &arenas.exprs[*condition] else {
+        return false;
+    };
+    if function_trait_name_from_fn_id(db, &not_call.function) != BOOL_NOT {
+        return false;
+    }
+    let [ExprFunctionCallArg::Value(negated)] = &not_call.args[..] else {
+        return false;
+    };
+    let Expr::FunctionCall(contains_call) = &arenas.exprs[*negated] else {
+        return false;
+    };
+    let Some((array, value)) = contains_call_parts(db, contains_call, arenas) else {
+        return false;
+    };
+
+    let Some(append_call) = single_call_of_block(arenas, if_expr.if_block) else {
+        return false;
+    };
+    if function_trait_name_from_fn_id(db, &append_call.function) != ARRAY_APPEND {
+        return false;
+    }
+    let [
+        ExprFunctionCallArg::Reference(append_array),
+        ExprFunctionCallArg::Value(appended_value),
+    ] = &append_call.args[..]
+    else {
+        return false;
+    };
+
+    append_array.base_var() == array && variable_of(arenas, *appended_value) == Some(value)
+}
+
+/// Returns the array and the searched value variables of a `contains` method call on an array
+/// or a span.
+fn contains_call_parts<'db>(
+    db: &'db dyn Database,
+    contains_call: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<(VarId<'db>, VarId<'db>)> {
+    if !function_trait_name_from_fn_id(db, &contains_call.function).ends_with("::contains") {
+        return None;
+    }
+    let [
+        ExprFunctionCallArg::Value(collection),
+        ExprFunctionCallArg::Value(value),
+    ] = &contains_call.args[..]
+    else {
+        return None;
+    };
+    if !is_array_or_span(db, arenas.exprs[*collection].ty().long(db)) {
+        return None;
+    }
+    Some((
+        variable_of(arenas, *collection)?,
+        variable_of(arenas, *value)?,
+    ))
+}
+
+/// Returns the function call if it's the only content of the block.
+fn single_call_of_block<'a, 'db>(
+    arenas: &'a Arenas<'db>,
+    block_id: ExprId,
+) -> Option<&'a ExprFunctionCall<'db>> {
+    let Expr::Block(block) = &arenas.exprs[block_id] else {
+        return None;
+    };
+    let call_id = match (&block.statements[..], block.tail) {
+        ([], Some(tail)) => tail,
+        ([statement], None) => {
+            let Statement::Expr(statement_expr) = &arenas.statements[*statement] else {
+                return None;
+            };
+            statement_expr.expr
+        }
+        _ => return None,
+    };
+    match &arenas.exprs[call_id] {
+        Expr::FunctionCall(call) => Some(call),
+        _ => None,
+    }
+}
+
+fn variable_of<'db>(arenas: &Arenas<'db>, expr_id: ExprId) -> Option<VarId<'db>> {
+    match &arenas.exprs[expr_id] {
+        Expr::Var(var) => Some(var.var),
+        Expr::Snapshot(snapshot) => variable_of(arenas, snapshot.inner),
+        Expr::Desnap(desnap) => variable_of(arenas, desnap.inner),
+        _ => None,
+    }
+}
+
+fn is_array_or_span(db: &dyn Database, type_long_id: &TypeLongId) -> bool {
+    match type_long_id {
+        TypeLongId::Snapshot(type_id) => is_array_or_span(db, type_id.long(db)),
+        TypeLongId::Concrete(concrete_type_id) => {
+            let generic_type_name = concrete_type_id.generic_type(db).format(db);
+            [ARRAY, SPAN].contains(&generic_type_name.as_str())
+        }
+        _ => false,
+    }
+}
+
+fn is_inside_loop<'db>(db: &'db dyn Database, if_expr: &ExprIf<'db>) -> bool {
+    if_expr
+        .stable_ptr
+        .lookup(db)
+        .as_syntax_node()
+        .ancestors(db)
+        .any(|node| {
+            matches!(
+                node.kind(db),
+                SyntaxKind::ExprLoop | SyntaxKind::ExprWhile | SyntaxKind::ExprFor
+            )
+        })
+}
@@ -1,2 +1,3 @@
 pub mod inefficient_unwrap_or;
 pub mod inefficient_while_comp;
+pub mod manual_set_insert;
@@ -0,0 +1,169 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{Expr, ExprBlock, OptionTerminalSemicolon, Statement};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::replace_preserving_trivia;
+
+pub struct RedundantBlock;
+
+/// ## What it does
+///
+/// Checks for blocks containing only a single expression, used where the expression itself
+/// would suffice, i.e. as the value of a `let` statement or as a function call argument.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() -> u32 {
+///     let y = 5;
+///     let x = { y };
+///     x
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main() -> u32 {
+///     let y = 5;
+///     let x = y;
+///     x
+/// }
+/// ```
+impl Lint for RedundantBlock {
+    fn allowed_name(&self) -> &'static str {
+        "redundant_block"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0061"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Redundant block containing a single expression. Consider using the expression directly."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::RedundantBlock
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_redundant_block(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Remove the redundant block")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_redundant_block<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let item_node = match item {
+        ModuleItemId::FreeFunction(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::Impl(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        // Trait can have a default function impl.
+        ModuleItemId::Trait(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        _ => return,
+    };
+
+    for node in item_node.descendants(db) {
+        if node.kind(db) == SyntaxKind::ExprBlock
+            && is_in_block_free_position(db, node)
+            && single_tail_expr(db, &ExprBlock::from_syntax_node(db, node)).is_some()
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: node.stable_ptr(db),
+                message: RedundantBlock.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Checks if the block is used in a position where any expression is accepted.
+/// Blocks required by the syntax (function bodies, `if`/`loop` bodies, match arms etc.)
+/// are never direct children of these nodes.
+fn is_in_block_free_position<'db>(db: &'db dyn Database, node: SyntaxNode<'db>) -> bool {
+    node.parent(db).is_some_and(|parent| {
+        matches!(
+            parent.kind(db),
+            SyntaxKind::StatementLet | SyntaxKind::ArgClauseUnnamed
+        )
+    })
+}
+
+/// Returns the only expression of the block if the block has no statements other than the tail
+/// expression. Blocks containing comments are skipped, as unwrapping them would lose the comments.
+fn single_tail_expr<'db>(db: &'db dyn Database, block: &ExprBlock<'db>) -> Option<Expr<'db>> {
+    let mut statements = block.statements(db).elements(db);
+    if statements.len() != 1 {
+        return None;
+    }
+    let Statement::Expr(statement_expr) = statements.next()? else {
+        return None;
+    };
+    if !matches!(
+        statement_expr.semicolon(db),
+        OptionTerminalSemicolon::Empty(_)
+    ) || block
+        .as_syntax_node()
+        .get_text_without_trivia(db)
+        .long(db)
+        .contains("//")
+    {
+        return None;
+    }
+    Some(statement_expr.expr(db))
+}
+
+/// Replaces a block containing a single expression with the expression itself.
+///
+/// # Arguments
+///
+/// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+/// * `node` - The `SyntaxNode` containing the block expression.
+///
+/// # Returns
+///
+/// An `InternalFix` containing the inner expression.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_redundant_block<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let block = ExprBlock::cast(db, node)?;
+    let expr = single_tail_expr(db, &block)?;
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(
+            db,
+            node,
+            expr.as_syntax_node().get_text_without_trivia(db).long(db),
+        ),
+        description: RedundantBlock.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -1,5 +1,6 @@
+use crate::LinterDiagnosticParams;
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     fixer::InternalFix,
     queries::get_all_function_bodies,
 };
@@ -50,6 +51,10 @@ impl Lint for RedundantBracketsInEnumCall {
         "redundant_brackets_in_enum_call"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0047"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "redundant parentheses in enum call"
     }
@@ -58,6 +63,10 @@ impl Lint for RedundantBracketsInEnumCall {
         CairoLintKind::EnumEmptyVariantBrackets
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -75,6 +84,7 @@ impl Lint for RedundantBracketsInEnumCall {
 pub fn check_redundant_brackets_in_enum_call<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -8,8 +8,9 @@ use cairo_lang_semantic::{
 use cairo_lang_syntax::node::TypedStablePtr;
 use salsa::Database;
 
+use crate::LinterDiagnosticParams;
 use crate::LinterGroup;
-use crate::context::{CairoLintKind, Lint};
+use crate::context::{CairoLintKind, Lint, LintGroup};
 use crate::queries::{get_all_function_bodies, get_all_function_calls};
 
 pub struct RedundantInto;
@@ -32,6 +33,10 @@ impl Lint for RedundantInto {
         "redundant_into"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0051"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "Redundant conversion: input and output types are the same."
     }
@@ -39,12 +44,17 @@ impl Lint for RedundantInto {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::RedundantInto
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn check_redundant_into<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -73,7 +83,7 @@ fn check_single_redundant_into<'db>(
         return;
     };
 
-    let function = impl_generic_func_id.function;
+    let function = Some(impl_generic_func_id.function);
 
     let target_ty: TypeId = if function == into_fn_id {
         expr_func.ty
@@ -110,7 +120,7 @@ fn check_single_redundant_into<'db>(
 }
 
 /// Extracts T from `core::option::Option::<T, E>`
-fn result_ok_type<'db>(db: &'db dyn Database, ty: TypeId<'db>) -> Option<TypeId<'db>> {
+pub fn result_ok_type<'db>(db: &'db dyn Database, ty: TypeId<'db>) -> Option<TypeId<'db>> {
     if let TypeLongId::Concrete(conc) = ty.long(db) {
         let generic_ty = conc.generic_type(db);
         let corelib_context = db.corelib_context();
@@ -121,7 +131,7 @@ fn result_ok_type<'db>(db: &'db dyn Database, ty: TypeId<'db>) -> Option<TypeId<
             return None;
         };
 
-        if type_id == option_enum_id {
+        if Some(type_id) == option_enum_id {
             let mut args = conc.generic_args(db).into_iter();
             if let Some(GenericArgumentId::Type(ok_ty)) = args.next() {
                 return Some(ok_ty);
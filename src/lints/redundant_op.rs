@@ -1,21 +1,27 @@
-use super::{ADD, DIV, MUL, SUB};
-use crate::context::{CairoLintKind, Lint};
+use super::{ADD, DIV, MUL, OR, SUB, XOR};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 
-use crate::helper::{is_one, is_zero};
+use crate::fixer::InternalFix;
+use crate::helper::{is_one, is_zero, replace_preserving_trivia};
 use crate::lints::function_trait_name_from_fn_id;
 use crate::queries::{get_all_function_bodies, get_all_function_calls};
 use cairo_lang_defs::ids::ModuleItemId;
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::{Arenas, ExprFunctionCall};
-use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use num_bigint::BigInt;
 use salsa::Database;
 
 pub struct RedundantOperation;
 
 /// ## What it does
 ///
-/// Checks for redundant arithmetic operations like `x + 0`, `x - 0`, `x * 1`, `x / 1`
+/// Checks for redundant arithmetic operations like `x + 0`, `x - 0`, `x * 1`, `x / 1`, and
+/// redundant bitwise operations like `x | 0` or `x ^ 0`. The operations with identical operands,
+/// e.g. `x & x`, are reported by `eq_logical_op` and `eq_bitwise_op`.
 ///
 /// ## Example
 ///
@@ -39,6 +45,10 @@ impl Lint for RedundantOperation {
         "redundant_op"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0042"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "This operation doesn't change the value and can be simplified."
     }
@@ -46,12 +56,29 @@ impl Lint for RedundantOperation {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::RedundantOperation
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_redundant_operation(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the operation with its result")
+    }
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn check_redundant_operation<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
@@ -76,6 +103,7 @@ fn check_single_redundant_operation<'db>(
         SUB => is_zero(&expr_func.args[1], arenas),
         MUL => is_one(&expr_func.args[0], arenas) || is_one(&expr_func.args[1], arenas),
         DIV => is_one(&expr_func.args[1], arenas),
+        OR | XOR => is_zero(&expr_func.args[0], arenas) || is_zero(&expr_func.args[1], arenas),
         _ => false,
     };
 
@@ -89,3 +117,53 @@ fn check_single_redundant_operation<'db>(
         });
     }
 }
+
+/// Replaces a redundant operation with its result, e.g. `x + 0` with `x`.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_redundant_operation<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let AstExpr::Binary(binary) = AstExpr::from_syntax_node(db, node) else {
+        return None;
+    };
+    let lhs = binary.lhs(db);
+    let rhs = binary.rhs(db);
+    let text = |expr: &AstExpr<'db>| {
+        expr.as_syntax_node()
+            .get_text_without_trivia(db)
+            .long(db)
+            .to_string()
+    };
+
+    let result = match binary.op(db) {
+        BinaryOperator::Plus(_) | BinaryOperator::Or(_) | BinaryOperator::Xor(_)
+            if is_literal(db, &rhs, 0) =>
+        {
+            text(&lhs)
+        }
+        BinaryOperator::Plus(_) | BinaryOperator::Or(_) | BinaryOperator::Xor(_)
+            if is_literal(db, &lhs, 0) =>
+        {
+            text(&rhs)
+        }
+        BinaryOperator::Minus(_) if is_literal(db, &rhs, 0) => text(&lhs),
+        BinaryOperator::Mul(_) | BinaryOperator::Div(_) if is_literal(db, &rhs, 1) => text(&lhs),
+        BinaryOperator::Mul(_) if is_literal(db, &lhs, 1) => text(&rhs),
+        _ => return None,
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &result),
+        description: RedundantOperation.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+fn is_literal<'db>(db: &'db dyn Database, expr: &AstExpr<'db>, value: u8) -> bool {
+    matches!(
+        expr,
+        AstExpr::Literal(literal) if literal.numeric_value(db) == Some(BigInt::from(value))
+    )
+}
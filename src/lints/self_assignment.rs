@@ -0,0 +1,163 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::{Arenas, Expr, ExprVarMemberPath};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::queries::{get_all_assignment_expressions, get_all_function_bodies};
+
+pub struct SelfAssignment;
+
+/// ## What it does
+///
+/// Checks for assignments of a variable or a struct member to itself, which have no effect.
+///
+/// ## Example
+///
+/// ```cairo
+/// #[derive(Drop)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// fn main() {
+///     let mut p = Point { x: 1, y: 2 };
+///     p.x = p.x;
+/// }
+/// ```
+///
+/// The assignment can be removed:
+///
+/// ```cairo
+/// #[derive(Drop)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// fn main() {
+///     let mut p = Point { x: 1, y: 2 };
+/// }
+/// ```
+impl Lint for SelfAssignment {
+    fn allowed_name(&self) -> &'static str {
+        "self_assignment"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0076"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Assignment of a value to itself has no effect. Consider removing it."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::SelfAssignment
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_self_assignment(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Remove the self-assignment")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_self_assignment<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let assignment_exprs = get_all_assignment_expressions(function_body);
+        let arenas = &function_body.arenas;
+        for assignment_expr in assignment_exprs.iter() {
+            if is_same_place(
+                &assignment_expr.ref_arg,
+                &arenas.exprs[assignment_expr.rhs],
+                arenas,
+            ) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: assignment_expr.stable_ptr.untyped(),
+                    message: SelfAssignment.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the expression reads exactly the variable or the member path being assigned.
+/// Any other expression, e.g. a method call, might have side effects.
+pub fn is_same_place<'db>(
+    target: &ExprVarMemberPath<'db>,
+    expr: &Expr<'db>,
+    arenas: &Arenas<'db>,
+) -> bool {
+    match (target, expr) {
+        (ExprVarMemberPath::Var(target_var), Expr::Var(expr_var)) => target_var.var == expr_var.var,
+        (
+            ExprVarMemberPath::Member {
+                parent, member_id, ..
+            },
+            Expr::MemberAccess(member_access),
+        ) => {
+            *member_id == member_access.member
+                && is_same_place(parent, &arenas.exprs[member_access.expr], arenas)
+        }
+        _ => false,
+    }
+}
+
+/// Removes the whole statement of the self-assignment, keeping the comments preceding it.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_self_assignment<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let statement_node = node.parent(db)?;
+    if statement_node.kind(db) != SyntaxKind::StatementExpr {
+        return None;
+    }
+
+    let span = statement_node.span(db);
+    let leading_trivia = statement_node.get_text_of_span(
+        db,
+        TextSpan {
+            start: span.start,
+            end: statement_node.span_without_trivia(db).start,
+        },
+    );
+
+    Some(InternalFix {
+        node: statement_node,
+        suggestion: leading_trivia
+            .rsplit_once('\n')
+            .map(|(comments, _indentation)| format!("{comments}\n"))
+            .unwrap_or_default(),
+        description: SelfAssignment.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -11,7 +11,8 @@ use cairo_lang_syntax::node::{
 };
 use if_chain::if_chain;
 
-use crate::context::{CairoLintKind, Lint};
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
 use crate::fixer::InternalFix;
 use crate::helper::indent_snippet;
 use crate::queries::{get_all_function_bodies, get_all_match_expressions};
@@ -45,6 +46,10 @@ impl Lint for DestructMatch {
         "destruct_match"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0001"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "you seem to be trying to use `match` for destructuring a single pattern. Consider using `if let`"
     }
@@ -53,6 +58,10 @@ impl Lint for DestructMatch {
         CairoLintKind::DestructMatch
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -93,6 +102,10 @@ impl Lint for EqualityMatch {
         "equality_match"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0002"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "you seem to be trying to use `match` for an equality check. Consider using `if`"
     }
@@ -100,12 +113,17 @@ impl Lint for EqualityMatch {
     fn kind(&self) -> CairoLintKind {
         CairoLintKind::MatchForEquality
     }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn check_single_matches<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
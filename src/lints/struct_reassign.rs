@@ -0,0 +1,177 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{
+    Arenas, ConcreteTypeId, Expr, ExprId, ExprVarMemberPath, Pattern, Statement, StatementId,
+    TypeLongId, VarId,
+};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::{DEFAULT, function_trait_name_from_fn_id};
+use crate::queries::get_all_function_bodies;
+
+pub struct FieldReassignWithDefault;
+
+/// ## What it does
+///
+/// Checks for a struct created with `Default::default()` whose fields are then assigned one by
+/// one right away.
+///
+/// ## Example
+///
+/// ```cairo
+/// #[derive(Default, Drop)]
+/// struct Config {
+///     size: u32,
+///     verbose: bool,
+/// }
+///
+/// fn main() -> Config {
+///     let mut config: Config = Default::default();
+///     config.size = 10;
+///     config.verbose = true;
+///     config
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// #[derive(Default, Drop)]
+/// struct Config {
+///     size: u32,
+///     verbose: bool,
+/// }
+///
+/// fn main() -> Config {
+///     let config = Config { size: 10, verbose: true };
+///     config
+/// }
+/// ```
+impl Lint for FieldReassignWithDefault {
+    fn allowed_name(&self) -> &'static str {
+        "field_reassign_with_default"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0082"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Fields of a struct created with `Default::default()` are assigned right away. Consider initializing the struct with these fields directly."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::FieldReassignWithDefault
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_field_reassign_with_default<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for (_expression_id, expression) in arenas.exprs.iter() {
+            let Expr::Block(block) = expression else {
+                continue;
+            };
+            for (index, statement_id) in block.statements.iter().enumerate() {
+                let Some(struct_var) = default_struct_binding(db, arenas, *statement_id) else {
+                    continue;
+                };
+                if is_reassigned_right_away(db, arenas, struct_var, &block.statements[index + 1..])
+                {
+                    diagnostics.push(PluginDiagnostic {
+                        stable_ptr: arenas.statements[*statement_id].stable_ptr().untyped(),
+                        message: FieldReassignWithDefault.diagnostic_message().to_string(),
+                        severity: Severity::Warning,
+                        inner_span: None,
+                        error_code: None,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Returns the variable bound by the statement, if it's `let mut s = Default::default();` and `s`
+/// is a struct.
+fn default_struct_binding<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    statement_id: StatementId,
+) -> Option<VarId<'db>> {
+    let Statement::Let(let_statement) = &arenas.statements[statement_id] else {
+        return None;
+    };
+    let Pattern::Variable(pattern) = &arenas.patterns[let_statement.pattern] else {
+        return None;
+    };
+    let Expr::FunctionCall(func_call) = &arenas.exprs[let_statement.expr] else {
+        return None;
+    };
+    let is_struct = matches!(
+        func_call.ty.long(db),
+        TypeLongId::Concrete(ConcreteTypeId::Struct(_))
+    );
+    (pattern.var.is_mut
+        && is_struct
+        && function_trait_name_from_fn_id(db, &func_call.function) == DEFAULT)
+        .then_some(VarId::Local(pattern.var.id))
+}
+
+/// Checks if the statements following the binding start with assignments to fields of the struct,
+/// and the struct isn't read by any of them.
+fn is_reassigned_right_away<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    struct_var: VarId<'db>,
+    following_statements: &[StatementId],
+) -> bool {
+    let assignments = following_statements
+        .iter()
+        .map_while(|statement_id| {
+            let Statement::Expr(statement_expr) = &arenas.statements[*statement_id] else {
+                return None;
+            };
+            let Expr::Assignment(assignment) = &arenas.exprs[statement_expr.expr] else {
+                return None;
+            };
+            let ExprVarMemberPath::Member { parent, .. } = &assignment.ref_arg else {
+                return None;
+            };
+            matches!(parent.as_ref(), ExprVarMemberPath::Var(var) if var.var == struct_var)
+                .then_some(assignment.rhs)
+        })
+        .collect::<Vec<_>>();
+    if assignments.is_empty() {
+        return false;
+    }
+
+    let expr_span = |expr_id: ExprId| {
+        arenas.exprs[expr_id]
+            .stable_ptr()
+            .lookup(db)
+            .as_syntax_node()
+            .span(db)
+    };
+    let assigned_value_spans = assignments.into_iter().map(expr_span).collect::<Vec<_>>();
+    !arenas.exprs.iter().any(|(expression_id, expression)| {
+        matches!(expression, Expr::Var(var) if var.var == struct_var)
+            && assigned_value_spans
+                .iter()
+                .any(|span| span.contains(expr_span(expression_id)))
+    })
+}
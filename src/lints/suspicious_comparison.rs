@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{MemberId, ModuleItemId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCallArg, ExprId, LogicalOperator, VarId};
+use cairo_lang_syntax::node::TypedStablePtr;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::{EQ, NE, function_trait_name_from_fn_id};
+use crate::queries::get_all_function_bodies;
+
+pub struct SuspiciousOperationGrouping;
+
+/// ## What it does
+///
+/// Checks for chains of `&&` or `||` comparing the members of two variables, where one of the
+/// comparisons uses different members on both sides, while all the other ones compare the same
+/// members. This is most likely a copy-paste mistake.
+///
+/// The check is conservative: a comparison is flagged only if it is the only one with different
+/// members in the chain, and one of its members is already compared elsewhere in the chain.
+///
+/// ## Example
+///
+/// ```cairo
+/// #[derive(Drop, PartialEq)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// fn main(a: Point, b: Point) -> bool {
+///     a.x == b.x && a.y == b.x
+/// }
+/// ```
+///
+/// The second comparison should likely be `a.y == b.y`.
+impl Lint for SuspiciousOperationGrouping {
+    fn allowed_name(&self) -> &'static str {
+        "suspicious_operation_groupings"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0107"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Suspicious comparison of different members in a chain of comparisons. Check if the compared members are correct."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::SuspiciousOperationGrouping
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_suspicious_operation_groupings<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        // The operands being a part of a longer chain are checked together with the whole chain.
+        let chained_operands: HashSet<ExprId> = arenas
+            .exprs
+            .iter()
+            .filter_map(|(_expression_id, expression)| match expression {
+                Expr::LogicalOperator(logical_operator) => Some(logical_operator),
+                _ => None,
+            })
+            .flat_map(|logical_operator| {
+                [logical_operator.lhs, logical_operator.rhs]
+                    .into_iter()
+                    .filter(|operand| {
+                        matches!(
+                            &arenas.exprs[*operand],
+                            Expr::LogicalOperator(inner) if inner.op == logical_operator.op
+                        )
+                    })
+            })
+            .collect();
+
+        for (expression_id, expression) in arenas.exprs.iter() {
+            let Expr::LogicalOperator(logical_operator) = expression else {
+                continue;
+            };
+            if chained_operands.contains(&expression_id) {
+                continue;
+            }
+            let mut comparisons = Vec::new();
+            collect_chain(arenas, expression_id, logical_operator.op, &mut comparisons);
+            if let Some(suspicious) = suspicious_comparison(db, arenas, &comparisons) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: arenas.exprs[suspicious].stable_ptr().untyped(),
+                    message: SuspiciousOperationGrouping.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// Collects the operands of a chain of the same logical operator, from left to right.
+fn collect_chain(
+    arenas: &Arenas,
+    expr_id: ExprId,
+    operator: LogicalOperator,
+    operands: &mut Vec<ExprId>,
+) {
+    match &arenas.exprs[expr_id] {
+        Expr::LogicalOperator(logical_operator) if logical_operator.op == operator => {
+            collect_chain(arenas, logical_operator.lhs, operator, operands);
+            collect_chain(arenas, logical_operator.rhs, operator, operands);
+        }
+        _ => operands.push(expr_id),
+    }
+}
+
+/// A comparison of members of two variables, e.g. `a.x == b.y`.
+struct MemberComparison<'db> {
+    expr_id: ExprId,
+    lhs: (VarId<'db>, MemberId<'db>),
+    rhs: (VarId<'db>, MemberId<'db>),
+}
+
+/// Returns the only comparison in the chain comparing different members, if it's suspicious.
+///
+/// All the operands of the chain have to be `==` or `!=` comparisons of members of the same two
+/// variables, and one of the members of the suspicious comparison has to be compared in another
+/// comparison too.
+fn suspicious_comparison<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    operands: &[ExprId],
+) -> Option<ExprId> {
+    if operands.len() < 2 {
+        return None;
+    }
+    let comparisons = operands
+        .iter()
+        .map(|operand| member_comparison(db, arenas, *operand))
+        .collect::<Option<Vec<_>>>()?;
+
+    let (lhs_var, rhs_var) = (comparisons[0].lhs.0, comparisons[0].rhs.0);
+    if lhs_var == rhs_var
+        || comparisons
+            .iter()
+            .any(|comparison| comparison.lhs.0 != lhs_var || comparison.rhs.0 != rhs_var)
+    {
+        return None;
+    }
+
+    let mut mismatched = comparisons
+        .iter()
+        .filter(|comparison| comparison.lhs.1 != comparison.rhs.1);
+    let (Some(suspicious), None) = (mismatched.next(), mismatched.next()) else {
+        return None;
+    };
+    comparisons
+        .iter()
+        .filter(|comparison| comparison.expr_id != suspicious.expr_id)
+        .any(|comparison| {
+            comparison.lhs.1 == suspicious.lhs.1 || comparison.rhs.1 == suspicious.rhs.1
+        })
+        .then_some(suspicious.expr_id)
+}
+
+fn member_comparison<'db>(
+    db: &'db dyn Database,
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+) -> Option<MemberComparison<'db>> {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[expr_id] else {
+        return None;
+    };
+    let function_name = function_trait_name_from_fn_id(db, &func_call.function);
+    if function_name != EQ && function_name != NE {
+        return None;
+    }
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = &func_call.args[..]
+    else {
+        return None;
+    };
+    Some(MemberComparison {
+        expr_id,
+        lhs: variable_member(arenas, *lhs)?,
+        rhs: variable_member(arenas, *rhs)?,
+    })
+}
+
+/// Returns the variable and the member, if the expression is a member access of a variable.
+fn variable_member<'db>(
+    arenas: &Arenas<'db>,
+    expr_id: ExprId,
+) -> Option<(VarId<'db>, MemberId<'db>)> {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(snapshot) => variable_member(arenas, snapshot.inner),
+        Expr::MemberAccess(member_access) => match &arenas.exprs[member_access.expr] {
+            Expr::Var(var) => Some((var.var, member_access.member)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,102 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::FunctionDeclaration;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+
+/// Default maximum number of arguments of a function, `self` excluded.
+pub const DEFAULT_TOO_MANY_ARGUMENTS_THRESHOLD: usize = 7;
+
+pub struct TooManyArguments;
+
+/// ## What it does
+///
+/// Checks for functions taking more arguments than the configured threshold (7 by default).
+/// The `self` argument is not counted.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn draw(x: u32, y: u32, z: u32, width: u32, height: u32, depth: u32, color: u32, alpha: u32) {}
+/// ```
+///
+/// Can be rewritten with the related arguments grouped in structs:
+///
+/// ```cairo
+/// #[derive(Drop)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+///     z: u32,
+/// }
+///
+/// #[derive(Drop)]
+/// struct Size {
+///     width: u32,
+///     height: u32,
+///     depth: u32,
+/// }
+///
+/// fn draw(position: Point, size: Size, color: u32, alpha: u32) {}
+/// ```
+impl Lint for TooManyArguments {
+    fn allowed_name(&self) -> &'static str {
+        "too_many_arguments"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0086"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "This function has too many arguments. Consider grouping some of them in a struct."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::TooManyArguments
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_too_many_arguments<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let item_node = match item {
+        ModuleItemId::FreeFunction(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::Impl(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::Trait(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        _ => return,
+    };
+    for node in item_node
+        .descendants(db)
+        .filter(|node| node.kind(db) == SyntaxKind::FunctionDeclaration)
+    {
+        let signature = FunctionDeclaration::from_syntax_node(db, node).signature(db);
+        let arguments_count = signature
+            .parameters(db)
+            .elements(db)
+            .filter(|param| param.name(db).text(db).to_string(db) != "self")
+            .count();
+        if arguments_count > params.too_many_arguments_threshold {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: signature.stable_ptr(db).untyped(),
+                message: TooManyArguments.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
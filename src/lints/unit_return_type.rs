@@ -7,9 +7,10 @@ use cairo_lang_syntax::node::{
     ast::{FunctionSignature, OptionReturnTypeClause},
 };
 
+use crate::LinterDiagnosticParams;
 use crate::fixer::InternalFix;
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     queries::get_all_checkable_functions,
 };
 use salsa::Database;
@@ -40,6 +41,10 @@ impl Lint for UnitReturnType {
         "unit_return_type"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0049"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "unnecessary declared unit return type `()`"
     }
@@ -48,6 +53,10 @@ impl Lint for UnitReturnType {
         CairoLintKind::UnitReturnType
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -65,6 +74,7 @@ impl Lint for UnitReturnType {
 pub fn check_unit_return_type<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let functions = get_all_checkable_functions(db, item);
@@ -0,0 +1,172 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, ExprFunctionCall, ExprFunctionCallArg, TypeId, TypeLongId};
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr, ExprInlineMacro};
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::lints::ARRAY;
+use crate::queries::{
+    get_all_for_expressions, get_all_function_bodies, get_all_function_calls,
+    syntax_node_to_str_without_all_nested_trivia,
+};
+
+const EMPTY_ARRAY_MACRO: &str = "array![]";
+/// Methods which only iterate over the elements of the array or view them.
+const CONSUMING_METHODS: [&str; 2] = ["span", "into_iter"];
+
+pub struct UnnecessaryArray;
+
+/// ## What it does
+///
+/// Checks for arrays created with the `array!` macro only to be iterated over or converted to a
+/// span right away. A fixed-size array avoids allocating the elements in memory one by one.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main() -> u32 {
+///     let mut sum = 0;
+///     for value in array![1, 2, 3] {
+///         sum += value;
+///     }
+///     sum
+/// }
+/// ```
+///
+/// Can be rewritten as:
+///
+/// ```cairo
+/// fn main() -> u32 {
+///     let mut sum = 0;
+///     for value in [1, 2, 3] {
+///         sum += value;
+///     }
+///     sum
+/// }
+/// ```
+impl Lint for UnnecessaryArray {
+    fn allowed_name(&self) -> &'static str {
+        "unnecessary_array"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0094"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Array created only to be iterated over or turned into a span. Consider using a fixed-size array instead."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UnnecessaryArray
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Performance
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_unnecessary_array<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        let method_receivers = get_all_function_calls(function_body)
+            .filter_map(|function_call_expr| consumed_array_macro(db, &function_call_expr, arenas));
+        // The iterated expression of a `for` loop is consumed by an implicit `into_iter()` call.
+        let iterated_arrays = get_all_for_expressions(function_body)
+            .into_iter()
+            .filter(|for_expr| is_array(db, arenas.exprs[for_expr.expr_id].ty()))
+            .filter_map(|for_expr| match for_expr.stable_ptr.lookup(db) {
+                AstExpr::For(for_syntax) => match for_syntax.expr(db) {
+                    AstExpr::InlineMacro(inline_macro) => Some(inline_macro),
+                    _ => None,
+                },
+                _ => None,
+            });
+
+        for inline_macro in method_receivers.chain(iterated_arrays) {
+            if !is_non_empty_array_macro(db, &inline_macro) {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: inline_macro.stable_ptr(db).untyped(),
+                message: UnnecessaryArray.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Returns the macro call if the function call is `array![..].span()` or `array![..].into_iter()`.
+/// Such an array is a temporary value, so it can't be used anywhere else.
+fn consumed_array_macro<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<ExprInlineMacro<'db>> {
+    let [ExprFunctionCallArg::Value(receiver)] = function_call_expr.args[..] else {
+        return None;
+    };
+    if !is_array(db, arenas.exprs[receiver].ty()) {
+        return None;
+    }
+    let AstExpr::Binary(binary) = function_call_expr.stable_ptr.lookup(db) else {
+        return None;
+    };
+    if !matches!(binary.op(db), BinaryOperator::Dot(_)) {
+        return None;
+    }
+    let AstExpr::FunctionCall(method_call) = binary.rhs(db) else {
+        return None;
+    };
+    let method_name = method_call
+        .path(db)
+        .segments(db)
+        .elements(db)
+        .last()?
+        .identifier(db);
+    if !CONSUMING_METHODS.contains(&method_name.long(db).as_str()) {
+        return None;
+    }
+    match binary.lhs(db) {
+        AstExpr::InlineMacro(inline_macro) => Some(inline_macro),
+        _ => None,
+    }
+}
+
+fn is_non_empty_array_macro<'db>(
+    db: &'db dyn Database,
+    inline_macro: &ExprInlineMacro<'db>,
+) -> bool {
+    let name = inline_macro
+        .path(db)
+        .as_syntax_node()
+        .get_text_without_trivia(db);
+    name.long(db).as_str() == "array"
+        && syntax_node_to_str_without_all_nested_trivia(db, inline_macro.as_syntax_node())
+            != EMPTY_ARRAY_MACRO
+}
+
+/// Checks if the type is an `Array` or a snapshot of it.
+fn is_array<'db>(db: &'db dyn Database, ty: TypeId<'db>) -> bool {
+    match ty.long(db) {
+        TypeLongId::Snapshot(inner) => is_array(db, *inner),
+        TypeLongId::Concrete(concrete_type_id) => {
+            concrete_type_id.generic_type(db).format(db) == ARRAY
+        }
+        _ => false,
+    }
+}
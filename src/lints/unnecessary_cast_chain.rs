@@ -0,0 +1,283 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, TypeId};
+use cairo_lang_syntax::node::ast::{
+    ArgClause, BinaryOperator, Expr as AstExpr, ExprFunctionCall as AstExprFunctionCall,
+};
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::LinterGroup;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::{is_side_effect_free_syntax, replace_preserving_trivia};
+use crate::lints::redundant_into::result_ok_type;
+use crate::lints::{OPTION_UNWRAP, function_trait_name_from_fn_id};
+use crate::queries::{get_all_function_bodies, get_all_function_calls};
+
+pub struct UnnecessaryCastChain;
+
+/// ## What it does
+///
+/// Detects chains of `into()` and `try_into()` conversions which end up with the same type the
+/// chain started with, so the conversions cancel out.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u8) -> u8 {
+///     Into::<u8, u64>::into(x).try_into().unwrap()
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(x: u8) -> u8 {
+///     x
+/// }
+/// ```
+impl Lint for UnnecessaryCastChain {
+    fn allowed_name(&self) -> &'static str {
+        "unnecessary_cast_chain"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0083"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Unnecessary conversion chain: the value is converted back to its original type."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UnnecessaryCastChain
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_unnecessary_cast_chain(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Remove the conversions")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_unnecessary_cast_chain<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        let chains = get_all_function_calls(function_body)
+            .filter_map(|function_call_expr| {
+                let chain = conversion_chain(db, &function_call_expr, arenas)?;
+                Some((function_call_expr, chain))
+            })
+            .collect::<Vec<_>>();
+        // Only the outermost call of a chain is reported.
+        let inner_links = chains
+            .iter()
+            .flat_map(|(_, chain)| chain.inner_links.iter())
+            .map(|expr_id| arenas.exprs[*expr_id].stable_ptr())
+            .collect::<HashSet<_>>();
+
+        for (function_call_expr, chain) in chains {
+            if chain.conversions < 2 || inner_links.contains(&function_call_expr.stable_ptr) {
+                continue;
+            }
+            let Some(final_ty) = chain.final_ty else {
+                continue;
+            };
+            if final_ty == arenas.exprs[chain.base].ty() {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: function_call_expr.stable_ptr.untyped(),
+                    message: UnnecessaryCastChain.diagnostic_message().to_string(),
+                    severity: Severity::Warning,
+                    inner_span: None,
+                    error_code: None,
+                });
+            }
+        }
+    }
+}
+
+/// A chain of conversions, e.g. `x.into().try_into().unwrap()`.
+struct ConversionChain<'db> {
+    /// The converted expression.
+    base: ExprId,
+    /// The calls of the chain, except the outermost one.
+    inner_links: Vec<ExprId>,
+    /// The number of `into()` and `try_into()` calls.
+    conversions: usize,
+    /// The type the value is converted to. For a chain ending with `try_into()`, it's the type
+    /// wrapped in the returned `Option`.
+    final_ty: Option<TypeId<'db>>,
+}
+
+#[derive(PartialEq)]
+enum ConversionLink {
+    Into,
+    TryInto,
+    /// An `unwrap()` of a `try_into()` result.
+    Unwrap,
+}
+
+fn conversion_chain<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<ConversionChain<'db>> {
+    let (mut inner, outermost_link) = conversion_link(db, function_call_expr, arenas)?;
+    let final_ty = match outermost_link {
+        ConversionLink::TryInto => result_ok_type(db, function_call_expr.ty),
+        ConversionLink::Into | ConversionLink::Unwrap => Some(function_call_expr.ty),
+    };
+
+    let mut inner_links = vec![];
+    let mut conversions = usize::from(outermost_link != ConversionLink::Unwrap);
+    while let Expr::FunctionCall(inner_call) = &arenas.exprs[inner]
+        && let Some((next, link)) = conversion_link(db, inner_call, arenas)
+    {
+        if link != ConversionLink::Unwrap {
+            conversions += 1;
+        }
+        inner_links.push(inner);
+        inner = next;
+    }
+
+    Some(ConversionChain {
+        base: inner,
+        inner_links,
+        conversions,
+        final_ty,
+    })
+}
+
+/// Returns the converted expression if the call is a link of a conversion chain.
+fn conversion_link<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<(ExprId, ConversionLink)> {
+    let [ExprFunctionCallArg::Value(arg)] = function_call_expr.args[..] else {
+        return None;
+    };
+
+    if function_trait_name_from_fn_id(db, &function_call_expr.function) == OPTION_UNWRAP {
+        let Expr::FunctionCall(unwrapped_call) = &arenas.exprs[arg] else {
+            return None;
+        };
+        return (conversion_kind(db, unwrapped_call)? == ConversionLink::TryInto)
+            .then_some((arg, ConversionLink::Unwrap));
+    }
+    Some((arg, conversion_kind(db, function_call_expr)?))
+}
+
+fn conversion_kind<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+) -> Option<ConversionLink> {
+    let GenericFunctionId::Impl(impl_generic_func_id) = function_call_expr
+        .function
+        .get_concrete(db)
+        .generic_function
+    else {
+        return None;
+    };
+    let corelib_context = db.corelib_context();
+    let function = Some(impl_generic_func_id.function);
+    if function == corelib_context.get_into_trait_function_id() {
+        Some(ConversionLink::Into)
+    } else if function == corelib_context.get_try_into_trait_function_id() {
+        Some(ConversionLink::TryInto)
+    } else {
+        None
+    }
+}
+
+/// Replaces a chain of `into()` calls with the converted expression. Chains containing
+/// `try_into()` are not fixed, as they can fail.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_unnecessary_cast_chain<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let mut base = AstExpr::from_syntax_node(db, node);
+    let mut conversions = 0;
+    while let Some(inner) = into_call_argument(db, &base) {
+        conversions += 1;
+        base = inner;
+    }
+    if conversions < 2 {
+        return None;
+    }
+    if !is_side_effect_free_syntax(db, &base) {
+        return None;
+    }
+
+    let base_text = base.as_syntax_node().get_text_without_trivia(db);
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, base_text.long(db)),
+        description: UnnecessaryCastChain.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
+
+/// Returns the converted expression if the expression is `x.into()` or `Into::into(x)`.
+fn into_call_argument<'db>(db: &'db dyn Database, expr: &AstExpr<'db>) -> Option<AstExpr<'db>> {
+    match expr {
+        AstExpr::Binary(binary) if matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+            let AstExpr::FunctionCall(call) = binary.rhs(db) else {
+                return None;
+            };
+            (is_into_call(db, &call)
+                && call
+                    .arguments(db)
+                    .arguments(db)
+                    .elements(db)
+                    .next()
+                    .is_none())
+            .then(|| binary.lhs(db))
+        }
+        AstExpr::FunctionCall(call) if is_into_call(db, call) => {
+            let mut arguments = call.arguments(db).arguments(db).elements(db);
+            let (Some(argument), None) = (arguments.next(), arguments.next()) else {
+                return None;
+            };
+            match argument.arg_clause(db) {
+                ArgClause::Unnamed(argument) => Some(argument.value(db)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_into_call<'db>(db: &'db dyn Database, call: &AstExprFunctionCall<'db>) -> bool {
+    call.path(db)
+        .segments(db)
+        .elements(db)
+        .last()
+        .is_some_and(|segment| segment.identifier(db).long(db) == "into")
+}
@@ -0,0 +1,206 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId};
+use cairo_lang_syntax::node::ast::{BinaryOperator, ExprBinary};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use num_bigint::BigInt;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::is_side_effect_free_syntax;
+use crate::helper::replace_preserving_trivia;
+use crate::lints::{GE, GT, LE, LT, UNSIGNED_INTEGER_TYPES, function_trait_name_from_fn_id};
+use crate::queries::{get_all_function_bodies, get_all_function_calls};
+
+pub struct ImpossibleUnsignedComparison;
+
+/// ## What it does
+///
+/// Checks for comparisons of an unsigned integer being less than zero. Those are always false.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32) {
+///     if x < 0 {
+///         // impossible to reach
+///     }
+/// }
+/// ```
+impl Lint for ImpossibleUnsignedComparison {
+    fn allowed_name(&self) -> &'static str {
+        "impossible_unsigned_comparison"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0091"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Impossible condition, an unsigned integer is never negative. Always false"
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ImpossibleUnsignedComparison
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_unsigned_comparison(db, node, false)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the comparison with `false`")
+    }
+}
+
+pub struct RedundantUnsignedComparison;
+
+/// ## What it does
+///
+/// Checks for comparisons of an unsigned integer being greater than or equal to zero. Those are
+/// always true.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32) {
+///     if x >= 0 {
+///         // always reached
+///     }
+/// }
+/// ```
+impl Lint for RedundantUnsignedComparison {
+    fn allowed_name(&self) -> &'static str {
+        "redundant_unsigned_comparison"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0092"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Redundant condition, an unsigned integer is never negative. Always true"
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::RedundantUnsignedComparison
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_unsigned_comparison(db, node, true)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the comparison with `true`")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_unsigned_comparison<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for function_call_expr in get_all_function_calls(function_body) {
+            let message = match unsigned_comparison_with_zero(db, &function_call_expr, arenas) {
+                Some(false) => ImpossibleUnsignedComparison.diagnostic_message(),
+                Some(true) => RedundantUnsignedComparison.diagnostic_message(),
+                None => continue,
+            };
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: function_call_expr.stable_ptr.untyped(),
+                message: message.to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Returns the constant result of a comparison between an unsigned integer and zero,
+/// i.e. `false` for `x < 0` and `0 > x`, `true` for `x >= 0` and `0 <= x`.
+fn unsigned_comparison_with_zero<'db>(
+    db: &'db dyn Database,
+    function_call_expr: &ExprFunctionCall<'db>,
+    arenas: &Arenas<'db>,
+) -> Option<bool> {
+    let [
+        ExprFunctionCallArg::Value(lhs),
+        ExprFunctionCallArg::Value(rhs),
+    ] = function_call_expr.args[..]
+    else {
+        return None;
+    };
+    let (operand, always_true) =
+        match function_trait_name_from_fn_id(db, &function_call_expr.function).as_str() {
+            LT if is_zero(rhs, arenas) => (lhs, false),
+            GT if is_zero(lhs, arenas) => (rhs, false),
+            GE if is_zero(rhs, arenas) => (lhs, true),
+            LE if is_zero(lhs, arenas) => (rhs, true),
+            _ => return None,
+        };
+    let operand_type = arenas.exprs[operand].ty().format(db);
+    UNSIGNED_INTEGER_TYPES
+        .iter()
+        .any(|(name, _)| *name == operand_type)
+        .then_some(always_true)
+}
+
+fn is_zero(expr_id: ExprId, arenas: &Arenas) -> bool {
+    matches!(&arenas.exprs[expr_id], Expr::Literal(literal) if literal.value == BigInt::ZERO)
+}
+
+/// Replaces the comparison with its constant result, if the compared value has no side effects.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_unsigned_comparison<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+    result: bool,
+) -> Option<InternalFix<'db>> {
+    let binary = ExprBinary::cast(db, node)?;
+    let operand = match binary.op(db) {
+        BinaryOperator::LT(_) | BinaryOperator::GE(_) => binary.lhs(db),
+        BinaryOperator::GT(_) | BinaryOperator::LE(_) => binary.rhs(db),
+        _ => return None,
+    };
+    if !is_side_effect_free_syntax(db, &operand) {
+        return None;
+    }
+
+    let description = if result {
+        RedundantUnsignedComparison.fix_message()
+    } else {
+        ImpossibleUnsignedComparison.fix_message()
+    };
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, &result.to_string()),
+        description: description.unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{Attribute, OptionArgListParenthesized};
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{
+    CairoLintKind, Lint, LintGroup, get_group_for_diagnostic_message, get_lints_in_group,
+    get_name_for_diagnostic_message, get_unique_allowed_names,
+};
+
+pub struct UnusedAllow;
+
+/// ## What it does
+///
+/// Checks for `#[allow(...)]` attributes of lints that don't report anything in the code covered
+/// by the attribute, which makes the attribute unnecessary.
+///
+/// ## Example
+///
+/// ```cairo
+/// #[allow(duplicate_underscore_args)]
+/// fn foo(a: u32, b: u32) {}
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn foo(a: u32, b: u32) {}
+/// ```
+impl Lint for UnusedAllow {
+    fn allowed_name(&self) -> &'static str {
+        "unused_allow"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0071"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Unnecessary `allow` attribute, the allowed lint is not reported in the covered code."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UnusedAllow
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+}
+
+/// Reports the `#[allow(...)]` attributes within the item that didn't suppress any of the
+/// `item_diagnostics`, which must be all the lint diagnostics reported for the item.
+///
+/// Submodules are skipped, as the diagnostics of their items are computed separately.
+/// The attributes allowing lints that were not selected by `params.enabled_lints` are skipped too,
+/// as those lints didn't run.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_unused_allows<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    params: &LinterDiagnosticParams,
+    item_diagnostics: &[PluginDiagnostic<'db>],
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    if matches!(item, ModuleItemId::Submodule(_)) {
+        return;
+    }
+
+    // The allow attributes are identified by the node they are attached to and the allowed name.
+    let mut used_allows: HashSet<(SyntaxNode<'db>, &'static str)> = HashSet::new();
+    for diagnostic in item_diagnostics {
+        let Some(allowed_name) = get_name_for_diagnostic_message(&diagnostic.message) else {
+            continue;
+        };
+        let Some(group) = get_group_for_diagnostic_message(&diagnostic.message) else {
+            continue;
+        };
+        let node = diagnostic.stable_ptr.lookup(db);
+        for name in [allowed_name, group.allowed_name()] {
+            if let Some(allowing_node) = node
+                .ancestors_with_self(db)
+                .find(|ancestor| ancestor.has_attr_with_arg(db, "allow", name))
+            {
+                used_allows.insert((allowing_node, name));
+            }
+        }
+    }
+
+    let item_node = item.stable_location(db).stable_ptr().lookup(db);
+    for node in item_node.descendants(db) {
+        if node.kind(db) != SyntaxKind::Attribute {
+            continue;
+        }
+        let Some(allowed_name) = allowed_lint_name(db, &Attribute::from_syntax_node(db, node))
+        else {
+            continue;
+        };
+        if !were_allowed_lints_run(params, allowed_name) {
+            continue;
+        }
+        let Some(allowing_node) = node
+            .ancestors(db)
+            .find(|ancestor| ancestor.has_attr_with_arg(db, "allow", allowed_name))
+        else {
+            continue;
+        };
+        if allowing_node.kind(db) == SyntaxKind::ItemModule
+            || used_allows.contains(&(allowing_node, allowed_name))
+        {
+            continue;
+        }
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: node.stable_ptr(db),
+            message: UnusedAllow.diagnostic_message().to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
+
+/// Checks if all the lints allowed by the name of a lint or a lint group were run.
+fn were_allowed_lints_run(params: &LinterDiagnosticParams, allowed_name: &str) -> bool {
+    let Some(enabled_lints) = &params.enabled_lints else {
+        return true;
+    };
+    match LintGroup::all()
+        .into_iter()
+        .find(|group| group.allowed_name() == allowed_name)
+    {
+        Some(group) => get_lints_in_group(group)
+            .into_iter()
+            .all(|name| enabled_lints.contains(name)),
+        None => enabled_lints.contains(allowed_name),
+    }
+}
+
+/// Returns the name of the lint or lint group allowed by the attribute, if it's an `allow`
+/// attribute with a single argument naming one of them.
+fn allowed_lint_name<'db>(
+    db: &'db dyn Database,
+    attribute: &Attribute<'db>,
+) -> Option<&'static str> {
+    if attribute
+        .attr(db)
+        .as_syntax_node()
+        .get_text_without_trivia(db)
+        .long(db)
+        .as_str()
+        != "allow"
+    {
+        return None;
+    }
+    let OptionArgListParenthesized::ArgListParenthesized(arguments) = attribute.arguments(db)
+    else {
+        return None;
+    };
+    let mut arguments = arguments.arguments(db).elements(db);
+    if arguments.len() != 1 {
+        return None;
+    }
+    let argument = arguments
+        .next()?
+        .as_syntax_node()
+        .get_text_without_trivia(db);
+    let argument = argument.long(db).as_str();
+    get_unique_allowed_names()
+        .into_iter()
+        .chain(LintGroup::all().iter().map(LintGroup::allowed_name))
+        .filter(|name| *name != UnusedAllow.allowed_name())
+        .find(|name| *name == argument)
+}
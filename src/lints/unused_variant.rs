@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId, VariantId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::Expr;
+use cairo_lang_semantic::items::enm::EnumSemantic;
+use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_syntax::node::ast::{OptionVisibilityPubArgumentClause, Visibility};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::queries::get_all_function_bodies;
+
+pub struct UnusedEnumVariant;
+
+/// ## What it does
+///
+/// Checks for enum variants which are never constructed within the crate, only matched on or not
+/// referenced at all. Such variants are most likely dead code.
+///
+/// The variants of public enums are not checked by default, as they can be constructed by the
+/// crates depending on this one. It can be changed with the `check_public_enum_variants` option.
+///
+/// ## Example
+///
+/// ```cairo
+/// #[derive(Drop)]
+/// enum Shape {
+///     Circle: u32,
+///     Square: u32,
+/// }
+///
+/// fn area(shape: Shape) -> u32 {
+///     match shape {
+///         Shape::Circle(radius) => 3 * radius * radius,
+///         Shape::Square(side) => side * side,
+///     }
+/// }
+///
+/// fn main() -> u32 {
+///     area(Shape::Square(2))
+/// }
+/// ```
+///
+/// `Shape::Circle` is never constructed.
+impl Lint for UnusedEnumVariant {
+    fn allowed_name(&self) -> &'static str {
+        "unused_enum_variant"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0108"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Enum variant is never constructed. Consider removing it."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UnusedEnumVariant
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_unused_enum_variant<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let ModuleItemId::Enum(enum_id) = item else {
+        return;
+    };
+    // `pub(crate)` enums can't be used outside of the crate, so they are always checked.
+    if !params.check_public_enum_variants
+        && let Visibility::Pub(visibility) = enum_id.stable_ptr(db).lookup(db).visibility(db)
+        && matches!(
+            visibility.argument_clause(db),
+            OptionVisibilityPubArgumentClause::Empty(_)
+        )
+    {
+        return;
+    }
+    let Ok(variants) = db.enum_variants(*enum_id) else {
+        return;
+    };
+
+    let constructed_variants =
+        constructed_enum_variants(db, enum_id.parent_module(db).owning_crate(db));
+    for variant in variants.values() {
+        if constructed_variants.contains(variant) {
+            continue;
+        }
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: variant.stable_ptr(db).untyped(),
+            message: UnusedEnumVariant.diagnostic_message().to_string(),
+            severity: Severity::Warning,
+            inner_span: None,
+            error_code: None,
+        });
+    }
+}
+
+/// Returns the enum variants constructed anywhere in the functions of the crate, including the
+/// ones generated by plugins, e.g. by `#[derive(Serde)]`.
+#[salsa::tracked(returns(ref))]
+fn constructed_enum_variants<'db>(
+    db: &'db dyn Database,
+    crate_id: CrateId<'db>,
+) -> HashSet<VariantId<'db>> {
+    db.crate_modules(crate_id)
+        .iter()
+        .filter_map(|module_id| module_id.module_data(db).ok())
+        .flat_map(|module_data| module_data.items(db).iter())
+        .flat_map(|item| get_all_function_bodies(db, item))
+        .flat_map(|function_body| function_body.arenas.exprs.iter())
+        .filter_map(|(_expression_id, expression)| match expression {
+            Expr::EnumVariantCtor(variant_ctor) => Some(variant_ctor.variant.id),
+            _ => None,
+        })
+        .collect()
+}
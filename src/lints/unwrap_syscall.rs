@@ -1,5 +1,6 @@
+use crate::LinterDiagnosticParams;
 use crate::{
-    context::{CairoLintKind, Lint},
+    context::{CairoLintKind, Lint, LintGroup},
     fixer::InternalFix,
     queries::{get_all_function_bodies, get_all_function_calls},
 };
@@ -53,6 +54,10 @@ impl Lint for UnwrapSyscall {
         "unwrap_syscall"
     }
 
+    fn code(&self) -> &'static str {
+        "CL0050"
+    }
+
     fn diagnostic_message(&self) -> &'static str {
         "consider using `unwrap_syscall` instead of `unwrap`"
     }
@@ -61,6 +66,10 @@ impl Lint for UnwrapSyscall {
         CairoLintKind::UnwrapSyscall
     }
 
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
     fn has_fixer(&self) -> bool {
         true
     }
@@ -78,6 +87,7 @@ impl Lint for UnwrapSyscall {
 pub fn check_unwrap_syscall<'db>(
     db: &'db dyn Database,
     item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
     diagnostics: &mut Vec<PluginDiagnostic<'db>>,
 ) {
     let function_bodies = get_all_function_bodies(db, item);
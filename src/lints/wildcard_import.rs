@@ -0,0 +1,112 @@
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+
+pub struct WildcardImport;
+
+/// ## What it does
+///
+/// Checks for wildcard imports, which bring all the items of a module into scope.
+///
+/// ## Example
+///
+/// ```cairo
+/// use core::num::traits::*;
+///
+/// fn main() -> u32 {
+///     Zero::zero()
+/// }
+/// ```
+///
+/// Should be written as:
+///
+/// ```cairo
+/// use core::num::traits::Zero;
+///
+/// fn main() -> u32 {
+///     Zero::zero()
+/// }
+/// ```
+impl Lint for WildcardImport {
+    fn allowed_name(&self) -> &'static str {
+        "wildcard_import"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0090"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "Wildcard import obscures which names are in scope. Consider importing the used items explicitly."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::WildcardImport
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_wildcard_import<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    // Wildcard imports aren't module items, so the imports of a module are checked along with its
+    // first item.
+    let Ok(module_data) = item.parent_module(db).module_data(db) else {
+        return;
+    };
+    if module_data.items(db).first() != Some(item) {
+        return;
+    }
+    let item_node = item.stable_location(db).stable_ptr().lookup(db);
+    let Some(item_list) = item_node
+        .ancestors_with_self(db)
+        .filter_map(|node| node.parent(db))
+        .find(|parent| parent.kind(db) == SyntaxKind::ModuleItemList)
+    else {
+        return;
+    };
+
+    for use_item in item_list
+        .get_children(db)
+        .iter()
+        .filter(|child| child.kind(db) == SyntaxKind::ItemUse)
+    {
+        for star in use_item
+            .descendants(db)
+            .filter(|node| node.kind(db) == SyntaxKind::UsePathStar)
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: wildcard_path(db, star).stable_ptr(db),
+                message: WildcardImport.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Returns the whole path ending with the `*`, e.g. `a::b::*` in `use a::b::*;` or `b::*` in
+/// `use a::{b::*, c};`.
+fn wildcard_path<'db>(db: &'db dyn Database, star: SyntaxNode<'db>) -> SyntaxNode<'db> {
+    let mut path = star;
+    while let Some(parent) = path.parent(db)
+        && parent.kind(db) == SyntaxKind::UsePathSingle
+    {
+        path = parent;
+    }
+    path
+}
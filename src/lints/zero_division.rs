@@ -0,0 +1,123 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{BinaryOperator, ExprBinary};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use crate::LinterDiagnosticParams;
+use crate::context::{CairoLintKind, Lint, LintGroup};
+use crate::fixer::InternalFix;
+use crate::helper::is_side_effect_free_syntax;
+use crate::helper::{is_zero, replace_preserving_trivia};
+use crate::lints::{REM, function_trait_name_from_fn_id};
+use crate::queries::{get_all_function_bodies, get_all_function_calls};
+
+pub struct ZeroDividedBy;
+
+/// ## What it does
+///
+/// Checks for zero taken modulo a value, which always results in zero.
+///
+/// Zero divided by a value, i.e. `0 / x`, is still reported by `erasing_op`, so that existing
+/// `#[allow(erasing_op)]` attributes keep silencing it. Only `0 % x` is reported by this lint.
+///
+/// ## Example
+///
+/// ```cairo
+/// fn main(x: u32) -> u32 {
+///     0 % x
+/// }
+/// ```
+///
+/// Can be simplified to:
+///
+/// ```cairo
+/// fn main(x: u32) -> u32 {
+///     0
+/// }
+/// ```
+impl Lint for ZeroDividedBy {
+    fn allowed_name(&self) -> &'static str {
+        "zero_divided_by"
+    }
+
+    fn code(&self) -> &'static str {
+        "CL0112"
+    }
+
+    fn diagnostic_message(&self) -> &'static str {
+        "The remainder of zero divided by a value is always zero. Consider replacing the entire expression with 0."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ZeroDividedBy
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn has_fixer(&self) -> bool {
+        true
+    }
+
+    fn fix<'db>(&self, db: &'db dyn Database, node: SyntaxNode<'db>) -> Option<InternalFix<'db>> {
+        fix_zero_divided_by(db, node)
+    }
+
+    fn fix_message(&self) -> Option<&'static str> {
+        Some("Replace the expression with 0")
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn check_zero_divided_by<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+    _params: &LinterDiagnosticParams,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) {
+    let function_bodies = get_all_function_bodies(db, item);
+    for function_body in function_bodies.iter() {
+        let arenas = &function_body.arenas;
+        for function_call in get_all_function_calls(function_body) {
+            // `0 / x` is reported by `erasing_op`.
+            if function_trait_name_from_fn_id(db, &function_call.function) != REM {
+                continue;
+            }
+            if !is_zero(&function_call.args[0], arenas) {
+                continue;
+            }
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: function_call.stable_ptr.untyped(),
+                message: ZeroDividedBy.diagnostic_message().to_string(),
+                severity: Severity::Warning,
+                inner_span: None,
+                error_code: None,
+            });
+        }
+    }
+}
+
+/// Replaces the modulo operation with its zero numerator, if the divisor has no side effects.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn fix_zero_divided_by<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+) -> Option<InternalFix<'db>> {
+    let binary = ExprBinary::cast(db, node)?;
+    if !matches!(binary.op(db), BinaryOperator::Mod(_))
+        || !is_side_effect_free_syntax(db, &binary.rhs(db))
+    {
+        return None;
+    }
+    let zero = binary.lhs(db).as_syntax_node().get_text_without_trivia(db);
+
+    Some(InternalFix {
+        node,
+        suggestion: replace_preserving_trivia(db, node, zero.long(db)),
+        description: ZeroDividedBy.fix_message().unwrap().to_string(),
+        import_addition_paths: None,
+    })
+}
@@ -3,7 +3,7 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
 use salsa::Database;
 
-use crate::context::get_unique_allowed_names;
+use crate::context::{LintGroup, get_unique_allowed_names};
 
 pub fn cairo_lint_allow_plugin_suite() -> PluginSuite {
     let mut suite = PluginSuite::default();
@@ -13,6 +13,9 @@ pub fn cairo_lint_allow_plugin_suite() -> PluginSuite {
 
 /// Plugin with `declared_allows` that does not emit diagnostics.
 /// Add it to avoid compiler warnings on unsupported `allow` attribute arguments.
+///
+/// The lint diagnostics themselves come from the `linter_diagnostics` query, which also applies
+/// the configured severities, e.g. reports the denied lints as errors.
 #[derive(Debug, Default)]
 pub struct CairoLintAllow;
 
@@ -27,7 +30,8 @@ impl AnalyzerPlugin for CairoLintAllow {
 
     fn declared_allows(&self) -> Vec<String> {
         get_unique_allowed_names()
-            .iter()
+            .into_iter()
+            .chain(LintGroup::all().iter().map(LintGroup::allowed_name))
             .map(ToString::to_string)
             .collect()
     }
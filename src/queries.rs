@@ -3,11 +3,12 @@ use cairo_lang_semantic::items::function_with_body::FunctionWithBodySemantic;
 use cairo_lang_semantic::items::imp::ImplSemantic;
 use cairo_lang_semantic::items::trt::TraitSemantic;
 use cairo_lang_semantic::{
-    Arenas, Condition, Expr, ExprFunctionCall, ExprIf, ExprLogicalOperator, ExprLoop, ExprMatch,
-    ExprWhile, FunctionBody, Pattern, Statement, StatementBreak,
+    Arenas, Condition, Expr, ExprAssignment, ExprFor, ExprFunctionCall, ExprIf,
+    ExprLogicalOperator, ExprLoop, ExprMatch, ExprWhile, FunctionBody, Pattern, Statement,
+    StatementBreak,
 };
 use cairo_lang_syntax::node::TypedSyntaxNode;
-use cairo_lang_syntax::node::ast::{ExprInlineMacro, ExprParenthesized};
+use cairo_lang_syntax::node::ast::{ExprInlineMacro, ExprParenthesized, ExprUnary};
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
 use if_chain::if_chain;
@@ -89,6 +90,26 @@ pub fn get_all_parenthesized_expressions<'db>(
         .collect()
 }
 
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn get_all_unary_expressions<'db>(
+    db: &'db dyn Database,
+    item: &ModuleItemId<'db>,
+) -> Vec<ExprUnary<'db>> {
+    let node = match item {
+        ModuleItemId::Constant(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::FreeFunction(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        ModuleItemId::Impl(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        // Trait can have a default function impl.
+        ModuleItemId::Trait(id) => id.stable_ptr(db).lookup(db).as_syntax_node(),
+        _ => return vec![],
+    };
+
+    node.descendants(db)
+        .filter(|node| node.kind(db) == SyntaxKind::ExprUnary)
+        .map(|node| ExprUnary::from_syntax_node(db, node))
+        .collect()
+}
+
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn get_all_match_expressions<'db>(
     function_body: &'db FunctionBody<'db>,
@@ -123,6 +144,22 @@ pub fn get_all_loop_expressions<'db>(function_body: &'db FunctionBody<'db>) -> V
         .collect()
 }
 
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn get_all_for_expressions<'db>(function_body: &'db FunctionBody<'db>) -> Vec<ExprFor<'db>> {
+    function_body
+        .arenas
+        .exprs
+        .iter()
+        .filter_map(|(_expression_id, expression)| {
+            if let Expr::For(expr_for) = expression {
+                Some(expr_for.clone())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn get_all_function_calls<'db>(
     function_body: &'db FunctionBody<'db>,
@@ -185,6 +222,24 @@ pub fn get_all_conditions<'db>(function_body: &'db FunctionBody<'db>) -> Vec<Con
     chain!(if_expr_conditions, while_expr_conditions,).collect()
 }
 
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn get_all_assignment_expressions<'db>(
+    function_body: &'db FunctionBody<'db>,
+) -> Vec<ExprAssignment<'db>> {
+    function_body
+        .arenas
+        .exprs
+        .iter()
+        .filter_map(|(_expression_id, expression)| {
+            if let Expr::Assignment(expr_assignment) = expression {
+                Some(expr_assignment.clone())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 #[tracing::instrument(skip_all, level = "trace")]
 pub fn get_all_while_expressions<'db>(
     function_body: &'db FunctionBody<'db>,
@@ -0,0 +1,78 @@
+//! A downstream-style consumer, which only depends on the `api` module.
+
+use cairo_lang_diagnostics::Severity;
+use cairo_lint::api::{
+    API_VERSION, CairoLintSeverities, CairoLintToolMetadata, DiagnosticFixSuggestion, FixSummary,
+    LintSeverity, LinterDiagnosticParams, lint_string,
+};
+
+use crate::helpers::get_corelib_path;
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+fn lint(params: &LinterDiagnosticParams) -> Vec<(String, Severity)> {
+    lint_string(
+        DOUBLE_PARENS,
+        params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| (diagnostic.message, diagnostic.severity))
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn api_version_is_set() {
+    assert_eq!(API_VERSION, 1);
+}
+
+#[test]
+fn lint_with_default_params() {
+    assert_eq!(
+        lint(&LinterDiagnosticParams::default()),
+        vec![(
+            "unnecessary double parentheses found. Consider removing them.".to_string(),
+            Severity::Warning
+        )]
+    );
+}
+
+#[test]
+fn lint_disabled_in_tool_metadata() {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("double_parens".to_string(), false)]),
+        ..Default::default()
+    };
+    assert_eq!(lint(&params), vec![]);
+}
+
+#[test]
+fn lint_denied_in_severities() {
+    let params = LinterDiagnosticParams {
+        severities: CairoLintSeverities::from([("double_parens".to_string(), LintSeverity::Deny)]),
+        ..Default::default()
+    };
+    assert_eq!(
+        lint(&params),
+        vec![(
+            "unnecessary double parentheses found. Consider removing them.".to_string(),
+            Severity::Error
+        )]
+    );
+}
+
+#[test]
+fn summary_of_no_fixes() {
+    let fixes: Vec<DiagnosticFixSuggestion> = vec![];
+    let summary = FixSummary::from_fixes(&fixes);
+    assert_eq!(summary.fixes_count(), 0);
+    assert_eq!(summary.lints_count(), 0);
+}
@@ -0,0 +1,63 @@
+use cairo_lang_formatter::FormatterConfig;
+use cairo_lint::{LinterAnalysisDatabase, LinterDiagnosticParams, apply_single_fix, get_fixes};
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags, init_corelib,
+    setup::setup_test_crate_ex,
+};
+
+const SYSCALL_AND_DOUBLE_PARENS: &str = r#"use starknet::syscalls::get_execution_info_syscall;
+
+fn main() {
+    let result = get_execution_info_syscall();
+    result.unwrap();
+}
+
+fn foo() -> u32 {
+    ((0))
+}
+"#;
+
+const SYSCALL_FIXED: &str = r#"use starknet::SyscallResultTrait;
+use starknet::syscalls::get_execution_info_syscall;
+
+fn main() {
+    let result = get_execution_info_syscall();
+    result.unwrap_syscall();
+}
+
+fn foo() -> u32 {
+    ((0))
+}
+"#;
+
+#[test]
+fn apply_only_selected_fix() {
+    let mut db = LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap();
+    let test_crate = setup_test_crate_ex(&mut db, SYSCALL_AND_DOUBLE_PARENS);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    let linter_params = LinterDiagnosticParams {
+        only_generated_files: true,
+        tool_metadata: get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+        ..Default::default()
+    };
+    let (file_id, fixes) = get_fixes(&db, &linter_params, diags)
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_eq!(fixes.len(), 2);
+    let unwrap_syscall_fix = fixes
+        .iter()
+        .find(|fix| fix.lint_names == ["unwrap_syscall"])
+        .unwrap();
+
+    let fixed_content =
+        apply_single_fix(file_id, unwrap_syscall_fix, &db, FormatterConfig::default()).unwrap();
+
+    assert_eq!(fixed_content, SYSCALL_FIXED);
+}
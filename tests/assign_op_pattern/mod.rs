@@ -0,0 +1,349 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const ADDITION: &str = r#"
+fn main() -> u32 {
+    let mut counter: u32 = 0;
+    counter = counter + 1;
+    counter
+}
+"#;
+
+const ADDITION_ALLOWED: &str = r#"
+fn main() -> u32 {
+    let mut counter: u32 = 0;
+    #[allow(assign_op_pattern)]
+    counter = counter + 1;
+    counter
+}
+"#;
+
+const MULTIPLICATION: &str = r#"
+fn main(scale: u32) -> u32 {
+    let mut total: u32 = 10;
+    total = total * scale;
+    total
+}
+"#;
+
+const NON_COMMUTATIVE_OPERATIONS: &str = r#"
+fn main(y: u32) -> u32 {
+    let mut x: u32 = 100;
+    x = x - y;
+    x = x / 2;
+    x = x % 7;
+    x
+}
+"#;
+
+const COMMUTATIVE_OPERAND_ON_THE_RIGHT: &str = r#"
+fn main(y: u32) -> u32 {
+    let mut x: u32 = 5;
+    x = y + x;
+    x = y * x;
+    x
+}
+"#;
+
+const NON_COMMUTATIVE_OPERAND_ON_THE_RIGHT: &str = r#"
+fn main(y: u32) -> u32 {
+    let mut x: u32 = 5;
+    x = y - x;
+    x = y / x;
+    x
+}
+"#;
+
+const BYTE_ARRAY_OPERAND_ON_THE_RIGHT: &str = r#"
+fn main(t: ByteArray) -> ByteArray {
+    let mut s: ByteArray = "world";
+    s = t + s;
+    s
+}
+"#;
+
+const CUSTOM_TYPE_OPERAND_ON_THE_RIGHT: &str = r#"
+#[derive(Copy, Drop)]
+struct Meters {
+    value: u32,
+}
+
+impl MetersAdd of Add<Meters> {
+    fn add(lhs: Meters, rhs: Meters) -> Meters {
+        Meters { value: lhs.value + rhs.value }
+    }
+}
+
+fn main(y: Meters) -> Meters {
+    let mut x = Meters { value: 5 };
+    x = y + x;
+    x
+}
+"#;
+
+const COMPLEX_OPERAND: &str = r#"
+fn main(y: u32, z: u32) -> u32 {
+    let mut x: u32 = 5;
+    x = x + y * z;
+    x
+}
+"#;
+
+const TARGET_NOT_THE_LEFT_OPERAND: &str = r#"
+fn main(y: u32, z: u32) -> u32 {
+    let mut x: u32 = 5;
+    x = x * y + z;
+    x
+}
+"#;
+
+const STRUCT_MEMBER: &str = r#"
+#[derive(Drop)]
+struct Counter {
+    value: u32,
+}
+
+fn main() -> u32 {
+    let mut counter = Counter { value: 0 };
+    counter.value = counter.value + 1;
+    counter.value
+}
+"#;
+
+#[test]
+fn addition_diagnostics() {
+    test_lint_diagnostics!(ADDITION, @r"
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:4:5
+        counter = counter + 1;
+        ^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn addition_fixer() {
+    test_lint_fixer!(ADDITION, @r"
+    fn main() -> u32 {
+        let mut counter: u32 = 0;
+        counter += 1;
+        counter
+    }
+    ");
+}
+
+#[test]
+fn addition_allowed_diagnostics() {
+    test_lint_diagnostics!(ADDITION_ALLOWED, @"");
+}
+
+#[test]
+fn addition_allowed_fixer() {
+    test_lint_fixer!(ADDITION_ALLOWED, @r"
+    fn main() -> u32 {
+        let mut counter: u32 = 0;
+        #[allow(assign_op_pattern)]
+        counter = counter + 1;
+        counter
+    }
+    ");
+}
+
+#[test]
+fn multiplication_diagnostics() {
+    test_lint_diagnostics!(MULTIPLICATION, @r"
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:4:5
+        total = total * scale;
+        ^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn multiplication_fixer() {
+    test_lint_fixer!(MULTIPLICATION, @r"
+    fn main(scale: u32) -> u32 {
+        let mut total: u32 = 10;
+        total *= scale;
+        total
+    }
+    ");
+}
+
+#[test]
+fn non_commutative_operations_diagnostics() {
+    test_lint_diagnostics!(NON_COMMUTATIVE_OPERATIONS, @r"
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:4:5
+        x = x - y;
+        ^^^^^^^^^
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:5:5
+        x = x / 2;
+        ^^^^^^^^^
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:6:5
+        x = x % 7;
+        ^^^^^^^^^
+    ");
+}
+
+#[test]
+fn non_commutative_operations_fixer() {
+    test_lint_fixer!(NON_COMMUTATIVE_OPERATIONS, @r"
+    fn main(y: u32) -> u32 {
+        let mut x: u32 = 100;
+        x -= y;
+        x /= 2;
+        x %= 7;
+        x
+    }
+    ");
+}
+
+#[test]
+fn commutative_operand_on_the_right_diagnostics() {
+    test_lint_diagnostics!(COMMUTATIVE_OPERAND_ON_THE_RIGHT, @r"
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:4:5
+        x = y + x;
+        ^^^^^^^^^
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:5:5
+        x = y * x;
+        ^^^^^^^^^
+    ");
+}
+
+#[test]
+fn commutative_operand_on_the_right_fixer() {
+    test_lint_fixer!(COMMUTATIVE_OPERAND_ON_THE_RIGHT, @r"
+    fn main(y: u32) -> u32 {
+        let mut x: u32 = 5;
+        x += y;
+        x *= y;
+        x
+    }
+    ");
+}
+
+#[test]
+fn non_commutative_operand_on_the_right_diagnostics() {
+    test_lint_diagnostics!(NON_COMMUTATIVE_OPERAND_ON_THE_RIGHT, @"");
+}
+
+#[test]
+fn non_commutative_operand_on_the_right_fixer() {
+    test_lint_fixer!(NON_COMMUTATIVE_OPERAND_ON_THE_RIGHT, @r"
+    fn main(y: u32) -> u32 {
+        let mut x: u32 = 5;
+        x = y - x;
+        x = y / x;
+        x
+    }
+    ");
+}
+
+#[test]
+fn byte_array_operand_on_the_right_diagnostics() {
+    test_lint_diagnostics!(BYTE_ARRAY_OPERAND_ON_THE_RIGHT, @"");
+}
+
+#[test]
+fn byte_array_operand_on_the_right_fixer() {
+    test_lint_fixer!(BYTE_ARRAY_OPERAND_ON_THE_RIGHT, @r#"
+    fn main(t: ByteArray) -> ByteArray {
+        let mut s: ByteArray = "world";
+        s = t + s;
+        s
+    }
+    "#);
+}
+
+#[test]
+fn custom_type_operand_on_the_right_diagnostics() {
+    test_lint_diagnostics!(CUSTOM_TYPE_OPERAND_ON_THE_RIGHT, @"");
+}
+
+#[test]
+fn custom_type_operand_on_the_right_fixer() {
+    test_lint_fixer!(CUSTOM_TYPE_OPERAND_ON_THE_RIGHT, @r"
+    #[derive(Copy, Drop)]
+    struct Meters {
+        value: u32,
+    }
+
+    impl MetersAdd of Add<Meters> {
+        fn add(lhs: Meters, rhs: Meters) -> Meters {
+            Meters { value: lhs.value + rhs.value }
+        }
+    }
+
+    fn main(y: Meters) -> Meters {
+        let mut x = Meters { value: 5 };
+        x = y + x;
+        x
+    }
+    ");
+}
+
+#[test]
+fn complex_operand_diagnostics() {
+    test_lint_diagnostics!(COMPLEX_OPERAND, @r"
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:4:5
+        x = x + y * z;
+        ^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn complex_operand_fixer() {
+    test_lint_fixer!(COMPLEX_OPERAND, @r"
+    fn main(y: u32, z: u32) -> u32 {
+        let mut x: u32 = 5;
+        x += y * z;
+        x
+    }
+    ");
+}
+
+#[test]
+fn target_not_the_left_operand_diagnostics() {
+    test_lint_diagnostics!(TARGET_NOT_THE_LEFT_OPERAND, @"");
+}
+
+#[test]
+fn target_not_the_left_operand_fixer() {
+    test_lint_fixer!(TARGET_NOT_THE_LEFT_OPERAND, @r"
+    fn main(y: u32, z: u32) -> u32 {
+        let mut x: u32 = 5;
+        x = x * y + z;
+        x
+    }
+    ");
+}
+
+#[test]
+fn struct_member_diagnostics() {
+    test_lint_diagnostics!(STRUCT_MEMBER, @r"
+    Plugin diagnostic: Manual implementation of an assign operation. Consider using a compound assignment operator.
+     --> lib.cairo:9:5
+        counter.value = counter.value + 1;
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn struct_member_fixer() {
+    test_lint_fixer!(STRUCT_MEMBER, @r"
+    #[derive(Drop)]
+    struct Counter {
+        value: u32,
+    }
+
+    fn main() -> u32 {
+        let mut counter = Counter { value: 0 };
+        counter.value += 1;
+        counter.value
+    }
+    ");
+}
@@ -0,0 +1,93 @@
+use cairo_lint::diagnostics::baseline::{Baseline, filter_baseline_diagnostics, generate_baseline};
+use cairo_lint::diagnostics::format_diagnostic;
+
+use crate::helpers::{get_diags, init_corelib, setup::setup_test_crate_ex};
+
+const ORIGINAL: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+
+fn foo() -> u32 {
+    ((1))
+}
+"#;
+
+const MODIFIED: &str = r#"
+fn bar() -> u32 {
+    2
+}
+
+fn main() -> u32 {
+    ((0))
+}
+
+fn foo() -> u32 {
+    ((1))
+}
+
+fn baz() -> u32 {
+    ((3))
+}
+"#;
+
+fn linter_database() -> ::cairo_lint::LinterAnalysisDatabase {
+    ::cairo_lint::LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap()
+}
+
+fn baseline_of(code: &str) -> Baseline {
+    let mut db = linter_database();
+    let test_crate = setup_test_crate_ex(&mut db, code);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    generate_baseline(&diags, &db)
+}
+
+#[test]
+fn baseline_filters_known_diagnostics() {
+    let baseline = baseline_of(ORIGINAL);
+    assert_eq!(baseline.entries.len(), 2);
+
+    let mut db = linter_database();
+    let test_crate = setup_test_crate_ex(&mut db, ORIGINAL);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    assert!(filter_baseline_diagnostics(diags, &baseline, &db).is_empty());
+}
+
+#[test]
+fn baseline_reports_only_new_diagnostics_after_line_shift() {
+    let baseline = baseline_of(ORIGINAL);
+
+    let mut db = linter_database();
+    let test_crate = setup_test_crate_ex(&mut db, MODIFIED);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    assert_eq!(diags.len(), 3);
+
+    let new_diags = filter_baseline_diagnostics(diags, &baseline, &db);
+    let formatted = new_diags
+        .iter()
+        .map(|diag| format_diagnostic(diag, &db))
+        .collect::<Vec<_>>();
+    insta::assert_snapshot!(formatted.join(""), @r"
+    Plugin diagnostic: unnecessary double parentheses found. Consider removing them.
+     --> lib.cairo:15:5
+        ((3))
+        ^^^^^
+    ");
+}
+
+#[test]
+fn baseline_round_trip() {
+    let baseline = baseline_of(ORIGINAL);
+    let serialized = serde_json::to_string(&baseline).unwrap();
+    let deserialized: Baseline = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, baseline);
+    assert_eq!(deserialized.entries[0].lint, "double_parens");
+    assert_eq!(deserialized.entries[0].fingerprint, "((0))");
+}
@@ -55,6 +55,30 @@ fn main() {
 }
 "#;
 
+const EVEN_CHECK: &str = r#"
+fn main() {
+    let x = 17_u64;
+    if (x & 1) == 0 {
+        println!("Even number");
+    }
+}
+"#;
+
+const WITH_BINARY_OPERAND: &str = r#"
+fn main() {
+    let x = 17_u32;
+    let y = 4_u32;
+    let _a = x + y & 1;
+}
+"#;
+
+const WITH_OTHER_MASK: &str = r#"
+fn main() {
+    let x = 17_u32;
+    let _a = x & 3;
+}
+"#;
+
 #[test]
 fn with_single_variable_diagnostics() {
     test_lint_diagnostics!(WITH_SINGLE_VARIABLE, @r"
@@ -69,7 +93,7 @@ fn with_single_variable_diagnostics() {
 fn with_single_variable_fixer() {
     test_lint_fixer!(WITH_SINGLE_VARIABLE, @r#"
     fn main() {
-        let _a = 200_u32 & 1;
+        let _a = 200_u32 % 2;
     }
     "#);
 }
@@ -94,7 +118,7 @@ fn with_multiple_variables_fixer() {
     fn main() {
         let x = 150_u32;
         let y = 47;
-        let _result = (x & 1) + (y & 1);
+        let _result = (x % 2) + (y % 2);
     }
     "#);
 }
@@ -132,7 +156,7 @@ fn in_a_loop_fixer() {
     test_lint_fixer!(IN_A_LOOP, @r#"
     fn main() {
         for i in 0..10_u8 {
-            let y = i & 1;
+            let y = i % 2;
             println!("{}", y);
         }
     }
@@ -154,7 +178,7 @@ fn with_conditional_logic_fixer() {
     test_lint_fixer!(WITH_CONDITIONAL_LOGIC, @r#"
     fn main() {
         let x = 17_u32;
-        if (x & 1) == 1 {
+        if x % 2 == 1 {
             println!("Odd number");
         } else {
             println!("Even number");
@@ -183,3 +207,51 @@ fn with_conditional_logic_allowed_fixer() {
     }
     "#);
 }
+
+#[test]
+fn even_check_diagnostics() {
+    test_lint_diagnostics!(EVEN_CHECK, @r"
+    Plugin diagnostic: You seem to be trying to use `&` for parity check. Consider using `DivRem::div_rem()` instead.
+     --> lib.cairo:4:9
+        if (x & 1) == 0 {
+            ^^^^^
+    ");
+}
+
+#[test]
+fn even_check_fixer() {
+    test_lint_fixer!(EVEN_CHECK, @r#"
+    fn main() {
+        let x = 17_u64;
+        if x % 2 == 0 {
+            println!("Even number");
+        }
+    }
+    "#);
+}
+
+#[test]
+fn with_binary_operand_diagnostics() {
+    test_lint_diagnostics!(WITH_BINARY_OPERAND, @r"
+    Plugin diagnostic: You seem to be trying to use `&` for parity check. Consider using `DivRem::div_rem()` instead.
+     --> lib.cairo:5:14
+        let _a = x + y & 1;
+                 ^^^^^^^^^
+    ");
+}
+
+#[test]
+fn with_binary_operand_fixer() {
+    test_lint_fixer!(WITH_BINARY_OPERAND, @r#"
+    fn main() {
+        let x = 17_u32;
+        let y = 4_u32;
+        let _a = (x + y) % 2;
+    }
+    "#);
+}
+
+#[test]
+fn with_other_mask_diagnostics() {
+    test_lint_diagnostics!(WITH_OTHER_MASK, @"");
+}
@@ -82,6 +82,26 @@ fn main() {
 }
 "#;
 
+const NESTED_COMPARISONS_IN_LOGICAL_AND: &str = r#"
+fn main(x: bool, y: bool) {
+    if (x == true) && (y == false) {
+        println!("x and not y");
+    }
+}
+"#;
+
+const NESTED_COMPARISON_IN_LOGICAL_OR: &str = r#"
+fn main(x: bool, y: bool) -> bool {
+    y || (x != true)
+}
+"#;
+
+const COMPARISON_IN_ASSERT: &str = r#"
+fn main(x: bool) {
+    assert!(x == true, "x should be true");
+}
+"#;
+
 #[test]
 fn comparison_with_true_diagnostics() {
     test_lint_diagnostics!(COMPARISON_WITH_TRUE, @r"
@@ -276,3 +296,66 @@ fn negated_comparison_with_false_on_lhs_fixer() {
     }
     "#);
 }
+
+#[test]
+fn nested_comparisons_in_logical_and_diagnostics() {
+    test_lint_diagnostics!(NESTED_COMPARISONS_IN_LOGICAL_AND, @r"
+    Plugin diagnostic: Unnecessary comparison with a boolean value. Use the variable directly.
+     --> lib.cairo:3:9
+        if (x == true) && (y == false) {
+            ^^^^^^^^^
+    Plugin diagnostic: Unnecessary comparison with a boolean value. Use the variable directly.
+     --> lib.cairo:3:24
+        if (x == true) && (y == false) {
+                           ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn nested_comparisons_in_logical_and_fixer() {
+    test_lint_fixer!(NESTED_COMPARISONS_IN_LOGICAL_AND, @r#"
+    fn main(x: bool, y: bool) {
+        if x && !y {
+            println!("x and not y");
+        }
+    }
+    "#);
+}
+
+#[test]
+fn nested_comparison_in_logical_or_diagnostics() {
+    test_lint_diagnostics!(NESTED_COMPARISON_IN_LOGICAL_OR, @r"
+    Plugin diagnostic: Unnecessary comparison with a boolean value. Use the variable directly.
+     --> lib.cairo:3:11
+        y || (x != true)
+              ^^^^^^^^^
+    ");
+}
+
+#[test]
+fn nested_comparison_in_logical_or_fixer() {
+    test_lint_fixer!(NESTED_COMPARISON_IN_LOGICAL_OR, @r"
+    fn main(x: bool, y: bool) -> bool {
+        y || !x
+    }
+    ");
+}
+
+#[test]
+fn comparison_in_assert_diagnostics() {
+    test_lint_diagnostics!(COMPARISON_IN_ASSERT, @r#"
+    Plugin diagnostic: Unnecessary comparison with a boolean value. Use the variable directly.
+     --> lib.cairo:3:13
+        assert!(x == true, "x should be true");
+                ^^^^^^^^^
+    "#);
+}
+
+#[test]
+fn comparison_in_assert_fixer() {
+    test_lint_fixer!(COMPARISON_IN_ASSERT, @r#"
+    fn main(x: bool) {
+        assert!(x, "x should be true");
+    }
+    "#);
+}
@@ -0,0 +1,202 @@
+use crate::test_lint_diagnostics;
+
+const SHARED_PREFIX: &str = r#"
+fn foo(_x: u32) {}
+
+fn main(flag: bool) {
+    if flag {
+        foo(0);
+        foo(1);
+    } else {
+        foo(0);
+        foo(2);
+    }
+}
+"#;
+
+const SHARED_SUFFIX: &str = r#"
+fn foo(_x: u32) {}
+
+fn main(flag: bool) {
+    if flag {
+        foo(1);
+        foo(3);
+    } else {
+        foo(2);
+        foo(3);
+    }
+}
+"#;
+
+const SHARED_PREFIX_AND_SUFFIX: &str = r#"
+fn foo(_x: u32) {}
+
+fn main(flag: bool) {
+    if flag {
+        foo(0);
+        foo(1);
+        foo(3);
+    } else {
+        foo(0);
+        foo(2);
+        foo(3);
+    }
+}
+"#;
+
+const SHARED_PREFIX_WITH_TAIL: &str = r#"
+fn foo(_x: u32) {}
+
+fn main(flag: bool) -> u32 {
+    if flag {
+        foo(0);
+        1
+    } else {
+        foo(0);
+        2
+    }
+}
+"#;
+
+const SHARED_SUFFIX_BEFORE_TAIL: &str = r#"
+fn foo(_x: u32) {}
+
+fn main(flag: bool) -> u32 {
+    if flag {
+        foo(1);
+        foo(3);
+        1
+    } else {
+        foo(2);
+        foo(3);
+        2
+    }
+}
+"#;
+
+const SHARED_SUFFIX_USING_BRANCH_VARIABLES: &str = r#"
+fn foo(_x: u32) {}
+
+fn main(flag: bool) {
+    if flag {
+        let x = 1;
+        foo(x);
+    } else {
+        let x = 2;
+        foo(x);
+    }
+}
+"#;
+
+const NO_SHARED_CODE: &str = r#"
+fn foo(_x: u32) {}
+
+fn main(flag: bool) {
+    if flag {
+        foo(1);
+    } else {
+        foo(2);
+    }
+}
+"#;
+
+const ELSE_IF_CHAIN: &str = r#"
+fn foo(_x: u32) {}
+
+fn main(x: u32) {
+    if x == 1 {
+        foo(0);
+        foo(1);
+    } else if x == 2 {
+        foo(0);
+        foo(2);
+    } else {
+        foo(0);
+        foo(3);
+    }
+}
+"#;
+
+const SHARED_PREFIX_ALLOWED: &str = r#"
+fn foo(_x: u32) {}
+
+#[allow(branches_sharing_code)]
+fn main(flag: bool) {
+    if flag {
+        foo(0);
+        foo(1);
+    } else {
+        foo(0);
+        foo(2);
+    }
+}
+"#;
+
+#[test]
+fn shared_prefix_diagnostics() {
+    test_lint_diagnostics!(SHARED_PREFIX, @r"
+    Plugin diagnostic: All branches of this `if` start or end with the same statements. Consider moving them out of the `if`.
+     --> lib.cairo:6:9
+            foo(0);
+            ^^^^^^^
+    ");
+}
+
+#[test]
+fn shared_suffix_diagnostics() {
+    test_lint_diagnostics!(SHARED_SUFFIX, @r"
+    Plugin diagnostic: All branches of this `if` start or end with the same statements. Consider moving them out of the `if`.
+     --> lib.cairo:7:9
+            foo(3);
+            ^^^^^^^
+    ");
+}
+
+#[test]
+fn shared_prefix_and_suffix_diagnostics() {
+    test_lint_diagnostics!(SHARED_PREFIX_AND_SUFFIX, @r"
+    Plugin diagnostic: All branches of this `if` start or end with the same statements. Consider moving them out of the `if`.
+     --> lib.cairo:6:9
+            foo(0);
+            ^^^^^^^
+    Plugin diagnostic: All branches of this `if` start or end with the same statements. Consider moving them out of the `if`.
+     --> lib.cairo:8:9
+            foo(3);
+            ^^^^^^^
+    ");
+}
+
+#[test]
+fn shared_prefix_with_tail_diagnostics() {
+    test_lint_diagnostics!(SHARED_PREFIX_WITH_TAIL, @r"
+    Plugin diagnostic: All branches of this `if` start or end with the same statements. Consider moving them out of the `if`.
+     --> lib.cairo:6:9
+            foo(0);
+            ^^^^^^^
+    ");
+}
+
+#[test]
+fn shared_suffix_before_tail_diagnostics() {
+    test_lint_diagnostics!(SHARED_SUFFIX_BEFORE_TAIL, @"");
+}
+
+#[test]
+fn shared_suffix_using_branch_variables_diagnostics() {
+    test_lint_diagnostics!(SHARED_SUFFIX_USING_BRANCH_VARIABLES, @"");
+}
+
+#[test]
+fn no_shared_code_diagnostics() {
+    test_lint_diagnostics!(NO_SHARED_CODE, @"");
+}
+
+#[test]
+fn else_if_chain_diagnostics() {
+    test_lint_diagnostics!(ELSE_IF_CHAIN, @"");
+}
+
+#[test]
+fn shared_prefix_allowed_diagnostics() {
+    test_lint_diagnostics!(SHARED_PREFIX_ALLOWED, @"");
+}
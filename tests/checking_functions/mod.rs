@@ -0,0 +1,39 @@
+use std::collections::{BTreeSet, HashSet};
+
+use cairo_lint::context::{get_all_checking_functions, get_checking_functions_for_lints};
+
+#[test]
+fn checking_functions_are_built_once() {
+    let first = get_all_checking_functions();
+    let second = get_all_checking_functions();
+
+    assert!(!first.is_empty());
+    // The same list is returned on every call instead of being rebuilt.
+    assert!(std::ptr::eq(first, second));
+}
+
+#[test]
+fn checking_functions_are_unique() {
+    let checking_functions = get_all_checking_functions();
+    let unique_count = checking_functions
+        .iter()
+        .map(|checking_function| *checking_function as usize)
+        .collect::<HashSet<_>>()
+        .len();
+
+    assert_eq!(unique_count, checking_functions.len());
+}
+
+#[test]
+fn checking_functions_for_lints_are_selected() {
+    let lint_names = BTreeSet::from(["double_parens".to_string(), "eq_comp_op".to_string()]);
+
+    assert_eq!(
+        get_checking_functions_for_lints(Some(&lint_names)).count(),
+        2
+    );
+    assert_eq!(
+        get_checking_functions_for_lints(None).count(),
+        get_all_checking_functions().len()
+    );
+}
@@ -0,0 +1,150 @@
+use crate::test_lint_diagnostics;
+
+const GREATER_LESS_ELSE: &str = r#"
+fn main(a: u32, b: u32) -> felt252 {
+    if a > b {
+        'greater'
+    } else if a < b {
+        'less'
+    } else {
+        'equal'
+    }
+}
+"#;
+
+const EQUAL_WITH_SWAPPED_OPERANDS: &str = r#"
+fn main(a: u32, b: u32) -> felt252 {
+    if a == b {
+        'equal'
+    } else if b > a {
+        'less'
+    } else {
+        'greater'
+    }
+}
+"#;
+
+const ALL_ORDERINGS_WITH_ELSE: &str = r#"
+fn main(a: u32, b: u32) -> felt252 {
+    if a < b {
+        'less'
+    } else if a == b {
+        'equal'
+    } else if a > b {
+        'greater'
+    } else {
+        'unreachable'
+    }
+}
+"#;
+
+const DIFFERENT_OPERANDS: &str = r#"
+fn main(a: u32, b: u32, c: u32) -> felt252 {
+    if a > b {
+        'greater'
+    } else if a < c {
+        'less'
+    } else {
+        'other'
+    }
+}
+"#;
+
+const SINGLE_COMPARISON: &str = r#"
+fn main(a: u32, b: u32) -> felt252 {
+    if a > b {
+        'greater'
+    } else {
+        'not greater'
+    }
+}
+"#;
+
+const OPERANDS_WITH_SIDE_EFFECTS: &str = r#"
+fn compute() -> u32 {
+    5
+}
+
+fn main(a: u32) -> felt252 {
+    if compute() > a {
+        'greater'
+    } else if compute() < a {
+        'less'
+    } else {
+        'equal'
+    }
+}
+"#;
+
+const GREATER_LESS_ELSE_ALLOWED: &str = r#"
+fn main(a: u32, b: u32) -> felt252 {
+    #[allow(comparison_chain)]
+    let result = if a > b {
+        'greater'
+    } else if a < b {
+        'less'
+    } else {
+        'equal'
+    };
+    result
+}
+"#;
+
+#[test]
+fn greater_less_else_diagnostics() {
+    test_lint_diagnostics!(GREATER_LESS_ELSE, @r"
+    Plugin diagnostic: `if` chain comparing the same operands detected. Consider matching on the ordering of the operands instead.
+     --> lib.cairo:3:5-9:5
+          if a > b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn equal_with_swapped_operands_diagnostics() {
+    test_lint_diagnostics!(EQUAL_WITH_SWAPPED_OPERANDS, @r"
+    Plugin diagnostic: `if` chain comparing the same operands detected. Consider matching on the ordering of the operands instead.
+     --> lib.cairo:3:5-9:5
+          if a == b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn all_orderings_with_else_diagnostics() {
+    test_lint_diagnostics!(ALL_ORDERINGS_WITH_ELSE, @r"
+    Plugin diagnostic: `if` chain comparing the same operands detected. Consider matching on the ordering of the operands instead.
+     --> lib.cairo:3:5-11:5
+          if a < b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn different_operands_diagnostics() {
+    test_lint_diagnostics!(DIFFERENT_OPERANDS, @"");
+}
+
+#[test]
+fn single_comparison_diagnostics() {
+    test_lint_diagnostics!(SINGLE_COMPARISON, @"");
+}
+
+#[test]
+fn operands_with_side_effects_diagnostics() {
+    test_lint_diagnostics!(OPERANDS_WITH_SIDE_EFFECTS, @"");
+}
+
+#[test]
+fn greater_less_else_allowed_diagnostics() {
+    test_lint_diagnostics!(GREATER_LESS_ELSE_ALLOWED, @"");
+}
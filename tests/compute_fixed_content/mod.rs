@@ -0,0 +1,55 @@
+use cairo_lang_formatter::FormatterConfig;
+use cairo_lint::{
+    LinterAnalysisDatabase, LinterDiagnosticParams, compute_fixed_content, get_fixes,
+};
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags, init_corelib,
+    setup::setup_test_crate_ex,
+};
+
+const TWO_LINT_KINDS: &str = r#"fn foo() -> u32 {
+    ((0))
+}
+
+fn bar(y: u32) -> u32 {
+    let x = { y };
+    x + 1
+}
+"#;
+
+const TWO_LINT_KINDS_FIXED: &str = r#"fn foo() -> u32 {
+    0
+}
+
+fn bar(y: u32) -> u32 {
+    let x = y;
+    x + 1
+}
+"#;
+
+#[test]
+fn compute_fixed_content_returns_fixed_source() {
+    let mut db = LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap();
+    let test_crate = setup_test_crate_ex(&mut db, TWO_LINT_KINDS);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    let linter_params = LinterDiagnosticParams {
+        only_generated_files: true,
+        tool_metadata: get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+        ..Default::default()
+    };
+    let (file_id, fixes) = get_fixes(&db, &linter_params, diags)
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let fixed_content =
+        compute_fixed_content(file_id, &fixes, &db, FormatterConfig::default()).unwrap();
+
+    assert_eq!(fixed_content, TWO_LINT_KINDS_FIXED);
+}
@@ -0,0 +1,25 @@
+use cairo_lint::context::{conflicting_lints, get_unique_allowed_names};
+
+#[test]
+fn conflicting_lints_are_symmetric() {
+    assert!(conflicting_lints("destruct_match").contains(&"collapsible_match"));
+    assert!(conflicting_lints("collapsible_match").contains(&"destruct_match"));
+}
+
+#[test]
+fn conflicting_lints_are_registered() {
+    let allowed_names = get_unique_allowed_names();
+    for name in allowed_names.iter() {
+        for conflicting in conflicting_lints(name) {
+            assert!(
+                allowed_names.contains(&conflicting),
+                "`{conflicting}` conflicting with `{name}` is not a registered lint"
+            );
+        }
+    }
+}
+
+#[test]
+fn lint_without_conflicts() {
+    assert!(conflicting_lints("double_parens").is_empty());
+}
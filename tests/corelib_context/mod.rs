@@ -0,0 +1,16 @@
+use cairo_lint::{LinterAnalysisDatabase, LinterGroup};
+
+use crate::helpers::init_corelib;
+
+#[test]
+fn arithmetic_trait_functions_are_resolved() {
+    let mut db = LinterAnalysisDatabase::builder().build().unwrap();
+    init_corelib(&mut db);
+    let corelib_context = db.corelib_context();
+
+    assert!(corelib_context.get_add_trait_function_id().is_some());
+    assert!(corelib_context.get_sub_trait_function_id().is_some());
+    assert!(corelib_context.get_mul_trait_function_id().is_some());
+    assert!(corelib_context.get_div_trait_function_id().is_some());
+    assert!(corelib_context.get_rem_trait_function_id().is_some());
+}
@@ -0,0 +1,45 @@
+use cairo_lint::context::CairoLintKind;
+use cairo_lint::diagnostics::{kind_of, lint_name_of};
+use cairo_lint::{LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+#[test]
+fn lint_name_of_diagnostic_suppresses_it() {
+    let (name, kind) = lint_string(
+        DOUBLE_PARENS,
+        &LinterDiagnosticParams::default(),
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            let [diagnostic] = &diagnostics[..] else {
+                panic!("Expected a single diagnostic");
+            };
+            (lint_name_of(diagnostic), kind_of(diagnostic))
+        },
+    )
+    .unwrap();
+
+    assert_eq!(name, Some("double_parens"));
+    assert_eq!(kind, CairoLintKind::DoubleParens);
+
+    let allowed = format!(
+        "\n#[allow({})]{}",
+        name.unwrap(),
+        DOUBLE_PARENS.trim_start()
+    );
+    let diagnostics_count = lint_string(
+        &allowed,
+        &LinterDiagnosticParams::default(),
+        Some(get_corelib_path()),
+        |_db, diagnostics| diagnostics.len(),
+    )
+    .unwrap();
+
+    assert_eq!(diagnostics_count, 0);
+}
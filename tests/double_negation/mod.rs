@@ -0,0 +1,219 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const DOUBLE_NOT: &str = r#"
+fn main() -> bool {
+    let b = true;
+    let c = !!b;
+    c
+}
+"#;
+
+const DOUBLE_MINUS: &str = r#"
+fn main() -> i32 {
+    let x: i32 = 5;
+    --x
+}
+"#;
+
+const DOUBLE_MINUS_LITERAL: &str = r#"
+fn main() -> i32 {
+    - -5
+}
+"#;
+
+const TRIPLE_MINUS: &str = r#"
+fn main() -> i32 {
+    let x: i32 = 5;
+    let y: i32 = 3;
+    y----x
+}
+"#;
+
+const DOUBLE_NOT_WITH_PARENS: &str = r#"
+fn main() -> bool {
+    let a = 1_u32;
+    let b = 2_u32;
+    !!(a == b)
+}
+"#;
+
+const DOUBLE_NOT_WITH_REQUIRED_PARENS: &str = r#"
+fn main() -> bool {
+    let a = 1_u32;
+    let b = 2_u32;
+    let c = false;
+    !!(a == b) && c
+}
+"#;
+
+const DOUBLE_NOT_ALLOWED: &str = r#"
+#[allow(double_negation)]
+fn main() -> bool {
+    let b = true;
+    !!b
+}
+"#;
+
+const SINGLE_NOT: &str = r#"
+fn main() -> bool {
+    let b = true;
+    !b
+}
+"#;
+
+const NOT_OF_NEGATION: &str = r#"
+fn main() -> bool {
+    let x: i32 = 5;
+    !(-x == 5)
+}
+"#;
+
+#[test]
+fn double_not_diagnostics() {
+    test_lint_diagnostics!(DOUBLE_NOT, @r"
+    Plugin diagnostic: Double negation has no effect. Consider removing both operators.
+     --> lib.cairo:4:13
+        let c = !!b;
+                ^^^
+    ");
+}
+
+#[test]
+fn double_not_fixer() {
+    test_lint_fixer!(DOUBLE_NOT, @r"
+    fn main() -> bool {
+        let b = true;
+        let c = b;
+        c
+    }
+    ");
+}
+
+#[test]
+fn double_minus_diagnostics() {
+    test_lint_diagnostics!(DOUBLE_MINUS, @r"
+    Plugin diagnostic: Double negation has no effect. Consider removing both operators.
+     --> lib.cairo:4:5
+        --x
+        ^^^
+    ");
+}
+
+#[test]
+fn double_minus_fixer() {
+    test_lint_fixer!(DOUBLE_MINUS, @r"
+    fn main() -> i32 {
+        let x: i32 = 5;
+        x
+    }
+    ");
+}
+
+#[test]
+fn double_minus_literal_diagnostics() {
+    test_lint_diagnostics!(DOUBLE_MINUS_LITERAL, @r"
+    Plugin diagnostic: Double negation has no effect. Consider removing both operators.
+     --> lib.cairo:3:5
+        - -5
+        ^^^^
+    ");
+}
+
+#[test]
+fn double_minus_literal_fixer() {
+    test_lint_fixer!(DOUBLE_MINUS_LITERAL, @r"
+    fn main() -> i32 {
+        5
+    }
+    ");
+}
+
+#[test]
+fn triple_minus_diagnostics() {
+    test_lint_diagnostics!(TRIPLE_MINUS, @r"
+    Plugin diagnostic: Double negation has no effect. Consider removing both operators.
+     --> lib.cairo:5:7
+        y----x
+          ^^^^
+    ");
+}
+
+#[test]
+fn triple_minus_fixer() {
+    test_lint_fixer!(TRIPLE_MINUS, @r"
+    fn main() -> i32 {
+        let x: i32 = 5;
+        let y: i32 = 3;
+        y- -x
+    }
+    ");
+}
+
+#[test]
+fn double_not_with_parens_diagnostics() {
+    test_lint_diagnostics!(DOUBLE_NOT_WITH_PARENS, @r"
+    Plugin diagnostic: Double negation has no effect. Consider removing both operators.
+     --> lib.cairo:5:5
+        !!(a == b)
+        ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn double_not_with_parens_fixer() {
+    test_lint_fixer!(DOUBLE_NOT_WITH_PARENS, @r"
+    fn main() -> bool {
+        let a = 1_u32;
+        let b = 2_u32;
+        a == b
+    }
+    ");
+}
+
+#[test]
+fn double_not_with_required_parens_diagnostics() {
+    test_lint_diagnostics!(DOUBLE_NOT_WITH_REQUIRED_PARENS, @r"
+    Plugin diagnostic: Double negation has no effect. Consider removing both operators.
+     --> lib.cairo:6:5
+        !!(a == b) && c
+        ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn double_not_with_required_parens_fixer() {
+    test_lint_fixer!(DOUBLE_NOT_WITH_REQUIRED_PARENS, @r"
+    fn main() -> bool {
+        let a = 1_u32;
+        let b = 2_u32;
+        let c = false;
+        (a == b) && c
+    }
+    ");
+}
+
+#[test]
+fn double_not_allowed_diagnostics() {
+    test_lint_diagnostics!(DOUBLE_NOT_ALLOWED, @"");
+}
+
+#[test]
+fn double_not_allowed_fixer() {
+    test_lint_fixer!(DOUBLE_NOT_ALLOWED, @r"
+    #[allow(double_negation)]
+    fn main() -> bool {
+        let b = true;
+        !!b
+    }
+    ");
+}
+
+#[test]
+fn single_not_diagnostics() {
+    test_lint_diagnostics!(SINGLE_NOT, @"");
+}
+
+#[test]
+fn not_of_negation_diagnostics() {
+    test_lint_diagnostics!(NOT_OF_NEGATION, @"");
+}
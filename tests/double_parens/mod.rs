@@ -165,6 +165,13 @@ fn main() {
 
 "#;
 
+const DOUBLE_PARENS_WITH_TRAILING_TRIVIA: &str = r#"
+fn main() -> u32 {
+    let x = ((0))   ; // Just a comment.
+    x
+}
+"#;
+
 #[test]
 fn simple_double_parens_diagnostics() {
     test_lint_diagnostics!(SIMPLE_DOUBLE_PARENS, @r"
@@ -701,3 +708,23 @@ fn double_parens_not_firing_for_necessary_cases_fixer() {
     }
     "#);
 }
+
+#[test]
+fn double_parens_with_trailing_trivia_diagnostics() {
+    test_lint_diagnostics!(DOUBLE_PARENS_WITH_TRAILING_TRIVIA, @r"
+    Plugin diagnostic: unnecessary double parentheses found. Consider removing them.
+     --> lib.cairo:3:13
+        let x = ((0))   ; // Just a comment.
+                ^^^^^
+    ");
+}
+
+#[test]
+fn double_parens_with_trailing_trivia_fixer() {
+    test_lint_fixer!(DOUBLE_PARENS_WITH_TRAILING_TRIVIA, @r"
+    fn main() -> u32 {
+        let x = 0; // Just a comment.
+        x
+    }
+    ");
+}
@@ -25,6 +25,18 @@ const DUPLICATE_UNDERSCORE_LONGER_ARGS4: &str = r#"
 fn foo(_test: u32, test: u32) {}
 "#;
 
+const DUPLICATE_UNDERSCORE_ARGS_USED: &str = r#"
+fn foo(a: u32, _a: u32) -> u32 {
+    a + _a
+}
+"#;
+
+const DUPLICATE_UNDERSCORE_ARGS_SUFFIX_TAKEN: &str = r#"
+fn foo(a: u32, _a: u32, _a2: u32) -> u32 {
+    _a + _a2
+}
+"#;
+
 #[test]
 fn duplicate_underscore_args_allowed_diagnostics() {
     test_lint_diagnostics!(DUPLICATE_UNDERSCORE_ARGS_ALLOWED, @r#"
@@ -52,7 +64,7 @@ fn duplicate_underscore_args2_diagnostics() {
 #[test]
 fn duplicate_underscore_args2_fixer() {
     test_lint_fixer!(DUPLICATE_UNDERSCORE_ARGS2, @r#"
-    fn foo(c: u32, _c: u32) {}
+    fn foo(c: u32, _c2: u32) {}
     "#);
 }
 
@@ -69,7 +81,7 @@ fn duplicate_underscore_longer_args_diagnostics() {
 #[test]
 fn duplicate_underscore_longer_args_fixer() {
     test_lint_fixer!(DUPLICATE_UNDERSCORE_LONGER_ARGS, @r#"
-    fn foo(test: u32, _test: u32) {}
+    fn foo(test: u32, _test2: u32) {}
     "#);
 }
 
@@ -86,7 +98,7 @@ fn duplicate_underscore_longer_args2_diagnostics() {
 #[test]
 fn duplicate_underscore_longer_args2_fixer() {
     test_lint_fixer!(DUPLICATE_UNDERSCORE_LONGER_ARGS2, @r#"
-    fn foo(darth: u32, _darth: u32) {}
+    fn foo(darth: u32, _darth2: u32) {}
     "#);
 }
 
@@ -103,7 +115,7 @@ fn duplicate_underscore_longer_args3_diagnostics() {
 #[test]
 fn duplicate_underscore_longer_args3_fixer() {
     test_lint_fixer!(DUPLICATE_UNDERSCORE_LONGER_ARGS3, @r#"
-    fn foo(stark: u32, _stark: u32) {}
+    fn foo(stark: u32, _stark2: u32) {}
     "#);
 }
 
@@ -120,6 +132,44 @@ fn duplicate_underscore_longer_args4_diagnostics() {
 #[test]
 fn duplicate_underscore_longer_args4_fixer() {
     test_lint_fixer!(DUPLICATE_UNDERSCORE_LONGER_ARGS4, @r#"
-    fn foo(_test: u32, test: u32) {}
+    fn foo(_test2: u32, test: u32) {}
+    "#);
+}
+
+#[test]
+fn duplicate_underscore_args_used_diagnostics() {
+    test_lint_diagnostics!(DUPLICATE_UNDERSCORE_ARGS_USED, @r"
+    Plugin diagnostic: duplicate arguments, having another argument having almost the same name makes code comprehension and documentation more difficult
+     --> lib.cairo:2:16
+    fn foo(a: u32, _a: u32) -> u32 {
+                   ^^
+    ");
+}
+
+#[test]
+fn duplicate_underscore_args_used_fixer() {
+    test_lint_fixer!(DUPLICATE_UNDERSCORE_ARGS_USED, @r#"
+    fn foo(a: u32, _a2: u32) -> u32 {
+        a + _a2
+    }
+    "#);
+}
+
+#[test]
+fn duplicate_underscore_args_suffix_taken_diagnostics() {
+    test_lint_diagnostics!(DUPLICATE_UNDERSCORE_ARGS_SUFFIX_TAKEN, @r"
+    Plugin diagnostic: duplicate arguments, having another argument having almost the same name makes code comprehension and documentation more difficult
+     --> lib.cairo:2:16
+    fn foo(a: u32, _a: u32, _a2: u32) -> u32 {
+                   ^^
+    ");
+}
+
+#[test]
+fn duplicate_underscore_args_suffix_taken_fixer() {
+    test_lint_fixer!(DUPLICATE_UNDERSCORE_ARGS_SUFFIX_TAKEN, @r#"
+    fn foo(a: u32, _a3: u32, _a2: u32) -> u32 {
+        _a3 + _a2
+    }
     "#);
 }
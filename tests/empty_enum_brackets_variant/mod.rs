@@ -1,3 +1,5 @@
+use cairo_lint::context::{CairoLintKind, get_lint_type_from_diagnostic_message};
+
 use crate::{test_lint_diagnostics, test_lint_fixer};
 
 const MULTIPLE_EMPTY_VARIANTS: &str = r#"
@@ -54,6 +56,26 @@ enum MyEnum {
 }
 "#;
 
+const GENERIC_ENUM_WITH_UNIT_VARIANT: &str = r#"
+#[derive(Drop)]
+enum Wrapper<T> {
+    Value: T,
+    Empty: (),
+}
+
+fn main() -> Wrapper<()> {
+    Wrapper::Empty
+}
+"#;
+
+const SPACED_UNIT_VARIANT: &str = r#"
+#[derive(Drop)]
+enum MyEnum {
+    Data: u8,
+    Empty : (),
+}
+"#;
+
 #[test]
 fn multiple_empty_variants_diagnostics() {
     test_lint_diagnostics!(MULTIPLE_EMPTY_VARIANTS, @r"
@@ -152,3 +174,57 @@ fn allow_multiple_empty_variants_fixer() {
     }
     ");
 }
+
+#[test]
+fn generic_enum_with_unit_variant_diagnostics() {
+    test_lint_diagnostics!(GENERIC_ENUM_WITH_UNIT_VARIANT, @r"
+    Plugin diagnostic: redundant parentheses in enum variant definition
+     --> lib.cairo:5:5
+        Empty: (),
+        ^^^^^^^^^
+    ");
+}
+
+#[test]
+fn generic_enum_with_unit_variant_fixer() {
+    test_lint_fixer!(GENERIC_ENUM_WITH_UNIT_VARIANT, @r"
+    #[derive(Drop)]
+    enum Wrapper<T> {
+        Value: T,
+        Empty,
+    }
+
+    fn main() -> Wrapper<()> {
+        Wrapper::Empty
+    }
+    ");
+}
+
+#[test]
+fn spaced_unit_variant_diagnostics() {
+    test_lint_diagnostics!(SPACED_UNIT_VARIANT, @r"
+    Plugin diagnostic: redundant parentheses in enum variant definition
+     --> lib.cairo:5:5
+        Empty : (),
+        ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn spaced_unit_variant_fixer() {
+    test_lint_fixer!(SPACED_UNIT_VARIANT, @r"
+    #[derive(Drop)]
+    enum MyEnum {
+        Data: u8,
+        Empty,
+    }
+    ");
+}
+
+#[test]
+fn empty_enum_brackets_variant_is_registered() {
+    assert_eq!(
+        get_lint_type_from_diagnostic_message("redundant parentheses in enum variant definition"),
+        CairoLintKind::EnumEmptyVariantBrackets
+    );
+}
@@ -0,0 +1,165 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const WILDCARD_MATCH: &str = r#"
+fn main() {
+    let x: Option<u32> = Option::Some(1);
+    match x {
+        _ => (),
+    }
+}
+"#;
+
+const WILDCARD_MATCH_ALLOWED: &str = r#"
+fn main() {
+    let x: Option<u32> = Option::Some(1);
+    #[allow(empty_match)]
+    match x {
+        _ => (),
+    }
+}
+"#;
+
+const WILDCARD_MATCH_EMPTY_BLOCK: &str = r#"
+fn main() {
+    let x: Option<u32> = Option::Some(1);
+    match x {
+        _ => {},
+    };
+}
+"#;
+
+const WILDCARD_MATCH_WITH_COMMENT: &str = r#"
+fn main() {
+    let x: Option<u32> = Option::Some(1);
+    // Nothing to do here.
+    match x {
+        _ => (),
+    }
+}
+"#;
+
+const WILDCARD_MATCH_ON_FUNCTION_CALL: &str = r#"
+fn foo() -> Option<u32> {
+    Option::Some(1)
+}
+
+fn main() {
+    match foo() {
+        _ => (),
+    }
+}
+"#;
+
+const WILDCARD_MATCH_WITH_STATEMENTS: &str = r#"
+fn main() {
+    let x: Option<u32> = Option::Some(1);
+    match x {
+        _ => {
+            println!("x");
+        },
+    }
+}
+"#;
+
+#[test]
+fn wildcard_match_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_MATCH, @r"
+    Plugin diagnostic: This `match` does nothing. Consider removing it.
+     --> lib.cairo:4:5-6:5
+          match x {
+     _____^
+    |         _ => (),
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn wildcard_match_fixer() {
+    test_lint_fixer!(WILDCARD_MATCH, @r"
+    fn main() {
+        let x: Option<u32> = Option::Some(1);
+    }
+    ");
+}
+
+#[test]
+fn wildcard_match_allowed_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_MATCH_ALLOWED, @"");
+}
+
+#[test]
+fn wildcard_match_empty_block_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_MATCH_EMPTY_BLOCK, @r"
+    Plugin diagnostic: This `match` does nothing. Consider removing it.
+     --> lib.cairo:4:5-6:5
+          match x {
+     _____^
+    |         _ => {},
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn wildcard_match_empty_block_fixer() {
+    test_lint_fixer!(WILDCARD_MATCH_EMPTY_BLOCK, @r"
+    fn main() {
+        let x: Option<u32> = Option::Some(1);
+    }
+    ");
+}
+
+#[test]
+fn wildcard_match_with_comment_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_MATCH_WITH_COMMENT, @r"
+    Plugin diagnostic: This `match` does nothing. Consider removing it.
+     --> lib.cairo:5:5-7:5
+          match x {
+     _____^
+    |         _ => (),
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn wildcard_match_with_comment_fixer() {
+    test_lint_fixer!(WILDCARD_MATCH_WITH_COMMENT, @r"
+    fn main() {
+        let x: Option<u32> = Option::Some(1);
+        // Nothing to do here.
+    }
+    ");
+}
+
+#[test]
+fn wildcard_match_on_function_call_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_MATCH_ON_FUNCTION_CALL, @r"
+    Plugin diagnostic: This `match` does nothing. Consider removing it.
+     --> lib.cairo:7:5-9:5
+          match foo() {
+     _____^
+    |         _ => (),
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn wildcard_match_on_function_call_fixer() {
+    test_lint_fixer!(WILDCARD_MATCH_ON_FUNCTION_CALL, @r"
+    fn foo() -> Option<u32> {
+        Option::Some(1)
+    }
+
+    fn main() {
+        foo();
+    }
+    ");
+}
+
+#[test]
+fn wildcard_match_with_statements_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_MATCH_WITH_STATEMENTS, @"");
+}
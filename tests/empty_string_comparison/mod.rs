@@ -0,0 +1,186 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const EQUALS_EMPTY_STRING: &str = r#"
+fn main() -> bool {
+    let s: ByteArray = "abc";
+    s == ""
+}
+"#;
+
+const NOT_EQUALS_EMPTY_STRING: &str = r#"
+fn main() -> bool {
+    let s: ByteArray = "abc";
+    s != ""
+}
+"#;
+
+const EMPTY_STRING_ON_LEFT: &str = r#"
+fn main() -> bool {
+    let s: ByteArray = "abc";
+    "" == s
+}
+"#;
+
+const EMPTY_STRING_IN_IF: &str = r#"
+fn main() {
+    let s: ByteArray = "abc";
+    if s == "" {
+        println!("empty");
+    }
+}
+"#;
+
+const SNAPSHOT_EQUALS_EMPTY_STRING: &str = r#"
+fn is_blank(s: @ByteArray) -> bool {
+    *s == ""
+}
+"#;
+
+const EQUALS_EMPTY_STRING_ALLOWED: &str = r#"
+#[allow(empty_string_comparison)]
+fn main() -> bool {
+    let s: ByteArray = "abc";
+    s == ""
+}
+"#;
+
+const EQUALS_NON_EMPTY_STRING: &str = r#"
+fn main() -> bool {
+    let s: ByteArray = "abc";
+    s == "a"
+}
+"#;
+
+const EQUALS_EMPTY_SHORT_STRING: &str = r#"
+fn main() -> bool {
+    let s: felt252 = 'abc';
+    s == ''
+}
+"#;
+
+#[test]
+fn equals_empty_string_diagnostics() {
+    test_lint_diagnostics!(EQUALS_EMPTY_STRING, @r#"
+    Plugin diagnostic: Comparison with an empty string. Consider checking the length of the string instead.
+     --> lib.cairo:4:5
+        s == ""
+        ^^^^^^^
+    "#);
+}
+
+#[test]
+fn equals_empty_string_fixer() {
+    test_lint_fixer!(EQUALS_EMPTY_STRING, @r#"
+    fn main() -> bool {
+        let s: ByteArray = "abc";
+        s.len() == 0
+    }
+    "#);
+}
+
+#[test]
+fn not_equals_empty_string_diagnostics() {
+    test_lint_diagnostics!(NOT_EQUALS_EMPTY_STRING, @r#"
+    Plugin diagnostic: Comparison with an empty string. Consider checking the length of the string instead.
+     --> lib.cairo:4:5
+        s != ""
+        ^^^^^^^
+    "#);
+}
+
+#[test]
+fn not_equals_empty_string_fixer() {
+    test_lint_fixer!(NOT_EQUALS_EMPTY_STRING, @r#"
+    fn main() -> bool {
+        let s: ByteArray = "abc";
+        s.len() != 0
+    }
+    "#);
+}
+
+#[test]
+fn empty_string_on_left_diagnostics() {
+    test_lint_diagnostics!(EMPTY_STRING_ON_LEFT, @r#"
+    Plugin diagnostic: Comparison with an empty string. Consider checking the length of the string instead.
+     --> lib.cairo:4:5
+        "" == s
+        ^^^^^^^
+    "#);
+}
+
+#[test]
+fn empty_string_on_left_fixer() {
+    test_lint_fixer!(EMPTY_STRING_ON_LEFT, @r#"
+    fn main() -> bool {
+        let s: ByteArray = "abc";
+        s.len() == 0
+    }
+    "#);
+}
+
+#[test]
+fn empty_string_in_if_diagnostics() {
+    test_lint_diagnostics!(EMPTY_STRING_IN_IF, @r#"
+    Plugin diagnostic: Comparison with an empty string. Consider checking the length of the string instead.
+     --> lib.cairo:4:8
+        if s == "" {
+           ^^^^^^^
+    "#);
+}
+
+#[test]
+fn empty_string_in_if_fixer() {
+    test_lint_fixer!(EMPTY_STRING_IN_IF, @r#"
+    fn main() {
+        let s: ByteArray = "abc";
+        if s.len() == 0 {
+            println!("empty");
+        }
+    }
+    "#);
+}
+
+#[test]
+fn snapshot_equals_empty_string_diagnostics() {
+    test_lint_diagnostics!(SNAPSHOT_EQUALS_EMPTY_STRING, @r#"
+    Plugin diagnostic: Comparison with an empty string. Consider checking the length of the string instead.
+     --> lib.cairo:3:5
+        *s == ""
+        ^^^^^^^^
+    "#);
+}
+
+#[test]
+fn snapshot_equals_empty_string_fixer() {
+    test_lint_fixer!(SNAPSHOT_EQUALS_EMPTY_STRING, @r"
+    fn is_blank(s: @ByteArray) -> bool {
+        (*s).len() == 0
+    }
+    ");
+}
+
+#[test]
+fn equals_empty_string_allowed_diagnostics() {
+    test_lint_diagnostics!(EQUALS_EMPTY_STRING_ALLOWED, @"");
+}
+
+#[test]
+fn equals_empty_string_allowed_fixer() {
+    test_lint_fixer!(EQUALS_EMPTY_STRING_ALLOWED, @r#"
+    #[allow(empty_string_comparison)]
+    fn main() -> bool {
+        let s: ByteArray = "abc";
+        s == ""
+    }
+    "#);
+}
+
+#[test]
+fn equals_non_empty_string_diagnostics() {
+    test_lint_diagnostics!(EQUALS_NON_EMPTY_STRING, @"");
+}
+
+#[test]
+fn equals_empty_short_string_diagnostics() {
+    test_lint_diagnostics!(EQUALS_EMPTY_SHORT_STRING, @"");
+}
@@ -0,0 +1,52 @@
+use std::collections::BTreeSet;
+
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const DOUBLE_PARENS_AND_PANIC: &str = r#"
+fn fail() {
+    panic!("error");
+}
+
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+fn lint_with_enabled_lints(enabled_lints: Option<BTreeSet<String>>) -> Vec<String> {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([
+            ("double_parens".to_string(), true),
+            ("panic".to_string(), true),
+        ]),
+        enabled_lints,
+        ..Default::default()
+    };
+    lint_string(
+        DOUBLE_PARENS_AND_PANIC,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.message)
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn all_lints_run_without_enabled_lints() {
+    assert_eq!(lint_with_enabled_lints(None).len(), 2);
+}
+
+#[test]
+fn only_enabled_lints_are_reported() {
+    let enabled_lints = BTreeSet::from(["double_parens".to_string()]);
+    assert_eq!(
+        lint_with_enabled_lints(Some(enabled_lints)),
+        vec!["unnecessary double parentheses found. Consider removing them.".to_string()]
+    );
+}
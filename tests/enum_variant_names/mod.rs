@@ -93,6 +93,61 @@ enum Cake {
 }
 "#;
 
+const ENUM_WITH_PREFIXED_VARIANTS_USED: &str = r#"
+#[derive(Drop)]
+enum Color {
+    ColorRed,
+    ColorBlue,
+}
+
+fn main() -> felt252 {
+    let color = Color::ColorRed;
+    match color {
+        Color::ColorRed => 1,
+        Color::ColorBlue => 2,
+    }
+}
+"#;
+
+const ENUM_WITH_COLLIDING_STRIPPED_VARIANTS: &str = r#"
+#[derive(Drop)]
+enum Direction {
+    DirectionUp,
+    Direction_Up,
+    DirectionDown,
+}
+"#;
+
+const ENUM_WITH_VARIANT_NAMED_AS_PREFIX: &str = r#"
+#[derive(Drop)]
+enum Cake {
+    Cake,
+    CakeBig,
+}
+"#;
+
+const ENUM_WITH_IMPORTED_VARIANT: &str = r#"
+#[derive(Drop)]
+enum Color {
+    ColorRed,
+    ColorBlue,
+}
+
+use Color::ColorRed;
+
+fn main() -> Color {
+    ColorRed
+}
+"#;
+
+const PUBLIC_ENUM_WITH_PREFIXED_VARIANTS: &str = r#"
+#[derive(Drop)]
+pub enum Color {
+    ColorRed,
+    ColorBlue,
+}
+"#;
+
 #[test]
 fn enum_with_suffixed_name_diagnostics() {
     test_lint_diagnostics!(ENUM_WITH_SUFFIXED_NAME, @r"
@@ -319,3 +374,86 @@ fn allow_enum_with_suffix_fixer() {
     }
     ")
 }
+
+#[test]
+fn enum_with_prefixed_variants_used_diagnostics() {
+    test_lint_diagnostics!(ENUM_WITH_PREFIXED_VARIANTS_USED, @r"
+    Plugin diagnostic: All enum variants are prefixed or suffixed by the same characters.
+     --> lib.cairo:3:1-6:1
+      enum Color {
+     _^
+    | ...
+    | }
+    |_^
+    ");
+}
+
+#[test]
+fn enum_with_prefixed_variants_used_fixer() {
+    test_lint_fixer!(ENUM_WITH_PREFIXED_VARIANTS_USED, @r"
+    #[derive(Drop)]
+    enum Color {
+        Red,
+        Blue,
+    }
+
+    fn main() -> felt252 {
+        let color = Color::Red;
+        match color {
+            Color::Red => 1,
+            Color::Blue => 2,
+        }
+    }
+    ");
+}
+
+#[test]
+fn enum_with_colliding_stripped_variants_fixer() {
+    test_lint_fixer!(ENUM_WITH_COLLIDING_STRIPPED_VARIANTS, @r"
+    #[derive(Drop)]
+    enum Direction {
+        DirectionUp,
+        Direction_Up,
+        DirectionDown,
+    }
+    ");
+}
+
+#[test]
+fn enum_with_variant_named_as_prefix_fixer() {
+    test_lint_fixer!(ENUM_WITH_VARIANT_NAMED_AS_PREFIX, @r"
+    #[derive(Drop)]
+    enum Cake {
+        Cake,
+        CakeBig,
+    }
+    ");
+}
+
+#[test]
+fn enum_with_imported_variant_fixer() {
+    test_lint_fixer!(ENUM_WITH_IMPORTED_VARIANT, @r"
+    #[derive(Drop)]
+    enum Color {
+        ColorRed,
+        ColorBlue,
+    }
+
+    use Color::ColorRed;
+
+    fn main() -> Color {
+        ColorRed
+    }
+    ");
+}
+
+#[test]
+fn public_enum_with_prefixed_variants_fixer() {
+    test_lint_fixer!(PUBLIC_ENUM_WITH_PREFIXED_VARIANTS, @r"
+    #[derive(Drop)]
+    pub enum Color {
+        ColorRed,
+        ColorBlue,
+    }
+    ");
+}
@@ -37,6 +37,18 @@ fn foo(a: u256) -> u256 {
 }
 "#;
 
+const SIMPLE_BITWISE_OR_OP: &str = r#"
+fn foo(a: u256) -> u256 {
+    a | a
+}
+"#;
+
+const BITWISE_OP_WITH_SIDE_EFFECTS: &str = r#"
+fn foo(span: Span<u256>) -> u256 {
+    *span.at(0) & *span.at(0)
+}
+"#;
+
 const SIMPLE_SUB_OP: &str = r#"
 fn foo(a: u256) -> u256 {
     a - a
@@ -173,7 +185,7 @@ fn simple_bitwise_op_diagnostics() {
 fn simple_bitwise_op_fixer() {
     test_lint_fixer!(SIMPLE_BITWISE_OP, @r#"
     fn foo(a: u256) -> u256 {
-        a & a
+        a
     }
     "#);
 }
@@ -194,6 +206,44 @@ fn simple_bitwise_op_allowed_fixer() {
     "#);
 }
 
+#[test]
+fn simple_bitwise_or_op_diagnostics() {
+    test_lint_diagnostics!(SIMPLE_BITWISE_OR_OP, @r"
+    Plugin diagnostic: Logical operation with identical operands, this operation always results in the same value and may indicate a logic error
+     --> lib.cairo:3:5
+        a | a
+        ^^^^^
+    ");
+}
+
+#[test]
+fn simple_bitwise_or_op_fixer() {
+    test_lint_fixer!(SIMPLE_BITWISE_OR_OP, @r#"
+    fn foo(a: u256) -> u256 {
+        a
+    }
+    "#);
+}
+
+#[test]
+fn bitwise_op_with_side_effects_diagnostics() {
+    test_lint_diagnostics!(BITWISE_OP_WITH_SIDE_EFFECTS, @r"
+    Plugin diagnostic: Logical operation with identical operands, this operation always results in the same value and may indicate a logic error
+     --> lib.cairo:3:5
+        *span.at(0) & *span.at(0)
+        ^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn bitwise_op_with_side_effects_fixer() {
+    test_lint_fixer!(BITWISE_OP_WITH_SIDE_EFFECTS, @r#"
+    fn foo(span: Span<u256>) -> u256 {
+        *span.at(0) & *span.at(0)
+    }
+    "#);
+}
+
 #[test]
 fn simple_sub_op_diagnostics() {
     test_lint_diagnostics!(SIMPLE_SUB_OP, @r"
@@ -0,0 +1,188 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const NESTING_AT_THRESHOLD: &str = r#"
+fn main(a: u32, b: u32) {
+    let mut x = a;
+    if a > 0 {
+        x += 1;
+        if b > 0 {
+            x += 1;
+            for i in 0..b {
+                x += i;
+                while x != 10 {
+                    x += 1;
+                    if x > b { x += 2; }
+                }
+            }
+        }
+    }
+}
+"#;
+
+const NESTING_OVER_THRESHOLD: &str = r#"
+fn main(a: u32, b: u32) {
+    let mut x = a;
+    if a > 0 {
+        x += 1;
+        if b > 0 {
+            x += 1;
+            for i in 0..b {
+                x += i;
+                while x != 10 {
+                    x += 1;
+                    if x > b {
+                        x += 2;
+                        if x > a { x += 3; }
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+
+const NESTING_OVER_THRESHOLD_ALLOWED: &str = r#"
+#[allow(excessive_nesting)]
+fn main(a: u32, b: u32) {
+    let mut x = a;
+    if a > 0 {
+        x += 1;
+        if b > 0 {
+            x += 1;
+            for i in 0..b {
+                x += i;
+                while x != 10 {
+                    x += 1;
+                    if x > b {
+                        x += 2;
+                        if x > a { x += 3; }
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+
+const ELSE_IF_CHAIN_AT_THRESHOLD: &str = r#"
+fn main(a: u32, b: u32) {
+    let mut x = a;
+    if a > 0 {
+        x += 1;
+        if b > 0 {
+            x += 1;
+            for i in 0..b {
+                x += i;
+                while x != 10 {
+                    x += 1;
+                    if x > b { x += 2; } else if x > a { x += 3; } else if x == 0 { x += 4; }
+                }
+            }
+        }
+    }
+}
+"#;
+
+#[test]
+fn nesting_at_threshold_diagnostics() {
+    test_lint_diagnostics!(NESTING_AT_THRESHOLD, @"");
+}
+
+#[test]
+fn nesting_at_threshold_fixer() {
+    test_lint_fixer!(NESTING_AT_THRESHOLD, @r"
+    fn main(a: u32, b: u32) {
+        let mut x = a;
+        if a > 0 {
+            x += 1;
+            if b > 0 {
+                x += 1;
+                for i in 0..b {
+                    x += i;
+                    while x != 10 {
+                        x += 1;
+                        if x > b {
+                            x += 2;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    ");
+}
+
+#[test]
+fn nesting_over_threshold_diagnostics() {
+    test_lint_diagnostics!(NESTING_OVER_THRESHOLD, @r"
+    Plugin diagnostic: Blocks are nested too deeply. Consider extracting some logic into separate functions or using early returns.
+     --> lib.cairo:14:25
+                            if x > a { x += 3; }
+                            ^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn nesting_over_threshold_fixer() {
+    test_lint_fixer!(NESTING_OVER_THRESHOLD, @r"
+    fn main(a: u32, b: u32) {
+        let mut x = a;
+        if a > 0 {
+            x += 1;
+            if b > 0 {
+                x += 1;
+                for i in 0..b {
+                    x += i;
+                    while x != 10 {
+                        x += 1;
+                        if x > b {
+                            x += 2;
+                            if x > a {
+                                x += 3;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    ");
+}
+
+#[test]
+fn nesting_over_threshold_allowed_diagnostics() {
+    test_lint_diagnostics!(NESTING_OVER_THRESHOLD_ALLOWED, @"");
+}
+
+#[test]
+fn nesting_over_threshold_allowed_fixer() {
+    test_lint_fixer!(NESTING_OVER_THRESHOLD_ALLOWED, @r"
+    #[allow(excessive_nesting)]
+    fn main(a: u32, b: u32) {
+        let mut x = a;
+        if a > 0 {
+            x += 1;
+            if b > 0 {
+                x += 1;
+                for i in 0..b {
+                    x += i;
+                    while x != 10 {
+                        x += 1;
+                        if x > b {
+                            x += 2;
+                            if x > a {
+                                x += 3;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    ");
+}
+
+#[test]
+fn else_if_chain_at_threshold_diagnostics() {
+    test_lint_diagnostics!(ELSE_IF_CHAIN_AT_THRESHOLD, @"");
+}
@@ -0,0 +1,130 @@
+use cairo_lang_defs::{db::DefsGroup, ids::ModuleId};
+use cairo_lang_filesystem::db::files_group_input;
+use cairo_lang_filesystem::ids::{CrateInput, FileId};
+use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_utils::Intern;
+use cairo_lint::{LinterAnalysisDatabase, LinterDiagnosticParams, LinterGroup};
+use salsa::Setter;
+
+use crate::helpers::{init_corelib, setup::setup_test_crate_with_files};
+
+const LIB: &str = r#"
+mod other;
+
+fn main() -> u32 {
+    ((1))
+}
+"#;
+
+const EDITED_LIB: &str = r#"
+mod other;
+
+fn main() -> u32 {
+    let x = ((1));
+    ((x))
+}
+"#;
+
+const OTHER: &str = r#"
+fn other() -> u32 {
+    ((2))
+}
+"#;
+
+const LIB_WITH_MACRO: &str = r#"
+mod other;
+
+fn main(x: u32) -> Array<u32> {
+    array![x & x]
+}
+"#;
+
+fn test_db() -> LinterAnalysisDatabase {
+    LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap()
+}
+
+fn linter_params() -> LinterDiagnosticParams {
+    LinterDiagnosticParams {
+        only_generated_files: true,
+        ..Default::default()
+    }
+}
+
+/// Returns the main files of the crate root and of the `other` submodule.
+fn crate_files<'db>(
+    db: &'db LinterAnalysisDatabase,
+    test_crate: &CrateInput,
+) -> (FileId<'db>, FileId<'db>) {
+    let crate_id = test_crate.clone().into_crate_long_id(db).intern(db);
+    let lib_file = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let other_file = db
+        .crate_modules(crate_id)
+        .iter()
+        .map(|module_id| db.module_main_file(*module_id).unwrap())
+        .find(|file_id| *file_id != lib_file)
+        .unwrap();
+    (lib_file, other_file)
+}
+
+/// Returns the linted code of the diagnostics reported in the file.
+fn linted_code(db: &LinterAnalysisDatabase, file_id: FileId) -> Vec<String> {
+    db.file_lint_diagnostics(linter_params(), file_id)
+        .iter()
+        .map(|diagnostic| {
+            let node: SyntaxNode = diagnostic.stable_ptr.lookup(db);
+            node.get_text_without_trivia(db).long(db).to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn file_lint_diagnostics_are_restricted_to_the_file() {
+    let mut db = test_db();
+    let test_crate =
+        setup_test_crate_with_files(&mut db, &[("lib.cairo", LIB), ("other.cairo", OTHER)]);
+    init_corelib(&mut db);
+
+    let (lib_file, other_file) = crate_files(&db, &test_crate);
+    assert_eq!(linted_code(&db, lib_file), vec!["((1))"]);
+    assert_eq!(linted_code(&db, other_file), vec!["((2))"]);
+}
+
+#[test]
+fn file_lint_diagnostics_follow_edits_of_the_file() {
+    let mut db = test_db();
+    let test_crate =
+        setup_test_crate_with_files(&mut db, &[("lib.cairo", LIB), ("other.cairo", OTHER)]);
+    init_corelib(&mut db);
+
+    let (lib_file, other_file) = crate_files(&db, &test_crate);
+    let other_before = linted_code(&db, other_file);
+    assert_eq!(linted_code(&db, lib_file), vec!["((1))"]);
+
+    let lib_input = lib_file.long(&db).into_file_input(&db);
+    let input = files_group_input(&db);
+    let mut overrides = input.file_overrides(&db).clone().unwrap();
+    overrides.insert(lib_input, EDITED_LIB.into());
+    input.set_file_overrides(&mut db).to(overrides.into());
+
+    let (lib_file, other_file) = crate_files(&db, &test_crate);
+    assert_eq!(linted_code(&db, lib_file), vec!["((1))", "((x))"]);
+    assert_eq!(linted_code(&db, other_file), other_before);
+}
+
+#[test]
+fn file_lint_diagnostics_include_code_generated_by_macros() {
+    let mut db = test_db();
+    let test_crate = setup_test_crate_with_files(
+        &mut db,
+        &[("lib.cairo", LIB_WITH_MACRO), ("other.cairo", OTHER)],
+    );
+    init_corelib(&mut db);
+
+    let (lib_file, other_file) = crate_files(&db, &test_crate);
+    assert_eq!(linted_code(&db, lib_file), vec!["x & x"]);
+    assert_eq!(linted_code(&db, other_file), vec!["((2))"]);
+}
@@ -0,0 +1,85 @@
+use cairo_lint::{fix_for_diagnostic, get_separated_fixes};
+
+use crate::helpers::{get_diags, init_corelib, setup::setup_test_crate_ex};
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+const DOUBLE_PARENS_WITH_TRAILING_TRIVIA: &str = r#"
+fn main() -> u32 {
+    let x = ((0))   ; // Just a comment.
+    x
+}
+"#;
+
+const MANUAL_UNWRAP_OR_DEFAULT: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(1);
+    let _v = match a {
+        Option::Some(v) => v,
+        Option::None => 0,
+    };
+}
+"#;
+
+fn test_db() -> ::cairo_lint::LinterAnalysisDatabase {
+    ::cairo_lint::LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn fix_for_single_diagnostic_matches_separated_fixes() {
+    let mut db = test_db();
+    let test_crate = setup_test_crate_ex(&mut db, DOUBLE_PARENS);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    assert_eq!(diags.len(), 1);
+
+    let fix = fix_for_diagnostic(&db, &diags[0]).expect("double_parens should have a fix");
+    let separated_fixes = get_separated_fixes(&db, diags)
+        .into_values()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    assert_eq!(separated_fixes, vec![fix]);
+}
+
+#[test]
+fn diagnostic_span_of_fix_skips_the_replaced_trivia() {
+    let mut db = test_db();
+    let test_crate = setup_test_crate_ex(&mut db, DOUBLE_PARENS_WITH_TRAILING_TRIVIA);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    assert_eq!(diags.len(), 1);
+
+    let fix = fix_for_diagnostic(&db, &diags[0]).expect("double_parens should have a fix");
+    let diagnostic_span = diags[0].stable_location.span_in_file(&db).span;
+    assert_eq!(fix.diagnostic_span, diagnostic_span);
+    assert_eq!(fix.suggestions.len(), 1);
+    assert!(fix.suggestions[0].span.contains(diagnostic_span));
+    assert_ne!(fix.suggestions[0].span, diagnostic_span);
+}
+
+#[test]
+fn fix_for_single_diagnostic_contains_the_suggested_code() {
+    let mut db = test_db();
+    let test_crate = setup_test_crate_ex(&mut db, MANUAL_UNWRAP_OR_DEFAULT);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    assert_eq!(diags.len(), 1);
+
+    let fix =
+        fix_for_diagnostic(&db, &diags[0]).expect("manual_unwrap_or_default should have a fix");
+    assert_eq!(fix.lint_names, vec!["manual_unwrap_or_default"]);
+    assert_eq!(fix.suggestions.len(), 1);
+    assert_eq!(
+        fix.suggestions[0].code.trim(),
+        "let _v = a.unwrap_or_default();"
+    );
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use cairo_lint::{FixSummary, LinterDiagnosticParams, get_fixes};
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags, init_corelib,
+    setup::setup_test_crate_ex,
+};
+
+const TWO_LINT_KINDS: &str = r#"
+fn foo() -> u32 {
+    ((0))
+}
+
+fn bar() -> u32 {
+    ((1))
+}
+
+fn baz(y: u32) -> u32 {
+    let x = { y };
+    x + 1
+}
+"#;
+
+const OVERLAPPING_FIXES: &str = r#"
+fn baz(y: u32) -> u32 {
+    let x = { ((y)) };
+    x + 1
+}
+"#;
+
+fn fix_summary_of(code: &str) -> FixSummary {
+    let mut db = ::cairo_lint::LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap();
+    let test_crate = setup_test_crate_ex(&mut db, code);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    let linter_params = LinterDiagnosticParams {
+        only_generated_files: true,
+        tool_metadata: get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+        ..Default::default()
+    };
+    let fixes = get_fixes(&db, &linter_params, diags)
+        .into_values()
+        .flatten()
+        .collect::<Vec<_>>();
+    FixSummary::from_fixes(&fixes)
+}
+
+#[test]
+fn summary_counts_fixes_per_lint() {
+    let summary = fix_summary_of(TWO_LINT_KINDS);
+    assert_eq!(
+        summary.fixes_per_lint,
+        BTreeMap::from([("double_parens", 2), ("redundant_block", 1)])
+    );
+    assert_eq!(summary.fixes_count(), 3);
+    assert_eq!(summary.lints_count(), 2);
+}
+
+#[test]
+fn summary_keeps_lints_of_merged_fixes() {
+    let summary = fix_summary_of(OVERLAPPING_FIXES);
+    assert_eq!(
+        summary.fixes_per_lint,
+        BTreeMap::from([("double_parens", 1), ("redundant_block", 1)])
+    );
+}
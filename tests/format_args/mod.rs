@@ -0,0 +1,141 @@
+use crate::test_lint_diagnostics;
+
+const FORMAT_SINGLE_PLACEHOLDER: &str = r#"
+fn main(name: ByteArray) -> ByteArray {
+    format!("{}", name)
+}
+"#;
+
+const PRINTLN_LITERAL_ARGUMENTS: &str = r#"
+fn main() {
+    println!("{}, {}!", "Hello", "world");
+}
+"#;
+
+const FORMAT_LITERAL_ARGUMENT: &str = r#"
+fn main(value: u32) -> ByteArray {
+    format!("{}: {}", "value", value)
+}
+"#;
+
+const FORMAT_SINGLE_PLACEHOLDER_IN_GENERATED_IMPL: &str = r#"
+#[derive(Drop)]
+struct Person {
+    name: ByteArray,
+}
+
+#[generate_trait]
+impl PersonImpl of PersonTrait {
+    fn describe(self: @Person) -> ByteArray {
+        format!("{}", self.name)
+    }
+}
+"#;
+
+const PRINTLN_SINGLE_PLACEHOLDER: &str = r#"
+fn main(value: u32) {
+    println!("{}", value);
+}
+"#;
+
+const FORMAT_WITH_TEXT: &str = r#"
+fn main(value: u32) -> ByteArray {
+    format!("value: {}", value)
+}
+"#;
+
+const DEBUG_PLACEHOLDER_WITH_LITERAL: &str = r#"
+fn main() {
+    println!("{:?}", "Hello");
+}
+"#;
+
+const NAMED_PLACEHOLDER: &str = r#"
+fn main(value: u32) -> ByteArray {
+    format!("{value}")
+}
+"#;
+
+const FORMAT_SINGLE_PLACEHOLDER_ALLOWED: &str = r#"
+#[allow(useless_format)]
+fn main(name: ByteArray) -> ByteArray {
+    format!("{}", name)
+}
+"#;
+
+const PRINTLN_LITERAL_ARGUMENTS_ALLOWED: &str = r#"
+#[allow(print_literal)]
+fn main() {
+    println!("{}, {}!", "Hello", "world");
+}
+"#;
+
+#[test]
+fn format_single_placeholder_diagnostics() {
+    test_lint_diagnostics!(FORMAT_SINGLE_PLACEHOLDER, @r#"
+    Plugin diagnostic: Useless `format!` of a single value. Consider converting the value to a `ByteArray` directly.
+     --> lib.cairo:3:5
+        format!("{}", name)
+        ^^^^^^^^^^^^^^^^^^^
+    "#);
+}
+
+#[test]
+fn println_literal_arguments_diagnostics() {
+    test_lint_diagnostics!(PRINTLN_LITERAL_ARGUMENTS, @r#"
+    Plugin diagnostic: String literal used as a format argument. Consider inlining it into the format string.
+     --> lib.cairo:3:5
+        println!("{}, {}!", "Hello", "world");
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    "#);
+}
+
+#[test]
+fn format_literal_argument_diagnostics() {
+    test_lint_diagnostics!(FORMAT_LITERAL_ARGUMENT, @r#"
+    Plugin diagnostic: String literal used as a format argument. Consider inlining it into the format string.
+     --> lib.cairo:3:5
+        format!("{}: {}", "value", value)
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    "#);
+}
+
+#[test]
+fn format_single_placeholder_in_generated_impl_diagnostics() {
+    test_lint_diagnostics!(FORMAT_SINGLE_PLACEHOLDER_IN_GENERATED_IMPL, @r#"
+    Plugin diagnostic: Useless `format!` of a single value. Consider converting the value to a `ByteArray` directly.
+     --> lib.cairo:10:9
+            format!("{}", self.name)
+            ^^^^^^^^^^^^^^^^^^^^^^^^
+    "#);
+}
+
+#[test]
+fn println_single_placeholder_diagnostics() {
+    test_lint_diagnostics!(PRINTLN_SINGLE_PLACEHOLDER, @"");
+}
+
+#[test]
+fn format_with_text_diagnostics() {
+    test_lint_diagnostics!(FORMAT_WITH_TEXT, @"");
+}
+
+#[test]
+fn debug_placeholder_with_literal_diagnostics() {
+    test_lint_diagnostics!(DEBUG_PLACEHOLDER_WITH_LITERAL, @"");
+}
+
+#[test]
+fn named_placeholder_diagnostics() {
+    test_lint_diagnostics!(NAMED_PLACEHOLDER, @"");
+}
+
+#[test]
+fn format_single_placeholder_allowed_diagnostics() {
+    test_lint_diagnostics!(FORMAT_SINGLE_PLACEHOLDER_ALLOWED, @"");
+}
+
+#[test]
+fn println_literal_arguments_allowed_diagnostics() {
+    test_lint_diagnostics!(PRINTLN_LITERAL_ARGUMENTS_ALLOWED, @"");
+}
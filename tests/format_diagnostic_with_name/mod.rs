@@ -0,0 +1,35 @@
+use cairo_lint::diagnostics::{format_diagnostic, format_diagnostic_with_name};
+
+use crate::helpers::{get_diags, init_corelib, setup::setup_test_crate_ex};
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+#[test]
+fn diagnostic_header_contains_lint_name_and_code() {
+    let mut db = ::cairo_lint::LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap();
+    let test_crate = setup_test_crate_ex(&mut db, DOUBLE_PARENS);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+
+    insta::assert_snapshot!(format_diagnostic_with_name(&diags[0], &db).trim(), @r"
+    Plugin diagnostic: unnecessary double parentheses found. Consider removing them. [double_parens, CL0003]
+     --> lib.cairo:3:5
+        ((0))
+        ^^^^^
+    ");
+    // The default format is left unchanged.
+    insta::assert_snapshot!(format_diagnostic(&diags[0], &db).trim(), @r"
+    Plugin diagnostic: unnecessary double parentheses found. Consider removing them.
+     --> lib.cairo:3:5
+        ((0))
+        ^^^^^
+    ");
+}
@@ -17,19 +17,35 @@ use std::path::PathBuf;
 mod scarb;
 pub mod setup;
 
-pub fn init_corelib(db: &mut LinterAnalysisDatabase) {
+pub fn get_corelib_path() -> PathBuf {
     if let Ok(path) = std::env::var("CORELIB_PATH") {
-        init_dev_corelib(db, PathBuf::from(path));
+        PathBuf::from(path)
     } else if let Some(path) = find_scarb_managed_core() {
-        init_dev_corelib(db, path);
+        path
     } else {
         panic!("Missing corelib path. CORELIB_PATH env or Scarb managed corelib is required.");
     }
 }
 
+pub fn init_corelib(db: &mut LinterAnalysisDatabase) {
+    init_dev_corelib(db, get_corelib_path());
+}
+
 pub fn get_diags<'db>(
     crate_id: CrateInput,
     db: &'db LinterAnalysisDatabase,
+) -> Vec<SemanticDiagnostic<'db>> {
+    get_diags_with_tool_metadata(
+        crate_id,
+        db,
+        get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+    )
+}
+
+pub fn get_diags_with_tool_metadata<'db>(
+    crate_id: CrateInput,
+    db: &'db LinterAnalysisDatabase,
+    tool_metadata: CairoLintToolMetadata,
 ) -> Vec<SemanticDiagnostic<'db>> {
     let mut diagnostics = Vec::new();
     let crate_id = crate_id.into_crate_long_id(db).intern(db);
@@ -44,7 +60,8 @@ pub fn get_diags<'db>(
 
     let linter_params = LinterDiagnosticParams {
         only_generated_files: true,
-        tool_metadata: get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+        tool_metadata,
+        ..Default::default()
     };
 
     for module_id in db.crate_modules(crate_id) {
@@ -74,11 +91,34 @@ pub fn get_diags<'db>(
         .collect::<Vec<_>>()
 }
 
+/// Lints left out of [`get_cairo_lint_tool_metadata_with_all_lints_enabled`], as they would report
+/// the `allow` attributes, the enums, the statements, the bindings and the returns of the tests that
+/// cover lints other than the tested one. Their own tests enable them explicitly.
+const LINTS_ENABLED_EXPLICITLY: [&str; 5] = [
+    "unused_allow",
+    "unused_enum_variant",
+    "needless_semicolon",
+    "let_and_return",
+    "needless_return",
+];
+
+/// Enables all the lints, except the ones in [`LINTS_ENABLED_EXPLICITLY`].
 pub fn get_cairo_lint_tool_metadata_with_all_lints_enabled() -> CairoLintToolMetadata {
+    get_cairo_lint_tool_metadata_enabling(&[])
+}
+
+/// Enables all the lints, like [`get_cairo_lint_tool_metadata_with_all_lints_enabled`], and also
+/// the given lints from [`LINTS_ENABLED_EXPLICITLY`].
+pub fn get_cairo_lint_tool_metadata_enabling(lints: &[&str]) -> CairoLintToolMetadata {
     let names = get_unique_allowed_names();
     names
         .into_iter()
-        .map(|name| (name.to_string(), true))
+        .map(|name| {
+            (
+                name.to_string(),
+                !LINTS_ENABLED_EXPLICITLY.contains(&name) || lints.contains(&name),
+            )
+        })
         .collect()
 }
 
@@ -91,12 +131,19 @@ macro_rules! test_lint_fixer {
   ($before:ident, @$expected_fix:literal) => {
     test_lint_fixer!($before, @$expected_fix, false)
   };
+  ($before:ident, enabling [$($lint:literal),*], @$expected_fix:literal) => {
+    test_lint_fixer!($before, @$expected_fix, false, enabling [$($lint),*])
+  };
   ($before:literal, @$expected_fix:literal, $is_nested:literal) => {{
     let expected_value: &str = $before;
     test_lint_fixer!(expected_value, @$expected_fix, $is_nested)
   }};
-  ($before:ident, @$expected_fix:literal, $is_nested:literal) => {{
+  ($before:ident, @$expected_fix:literal, $is_nested:literal) => {
+    test_lint_fixer!($before, @$expected_fix, $is_nested, enabling [])
+  };
+  ($before:ident, @$expected_fix:literal, $is_nested:literal, enabling [$($lint:literal),*]) => {{
     use ::cairo_lang_diagnostics::DiagnosticEntry;
+    let tool_metadata = $crate::helpers::get_cairo_lint_tool_metadata_enabling(&[$($lint),*]);
     use ::itertools::Itertools;
     let mut code = String::from($before);
     let mut db = ::cairo_lint::LinterAnalysisDatabase::builder()
@@ -106,11 +153,12 @@ macro_rules! test_lint_fixer {
       .unwrap();
     let test_crate = $crate::helpers::setup::setup_test_crate_ex(&mut db, $before);
     $crate::helpers::init_corelib(&mut db);
-    let diags = $crate::helpers::get_diags(test_crate, &db);
+    let diags = $crate::helpers::get_diags_with_tool_metadata(test_crate, &db, tool_metadata.clone());
     let mut fixes = Vec::new();
     let linter_params = ::cairo_lint::LinterDiagnosticParams {
         only_generated_files: true,
-        tool_metadata: $crate::helpers::get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+        tool_metadata: tool_metadata.clone(),
+        ..Default::default()
     };
     fixes.extend(::cairo_lint::get_fixes(&db, &linter_params, diags).values().flatten().cloned());
     let suggestions = fixes.iter().flat_map(|fix| fix.suggestions.iter()).sorted_by_key(|s| std::cmp::Reverse(s.span.start));
@@ -133,7 +181,7 @@ macro_rules! test_lint_fixer {
 
     let test_crate = $crate::helpers::setup::setup_test_crate_ex(&mut after_db, $before);
     $crate::helpers::init_corelib(&mut after_db);
-    let after_diags = $crate::helpers::get_diags(test_crate, &after_db);
+    let after_diags = $crate::helpers::get_diags_with_tool_metadata(test_crate, &after_db, tool_metadata);
     assert!(after_diags.iter().filter(|diag| diag.severity() == ::cairo_lang_diagnostics::Severity::Error).collect::<Vec<_>>().is_empty(), "Expected no diagnostics after fix, but found: {:?}", after_diags);
   }};
 }
@@ -144,7 +192,10 @@ macro_rules! test_lint_diagnostics {
     let expected_value = ::indoc::indoc!($before);
     test_lint_diagnostics!(expected_value, @$expected_diagnostics)
   }};
-  ($before:ident, @$expected_diagnostics:literal) => {{
+  ($before:ident, @$expected_diagnostics:literal) => {
+    test_lint_diagnostics!($before, enabling [], @$expected_diagnostics)
+  };
+  ($before:ident, enabling [$($lint:literal),*], @$expected_diagnostics:literal) => {{
     let mut db = ::cairo_lint::LinterAnalysisDatabase::builder()
       .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
       .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
@@ -152,7 +203,11 @@ macro_rules! test_lint_diagnostics {
       .unwrap();
     let test_crate = $crate::helpers::setup::setup_test_crate_ex(&mut db, $before);
     $crate::helpers::init_corelib(&mut db);
-    let diags = $crate::helpers::get_diags(test_crate, &db);
+    let diags = $crate::helpers::get_diags_with_tool_metadata(
+      test_crate,
+      &db,
+      $crate::helpers::get_cairo_lint_tool_metadata_enabling(&[$($lint),*]),
+    );
     let formatted_diags = diags
       .into_iter()
       .map(|diag| ::cairo_lint::diagnostics::format_diagnostic(&diag, &db))
@@ -12,6 +12,14 @@ use cairo_lint::LinterAnalysisDatabase;
 use salsa::Setter;
 
 pub fn setup_test_crate_ex(db: &mut LinterAnalysisDatabase, content: &str) -> CrateInput {
+    setup_test_crate_with_files(db, &[("lib.cairo", content)])
+}
+
+/// Sets up a crate made of the given virtual files. The first one is the crate root.
+pub fn setup_test_crate_with_files(
+    db: &mut LinterAnalysisDatabase,
+    files: &[(&str, &str)],
+) -> CrateInput {
     let settings = CrateSettings {
         name: None,
         edition: Edition::latest(),
@@ -26,18 +34,24 @@ pub fn setup_test_crate_ex(db: &mut LinterAnalysisDatabase, content: &str) -> Cr
         },
         cfg_set: Default::default(),
     };
-    let file = FileInput::Virtual(VirtualFileInput {
-        parent: None,
-        name: "lib.cairo".into(),
-        content: content.into(),
-        code_mappings: [].into(),
-        kind: FileKind::Module,
-        original_item_removed: false,
-    });
+    let files = files
+        .iter()
+        .map(|(name, content)| {
+            let file = FileInput::Virtual(VirtualFileInput {
+                parent: None,
+                name: (*name).into(),
+                content: (*content).into(),
+                code_mappings: [].into(),
+                kind: FileKind::Module,
+                original_item_removed: false,
+            });
+            (name.to_string(), file)
+        })
+        .collect::<Vec<_>>();
 
     let cr = CrateInput::Virtual {
         name: "test".into(),
-        file_long_id: file.clone(),
+        file_long_id: files[0].1.clone(),
         settings: CRATE_CONFIG.to_string(),
         cache_file: None,
     };
@@ -48,7 +62,7 @@ pub fn setup_test_crate_ex(db: &mut LinterAnalysisDatabase, content: &str) -> Cr
             cr.clone(),
             CrateConfigurationInput {
                 root: DirectoryInput::Virtual {
-                    files: BTreeMap::from([("lib.cairo".to_string(), file)]),
+                    files: BTreeMap::from_iter(files),
                     dirs: Default::default(),
                 },
                 settings,
@@ -14,6 +14,20 @@ fn main() {
 }
 "#;
 
+const ELSE_IF_WITH_COMMENT: &str = r#"
+fn main() {
+    let x = true;
+    if x {
+        println!("x is true");
+    } else {
+        // x is false here.
+        if !x {
+            println!("x is false");
+        }
+    }
+}
+"#;
+
 const SIMPLE_ELSE_IF_WITH_NEW_LINE_ALLOWED: &str = r#"
 fn main() {
     let x = true;
@@ -149,6 +163,35 @@ fn simple_else_if_with_new_line_fixer() {
     "#);
 }
 
+#[test]
+fn else_if_with_comment_diagnostics() {
+    test_lint_diagnostics!(ELSE_IF_WITH_COMMENT, @r"
+    Plugin diagnostic: Consider using else if instead of else { if ... }
+     --> lib.cairo:4:5-11:5
+          if x {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn else_if_with_comment_fixer() {
+    test_lint_fixer!(ELSE_IF_WITH_COMMENT, @r#"
+    fn main() {
+        let x = true;
+        if x {
+            println!("x is true");
+        }
+        // x is false here.
+        else if !x {
+            println!("x is false");
+        }
+    }
+    "#);
+}
+
 #[test]
 fn simple_else_if_with_new_line_allowed_diagnostics() {
     test_lint_diagnostics!(SIMPLE_ELSE_IF_WITH_NEW_LINE_ALLOWED, @r#"
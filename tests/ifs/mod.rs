@@ -2,3 +2,4 @@ mod collapsible_if;
 mod collapsible_if_else;
 mod equatable_if_let;
 mod ifs_same_cond;
+mod redundant_else;
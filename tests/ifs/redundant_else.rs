@@ -0,0 +1,268 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const RETURN_IN_IF: &str = r#"
+fn main(x: u32) -> u32 {
+    if x == 0 {
+        return 0;
+    } else {
+        let y = x * 2;
+        y + 1
+    }
+}
+"#;
+
+const RETURN_IN_IF_ALLOWED: &str = r#"
+fn main(x: u32) -> u32 {
+    #[allow(redundant_else)]
+    if x == 0 {
+        return 0;
+    } else {
+        let y = x * 2;
+        y + 1
+    }
+}
+"#;
+
+const BREAK_IN_LOOP: &str = r#"
+fn main() {
+    let mut a = 1_u32;
+    loop {
+        a += 1;
+        if a > 10 {
+            break;
+        } else {
+            a += 2;
+        }
+    }
+}
+"#;
+
+const PANIC_IN_IF: &str = r#"
+fn main(x: u32) {
+    if x == 0 {
+        panic_with_felt252('x is zero');
+    } else {
+        println!("x is {}", x);
+    }
+}
+"#;
+
+const COMMENTS_IN_ELSE: &str = r#"
+fn main(x: u32) -> u32 {
+    if x == 0 {
+        return 0;
+    } else {
+        // Double the value.
+        let y = x * 2;
+        y + 1
+    }
+}
+"#;
+
+const ELSE_IF_CHAIN: &str = r#"
+fn main(x: u32) -> u32 {
+    if x == 0 {
+        return 0;
+    } else if x == 1 {
+        return 1;
+    } else {
+        x
+    }
+}
+"#;
+
+const IF_NOT_DIVERGING: &str = r#"
+fn main(x: u32) -> u32 {
+    let mut y = x;
+    if x == 0 {
+        y += 1;
+    } else {
+        y += 2;
+    }
+    y
+}
+"#;
+
+const IF_USED_AS_EXPRESSION: &str = r#"
+fn main(x: u32) -> u32 {
+    let y = if x == 0 {
+        return 0;
+    } else {
+        x + 1
+    };
+    y
+}
+"#;
+
+const ELSE_BINDING_USED_AFTER_IF: &str = r#"
+fn main(x: u32) -> u32 {
+    let mut total = 1;
+    let y = 1;
+    if x == 0 {
+        return 0;
+    } else {
+        let y = x * 2;
+        total += y;
+    }
+    total + y
+}
+"#;
+
+#[test]
+fn return_in_if_diagnostics() {
+    test_lint_diagnostics!(RETURN_IN_IF, @r"
+    Plugin diagnostic: Redundant `else` block. The `if` block always diverges, so the `else` block can be removed.
+     --> lib.cairo:3:5-8:5
+          if x == 0 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn return_in_if_fixer() {
+    test_lint_fixer!(RETURN_IN_IF, @r"
+    fn main(x: u32) -> u32 {
+        if x == 0 {
+            return 0;
+        }
+        let y = x * 2;
+        y + 1
+    }
+    ");
+}
+
+#[test]
+fn return_in_if_allowed_diagnostics() {
+    test_lint_diagnostics!(RETURN_IN_IF_ALLOWED, @"");
+}
+
+#[test]
+fn return_in_if_allowed_fixer() {
+    test_lint_fixer!(RETURN_IN_IF_ALLOWED, @r"
+    fn main(x: u32) -> u32 {
+        #[allow(redundant_else)]
+        if x == 0 {
+            return 0;
+        } else {
+            let y = x * 2;
+            y + 1
+        }
+    }
+    ");
+}
+
+#[test]
+fn break_in_loop_diagnostics() {
+    test_lint_diagnostics!(BREAK_IN_LOOP, @r"
+    Plugin diagnostic: Redundant `else` block. The `if` block always diverges, so the `else` block can be removed.
+     --> lib.cairo:6:9-10:9
+              if a > 10 {
+     _________^
+    | ...
+    |         }
+    |_________^
+    ");
+}
+
+#[test]
+fn break_in_loop_fixer() {
+    test_lint_fixer!(BREAK_IN_LOOP, @r"
+    fn main() {
+        let mut a = 1_u32;
+        loop {
+            a += 1;
+            if a > 10 {
+                break;
+            }
+            a += 2;
+        }
+    }
+    ");
+}
+
+#[test]
+fn panic_in_if_diagnostics() {
+    test_lint_diagnostics!(PANIC_IN_IF, @r"
+    Plugin diagnostic: Redundant `else` block. The `if` block always diverges, so the `else` block can be removed.
+     --> lib.cairo:3:5-7:5
+          if x == 0 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn panic_in_if_fixer() {
+    test_lint_fixer!(PANIC_IN_IF, @r#"
+    fn main(x: u32) {
+        if x == 0 {
+            panic_with_felt252('x is zero');
+        }
+        println!("x is {}", x);
+    }
+    "#);
+}
+
+#[test]
+fn comments_in_else_fixer() {
+    test_lint_fixer!(COMMENTS_IN_ELSE, @r"
+    fn main(x: u32) -> u32 {
+        if x == 0 {
+            return 0;
+        }
+        // Double the value.
+        let y = x * 2;
+        y + 1
+    }
+    ");
+}
+
+#[test]
+fn else_if_chain_diagnostics() {
+    test_lint_diagnostics!(ELSE_IF_CHAIN, @"");
+}
+
+#[test]
+fn if_not_diverging_diagnostics() {
+    test_lint_diagnostics!(IF_NOT_DIVERGING, @"");
+}
+
+#[test]
+fn if_used_as_expression_diagnostics() {
+    test_lint_diagnostics!(IF_USED_AS_EXPRESSION, @"");
+}
+
+#[test]
+fn else_binding_used_after_if_diagnostics() {
+    test_lint_diagnostics!(ELSE_BINDING_USED_AFTER_IF, @r"
+    Plugin diagnostic: Redundant `else` block. The `if` block always diverges, so the `else` block can be removed.
+     --> lib.cairo:5:5-10:5
+          if x == 0 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn else_binding_used_after_if_fixer() {
+    test_lint_fixer!(ELSE_BINDING_USED_AFTER_IF, @r"
+    fn main(x: u32) -> u32 {
+        let mut total = 1;
+        let y = 1;
+        if x == 0 {
+            return 0;
+        } else {
+            let y = x * 2;
+            total += y;
+        }
+        total + y
+    }
+    ");
+}
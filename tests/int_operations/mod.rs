@@ -84,6 +84,102 @@ fn main() {
 }
 "#;
 
+const UNSIGNED_LT_ZERO: &str = r#"
+fn main(x: u32) -> bool {
+    x < 0
+}
+"#;
+
+const ZERO_GT_UNSIGNED: &str = r#"
+fn main() {
+    let x: u8 = 1;
+    if 0 > x {}
+}
+"#;
+
+const UNSIGNED_GE_ZERO: &str = r#"
+fn main() {
+    let x: u256 = 1;
+    if x >= 0 {}
+}
+"#;
+
+const ZERO_LE_UNSIGNED: &str = r#"
+fn main(x: u64) -> bool {
+    0 <= x
+}
+"#;
+
+const UNSIGNED_GE_ZERO_WITH_FUNCTION_CALL: &str = r#"
+fn f() -> u32 {
+    1
+}
+
+fn main() -> bool {
+    f() >= 0
+}
+"#;
+
+const SIGNED_LT_ZERO: &str = r#"
+fn main(x: i32) -> bool {
+    x < 0
+}
+"#;
+
+const UNSIGNED_LT_ZERO_ALLOWED: &str = r#"
+#[allow(impossible_unsigned_comparison)]
+fn main(x: u32) -> bool {
+    x < 0
+}
+"#;
+
+const U8_GT_MAX: &str = r#"
+fn main(x: u8) -> bool {
+    x > 255
+}
+"#;
+
+const U8_MAX_LT: &str = r#"
+fn main(x: u8) -> bool {
+    255 < x
+}
+"#;
+
+const U8_LE_MAX: &str = r#"
+fn main(x: u8) -> bool {
+    x <= 255
+}
+"#;
+
+const U8_LE_BOUNDED_MAX: &str = r#"
+use core::num::traits::Bounded;
+
+fn main(x: u8) -> bool {
+    x <= Bounded::MAX
+}
+"#;
+
+const U8_LT_BOUNDED_MIN: &str = r#"
+use core::num::traits::Bounded;
+
+fn main(x: u8) -> bool {
+    x < Bounded::MIN
+}
+"#;
+
+const U8_LT_MAX: &str = r#"
+fn main(x: u8) -> bool {
+    x < 255
+}
+"#;
+
+const U8_GT_MAX_ALLOWED: &str = r#"
+#[allow(absurd_extreme_comparisons)]
+fn main(x: u8) -> bool {
+    x > 255
+}
+"#;
+
 #[test]
 fn int_ge_plus_one_diagnostics() {
     test_lint_diagnostics!(INT_GE_PLUS_ONE, @r"
@@ -258,3 +354,174 @@ fn int_lt_min_one_fixer() {
     }
     "#);
 }
+
+#[test]
+fn unsigned_lt_zero_diagnostics() {
+    test_lint_diagnostics!(UNSIGNED_LT_ZERO, @r"
+    Plugin diagnostic: Impossible condition, an unsigned integer is never negative. Always false
+     --> lib.cairo:3:5
+        x < 0
+        ^^^^^
+    ");
+}
+
+#[test]
+fn unsigned_lt_zero_fixer() {
+    test_lint_fixer!(UNSIGNED_LT_ZERO, @r"
+    fn main(x: u32) -> bool {
+        false
+    }
+    ");
+}
+
+#[test]
+fn zero_gt_unsigned_diagnostics() {
+    test_lint_diagnostics!(ZERO_GT_UNSIGNED, @r"
+    Plugin diagnostic: Impossible condition, an unsigned integer is never negative. Always false
+     --> lib.cairo:4:8
+        if 0 > x {}
+           ^^^^^
+    ");
+}
+
+#[test]
+fn zero_gt_unsigned_fixer() {
+    test_lint_fixer!(ZERO_GT_UNSIGNED, @r"
+    fn main() {
+        let x: u8 = 1;
+        if false {}
+    }
+    ");
+}
+
+#[test]
+fn unsigned_ge_zero_diagnostics() {
+    test_lint_diagnostics!(UNSIGNED_GE_ZERO, @r"
+    Plugin diagnostic: Redundant condition, an unsigned integer is never negative. Always true
+     --> lib.cairo:4:8
+        if x >= 0 {}
+           ^^^^^^
+    ");
+}
+
+#[test]
+fn unsigned_ge_zero_fixer() {
+    test_lint_fixer!(UNSIGNED_GE_ZERO, @r"
+    fn main() {
+        let x: u256 = 1;
+        if true {}
+    }
+    ");
+}
+
+#[test]
+fn zero_le_unsigned_diagnostics() {
+    test_lint_diagnostics!(ZERO_LE_UNSIGNED, @r"
+    Plugin diagnostic: Redundant condition, an unsigned integer is never negative. Always true
+     --> lib.cairo:3:5
+        0 <= x
+        ^^^^^^
+    ");
+}
+
+#[test]
+fn zero_le_unsigned_fixer() {
+    test_lint_fixer!(ZERO_LE_UNSIGNED, @r"
+    fn main(x: u64) -> bool {
+        true
+    }
+    ");
+}
+
+#[test]
+fn unsigned_ge_zero_with_function_call_diagnostics() {
+    test_lint_diagnostics!(UNSIGNED_GE_ZERO_WITH_FUNCTION_CALL, @r"
+    Plugin diagnostic: Redundant condition, an unsigned integer is never negative. Always true
+     --> lib.cairo:7:5
+        f() >= 0
+        ^^^^^^^^
+    ");
+}
+
+#[test]
+fn unsigned_ge_zero_with_function_call_fixer() {
+    test_lint_fixer!(UNSIGNED_GE_ZERO_WITH_FUNCTION_CALL, @r"
+    fn f() -> u32 {
+        1
+    }
+
+    fn main() -> bool {
+        f() >= 0
+    }
+    ");
+}
+
+#[test]
+fn signed_lt_zero_diagnostics() {
+    test_lint_diagnostics!(SIGNED_LT_ZERO, @"");
+}
+
+#[test]
+fn unsigned_lt_zero_allowed_diagnostics() {
+    test_lint_diagnostics!(UNSIGNED_LT_ZERO_ALLOWED, @"");
+}
+
+#[test]
+fn u8_gt_max_diagnostics() {
+    test_lint_diagnostics!(U8_GT_MAX, @r"
+    Plugin diagnostic: Absurd condition, an integer never exceeds the bounds of its type. The result is always the same
+     --> lib.cairo:3:5
+        x > 255
+        ^^^^^^^
+    ");
+}
+
+#[test]
+fn u8_max_lt_diagnostics() {
+    test_lint_diagnostics!(U8_MAX_LT, @r"
+    Plugin diagnostic: Absurd condition, an integer never exceeds the bounds of its type. The result is always the same
+     --> lib.cairo:3:5
+        255 < x
+        ^^^^^^^
+    ");
+}
+
+#[test]
+fn u8_le_max_diagnostics() {
+    test_lint_diagnostics!(U8_LE_MAX, @r"
+    Plugin diagnostic: Absurd condition, an integer never exceeds the bounds of its type. The result is always the same
+     --> lib.cairo:3:5
+        x <= 255
+        ^^^^^^^^
+    ");
+}
+
+#[test]
+fn u8_le_bounded_max_diagnostics() {
+    test_lint_diagnostics!(U8_LE_BOUNDED_MAX, @r"
+    Plugin diagnostic: Absurd condition, an integer never exceeds the bounds of its type. The result is always the same
+     --> lib.cairo:5:5
+        x <= Bounded::MAX
+        ^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn u8_lt_bounded_min_diagnostics() {
+    test_lint_diagnostics!(U8_LT_BOUNDED_MIN, @r"
+    Plugin diagnostic: Absurd condition, an integer never exceeds the bounds of its type. The result is always the same
+     --> lib.cairo:5:5
+        x < Bounded::MIN
+        ^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn u8_lt_max_diagnostics() {
+    test_lint_diagnostics!(U8_LT_MAX, @"");
+}
+
+#[test]
+fn u8_gt_max_allowed_diagnostics() {
+    test_lint_diagnostics!(U8_GT_MAX_ALLOWED, @"");
+}
@@ -0,0 +1,34 @@
+use cairo_lint::diagnostics::{diagnostic_to_json, diagnostics_to_json};
+use serde_json::json;
+
+use crate::helpers::{get_diags, init_corelib, setup::setup_test_crate_ex};
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+#[test]
+fn double_parens_diagnostic_to_json() {
+    let mut db = ::cairo_lint::LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap();
+    let test_crate = setup_test_crate_ex(&mut db, DOUBLE_PARENS);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+
+    let expected = json!({
+        "lint": "double_parens",
+        "kind": "DoubleParens",
+        "file": "lib.cairo",
+        "start": { "line": 3, "column": 5 },
+        "end": { "line": 3, "column": 10 },
+        "message": "unnecessary double parentheses found. Consider removing them.",
+        "fix_available": true,
+    });
+    assert_eq!(diagnostic_to_json(&diags[0], &db), expected);
+    assert_eq!(diagnostics_to_json(&diags, &db), json!([expected]));
+}
@@ -0,0 +1,143 @@
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+use crate::test_lint_diagnostics;
+
+const LARGE_FIXED_SIZE_ARRAY_VARIANT: &str = r#"
+#[derive(Drop)]
+enum Message {
+    Quit,
+    Data: [felt252; 32],
+}
+"#;
+
+const LARGE_FIXED_SIZE_ARRAY_VARIANT_ALLOWED: &str = r#"
+#[allow(large_enum_variant)]
+#[derive(Drop)]
+enum Message {
+    Quit,
+    Data: [felt252; 32],
+}
+"#;
+
+const LARGE_STRUCT_VARIANT: &str = r#"
+#[derive(Drop)]
+struct Point {
+    x: u256,
+    y: u256,
+    z: u256,
+}
+
+#[derive(Drop)]
+enum Shape {
+    Empty,
+    Dot: Point,
+    Triangle: (Point, Point, Point),
+}
+"#;
+
+const SIMILARLY_SIZED_VARIANTS: &str = r#"
+#[derive(Drop)]
+enum Message {
+    First: [felt252; 32],
+    Second: [felt252; 20],
+}
+"#;
+
+const SMALL_VARIANTS: &str = r#"
+#[derive(Drop)]
+enum Message {
+    Quit,
+    Move: (u32, u32),
+    Write: ByteArray,
+}
+"#;
+
+const BOXED_VARIANT: &str = r#"
+#[derive(Drop)]
+enum Message {
+    Quit,
+    Data: Box<[felt252; 32]>,
+}
+"#;
+
+const SMALL_PAYLOAD: &str = r#"
+#[derive(Drop)]
+enum Message {
+    Quit,
+    Move: (u32, u32, u32),
+}
+"#;
+
+#[test]
+fn large_fixed_size_array_variant_diagnostics() {
+    test_lint_diagnostics!(LARGE_FIXED_SIZE_ARRAY_VARIANT, @r"
+    Plugin diagnostic: This enum variant is much larger than the other ones. Consider boxing its payload with `Box<T>`.
+     --> lib.cairo:5:5
+        Data: [felt252; 32],
+        ^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn large_fixed_size_array_variant_allowed_diagnostics() {
+    test_lint_diagnostics!(LARGE_FIXED_SIZE_ARRAY_VARIANT_ALLOWED, @"");
+}
+
+#[test]
+fn large_struct_variant_diagnostics() {
+    test_lint_diagnostics!(LARGE_STRUCT_VARIANT, @r"
+    Plugin diagnostic: This enum variant is much larger than the other ones. Consider boxing its payload with `Box<T>`.
+     --> lib.cairo:13:5
+        Triangle: (Point, Point, Point),
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn similarly_sized_variants_diagnostics() {
+    test_lint_diagnostics!(SIMILARLY_SIZED_VARIANTS, @"");
+}
+
+#[test]
+fn small_variants_diagnostics() {
+    test_lint_diagnostics!(SMALL_VARIANTS, @"");
+}
+
+#[test]
+fn boxed_variant_diagnostics() {
+    test_lint_diagnostics!(BOXED_VARIANT, @"");
+}
+
+#[test]
+fn small_payload_diagnostics() {
+    test_lint_diagnostics!(SMALL_PAYLOAD, @"");
+}
+
+fn large_enum_variant_count(code: &str, large_enum_variant_threshold: usize) -> usize {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("large_enum_variant".to_string(), true)]),
+        large_enum_variant_threshold,
+        ..Default::default()
+    };
+    lint_string(
+        code,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| diagnostics.len(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn lowered_threshold() {
+    assert_eq!(large_enum_variant_count(SMALL_PAYLOAD, 2), 1);
+}
+
+#[test]
+fn raised_threshold() {
+    assert_eq!(
+        large_enum_variant_count(LARGE_FIXED_SIZE_ARRAY_VARIANT, 32),
+        0
+    );
+}
@@ -0,0 +1,315 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const SPAN_LEN_GREATER_THAN_ZERO: &str = r#"
+fn main(values: Span<u32>) -> bool {
+    values.len() > 0
+}
+"#;
+
+const SPAN_LEN_GREATER_THAN_ZERO_ALLOWED: &str = r#"
+fn main(values: Span<u32>) -> bool {
+    #[allow(len_zero)]
+    values.len() > 0
+}
+"#;
+
+const ARRAY_LEN_EQUAL_TO_ZERO: &str = r#"
+fn main(values: @Array<u32>) -> bool {
+    values.len() == 0
+}
+"#;
+
+const ZERO_NOT_EQUAL_TO_ARRAY_LEN: &str = r#"
+fn main(values: @Array<u32>) -> bool {
+    0 != values.len()
+}
+"#;
+
+const ZERO_LESS_THAN_SPAN_LEN: &str = r#"
+fn main(values: Span<u32>) -> bool {
+    0 < values.len()
+}
+"#;
+
+const LEN_NOT_EQUAL_TO_ZERO_IN_CONDITION: &str = r#"
+fn main(values: Span<u32>) {
+    if values.len() != 0 {
+        println!("not empty");
+    }
+}
+"#;
+
+const LEN_EQUAL_TO_ZERO_IN_CONDITION: &str = r#"
+fn main(values: Span<u32>) {
+    if values.len() == 0 {
+        println!("empty");
+    }
+}
+"#;
+
+const BYTE_ARRAY_LEN_EQUAL_TO_ZERO: &str = r#"
+fn main(s: @ByteArray) -> bool {
+    s.len() == 0
+}
+"#;
+
+const LEN_COMPARED_IN_IS_EMPTY_IMPLEMENTATION: &str = r#"
+#[derive(Drop)]
+struct Stack {
+    items: Array<u32>,
+}
+
+#[generate_trait]
+impl StackImpl of StackTrait {
+    fn len(self: @Stack) -> usize {
+        self.items.len()
+    }
+
+    fn is_empty(self: @Stack) -> bool {
+        self.len() == 0
+    }
+}
+
+fn main(stack: @Stack) -> bool {
+    stack.len() > 0
+}
+"#;
+
+const LEN_OF_FIELD_COMPARED_IN_IS_EMPTY_IMPLEMENTATION: &str = r#"
+#[derive(Drop)]
+struct Queue {
+    items: Array<u32>,
+}
+
+#[generate_trait]
+impl QueueImpl of QueueTrait {
+    fn is_empty(self: @Queue) -> bool {
+        self.items.len() == 0
+    }
+}
+"#;
+
+const LEN_COMPARED_TO_ONE: &str = r#"
+fn main(values: Span<u32>) -> bool {
+    values.len() == 1
+}
+"#;
+
+#[test]
+fn span_len_greater_than_zero_diagnostics() {
+    test_lint_diagnostics!(SPAN_LEN_GREATER_THAN_ZERO, @r"
+    Plugin diagnostic: Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead.
+     --> lib.cairo:3:5
+        values.len() > 0
+        ^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn span_len_greater_than_zero_fixer() {
+    test_lint_fixer!(SPAN_LEN_GREATER_THAN_ZERO, @r"
+    fn main(values: Span<u32>) -> bool {
+        !values.is_empty()
+    }
+    ");
+}
+
+#[test]
+fn span_len_greater_than_zero_allowed_diagnostics() {
+    test_lint_diagnostics!(SPAN_LEN_GREATER_THAN_ZERO_ALLOWED, @"");
+}
+
+#[test]
+fn span_len_greater_than_zero_allowed_fixer() {
+    test_lint_fixer!(SPAN_LEN_GREATER_THAN_ZERO_ALLOWED, @r"
+    fn main(values: Span<u32>) -> bool {
+        #[allow(len_zero)]
+        values.len() > 0
+    }
+    ");
+}
+
+#[test]
+fn array_len_equal_to_zero_diagnostics() {
+    test_lint_diagnostics!(ARRAY_LEN_EQUAL_TO_ZERO, @r"
+    Plugin diagnostic: Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead.
+     --> lib.cairo:3:5
+        values.len() == 0
+        ^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn array_len_equal_to_zero_fixer() {
+    test_lint_fixer!(ARRAY_LEN_EQUAL_TO_ZERO, @r"
+    fn main(values: @Array<u32>) -> bool {
+        values.is_empty()
+    }
+    ");
+}
+
+#[test]
+fn zero_not_equal_to_array_len_diagnostics() {
+    test_lint_diagnostics!(ZERO_NOT_EQUAL_TO_ARRAY_LEN, @r"
+    Plugin diagnostic: Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead.
+     --> lib.cairo:3:5
+        0 != values.len()
+        ^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn zero_not_equal_to_array_len_fixer() {
+    test_lint_fixer!(ZERO_NOT_EQUAL_TO_ARRAY_LEN, @r"
+    fn main(values: @Array<u32>) -> bool {
+        !values.is_empty()
+    }
+    ");
+}
+
+#[test]
+fn zero_less_than_span_len_diagnostics() {
+    test_lint_diagnostics!(ZERO_LESS_THAN_SPAN_LEN, @r"
+    Plugin diagnostic: Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead.
+     --> lib.cairo:3:5
+        0 < values.len()
+        ^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn zero_less_than_span_len_fixer() {
+    test_lint_fixer!(ZERO_LESS_THAN_SPAN_LEN, @r"
+    fn main(values: Span<u32>) -> bool {
+        !values.is_empty()
+    }
+    ");
+}
+
+#[test]
+fn len_not_equal_to_zero_in_condition_diagnostics() {
+    test_lint_diagnostics!(LEN_NOT_EQUAL_TO_ZERO_IN_CONDITION, @r"
+    Plugin diagnostic: Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead.
+     --> lib.cairo:3:8
+        if values.len() != 0 {
+           ^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn len_not_equal_to_zero_in_condition_fixer() {
+    test_lint_fixer!(LEN_NOT_EQUAL_TO_ZERO_IN_CONDITION, @r#"
+    fn main(values: Span<u32>) {
+        if !values.is_empty() {
+            println!("not empty");
+        }
+    }
+    "#);
+}
+
+#[test]
+fn len_equal_to_zero_in_condition_diagnostics() {
+    test_lint_diagnostics!(LEN_EQUAL_TO_ZERO_IN_CONDITION, @r"
+    Plugin diagnostic: Manual check for `is_empty` detected. Consider using `is_empty()` instead
+     --> lib.cairo:3:8
+        if values.len() == 0 {
+           ^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn len_equal_to_zero_in_condition_fixer() {
+    test_lint_fixer!(LEN_EQUAL_TO_ZERO_IN_CONDITION, @r#"
+    fn main(values: Span<u32>) {
+        if values.is_empty() {
+            println!("empty");
+        }
+    }
+    "#);
+}
+
+#[test]
+fn byte_array_len_equal_to_zero_diagnostics() {
+    test_lint_diagnostics!(BYTE_ARRAY_LEN_EQUAL_TO_ZERO, @r"
+    Plugin diagnostic: Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead.
+     --> lib.cairo:3:5
+        s.len() == 0
+        ^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn len_compared_in_is_empty_implementation_diagnostics() {
+    test_lint_diagnostics!(LEN_COMPARED_IN_IS_EMPTY_IMPLEMENTATION, @r"
+    Plugin diagnostic: Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead.
+     --> lib.cairo:19:5
+        stack.len() > 0
+        ^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn len_compared_in_is_empty_implementation_fixer() {
+    test_lint_fixer!(LEN_COMPARED_IN_IS_EMPTY_IMPLEMENTATION, @r"
+    #[derive(Drop)]
+    struct Stack {
+        items: Array<u32>,
+    }
+
+    #[generate_trait]
+    impl StackImpl of StackTrait {
+        fn len(self: @Stack) -> usize {
+            self.items.len()
+        }
+
+        fn is_empty(self: @Stack) -> bool {
+            self.len() == 0
+        }
+    }
+
+    fn main(stack: @Stack) -> bool {
+        !stack.is_empty()
+    }
+    ");
+}
+
+#[test]
+fn len_of_field_compared_in_is_empty_implementation_diagnostics() {
+    test_lint_diagnostics!(LEN_OF_FIELD_COMPARED_IN_IS_EMPTY_IMPLEMENTATION, @r"
+    Plugin diagnostic: Comparison of the length with zero detected. Consider checking for emptiness with `is_empty()` instead.
+     --> lib.cairo:10:9
+            self.items.len() == 0
+            ^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn len_of_field_compared_in_is_empty_implementation_fixer() {
+    test_lint_fixer!(LEN_OF_FIELD_COMPARED_IN_IS_EMPTY_IMPLEMENTATION, @r"
+    #[derive(Drop)]
+    struct Queue {
+        items: Array<u32>,
+    }
+
+    #[generate_trait]
+    impl QueueImpl of QueueTrait {
+        fn is_empty(self: @Queue) -> bool {
+            self.items.is_empty()
+        }
+    }
+    ");
+}
+
+#[test]
+fn len_compared_to_one_diagnostics() {
+    test_lint_diagnostics!(LEN_COMPARED_TO_ONE, @"");
+}
+
+#[test]
+fn len_compared_to_one_fixer() {
+    test_lint_fixer!(LEN_COMPARED_TO_ONE, @r"
+    fn main(values: Span<u32>) -> bool {
+        values.len() == 1
+    }
+    ");
+}
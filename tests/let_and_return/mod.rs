@@ -0,0 +1,172 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const LET_AND_TAIL: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    let sum = a + b;
+    sum
+}
+"#;
+
+const LET_AND_RETURN: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    if a > b {
+        let difference = a - b;
+        return difference;
+    }
+    0
+}
+"#;
+
+const TAIL_NOT_JUST_THE_VARIABLE: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    let sum = a + b;
+    sum * 2
+}
+"#;
+
+const TYPE_ANNOTATION_OF_RETURN_TYPE: &str = r#"
+fn main(a: u8) -> u32 {
+    let value: u32 = a.into();
+    value
+}
+"#;
+
+const LOAD_BEARING_TYPE_ANNOTATION: &str = r#"
+fn main(a: u8) {
+    let value = {
+        let wide: u32 = a.into();
+        wide
+    };
+    println!("{}", value);
+}
+"#;
+
+const COMMENT_BEFORE_TAIL: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    let sum = a + b;
+    // The sum of both values.
+    sum
+}
+"#;
+
+const LET_AND_TAIL_ALLOWED: &str = r#"
+#[allow(let_and_return)]
+fn main(a: u32, b: u32) -> u32 {
+    let sum = a + b;
+    sum
+}
+"#;
+
+#[test]
+fn let_and_tail_diagnostics() {
+    test_lint_diagnostics!(LET_AND_TAIL, enabling ["let_and_return"], @r"
+    Plugin diagnostic: Returning a variable right after binding it. Consider returning the expression directly.
+     --> lib.cairo:3:5
+        let sum = a + b;
+        ^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn let_and_tail_fixer() {
+    test_lint_fixer!(LET_AND_TAIL, enabling ["let_and_return"], @r"
+    fn main(a: u32, b: u32) -> u32 {
+        a + b
+    }
+    ");
+}
+
+#[test]
+fn let_and_return_diagnostics() {
+    test_lint_diagnostics!(LET_AND_RETURN, enabling ["let_and_return"], @r"
+    Plugin diagnostic: Returning a variable right after binding it. Consider returning the expression directly.
+     --> lib.cairo:4:9
+            let difference = a - b;
+            ^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn let_and_return_fixer() {
+    test_lint_fixer!(LET_AND_RETURN, enabling ["let_and_return"], @r"
+    fn main(a: u32, b: u32) -> u32 {
+        if a > b {
+            return a - b;
+        }
+        0
+    }
+    ");
+}
+
+#[test]
+fn tail_not_just_the_variable_diagnostics() {
+    test_lint_diagnostics!(TAIL_NOT_JUST_THE_VARIABLE, enabling ["let_and_return"], @"");
+}
+
+#[test]
+fn type_annotation_of_return_type_diagnostics() {
+    test_lint_diagnostics!(TYPE_ANNOTATION_OF_RETURN_TYPE, enabling ["let_and_return"], @r"
+    Plugin diagnostic: Returning a variable right after binding it. Consider returning the expression directly.
+     --> lib.cairo:3:5
+        let value: u32 = a.into();
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn type_annotation_of_return_type_fixer() {
+    test_lint_fixer!(TYPE_ANNOTATION_OF_RETURN_TYPE, enabling ["let_and_return"], @r"
+    fn main(a: u8) -> u32 {
+        a.into()
+    }
+    ");
+}
+
+#[test]
+fn load_bearing_type_annotation_diagnostics() {
+    test_lint_diagnostics!(LOAD_BEARING_TYPE_ANNOTATION, enabling ["let_and_return"], @r"
+    Plugin diagnostic: Returning a variable right after binding it. Consider returning the expression directly.
+     --> lib.cairo:4:9
+            let wide: u32 = a.into();
+            ^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn load_bearing_type_annotation_fixer() {
+    test_lint_fixer!(LOAD_BEARING_TYPE_ANNOTATION, enabling ["let_and_return"], @r#"
+    fn main(a: u8) {
+        let value = {
+            let wide: u32 = a.into();
+            wide
+        };
+        println!("{}", value);
+    }
+    "#);
+}
+
+#[test]
+fn comment_before_tail_diagnostics() {
+    test_lint_diagnostics!(COMMENT_BEFORE_TAIL, enabling ["let_and_return"], @r"
+    Plugin diagnostic: Returning a variable right after binding it. Consider returning the expression directly.
+     --> lib.cairo:3:5
+        let sum = a + b;
+        ^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn comment_before_tail_fixer() {
+    test_lint_fixer!(COMMENT_BEFORE_TAIL, enabling ["let_and_return"], @r"
+    fn main(a: u32, b: u32) -> u32 {
+        let sum = a + b;
+        // The sum of both values.
+        sum
+    }
+    ");
+}
+
+#[test]
+fn let_and_tail_allowed_diagnostics() {
+    test_lint_diagnostics!(LET_AND_TAIL_ALLOWED, enabling ["let_and_return"], @"");
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+use cairo_lint::context::{
+    code_for_diagnostic_message, get_all_lint_codes, get_lint_by_code,
+    get_name_for_diagnostic_message,
+};
+
+#[test]
+fn every_lint_has_a_well_formed_code() {
+    for code in get_all_lint_codes() {
+        assert!(
+            code.len() == 6
+                && code.starts_with("CL")
+                && code[2..].chars().all(|c| c.is_ascii_digit()),
+            "`{code}` is not a valid lint code"
+        );
+    }
+}
+
+#[test]
+fn lint_codes_are_unique() {
+    let codes = get_all_lint_codes();
+    let unique_codes = codes.iter().collect::<HashSet<_>>();
+    assert_eq!(codes.len(), unique_codes.len(), "lint codes are not unique");
+}
+
+#[test]
+fn lint_by_code() {
+    let lint = get_lint_by_code("CL0003").unwrap();
+    assert_eq!(lint.allowed_name(), "double_parens");
+    assert!(get_lint_by_code("CL9999").is_none());
+}
+
+#[test]
+fn code_for_diagnostic_message_matches_lint() {
+    let message = "unnecessary double parentheses found. Consider removing them.";
+    assert_eq!(code_for_diagnostic_message(message), Some("CL0003"));
+    assert_eq!(
+        get_name_for_diagnostic_message(message),
+        get_lint_by_code("CL0003").map(|lint| lint.allowed_name())
+    );
+    assert!(code_for_diagnostic_message("not a lint message").is_none());
+}
@@ -0,0 +1,93 @@
+use cairo_lint::context::{LintGroup, get_lints_in_group};
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+use crate::test_lint_diagnostics;
+
+const COMPLEXITY_GROUP_ALLOWED: &str = r#"
+#[allow(cairo_lint::complexity)]
+fn main() {
+    let x = true;
+    let y = true;
+    if x {
+        if y {
+            println!("both");
+        }
+    }
+    panic!("panic");
+}
+"#;
+
+const COLLAPSIBLE_IF_AND_PANIC: &str = r#"
+fn main() {
+    let x = true;
+    let y = true;
+    if x {
+        if y {
+            println!("both");
+        }
+    }
+    panic!("panic");
+}
+"#;
+
+#[test]
+fn complexity_group_allowed_diagnostics() {
+    test_lint_diagnostics!(COMPLEXITY_GROUP_ALLOWED, @r#"
+    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+     --> lib.cairo:11:5
+        panic!("panic");
+        ^^^^^
+    "#);
+}
+
+fn lint_messages(tool_metadata: CairoLintToolMetadata) -> Vec<String> {
+    let params = LinterDiagnosticParams {
+        tool_metadata,
+        ..Default::default()
+    };
+    lint_string(
+        COLLAPSIBLE_IF_AND_PANIC,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.message)
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn complexity_group_contains_collapsible_if() {
+    let complexity_lints = get_lints_in_group(LintGroup::Complexity);
+    assert!(complexity_lints.contains(&"collapsible_if"));
+    assert!(!complexity_lints.contains(&"panic"));
+}
+
+#[test]
+fn group_disabled_in_tool_metadata() {
+    let messages = lint_messages(CairoLintToolMetadata::from([
+        ("complexity".to_string(), false),
+        ("panic".to_string(), true),
+    ]));
+    assert_eq!(
+        messages,
+        vec!["Leaving `panic` in the code is discouraged.".to_string()]
+    );
+}
+
+#[test]
+fn lint_entry_takes_precedence_over_group_entry() {
+    let messages = lint_messages(CairoLintToolMetadata::from([
+        ("complexity".to_string(), false),
+        ("collapsible_if".to_string(), true),
+        ("panic".to_string(), false),
+    ]));
+    assert_eq!(
+        messages,
+        vec!["Each `if`-statement adds one level of nesting, which makes code look more complex than it really is.".to_string()]
+    );
+}
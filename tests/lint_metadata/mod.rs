@@ -0,0 +1,52 @@
+use cairo_lint::context::{
+    CairoLintKind, LintGroup, all_lint_metadata, get_all_fix_messages, get_all_lint_codes,
+};
+
+#[test]
+fn metadata_covers_every_rule() {
+    let metadata = all_lint_metadata();
+    assert_eq!(metadata.len(), get_all_lint_codes().len());
+    assert_eq!(
+        metadata.iter().filter(|lint| lint.has_fixer).count(),
+        get_all_fix_messages().len()
+    );
+}
+
+#[test]
+fn every_lint_with_fixer_has_fix_message() {
+    for lint in all_lint_metadata() {
+        if lint.has_fixer {
+            assert!(
+                lint.fix_message.is_some_and(|message| !message.is_empty()),
+                "`{}` has a fixer but no fix message",
+                lint.name
+            );
+        } else {
+            assert_eq!(lint.fix_message, None);
+        }
+    }
+}
+
+#[test]
+fn metadata_of_a_lint() {
+    let double_parens = all_lint_metadata()
+        .into_iter()
+        .find(|lint| lint.code == "CL0003")
+        .unwrap();
+    assert_eq!(double_parens.name, "double_parens");
+    assert_eq!(double_parens.kind, CairoLintKind::DoubleParens);
+    assert_eq!(double_parens.group, LintGroup::Complexity);
+    assert!(double_parens.enabled_by_default);
+    assert!(double_parens.has_fixer);
+    assert!(double_parens.fix_message.is_some());
+}
+
+#[test]
+fn disabled_lint_metadata() {
+    let panic = all_lint_metadata()
+        .into_iter()
+        .find(|lint| lint.name == "panic")
+        .unwrap();
+    assert!(!panic.enabled_by_default);
+    assert!(!panic.has_fixer);
+}
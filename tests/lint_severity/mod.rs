@@ -0,0 +1,118 @@
+use cairo_lang_diagnostics::Severity;
+use cairo_lint::{
+    CairoLintSeverities, CairoLintToolMetadata, LintSeverity, LinterDiagnosticParams, lint_string,
+};
+
+use crate::helpers::get_corelib_path;
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+fn lint_with_severity(severity: LintSeverity) -> Vec<Severity> {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("double_parens".to_string(), true)]),
+        severities: CairoLintSeverities::from([("double_parens".to_string(), severity)]),
+        ..Default::default()
+    };
+    lint_string(
+        DOUBLE_PARENS,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.severity)
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn denied_lint_is_reported_as_error() {
+    assert_eq!(
+        lint_with_severity(LintSeverity::Deny),
+        vec![Severity::Error]
+    );
+}
+
+#[test]
+fn warned_lint_is_reported_as_warning() {
+    assert_eq!(
+        lint_with_severity(LintSeverity::Warn),
+        vec![Severity::Warning]
+    );
+}
+
+#[test]
+fn allowed_lint_is_filtered_out() {
+    assert!(lint_with_severity(LintSeverity::Allow).is_empty());
+}
+
+#[test]
+fn severity_overrides_disabled_tool_metadata() {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("double_parens".to_string(), false)]),
+        severities: CairoLintSeverities::from([("double_parens".to_string(), LintSeverity::Deny)]),
+        ..Default::default()
+    };
+    let severities = lint_string(
+        DOUBLE_PARENS,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.severity)
+                .collect::<Vec<_>>()
+        },
+    )
+    .unwrap();
+    assert_eq!(severities, vec![Severity::Error]);
+}
+
+const PANIC_AND_DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+
+fn fail() {
+    panic!("panic");
+}
+"#;
+
+#[test]
+fn denied_lint_is_error_while_default_lint_stays_warning() {
+    let params = LinterDiagnosticParams {
+        severities: CairoLintSeverities::from([("panic".to_string(), LintSeverity::Deny)]),
+        ..Default::default()
+    };
+    let diagnostics = lint_string(
+        PANIC_AND_DOUBLE_PARENS,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| (diagnostic.message, diagnostic.severity))
+                .collect::<Vec<_>>()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        diagnostics,
+        vec![
+            (
+                "unnecessary double parentheses found. Consider removing them.".to_string(),
+                Severity::Warning
+            ),
+            (
+                "Leaving `panic` in the code is discouraged.".to_string(),
+                Severity::Error
+            ),
+        ]
+    );
+}
@@ -0,0 +1,50 @@
+use cairo_lint::{LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+const DOUBLE_PARENS_ALLOWED: &str = r#"
+#[allow(double_parens)]
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+#[test]
+fn lint_string_returns_plugin_diagnostics() {
+    let messages = lint_string(
+        DOUBLE_PARENS,
+        &LinterDiagnosticParams::default(),
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.message)
+                .collect::<Vec<_>>()
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        messages,
+        vec!["unnecessary double parentheses found. Consider removing them."]
+    );
+}
+
+#[test]
+fn lint_string_respects_allow_attributes() {
+    let diagnostics_count = lint_string(
+        DOUBLE_PARENS_ALLOWED,
+        &LinterDiagnosticParams::default(),
+        Some(get_corelib_path()),
+        |_db, diagnostics| diagnostics.len(),
+    )
+    .unwrap();
+
+    assert_eq!(diagnostics_count, 0);
+}
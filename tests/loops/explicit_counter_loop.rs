@@ -0,0 +1,193 @@
+use crate::test_lint_diagnostics;
+
+const ADD_ASSIGN_COUNTER: &str = r#"
+fn main(values: Span<felt252>) -> felt252 {
+    let mut sum = 0;
+    let mut i: usize = 0;
+    for value in values {
+        sum += *value * i.into();
+        i += 1;
+    }
+    sum
+}
+"#;
+
+const ASSIGNMENT_COUNTER: &str = r#"
+fn main(values: Array<u32>) -> u32 {
+    let mut sum = 0;
+    let mut i = 0;
+    for value in values {
+        sum += value * i;
+        i = i + 1;
+    }
+    sum
+}
+"#;
+
+const ADD_ASSIGN_COUNTER_ALLOWED: &str = r#"
+#[allow(explicit_counter_loop)]
+fn main(values: Span<felt252>) -> felt252 {
+    let mut sum = 0;
+    let mut i: usize = 0;
+    for value in values {
+        sum += *value * i.into();
+        i += 1;
+    }
+    sum
+}
+"#;
+
+const COUNTER_USED_AFTER_LOOP: &str = r#"
+fn main(values: Span<felt252>) -> usize {
+    let mut i: usize = 0;
+    for _value in values {
+        i += 1;
+    }
+    i
+}
+"#;
+
+const COUNTER_NOT_STARTING_AT_ZERO: &str = r#"
+fn main(values: Span<felt252>) -> felt252 {
+    let mut sum = 0;
+    let mut i: usize = 1;
+    for value in values {
+        sum += *value * i.into();
+        i += 1;
+    }
+    sum
+}
+"#;
+
+const COUNTER_INCREMENTED_BY_TWO: &str = r#"
+fn main(values: Span<felt252>) -> felt252 {
+    let mut sum = 0;
+    let mut i: usize = 0;
+    for value in values {
+        sum += *value * i.into();
+        i += 2;
+    }
+    sum
+}
+"#;
+
+const COUNTER_INCREMENTED_BEFORE_END: &str = r#"
+fn main(values: Span<felt252>) -> felt252 {
+    let mut sum = 0;
+    let mut i: usize = 0;
+    for value in values {
+        i += 1;
+        sum += *value * i.into();
+    }
+    sum
+}
+"#;
+
+const COUNTER_MUTATED_IN_BODY: &str = r#"
+fn main(values: Span<felt252>) -> felt252 {
+    let mut sum = 0;
+    let mut i: usize = 0;
+    for value in values {
+        if *value == 0 {
+            i = 0;
+        }
+        sum += *value * i.into();
+        i += 1;
+    }
+    sum
+}
+"#;
+
+const COUNTER_SKIPPED_BY_CONTINUE: &str = r#"
+fn main(values: Span<felt252>) -> felt252 {
+    let mut sum = 0;
+    let mut i: usize = 0;
+    for value in values {
+        if *value == 0 {
+            continue;
+        }
+        sum += *value * i.into();
+        i += 1;
+    }
+    sum
+}
+"#;
+
+const COUNTER_USED_BEFORE_LOOP: &str = r#"
+fn main(values: Span<felt252>) -> felt252 {
+    let mut sum = 0;
+    let mut i: usize = 0;
+    let offset: felt252 = i.into();
+    for value in values {
+        sum += *value * i.into() + offset;
+        i += 1;
+    }
+    sum
+}
+"#;
+
+#[test]
+fn add_assign_counter_diagnostics() {
+    test_lint_diagnostics!(ADD_ASSIGN_COUNTER, @r"
+    Plugin diagnostic: The loop counter is only used as an index. Consider using `.enumerate()` on the iterator instead.
+     --> lib.cairo:5:5-8:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn assignment_counter_diagnostics() {
+    test_lint_diagnostics!(ASSIGNMENT_COUNTER, @r"
+    Plugin diagnostic: The loop counter is only used as an index. Consider using `.enumerate()` on the iterator instead.
+     --> lib.cairo:5:5-8:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn add_assign_counter_allowed_diagnostics() {
+    test_lint_diagnostics!(ADD_ASSIGN_COUNTER_ALLOWED, @"");
+}
+
+#[test]
+fn counter_used_after_loop_diagnostics() {
+    test_lint_diagnostics!(COUNTER_USED_AFTER_LOOP, @"");
+}
+
+#[test]
+fn counter_not_starting_at_zero_diagnostics() {
+    test_lint_diagnostics!(COUNTER_NOT_STARTING_AT_ZERO, @"");
+}
+
+#[test]
+fn counter_incremented_by_two_diagnostics() {
+    test_lint_diagnostics!(COUNTER_INCREMENTED_BY_TWO, @"");
+}
+
+#[test]
+fn counter_incremented_before_end_diagnostics() {
+    test_lint_diagnostics!(COUNTER_INCREMENTED_BEFORE_END, @"");
+}
+
+#[test]
+fn counter_mutated_in_body_diagnostics() {
+    test_lint_diagnostics!(COUNTER_MUTATED_IN_BODY, @"");
+}
+
+#[test]
+fn counter_skipped_by_continue_diagnostics() {
+    test_lint_diagnostics!(COUNTER_SKIPPED_BY_CONTINUE, @"");
+}
+
+#[test]
+fn counter_used_before_loop_diagnostics() {
+    test_lint_diagnostics!(COUNTER_USED_BEFORE_LOOP, @"");
+}
@@ -0,0 +1,232 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const ALL_WITH_FLAG: &str = r#"
+fn main(values: Span<u32>) -> bool {
+    let mut result = true;
+    for value in values {
+        if *value == 0 {
+            result = false;
+            break;
+        }
+    }
+    result
+}
+"#;
+
+const ALL_WITH_FLAG_ALLOWED: &str = r#"
+#[allow(manual_all)]
+fn main(values: Span<u32>) -> bool {
+    let mut result = true;
+    for value in values {
+        if *value == 0 {
+            result = false;
+            break;
+        }
+    }
+    result
+}
+"#;
+
+const ALL_WITH_RETURN: &str = r#"
+fn main(values: Span<u32>) -> bool {
+    for value in values {
+        if *value < 10 {
+            return false;
+        }
+    }
+    true
+}
+"#;
+
+const ANY_WITH_RETURN: &str = r#"
+fn main(values: Span<u32>) -> bool {
+    for value in values {
+        if *value > 10 {
+            return true;
+        }
+    }
+    false
+}
+"#;
+
+const ANY_WITH_RETURN_ALLOWED: &str = r#"
+#[allow(manual_any)]
+fn main(values: Span<u32>) -> bool {
+    for value in values {
+        if *value > 10 {
+            return true;
+        }
+    }
+    false
+}
+"#;
+
+const ANY_WITH_FLAG: &str = r#"
+fn main(values: Array<u32>) -> bool {
+    let mut result = false;
+    for value in values {
+        if value > 10 {
+            result = true;
+            break;
+        }
+    }
+    result
+}
+"#;
+
+const LOOP_WITH_SIDE_EFFECTS: &str = r#"
+fn main(values: Span<u32>) -> (bool, u32) {
+    let mut checked = 0;
+    for value in values {
+        checked += 1;
+        if *value == 0 {
+            return (false, checked);
+        }
+    }
+    (true, checked)
+}
+"#;
+
+const FLAG_WITHOUT_BREAK: &str = r#"
+fn main(values: Span<u32>) -> bool {
+    let mut result = true;
+    for value in values {
+        if *value == 0 {
+            result = false;
+        }
+    }
+    result
+}
+"#;
+
+#[test]
+fn all_with_flag_diagnostics() {
+    test_lint_diagnostics!(ALL_WITH_FLAG, @r"
+    Plugin diagnostic: Manual implementation of `all` detected. Consider using `.all()` on the iterator instead.
+     --> lib.cairo:4:5-9:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn all_with_flag_fixer() {
+    test_lint_fixer!(ALL_WITH_FLAG, @r"
+    fn main(values: Span<u32>) -> bool {
+        let mut result = true;
+        for value in values {
+            if *value == 0 {
+                result = false;
+                break;
+            }
+        }
+        result
+    }
+    ");
+}
+
+#[test]
+fn all_with_flag_allowed_diagnostics() {
+    test_lint_diagnostics!(ALL_WITH_FLAG_ALLOWED, @"");
+}
+
+#[test]
+fn all_with_return_diagnostics() {
+    test_lint_diagnostics!(ALL_WITH_RETURN, @r"
+    Plugin diagnostic: Manual implementation of `all` detected. Consider using `.all()` on the iterator instead.
+     --> lib.cairo:3:5-7:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn all_with_return_fixer() {
+    test_lint_fixer!(ALL_WITH_RETURN, @r"
+    fn main(values: Span<u32>) -> bool {
+        for value in values {
+            if *value < 10 {
+                return false;
+            }
+        }
+        true
+    }
+    ");
+}
+
+#[test]
+fn any_with_return_diagnostics() {
+    test_lint_diagnostics!(ANY_WITH_RETURN, @r"
+    Plugin diagnostic: Manual implementation of `any` detected. Consider using `.any()` on the iterator instead.
+     --> lib.cairo:3:5-7:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn any_with_return_fixer() {
+    test_lint_fixer!(ANY_WITH_RETURN, @r"
+    fn main(values: Span<u32>) -> bool {
+        for value in values {
+            if *value > 10 {
+                return true;
+            }
+        }
+        false
+    }
+    ");
+}
+
+#[test]
+fn any_with_return_allowed_diagnostics() {
+    test_lint_diagnostics!(ANY_WITH_RETURN_ALLOWED, @"");
+}
+
+#[test]
+fn any_with_flag_diagnostics() {
+    test_lint_diagnostics!(ANY_WITH_FLAG, @r"
+    Plugin diagnostic: Manual implementation of `any` detected. Consider using `.any()` on the iterator instead.
+     --> lib.cairo:4:5-9:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn any_with_flag_fixer() {
+    test_lint_fixer!(ANY_WITH_FLAG, @r"
+    fn main(values: Array<u32>) -> bool {
+        let mut result = false;
+        for value in values {
+            if value > 10 {
+                result = true;
+                break;
+            }
+        }
+        result
+    }
+    ");
+}
+
+#[test]
+fn loop_with_side_effects_diagnostics() {
+    test_lint_diagnostics!(LOOP_WITH_SIDE_EFFECTS, @"");
+}
+
+#[test]
+fn flag_without_break_diagnostics() {
+    test_lint_diagnostics!(FLAG_WITHOUT_BREAK, @"");
+}
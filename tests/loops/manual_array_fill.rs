@@ -0,0 +1,97 @@
+use crate::test_lint_diagnostics;
+
+const FOR_RANGE_CONSTANT_FILL: &str = r#"
+fn main(n: u32) -> Array<felt252> {
+    let mut arr = array![];
+    for _ in 0..n {
+        arr.append(0);
+    }
+    arr
+}
+"#;
+
+const WHILE_COUNTER_VARIABLE_FILL: &str = r#"
+fn main(n: u32, value: felt252) -> Array<felt252> {
+    let mut arr = array![];
+    let mut i = 0;
+    while i < n {
+        arr.append(value);
+        i += 1;
+    }
+    arr
+}
+"#;
+
+const FOR_RANGE_APPENDING_INDEX: &str = r#"
+fn main(n: u32) -> Array<u32> {
+    let mut arr = array![];
+    for i in 0..n {
+        arr.append(i);
+    }
+    arr
+}
+"#;
+
+const WHILE_COUNTER_APPENDING_COUNTER: &str = r#"
+fn main(n: u32) -> Array<u32> {
+    let mut arr = array![];
+    let mut i = 0;
+    while i < n {
+        arr.append(i);
+        i += 1;
+    }
+    arr
+}
+"#;
+
+const FOR_RANGE_CONSTANT_FILL_ALLOWED: &str = r#"
+fn main(n: u32) -> Array<felt252> {
+    let mut arr = array![];
+    #[allow(manual_array_fill)]
+    for _ in 0..n {
+        arr.append(0);
+    }
+    arr
+}
+"#;
+
+#[test]
+fn for_range_constant_fill_diagnostics() {
+    test_lint_diagnostics!(FOR_RANGE_CONSTANT_FILL, @r"
+    Plugin diagnostic: Loop appends the same value to an array on every iteration. Consider filling the array with a repeat helper instead.
+     --> lib.cairo:4:5-6:5
+          for _ in 0..n {
+     _____^
+    |         arr.append(0);
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn while_counter_variable_fill_diagnostics() {
+    test_lint_diagnostics!(WHILE_COUNTER_VARIABLE_FILL, @r"
+    Plugin diagnostic: Loop appends the same value to an array on every iteration. Consider filling the array with a repeat helper instead.
+     --> lib.cairo:5:5-8:5
+          while i < n {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn for_range_appending_index_diagnostics() {
+    test_lint_diagnostics!(FOR_RANGE_APPENDING_INDEX, @"");
+}
+
+#[test]
+fn while_counter_appending_counter_diagnostics() {
+    test_lint_diagnostics!(WHILE_COUNTER_APPENDING_COUNTER, @"");
+}
+
+#[test]
+fn for_range_constant_fill_allowed_diagnostics() {
+    test_lint_diagnostics!(FOR_RANGE_CONSTANT_FILL_ALLOWED, @"");
+}
@@ -0,0 +1,172 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const RETURN_TRUE_ON_MATCH: &str = r#"
+fn main(values: Span<felt252>, needle: felt252) -> bool {
+    for value in values {
+        if *value == needle {
+            return true;
+        }
+    }
+    false
+}
+"#;
+
+const RETURN_TRUE_ON_MATCH_ALLOWED: &str = r#"
+#[allow(manual_contains)]
+fn main(values: Span<felt252>, needle: felt252) -> bool {
+    for value in values {
+        if *value == needle {
+            return true;
+        }
+    }
+    false
+}
+"#;
+
+const FLAG_SET_WITH_BREAK: &str = r#"
+fn main(values: Span<felt252>, needle: felt252) -> bool {
+    let mut found = false;
+    for value in values {
+        if *value == needle {
+            found = true;
+            break;
+        }
+    }
+    found
+}
+"#;
+
+const FLAG_SET_WITHOUT_BREAK: &str = r#"
+fn main(values: Array<felt252>, needle: felt252) -> bool {
+    let mut found = false;
+    for value in values {
+        if needle == value {
+            found = true;
+        }
+    }
+    found
+}
+"#;
+
+const LOOP_WITH_OTHER_STATEMENTS: &str = r#"
+fn main(values: Span<felt252>, needle: felt252) -> (bool, u32) {
+    let mut checked = 0;
+    for value in values {
+        checked += 1;
+        if *value == needle {
+            return (true, checked);
+        }
+    }
+    (false, checked)
+}
+"#;
+
+const LOOP_RETURNING_ELEMENT: &str = r#"
+fn main(values: Span<felt252>, needle: felt252) -> felt252 {
+    for value in values {
+        if *value == needle {
+            return *value;
+        }
+    }
+    0
+}
+"#;
+
+#[test]
+fn return_true_on_match_diagnostics() {
+    test_lint_diagnostics!(RETURN_TRUE_ON_MATCH, @r"
+    Plugin diagnostic: Manual membership check detected. Consider using `.contains()` or `.any()` on the iterator instead.
+     --> lib.cairo:3:5-7:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn return_true_on_match_fixer() {
+    test_lint_fixer!(RETURN_TRUE_ON_MATCH, @r"
+    fn main(values: Span<felt252>, needle: felt252) -> bool {
+        for value in values {
+            if *value == needle {
+                return true;
+            }
+        }
+        false
+    }
+    ");
+}
+
+#[test]
+fn return_true_on_match_allowed_diagnostics() {
+    test_lint_diagnostics!(RETURN_TRUE_ON_MATCH_ALLOWED, @"");
+}
+
+#[test]
+fn flag_set_with_break_diagnostics() {
+    test_lint_diagnostics!(FLAG_SET_WITH_BREAK, @r"
+    Plugin diagnostic: Manual membership check detected. Consider using `.contains()` or `.any()` on the iterator instead.
+     --> lib.cairo:4:5-9:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn flag_set_with_break_fixer() {
+    test_lint_fixer!(FLAG_SET_WITH_BREAK, @r"
+    fn main(values: Span<felt252>, needle: felt252) -> bool {
+        let mut found = false;
+        for value in values {
+            if *value == needle {
+                found = true;
+                break;
+            }
+        }
+        found
+    }
+    ");
+}
+
+#[test]
+fn flag_set_without_break_diagnostics() {
+    test_lint_diagnostics!(FLAG_SET_WITHOUT_BREAK, @r"
+    Plugin diagnostic: Manual membership check detected. Consider using `.contains()` or `.any()` on the iterator instead.
+     --> lib.cairo:4:5-8:5
+          for value in values {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn flag_set_without_break_fixer() {
+    test_lint_fixer!(FLAG_SET_WITHOUT_BREAK, @r"
+    fn main(values: Array<felt252>, needle: felt252) -> bool {
+        let mut found = false;
+        for value in values {
+            if needle == value {
+                found = true;
+            }
+        }
+        found
+    }
+    ");
+}
+
+#[test]
+fn loop_with_other_statements_diagnostics() {
+    test_lint_diagnostics!(LOOP_WITH_OTHER_STATEMENTS, @"");
+}
+
+#[test]
+fn loop_returning_element_diagnostics() {
+    test_lint_diagnostics!(LOOP_RETURNING_ELEMENT, @"");
+}
@@ -0,0 +1,138 @@
+use crate::test_lint_diagnostics;
+
+const FILTER_ARRAY_INTO_NEW_ARRAY: &str = r#"
+fn main(numbers: Array<u32>) -> Array<u32> {
+    let mut evens = array![];
+    for number in numbers {
+        if number % 2 == 0 {
+            evens.append(number);
+        }
+    }
+    evens
+}
+"#;
+
+const FILTER_SPAN_INTO_NEW_ARRAY: &str = r#"
+fn main(numbers: Span<u32>) -> Array<u32> {
+    let mut large = ArrayTrait::new();
+    for number in numbers {
+        if *number > 100 {
+            large.append(*number);
+        }
+    }
+    large
+}
+"#;
+
+const FILTER_AND_TRANSFORM: &str = r#"
+fn main(numbers: Array<u32>) -> Array<u32> {
+    let mut doubled = array![];
+    for number in numbers {
+        if number % 2 == 0 {
+            doubled.append(number * 2);
+        }
+    }
+    doubled
+}
+"#;
+
+const PREDICATE_USING_OTHER_VARIABLE: &str = r#"
+fn main(numbers: Array<u32>, threshold: u32) -> Array<u32> {
+    let mut large = array![];
+    for number in numbers {
+        if number > threshold {
+            large.append(number);
+        }
+    }
+    large
+}
+"#;
+
+const DESTINATION_NOT_EMPTY: &str = r#"
+fn main(numbers: Array<u32>) -> Array<u32> {
+    let mut evens = array![0];
+    for number in numbers {
+        if number % 2 == 0 {
+            evens.append(number);
+        }
+    }
+    evens
+}
+"#;
+
+const DESTINATION_USED_BEFORE_LOOP: &str = r#"
+fn main(numbers: Array<u32>) -> Array<u32> {
+    let mut evens = array![];
+    evens.append(0);
+    for number in numbers {
+        if number % 2 == 0 {
+            evens.append(number);
+        }
+    }
+    evens
+}
+"#;
+
+const FILTER_ARRAY_INTO_NEW_ARRAY_ALLOWED: &str = r#"
+fn main(numbers: Array<u32>) -> Array<u32> {
+    let mut evens = array![];
+    #[allow(manual_retain)]
+    for number in numbers {
+        if number % 2 == 0 {
+            evens.append(number);
+        }
+    }
+    evens
+}
+"#;
+
+#[test]
+fn filter_array_into_new_array_diagnostics() {
+    test_lint_diagnostics!(FILTER_ARRAY_INTO_NEW_ARRAY, @r"
+    Plugin diagnostic: Manual filtering of a collection into a new array detected. Consider collecting a filtered iterator instead.
+     --> lib.cairo:4:5-8:5
+          for number in numbers {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn filter_span_into_new_array_diagnostics() {
+    test_lint_diagnostics!(FILTER_SPAN_INTO_NEW_ARRAY, @r"
+    Plugin diagnostic: Manual filtering of a collection into a new array detected. Consider collecting a filtered iterator instead.
+     --> lib.cairo:4:5-8:5
+          for number in numbers {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn filter_and_transform_diagnostics() {
+    test_lint_diagnostics!(FILTER_AND_TRANSFORM, @"");
+}
+
+#[test]
+fn predicate_using_other_variable_diagnostics() {
+    test_lint_diagnostics!(PREDICATE_USING_OTHER_VARIABLE, @"");
+}
+
+#[test]
+fn destination_not_empty_diagnostics() {
+    test_lint_diagnostics!(DESTINATION_NOT_EMPTY, @"");
+}
+
+#[test]
+fn destination_used_before_loop_diagnostics() {
+    test_lint_diagnostics!(DESTINATION_USED_BEFORE_LOOP, @"");
+}
+
+#[test]
+fn filter_array_into_new_array_allowed_diagnostics() {
+    test_lint_diagnostics!(FILTER_ARRAY_INTO_NEW_ARRAY_ALLOWED, @"");
+}
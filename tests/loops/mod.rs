@@ -1,2 +1,7 @@
+mod explicit_counter_loop;
 mod loop_for_while;
 mod loops_match_pop_front;
+mod manual_all_any;
+mod manual_array_fill;
+mod manual_contains;
+mod manual_retain;
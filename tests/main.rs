@@ -1,33 +1,96 @@
+mod api;
+mod apply_single_fix;
 mod assert_on_const;
+mod assign_op_pattern;
+mod baseline;
 mod bitwise_for_parity_check;
 mod bool_comparison;
+mod branches_sharing_code;
 mod breaks;
+mod checking_functions;
 mod clone_on_copy;
 mod collapsible_match;
+mod comparison_chain;
+mod compute_fixed_content;
+mod conflicting_lints;
+mod corelib_context;
+mod diagnostic_lint_name;
 mod double_comparison;
+mod double_negation;
 mod double_parens;
 mod duplicate_underscore_args;
 mod empty_enum_brackets_variant;
+mod empty_match;
+mod empty_string_comparison;
+mod enabled_lints;
 mod enum_variant_names;
 mod eq_op;
 mod erasing_operations;
+mod excessive_nesting;
+mod file_lint_diagnostics;
+mod fix_for_diagnostic;
 mod fix_messages;
+mod fix_summary;
+mod format_args;
+mod format_diagnostic_with_name;
 mod helpers;
 mod ifs;
 mod int_operations;
+mod json_output;
+mod large_enum_variant;
+mod len_zero;
+mod let_and_return;
+mod lint_codes;
+mod lint_groups;
+mod lint_metadata;
+mod lint_severity;
+mod lint_string;
 mod loops;
 mod manual;
+mod manual_bool_logic;
+mod manual_clamp;
+mod manual_div_ceil;
+mod manual_flatten;
+mod manual_min_max;
+mod manual_pow;
+mod manual_saturating;
+mod manual_swap;
+mod manual_take;
+mod match_bool;
+mod merge_overlapping_fixes;
+mod missing_corelib_items;
+mod modulo_one;
+mod needless_question_mark;
+mod needless_return;
+mod needless_semicolon;
 mod nested_fixes;
+mod nested_if_threshold;
 mod panic;
+mod parallel_linting;
+mod parse_tool_metadata;
 mod performance;
+mod preview_fixes;
+mod redundant_block;
 mod redundant_brackets_in_enum_call;
 mod redundant_into;
 mod redundant_op;
+mod sarif;
+mod self_assignment;
 mod single_match;
+mod sort_and_group;
+mod struct_reassign;
+mod suspicious_comparison;
+mod too_many_arguments;
 mod unit_return_type;
+mod unnecessary_array;
+mod unnecessary_cast_chain;
+mod unused_allow;
 mod unused_imports;
 mod unused_variables;
+mod unused_variant;
 mod unwrap_syscall;
+mod wildcard_import;
+mod zero_division;
 
 pub const CRATE_CONFIG: &str = r#"
 edition = "2024_07"
@@ -203,6 +203,24 @@ fn main() {
 }
 "#;
 
+const TEST_MANUAL_ASSERT_WITH_NEGATED_CONDITION: &str = r#"
+fn main() {
+    let is_valid = true;
+    if !is_valid {
+        panic!("should be valid");
+    }
+}
+"#;
+
+const TEST_MANUAL_ASSERT_WITH_NEGATED_PARENTHESIZED_CONDITION: &str = r#"
+fn main() {
+    let a = 5;
+    if !(a == 5) {
+        panic!("a should be equal to 5");
+    }
+}
+"#;
+
 #[test]
 fn test_basic_manual_assert_diagnostics() {
     test_lint_diagnostics!(TEST_BASIC_MANUAL_ASSERT, @r#"
@@ -711,3 +729,57 @@ fn test_manual_assert_with_panic_in_else_block_fixer() {
     }
     "#);
 }
+
+#[test]
+fn test_manual_assert_with_negated_condition_diagnostics() {
+    test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_NEGATED_CONDITION, @r#"
+    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+     --> lib.cairo:5:9
+            panic!("should be valid");
+            ^^^^^
+    Plugin diagnostic: Manual assert detected. Consider using assert!() macro instead.
+     --> lib.cairo:4:5-6:5
+          if !is_valid {
+     _____^
+    |         panic!("should be valid");
+    |     }
+    |_____^
+    "#);
+}
+
+#[test]
+fn test_manual_assert_with_negated_condition_fixer() {
+    test_lint_fixer!(TEST_MANUAL_ASSERT_WITH_NEGATED_CONDITION, @r#"
+    fn main() {
+        let is_valid = true;
+        assert!(is_valid, "should be valid");
+    }
+    "#);
+}
+
+#[test]
+fn test_manual_assert_with_negated_parenthesized_condition_diagnostics() {
+    test_lint_diagnostics!(TEST_MANUAL_ASSERT_WITH_NEGATED_PARENTHESIZED_CONDITION, @r#"
+    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+     --> lib.cairo:5:9
+            panic!("a should be equal to 5");
+            ^^^^^
+    Plugin diagnostic: Manual assert detected. Consider using assert!() macro instead.
+     --> lib.cairo:4:5-6:5
+          if !(a == 5) {
+     _____^
+    |         panic!("a should be equal to 5");
+    |     }
+    |_____^
+    "#);
+}
+
+#[test]
+fn test_manual_assert_with_negated_parenthesized_condition_fixer() {
+    test_lint_fixer!(TEST_MANUAL_ASSERT_WITH_NEGATED_PARENTHESIZED_CONDITION, @r#"
+    fn main() {
+        let a = 5;
+        assert!(a == 5, "a should be equal to 5");
+    }
+    "#);
+}
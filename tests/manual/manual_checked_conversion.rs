@@ -0,0 +1,139 @@
+use crate::test_lint_diagnostics;
+
+const GUARDED_CONVERSION: &str = r#"
+fn main(x: u256) -> Option<u8> {
+    if x <= 255 {
+        Option::Some(x.try_into().unwrap())
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const GUARDED_CONVERSION_ALLOWED: &str = r#"
+#[allow(manual_checked_conversion)]
+fn main(x: u256) -> Option<u8> {
+    if x <= 255 {
+        Option::Some(x.try_into().unwrap())
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const GUARDED_CONVERSION_STRICT_BOUND: &str = r#"
+fn main(x: u256) -> Option<u8> {
+    if x < 256 {
+        Option::Some(x.try_into().unwrap())
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const GUARDED_CONVERSION_INVERTED: &str = r#"
+fn main(x: u256) -> Option<u8> {
+    if x > 255 {
+        Option::None
+    } else {
+        Option::Some(x.try_into().unwrap())
+    }
+}
+"#;
+
+const GUARDED_CONVERSION_LITERAL_ON_LEFT: &str = r#"
+fn main(x: u256) -> Option<u8> {
+    if 255 >= x {
+        Option::Some(x.try_into().unwrap())
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const WRONG_BOUND: &str = r#"
+fn main(x: u256) -> Option<u8> {
+    if x <= 100 {
+        Option::Some(x.try_into().unwrap())
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const WRONG_BRANCHES: &str = r#"
+fn main(x: u256) -> Option<u8> {
+    if x > 255 {
+        Option::Some(x.try_into().unwrap())
+    } else {
+        Option::None
+    }
+}
+"#;
+
+#[test]
+fn guarded_conversion_diagnostics() {
+    test_lint_diagnostics!(GUARDED_CONVERSION, @r"
+    Plugin diagnostic: Manual checked conversion detected. Consider using `try_into()` instead.
+     --> lib.cairo:3:5-7:5
+          if x <= 255 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn guarded_conversion_allowed_diagnostics() {
+    test_lint_diagnostics!(GUARDED_CONVERSION_ALLOWED, @"");
+}
+
+#[test]
+fn guarded_conversion_strict_bound_diagnostics() {
+    test_lint_diagnostics!(GUARDED_CONVERSION_STRICT_BOUND, @r"
+    Plugin diagnostic: Manual checked conversion detected. Consider using `try_into()` instead.
+     --> lib.cairo:3:5-7:5
+          if x < 256 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn guarded_conversion_inverted_diagnostics() {
+    test_lint_diagnostics!(GUARDED_CONVERSION_INVERTED, @r"
+    Plugin diagnostic: Manual checked conversion detected. Consider using `try_into()` instead.
+     --> lib.cairo:3:5-7:5
+          if x > 255 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn guarded_conversion_literal_on_left_diagnostics() {
+    test_lint_diagnostics!(GUARDED_CONVERSION_LITERAL_ON_LEFT, @r"
+    Plugin diagnostic: Manual checked conversion detected. Consider using `try_into()` instead.
+     --> lib.cairo:3:5-7:5
+          if 255 >= x {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn wrong_bound_diagnostics() {
+    test_lint_diagnostics!(WRONG_BOUND, @"");
+}
+
+#[test]
+fn wrong_branches_diagnostics() {
+    test_lint_diagnostics!(WRONG_BRANCHES, @"");
+}
@@ -388,8 +388,7 @@ fn test_manual_if_fixer() {
 
 #[test]
 fn test_manual_if_allowed_diagnostics() {
-    test_lint_diagnostics!(TEST_MANUAL_IF_ALLOWED, @r#"
-    "#);
+    test_lint_diagnostics!(TEST_MANUAL_IF_ALLOWED, @"");
 }
 
 #[test]
@@ -410,8 +409,7 @@ fn test_manual_if_allowed_fixer() {
 
 #[test]
 fn test_manual_if_with_additional_instructions_diagnostics() {
-    test_lint_diagnostics!(TEST_MANUAL_IF_WITH_ADDITIONAL_INSTRUCTIONS, @r#"
-    "#);
+    test_lint_diagnostics!(TEST_MANUAL_IF_WITH_ADDITIONAL_INSTRUCTIONS, @"");
 }
 
 #[test]
@@ -0,0 +1,113 @@
+use crate::test_lint_diagnostics;
+
+const MANUAL_FILTER: &str = r#"
+fn main(x: u32) -> Option<u32> {
+    if x > 10 {
+        Option::Some(x)
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const MANUAL_FILTER_WITH_FUNCTION_CONDITION: &str = r#"
+fn is_even(x: u32) -> bool {
+    x % 2 == 0
+}
+
+fn main(x: u32) -> Option<u32> {
+    let _a = if is_even(x) {
+        Option::Some(x)
+    } else {
+        Option::None
+    };
+    Option::None
+}
+"#;
+
+const MANUAL_FILTER_ALLOWED: &str = r#"
+#[allow(manual_filter)]
+fn main(x: u32) -> Option<u32> {
+    if x > 10 {
+        Option::Some(x)
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const CONDITION_NOT_ON_WRAPPED_VALUE: &str = r#"
+fn main(x: u32, flag: bool) -> Option<u32> {
+    if flag {
+        Option::Some(x)
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const ELSE_BRANCH_NOT_NONE: &str = r#"
+fn main(x: u32) -> Option<u32> {
+    if x > 10 {
+        Option::Some(x)
+    } else {
+        Option::Some(10)
+    }
+}
+"#;
+
+const WRAPPED_VALUE_NOT_A_VARIABLE: &str = r#"
+fn main(x: u32) -> Option<u32> {
+    if x > 10 {
+        Option::Some(x + 1)
+    } else {
+        Option::None
+    }
+}
+"#;
+
+#[test]
+fn manual_filter_diagnostics() {
+    test_lint_diagnostics!(MANUAL_FILTER, @r"
+    Plugin diagnostic: Manual `Option` filtering detected. Consider using `filter()` instead
+     --> lib.cairo:3:5-7:5
+          if x > 10 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_filter_with_function_condition_diagnostics() {
+    test_lint_diagnostics!(MANUAL_FILTER_WITH_FUNCTION_CONDITION, @r"
+    Plugin diagnostic: Manual `Option` filtering detected. Consider using `filter()` instead
+     --> lib.cairo:7:14-11:5
+          let _a = if is_even(x) {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_filter_allowed_diagnostics() {
+    test_lint_diagnostics!(MANUAL_FILTER_ALLOWED, @"");
+}
+
+#[test]
+fn condition_not_on_wrapped_value_diagnostics() {
+    test_lint_diagnostics!(CONDITION_NOT_ON_WRAPPED_VALUE, @"");
+}
+
+#[test]
+fn else_branch_not_none_diagnostics() {
+    test_lint_diagnostics!(ELSE_BRANCH_NOT_NONE, @"");
+}
+
+#[test]
+fn wrapped_value_not_a_variable_diagnostics() {
+    test_lint_diagnostics!(WRAPPED_VALUE_NOT_A_VARIABLE, @"");
+}
@@ -142,6 +142,29 @@ fn main() {
 }
 "#;
 
+const TEST_MANUAL_IS_EMPTY_REVERSED_LEN_CHECK: &str = r#"
+fn main() {
+    let a: Array<u32> = array![];
+    let _vl = if 0 == a.len() {
+        true
+    } else {
+        false
+    };
+}
+"#;
+
+const TEST_MANUAL_IS_EMPTY_LEN_CHECK_ALLOWED: &str = r#"
+fn main() {
+    let a: Array<u32> = array![];
+    #[allow(manual_is_empty)]
+    let _vl = if a.len() == 0 {
+        true
+    } else {
+        false
+    };
+}
+"#;
+
 #[test]
 fn test_is_manual_empty_via_len_check_diagnostics() {
     test_lint_diagnostics!(TEST_MANUAL_IS_EMPTY_LEN_CHECK_WITH_VAR, @r"
@@ -435,3 +458,47 @@ fn test_is_manual_empty_with_empty_array_macro_in_while_loop_fixer() {
     }
     ");
 }
+
+#[test]
+fn test_is_manual_empty_with_reversed_len_check_diagnostics() {
+    test_lint_diagnostics!(TEST_MANUAL_IS_EMPTY_REVERSED_LEN_CHECK, @r"
+    Plugin diagnostic: Manual check for `is_empty` detected. Consider using `is_empty()` instead
+     --> lib.cairo:4:18
+        let _vl = if 0 == a.len() {
+                     ^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn test_is_manual_empty_with_reversed_len_check_fixer() {
+    test_lint_fixer!(TEST_MANUAL_IS_EMPTY_REVERSED_LEN_CHECK, @r"
+    fn main() {
+        let a: Array<u32> = array![];
+        let _vl = if a.is_empty() {
+            true
+        } else {
+            false
+        };
+    }
+    ");
+}
+
+#[test]
+fn test_is_manual_empty_with_len_check_allowed_diagnostics() {
+    test_lint_diagnostics!(TEST_MANUAL_IS_EMPTY_LEN_CHECK_ALLOWED, @"");
+}
+
+#[test]
+fn test_is_manual_empty_with_len_check_allowed_fixer() {
+    test_lint_fixer!(TEST_MANUAL_IS_EMPTY_LEN_CHECK_ALLOWED, @r"
+    fn main() {
+        let a: Array<u32> = array![];
+        #[allow(manual_is_empty)]
+        let _vl = if a.len() == 0 {
+            true
+        } else {
+            false
+        };
+    }
+    ");
+}
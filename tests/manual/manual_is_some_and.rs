@@ -0,0 +1,196 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const MATCH_WITH_COMPARISON: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(10);
+    let _b = match a {
+        Option::Some(x) => x > 5,
+        Option::None => false,
+    };
+}
+"#;
+
+const IF_LET_WITH_COMPARISON: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(10);
+    let _b = if let Option::Some(x) = a {
+        x == 3
+    } else {
+        false
+    };
+}
+"#;
+
+const MATCH_WITH_FUNCTION_PREDICATE: &str = r#"
+fn is_even(x: u32) -> bool {
+    x % 2 == 0
+}
+
+fn main() {
+    let a: Option<u32> = Option::Some(10);
+    let _b = match a {
+        Option::Some(x) => is_even(x),
+        Option::None => false,
+    };
+}
+"#;
+
+const MATCH_WITH_REVERSED_ARMS: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(10);
+    let _b = match a {
+        Option::None => false,
+        Option::Some(x) => x > 5,
+    };
+}
+"#;
+
+const MATCH_WITH_TRUE_FALLBACK: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(10);
+    let _b = match a {
+        Option::Some(x) => x > 5,
+        Option::None => true,
+    };
+}
+"#;
+
+const MATCH_WITHOUT_BOUND_VARIABLE: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(10);
+    let _b = match a {
+        Option::Some(_) => true,
+        Option::None => false,
+    };
+}
+"#;
+
+const MATCH_WITH_COMPARISON_ALLOWED: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(10);
+    #[allow(manual_is_some_and)]
+    let _b = match a {
+        Option::Some(x) => x > 5,
+        Option::None => false,
+    };
+}
+"#;
+
+#[test]
+fn match_with_comparison_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITH_COMPARISON, @r"
+    Plugin diagnostic: Manual `is_some_and` detected. Consider using `is_some_and()` instead.
+     --> lib.cairo:4:14-7:5
+          let _b = match a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_with_comparison_fixer() {
+    test_lint_fixer!(MATCH_WITH_COMPARISON, @r"
+    fn main() {
+        let a: Option<u32> = Option::Some(10);
+        let _b = a.is_some_and(|x| x > 5);
+    }
+    ");
+}
+
+#[test]
+fn if_let_with_comparison_diagnostics() {
+    test_lint_diagnostics!(IF_LET_WITH_COMPARISON, @r"
+    Plugin diagnostic: Manual `is_some_and` detected. Consider using `is_some_and()` instead.
+     --> lib.cairo:4:14-8:5
+          let _b = if let Option::Some(x) = a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn if_let_with_comparison_fixer() {
+    test_lint_fixer!(IF_LET_WITH_COMPARISON, @r"
+    fn main() {
+        let a: Option<u32> = Option::Some(10);
+        let _b = a.is_some_and(|x| x == 3);
+    }
+    ");
+}
+
+#[test]
+fn match_with_function_predicate_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITH_FUNCTION_PREDICATE, @r"
+    Plugin diagnostic: Manual `is_some_and` detected. Consider using `is_some_and()` instead.
+     --> lib.cairo:8:14-11:5
+          let _b = match a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_with_function_predicate_fixer() {
+    test_lint_fixer!(MATCH_WITH_FUNCTION_PREDICATE, @r"
+    fn is_even(x: u32) -> bool {
+        x % 2 == 0
+    }
+
+    fn main() {
+        let a: Option<u32> = Option::Some(10);
+        let _b = a.is_some_and(|x| is_even(x));
+    }
+    ");
+}
+
+#[test]
+fn match_with_reversed_arms_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITH_REVERSED_ARMS, @r"
+    Plugin diagnostic: Manual `is_some_and` detected. Consider using `is_some_and()` instead.
+     --> lib.cairo:4:14-7:5
+          let _b = match a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_with_reversed_arms_fixer() {
+    test_lint_fixer!(MATCH_WITH_REVERSED_ARMS, @r"
+    fn main() {
+        let a: Option<u32> = Option::Some(10);
+        let _b = a.is_some_and(|x| x > 5);
+    }
+    ");
+}
+
+#[test]
+fn match_with_true_fallback_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITH_TRUE_FALLBACK, @"");
+}
+
+#[test]
+fn match_without_bound_variable_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITHOUT_BOUND_VARIABLE, @r"
+    Plugin diagnostic: Manual match for `is_some` detected. Consider using `is_some()` instead
+     --> lib.cairo:4:14-7:5
+          let _b = match a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_with_comparison_allowed_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITH_COMPARISON_ALLOWED, @"");
+}
@@ -0,0 +1,396 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const TEST_MATCH_WITH_WILDCARD: &str = r#"
+#[derive(Drop)]
+enum Shape {
+    Circle: u32,
+    Square: u32,
+    Triangle: u32,
+}
+
+fn main(shape: Shape) -> bool {
+    match shape {
+        Shape::Circle(_) => true,
+        _ => false,
+    }
+}
+"#;
+
+const TEST_MATCH_WITH_WILDCARD_ALLOWED: &str = r#"
+#[derive(Drop)]
+enum Shape {
+    Circle: u32,
+    Square: u32,
+    Triangle: u32,
+}
+
+#[allow(manual_is_variant)]
+fn main(shape: Shape) -> bool {
+    match shape {
+        Shape::Circle(_) => true,
+        _ => false,
+    }
+}
+"#;
+
+const TEST_MATCH_ON_ALL_VARIANTS: &str = r#"
+#[derive(Drop)]
+enum Shape {
+    Circle: u32,
+    Square: u32,
+}
+
+#[generate_trait]
+impl ShapeImpl of ShapeTrait {
+    fn is_circle(self: @Shape) -> bool {
+        match self {
+            Shape::Circle(_) => true,
+            Shape::Square(_) => false,
+        }
+    }
+
+    fn is_square(self: @Shape) -> bool {
+        match self {
+            Shape::Circle(_) => false,
+            Shape::Square(_) => true,
+        }
+    }
+}
+
+fn main(shape: Shape) -> bool {
+    match shape {
+        Shape::Circle(_) => false,
+        Shape::Square(_) => true,
+    }
+}
+"#;
+
+const TEST_NEGATED_MATCH: &str = r#"
+#[derive(Drop)]
+enum Shape {
+    Circle: u32,
+    Square: u32,
+}
+
+#[generate_trait]
+impl ShapeImpl of ShapeTrait {
+    fn is_circle(self: @Shape) -> bool {
+        match self {
+            Shape::Circle(_) => true,
+            Shape::Square(_) => false,
+        }
+    }
+
+    fn is_square(self: @Shape) -> bool {
+        match self {
+            Shape::Circle(_) => false,
+            Shape::Square(_) => true,
+        }
+    }
+}
+
+fn main(shape: Shape) -> bool {
+    match shape {
+        Shape::Circle(_) => false,
+        _ => true,
+    }
+}
+"#;
+
+const TEST_IF_LET_ON_UNIT_VARIANT: &str = r#"
+#[derive(Drop)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[generate_trait]
+impl DirectionImpl of DirectionTrait {
+    fn is_north(self: @Direction) -> bool {
+        if let Direction::North = self {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn main(direction: Direction) -> bool {
+    if let Direction::North = direction {
+        true
+    } else {
+        false
+    }
+}
+"#;
+
+const TEST_MATCH_WITH_COMMENT_ON_SNAPSHOT: &str = r#"
+#[derive(Drop)]
+enum Shape {
+    Circle: u32,
+    Square: u32,
+}
+
+#[generate_trait]
+impl ShapeImpl of ShapeTrait {
+    fn is_circle(self: @Shape) -> bool {
+        match self {
+            Shape::Circle(_) => true,
+            Shape::Square(_) => false,
+        }
+    }
+
+    fn is_square(self: @Shape) -> bool {
+        match self {
+            Shape::Circle(_) => false,
+            Shape::Square(_) => true,
+        }
+    }
+}
+
+fn main(shape: @Shape) -> bool {
+    match shape {
+        Shape::Circle(_) => {
+            // A comment.
+            true
+        },
+        _ => false,
+    }
+}
+"#;
+
+const TEST_ARM_USING_VALUE: &str = r#"
+#[derive(Drop)]
+enum Shape {
+    Circle: u32,
+    Square: u32,
+    Triangle: u32,
+}
+
+fn main(shape: Shape) -> bool {
+    match shape {
+        Shape::Circle(radius) => radius > 0,
+        _ => false,
+    }
+}
+"#;
+
+#[test]
+fn test_match_with_wildcard_diagnostics() {
+    test_lint_diagnostics!(TEST_MATCH_WITH_WILDCARD, @r"
+    Plugin diagnostic: Manual check of an enum variant detected. Consider using an `is_*` method of the enum instead
+     --> lib.cairo:10:5-13:5
+          match shape {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn test_match_with_wildcard_fixer() {
+    test_lint_fixer!(TEST_MATCH_WITH_WILDCARD, @r"
+    #[derive(Drop)]
+    enum Shape {
+        Circle: u32,
+        Square: u32,
+        Triangle: u32,
+    }
+
+    fn main(shape: Shape) -> bool {
+        match shape {
+            Shape::Circle(_) => true,
+            _ => false,
+        }
+    }
+    ");
+}
+
+#[test]
+fn test_match_with_wildcard_allowed_diagnostics() {
+    test_lint_diagnostics!(TEST_MATCH_WITH_WILDCARD_ALLOWED, @"");
+}
+
+#[test]
+fn test_match_on_all_variants_diagnostics() {
+    test_lint_diagnostics!(TEST_MATCH_ON_ALL_VARIANTS, @r"
+    Plugin diagnostic: Manual check of an enum variant detected. Consider using an `is_*` method of the enum instead
+     --> lib.cairo:26:5-29:5
+          match shape {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn test_match_on_all_variants_fixer() {
+    test_lint_fixer!(TEST_MATCH_ON_ALL_VARIANTS, @r"
+    #[derive(Drop)]
+    enum Shape {
+        Circle: u32,
+        Square: u32,
+    }
+
+    #[generate_trait]
+    impl ShapeImpl of ShapeTrait {
+        fn is_circle(self: @Shape) -> bool {
+            match self {
+                Shape::Circle(_) => true,
+                Shape::Square(_) => false,
+            }
+        }
+
+        fn is_square(self: @Shape) -> bool {
+            match self {
+                Shape::Circle(_) => false,
+                Shape::Square(_) => true,
+            }
+        }
+    }
+
+    fn main(shape: Shape) -> bool {
+        shape.is_square()
+    }
+    ");
+}
+
+#[test]
+fn test_negated_match_diagnostics() {
+    test_lint_diagnostics!(TEST_NEGATED_MATCH, @r"
+    Plugin diagnostic: Manual check of an enum variant detected. Consider using an `is_*` method of the enum instead
+     --> lib.cairo:26:5-29:5
+          match shape {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn test_negated_match_fixer() {
+    test_lint_fixer!(TEST_NEGATED_MATCH, @r"
+    #[derive(Drop)]
+    enum Shape {
+        Circle: u32,
+        Square: u32,
+    }
+
+    #[generate_trait]
+    impl ShapeImpl of ShapeTrait {
+        fn is_circle(self: @Shape) -> bool {
+            match self {
+                Shape::Circle(_) => true,
+                Shape::Square(_) => false,
+            }
+        }
+
+        fn is_square(self: @Shape) -> bool {
+            match self {
+                Shape::Circle(_) => false,
+                Shape::Square(_) => true,
+            }
+        }
+    }
+
+    fn main(shape: Shape) -> bool {
+        !shape.is_circle()
+    }
+    ");
+}
+
+#[test]
+fn test_if_let_on_unit_variant_diagnostics() {
+    test_lint_diagnostics!(TEST_IF_LET_ON_UNIT_VARIANT, @r"
+    Plugin diagnostic: Manual check of an enum variant detected. Consider using an `is_*` method of the enum instead
+     --> lib.cairo:22:5-26:5
+          if let Direction::North = direction {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn test_if_let_on_unit_variant_fixer() {
+    test_lint_fixer!(TEST_IF_LET_ON_UNIT_VARIANT, @r"
+    #[derive(Drop)]
+    enum Direction {
+        North,
+        South,
+        East,
+        West,
+    }
+
+    #[generate_trait]
+    impl DirectionImpl of DirectionTrait {
+        fn is_north(self: @Direction) -> bool {
+            if let Direction::North = self {
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    fn main(direction: Direction) -> bool {
+        direction.is_north()
+    }
+    ");
+}
+
+#[test]
+fn test_match_with_comment_on_snapshot_diagnostics() {
+    test_lint_diagnostics!(TEST_MATCH_WITH_COMMENT_ON_SNAPSHOT, @r"
+    Plugin diagnostic: Manual check of an enum variant detected. Consider using an `is_*` method of the enum instead
+     --> lib.cairo:26:5-32:5
+          match shape {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn test_match_with_comment_on_snapshot_fixer() {
+    test_lint_fixer!(TEST_MATCH_WITH_COMMENT_ON_SNAPSHOT, @r"
+    #[derive(Drop)]
+    enum Shape {
+        Circle: u32,
+        Square: u32,
+    }
+
+    #[generate_trait]
+    impl ShapeImpl of ShapeTrait {
+        fn is_circle(self: @Shape) -> bool {
+            match self {
+                Shape::Circle(_) => true,
+                Shape::Square(_) => false,
+            }
+        }
+
+        fn is_square(self: @Shape) -> bool {
+            match self {
+                Shape::Circle(_) => false,
+                Shape::Square(_) => true,
+            }
+        }
+    }
+
+    fn main(shape: @Shape) -> bool {
+        shape.is_circle()
+    }
+    ");
+}
+
+#[test]
+fn test_arm_using_value_diagnostics() {
+    test_lint_diagnostics!(TEST_ARM_USING_VALUE, @"");
+}
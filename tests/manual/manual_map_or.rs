@@ -0,0 +1,251 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const FUNCTION_CALL_MATCH: &str = r#"
+fn foo(x: u32) -> u64 {
+    x.into()
+}
+
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::Some(x) => foo(x),
+        Option::None => 0,
+    };
+}
+"#;
+
+const FUNCTION_CALL_MATCH_ALLOWED: &str = r#"
+fn foo(x: u32) -> u64 {
+    x.into()
+}
+
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    #[allow(manual_map_or)]
+    let _a = match opt {
+        Option::Some(x) => foo(x),
+        Option::None => 0,
+    };
+}
+"#;
+
+const FUNCTION_CALL_MATCH_BLOCK_ARMS: &str = r#"
+fn foo(x: u32) -> u64 {
+    x.into()
+}
+
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::None => { 0 },
+        Option::Some(x) => { foo(x) },
+    };
+}
+"#;
+
+const METHOD_CALL_MATCH: &str = r#"
+fn foo(x: u32) -> u64 {
+    x.into()
+}
+
+fn main() {
+    let opt: Option<Array<u32>> = Option::Some(array![1]);
+    let _a = match opt {
+        Option::Some(arr) => arr.len(),
+        Option::None => 0,
+    };
+}
+"#;
+
+const FUNCTION_CALL_IF_LET: &str = r#"
+fn foo(x: u32) -> u64 {
+    x.into()
+}
+
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = if let Option::Some(x) = opt {
+        foo(x)
+    } else {
+        0
+    };
+}
+"#;
+
+const IDENTITY_MATCH: &str = r#"
+fn foo(x: u32) -> u64 {
+    x.into()
+}
+
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::Some(x) => x,
+        Option::None => 0,
+    };
+}
+"#;
+
+const NON_CONSTANT_DEFAULT: &str = r#"
+fn foo(x: u32) -> u64 {
+    x.into()
+}
+
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::Some(x) => foo(x),
+        Option::None => foo(0),
+    };
+}
+"#;
+
+const FUNCTION_WITH_OTHER_ARGUMENTS: &str = r#"
+fn foo(x: u32) -> u64 {
+    x.into()
+}
+
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::Some(x) => x + 1,
+        Option::None => 0,
+    };
+}
+"#;
+
+#[test]
+fn function_call_match_diagnostics() {
+    test_lint_diagnostics!(FUNCTION_CALL_MATCH, @r"
+    Plugin diagnostic: Manual `map_or` detected. Consider using `map_or()` instead.
+     --> lib.cairo:8:14-13:5
+          let _a = match opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn function_call_match_fixer() {
+    test_lint_fixer!(FUNCTION_CALL_MATCH, @r"
+    fn foo(x: u32) -> u64 {
+        x.into()
+    }
+
+    fn main() {
+        let opt: Option<u32> = Option::Some(1);
+        let _a = opt.map_or(0, |x| foo(x));
+    }
+    ");
+}
+
+#[test]
+fn function_call_match_allowed_diagnostics() {
+    test_lint_diagnostics!(FUNCTION_CALL_MATCH_ALLOWED, @"");
+}
+
+#[test]
+fn function_call_match_block_arms_diagnostics() {
+    test_lint_diagnostics!(FUNCTION_CALL_MATCH_BLOCK_ARMS, @r"
+    Plugin diagnostic: Manual `map_or` detected. Consider using `map_or()` instead.
+     --> lib.cairo:8:14-13:5
+          let _a = match opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn function_call_match_block_arms_fixer() {
+    test_lint_fixer!(FUNCTION_CALL_MATCH_BLOCK_ARMS, @r"
+    fn foo(x: u32) -> u64 {
+        x.into()
+    }
+
+    fn main() {
+        let opt: Option<u32> = Option::Some(1);
+        let _a = opt.map_or(0, |x| foo(x));
+    }
+    ");
+}
+
+#[test]
+fn method_call_match_diagnostics() {
+    test_lint_diagnostics!(METHOD_CALL_MATCH, @r"
+    Plugin diagnostic: Manual `map_or` detected. Consider using `map_or()` instead.
+     --> lib.cairo:8:14-13:5
+          let _a = match opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn method_call_match_fixer() {
+    test_lint_fixer!(METHOD_CALL_MATCH, @r"
+    fn foo(x: u32) -> u64 {
+        x.into()
+    }
+
+    fn main() {
+        let opt: Option<Array<u32>> = Option::Some(array![1]);
+        let _a = opt.map_or(0, |arr| arr.len());
+    }
+    ");
+}
+
+#[test]
+fn function_call_if_let_diagnostics() {
+    test_lint_diagnostics!(FUNCTION_CALL_IF_LET, @r"
+    Plugin diagnostic: Manual `map_or` detected. Consider using `map_or()` instead.
+     --> lib.cairo:8:14-14:5
+          let _a = if let Option::Some(x) = opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn function_call_if_let_fixer() {
+    test_lint_fixer!(FUNCTION_CALL_IF_LET, @r"
+    fn foo(x: u32) -> u64 {
+        x.into()
+    }
+
+    fn main() {
+        let opt: Option<u32> = Option::Some(1);
+        let _a = opt.map_or(0, |x| foo(x));
+    }
+    ");
+}
+
+#[test]
+fn identity_match_diagnostics() {
+    test_lint_diagnostics!(IDENTITY_MATCH, @r"
+    Plugin diagnostic: Manual `unwrap_or` detected. Consider using `unwrap_or()` instead.
+     --> lib.cairo:8:14-13:5
+          let _a = match opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn non_constant_default_diagnostics() {
+    test_lint_diagnostics!(NON_CONSTANT_DEFAULT, @"");
+}
+
+#[test]
+fn function_with_other_arguments_diagnostics() {
+    test_lint_diagnostics!(FUNCTION_WITH_OTHER_ARGUMENTS, @"");
+}
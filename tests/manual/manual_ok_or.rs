@@ -171,6 +171,32 @@ fn main() {
 }
 "#;
 
+const TEST_COMPUTED_ERROR: &str = r#"
+fn make_err() -> felt252 {
+    'this is an err'
+}
+
+fn main() {
+    let foo: Option<i32> = Option::None;
+    let _foo = match foo {
+        Option::Some(v) => Result::Ok(v),
+        Option::None => Result::Err(make_err()),
+    };
+}
+"#;
+
+const TEST_MANUAL_IF_COMPUTED_ERROR: &str = r#"
+fn main() {
+    let opt_val: Option<i32> = Option::None;
+    let message: ByteArray = "err";
+    let _a = if let Option::Some(val) = opt_val {
+        Result::Ok(val)
+    } else {
+        Result::Err(message.clone())
+    };
+}
+"#;
+
 #[test]
 fn test_error_str_diagnostics() {
     test_lint_diagnostics!(TEST_ERROR_STR, @r"
@@ -476,3 +502,54 @@ fn match_with_reversed_arms_fixer() {
     }
     ");
 }
+
+#[test]
+fn test_computed_error_diagnostics() {
+    test_lint_diagnostics!(TEST_COMPUTED_ERROR, @r"
+    Plugin diagnostic: Manual match for Option<T> detected. Consider using ok_or instead
+     --> lib.cairo:8:16-11:5
+          let _foo = match foo {
+     ________________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn test_computed_error_fixer() {
+    test_lint_fixer!(TEST_COMPUTED_ERROR, @r"
+    fn make_err() -> felt252 {
+        'this is an err'
+    }
+
+    fn main() {
+        let foo: Option<i32> = Option::None;
+        let _foo = foo.ok_or_else(|| make_err());
+    }
+    ");
+}
+
+#[test]
+fn test_manual_if_computed_error_diagnostics() {
+    test_lint_diagnostics!(TEST_MANUAL_IF_COMPUTED_ERROR, @r"
+    Plugin diagnostic: Manual match for Option<T> detected. Consider using ok_or instead
+     --> lib.cairo:5:14-9:5
+          let _a = if let Option::Some(val) = opt_val {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn test_manual_if_computed_error_fixer() {
+    test_lint_fixer!(TEST_MANUAL_IF_COMPUTED_ERROR, @r#"
+    fn main() {
+        let opt_val: Option<i32> = Option::None;
+        let message: ByteArray = "err";
+        let _a = opt_val.ok_or_else(|| message.clone());
+    }
+    "#);
+}
@@ -0,0 +1,132 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const OK_OR_UNWRAP_OR_DEFAULT: &str = r#"
+fn main() {
+    let foo: Option<u32> = Option::None;
+    let _foo = foo.ok_or('this is an err').unwrap_or_default();
+}
+"#;
+
+const OK_OR_UNWRAP_OR_DEFAULT_ALLOWED: &str = r#"
+fn main() {
+    let foo: Option<u32> = Option::None;
+    #[allow(manual_ok_or_default)]
+    let _foo = foo.ok_or('this is an err').unwrap_or_default();
+}
+"#;
+
+const MATCH_UNWRAP_OR_DEFAULT: &str = r#"
+fn main() {
+    let foo: Option<u32> = Option::None;
+    let _foo = match foo {
+        Option::Some(v) => Result::Ok(v),
+        Option::None => Result::Err('this is an err'),
+    }.unwrap_or_default();
+}
+"#;
+
+const IF_LET_UNWRAP_OR_DEFAULT: &str = r#"
+fn main() {
+    let foo: Option<u32> = Option::None;
+    let _foo = (if let Option::Some(v) = foo {
+        Result::Ok(v)
+    } else {
+        Result::Err('this is an err')
+    }).unwrap_or_default();
+}
+"#;
+
+const OK_OR_WITHOUT_UNWRAP_OR_DEFAULT: &str = r#"
+fn main() {
+    let foo: Option<u32> = Option::None;
+    let _foo = foo.ok_or('this is an err').unwrap_or(1);
+}
+"#;
+
+const RESULT_UNWRAP_OR_DEFAULT: &str = r#"
+fn main() {
+    let foo: Result<u32, felt252> = Result::Err('this is an err');
+    let _foo = foo.unwrap_or_default();
+}
+"#;
+
+#[test]
+fn ok_or_unwrap_or_default_diagnostics() {
+    test_lint_diagnostics!(OK_OR_UNWRAP_OR_DEFAULT, @r"
+    Plugin diagnostic: Option<T> converted into a Result only to call unwrap_or_default on it. Consider using unwrap_or_default on the Option directly
+     --> lib.cairo:4:16
+        let _foo = foo.ok_or('this is an err').unwrap_or_default();
+                   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn ok_or_unwrap_or_default_fixer() {
+    test_lint_fixer!(OK_OR_UNWRAP_OR_DEFAULT, @r"
+    fn main() {
+        let foo: Option<u32> = Option::None;
+        let _foo = foo.unwrap_or_default();
+    }
+    ");
+}
+
+#[test]
+fn ok_or_unwrap_or_default_allowed_diagnostics() {
+    test_lint_diagnostics!(OK_OR_UNWRAP_OR_DEFAULT_ALLOWED, @"");
+}
+
+#[test]
+fn match_unwrap_or_default_diagnostics() {
+    test_lint_diagnostics!(MATCH_UNWRAP_OR_DEFAULT, @r"
+    Plugin diagnostic: Option<T> converted into a Result only to call unwrap_or_default on it. Consider using unwrap_or_default on the Option directly
+     --> lib.cairo:4:16-7:25
+          let _foo = match foo {
+     ________________^
+    | ...
+    |     }.unwrap_or_default();
+    |_________________________^
+    ");
+}
+
+#[test]
+fn match_unwrap_or_default_fixer() {
+    test_lint_fixer!(MATCH_UNWRAP_OR_DEFAULT, @r"
+    fn main() {
+        let foo: Option<u32> = Option::None;
+        let _foo = foo.unwrap_or_default();
+    }
+    ");
+}
+
+#[test]
+fn if_let_unwrap_or_default_diagnostics() {
+    test_lint_diagnostics!(IF_LET_UNWRAP_OR_DEFAULT, @r"
+    Plugin diagnostic: Option<T> converted into a Result only to call unwrap_or_default on it. Consider using unwrap_or_default on the Option directly
+     --> lib.cairo:4:16-8:26
+          let _foo = (if let Option::Some(v) = foo {
+     ________________^
+    | ...
+    |     }).unwrap_or_default();
+    |__________________________^
+    ");
+}
+
+#[test]
+fn if_let_unwrap_or_default_fixer() {
+    test_lint_fixer!(IF_LET_UNWRAP_OR_DEFAULT, @r"
+    fn main() {
+        let foo: Option<u32> = Option::None;
+        let _foo = foo.unwrap_or_default();
+    }
+    ");
+}
+
+#[test]
+fn ok_or_without_unwrap_or_default_diagnostics() {
+    test_lint_diagnostics!(OK_OR_WITHOUT_UNWRAP_OR_DEFAULT, @"");
+}
+
+#[test]
+fn result_unwrap_or_default_diagnostics() {
+    test_lint_diagnostics!(RESULT_UNWRAP_OR_DEFAULT, @"");
+}
@@ -0,0 +1,142 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const MANUAL_PARTITION_LOOP: &str = r#"
+fn main() -> (Array<u32>, Array<u32>) {
+    let numbers = array![1_u32, 2, 3, 4];
+    let mut even = array![];
+    let mut odd = array![];
+    for number in numbers {
+        if number % 2 == 0 {
+            even.append(number);
+        } else {
+            odd.append(number);
+        }
+    }
+    (even, odd)
+}
+"#;
+
+const MANUAL_PARTITION_LOOP_ALLOWED: &str = r#"
+#[allow(manual_partition)]
+fn main() -> (Array<u32>, Array<u32>) {
+    let numbers = array![1_u32, 2, 3, 4];
+    let mut even = array![];
+    let mut odd = array![];
+    for number in numbers {
+        if number % 2 == 0 {
+            even.append(number);
+        } else {
+            odd.append(number);
+        }
+    }
+    (even, odd)
+}
+"#;
+
+const PARTITION_WITH_ARRAY_TRAIT_NEW: &str = r#"
+fn main() -> (Array<u32>, Array<u32>) {
+    let numbers = array![1_u32, 2, 3, 4];
+    let mut small = ArrayTrait::new();
+    let mut big = ArrayTrait::new();
+    for number in numbers {
+        if number > 2 {
+            big.append(number);
+        } else {
+            small.append(number);
+        }
+    }
+    (small, big)
+}
+"#;
+
+const BRANCHES_APPEND_DIFFERENT_EXPRESSIONS: &str = r#"
+fn main() -> (Array<u32>, Array<u32>) {
+    let numbers = array![1_u32, 2, 3, 4];
+    let mut even = array![];
+    let mut odd = array![];
+    for number in numbers {
+        if number % 2 == 0 {
+            even.append(number);
+        } else {
+            odd.append(number * 2);
+        }
+    }
+    (even, odd)
+}
+"#;
+
+const ARRAYS_NOT_FRESHLY_INITIALIZED: &str = r#"
+fn main() -> (Array<u32>, Array<u32>) {
+    let numbers = array![1_u32, 2, 3, 4];
+    let mut even = array![0];
+    let mut odd = array![];
+    for number in numbers {
+        if number % 2 == 0 {
+            even.append(number);
+        } else {
+            odd.append(number);
+        }
+    }
+    (even, odd)
+}
+"#;
+
+#[test]
+fn manual_partition_loop_diagnostics() {
+    test_lint_diagnostics!(MANUAL_PARTITION_LOOP, @r"
+    Plugin diagnostic: Manual partition of a collection into two arrays detected. Consider extracting it into a dedicated partition helper.
+     --> lib.cairo:6:5-12:5
+          for number in numbers {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_partition_loop_fixer() {
+    test_lint_fixer!(MANUAL_PARTITION_LOOP, @r"
+    fn main() -> (Array<u32>, Array<u32>) {
+        let numbers = array![1_u32, 2, 3, 4];
+        let mut even = array![];
+        let mut odd = array![];
+        for number in numbers {
+            if number % 2 == 0 {
+                even.append(number);
+            } else {
+                odd.append(number);
+            }
+        }
+        (even, odd)
+    }
+    ");
+}
+
+#[test]
+fn manual_partition_loop_allowed_diagnostics() {
+    test_lint_diagnostics!(MANUAL_PARTITION_LOOP_ALLOWED, @"");
+}
+
+#[test]
+fn partition_with_array_trait_new_diagnostics() {
+    test_lint_diagnostics!(PARTITION_WITH_ARRAY_TRAIT_NEW, @r"
+    Plugin diagnostic: Manual partition of a collection into two arrays detected. Consider extracting it into a dedicated partition helper.
+     --> lib.cairo:6:5-12:5
+          for number in numbers {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn branches_append_different_expressions_diagnostics() {
+    test_lint_diagnostics!(BRANCHES_APPEND_DIFFERENT_EXPRESSIONS, @"");
+}
+
+#[test]
+fn arrays_not_freshly_initialized_diagnostics() {
+    test_lint_diagnostics!(ARRAYS_NOT_FRESHLY_INITIALIZED, @"");
+}
@@ -0,0 +1,219 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const REPEATED_CONSTANT_APPENDS: &str = r#"
+fn main() -> Array<u32> {
+    let mut a = array![];
+    a.append(0);
+    a.append(0);
+    a.append(0);
+    a
+}
+"#;
+
+const REPEATED_CONSTANT_APPENDS_ALLOWED: &str = r#"
+#[allow(manual_repeat)]
+fn main() -> Array<u32> {
+    let mut a = array![];
+    a.append(0);
+    a.append(0);
+    a.append(0);
+    a
+}
+"#;
+
+const REPEATED_APPENDS_TO_ARRAY_TRAIT_NEW: &str = r#"
+const VALUE: u32 = 5;
+
+fn main() -> Array<u32> {
+    let mut a = ArrayTrait::new();
+    a.append(VALUE);
+    a.append(VALUE);
+    a.append(VALUE);
+    a.append(VALUE);
+    a
+}
+"#;
+
+const REPEATED_SIDE_EFFECTING_CALL_APPENDS: &str = r#"
+fn compute() -> u32 {
+    42
+}
+
+fn main() -> Array<u32> {
+    let mut a = array![];
+    a.append(compute());
+    a.append(compute());
+    a.append(compute());
+    a
+}
+"#;
+
+const DIFFERENT_VALUES_APPENDS: &str = r#"
+fn main() -> Array<u32> {
+    let mut a = array![];
+    a.append(0);
+    a.append(1);
+    a.append(0);
+    a
+}
+"#;
+
+const TOO_FEW_APPENDS: &str = r#"
+fn main() -> Array<u32> {
+    let mut a = array![];
+    a.append(0);
+    a.append(0);
+    a
+}
+"#;
+
+const REPEATED_FIXED_SIZE_ARRAY_ITEMS: &str = r#"
+fn main() -> [u32; 4] {
+    let arr = [1, 1, 1, 1];
+    arr
+}
+"#;
+
+const REPEATED_FIXED_SIZE_ARRAY_ITEMS_ALLOWED: &str = r#"
+fn main() -> [u32; 4] {
+    #[allow(manual_repeat)]
+    let arr = [1, 1, 1, 1];
+    arr
+}
+"#;
+
+const FIXED_SIZE_ARRAY_OF_SIDE_EFFECTING_CALLS: &str = r#"
+fn compute() -> u32 {
+    42
+}
+
+fn main() -> [u32; 3] {
+    let arr = [compute(), compute(), compute()];
+    arr
+}
+"#;
+
+#[test]
+fn repeated_constant_appends_diagnostics() {
+    test_lint_diagnostics!(REPEATED_CONSTANT_APPENDS, @r"
+    Plugin diagnostic: Array is built from the same repeated value. Consider using the repeat syntax `[value; N]` instead.
+     --> lib.cairo:3:5
+        let mut a = array![];
+        ^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn repeated_constant_appends_fixer() {
+    test_lint_fixer!(REPEATED_CONSTANT_APPENDS, @r"
+    fn main() -> Array<u32> {
+        let mut a = array![];
+        a.append(0);
+        a.append(0);
+        a.append(0);
+        a
+    }
+    ");
+}
+
+#[test]
+fn repeated_constant_appends_allowed_diagnostics() {
+    test_lint_diagnostics!(REPEATED_CONSTANT_APPENDS_ALLOWED, @"");
+}
+
+#[test]
+fn repeated_appends_to_array_trait_new_diagnostics() {
+    test_lint_diagnostics!(REPEATED_APPENDS_TO_ARRAY_TRAIT_NEW, @r"
+    Plugin diagnostic: Array is built from the same repeated value. Consider using the repeat syntax `[value; N]` instead.
+     --> lib.cairo:5:5
+        let mut a = ArrayTrait::new();
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn repeated_side_effecting_call_appends_diagnostics() {
+    test_lint_diagnostics!(REPEATED_SIDE_EFFECTING_CALL_APPENDS, @"");
+}
+
+#[test]
+fn repeated_side_effecting_call_appends_fixer() {
+    test_lint_fixer!(REPEATED_SIDE_EFFECTING_CALL_APPENDS, @r"
+    fn compute() -> u32 {
+        42
+    }
+
+    fn main() -> Array<u32> {
+        let mut a = array![];
+        a.append(compute());
+        a.append(compute());
+        a.append(compute());
+        a
+    }
+    ");
+}
+
+#[test]
+fn different_values_appends_diagnostics() {
+    test_lint_diagnostics!(DIFFERENT_VALUES_APPENDS, @"");
+}
+
+#[test]
+fn too_few_appends_diagnostics() {
+    test_lint_diagnostics!(TOO_FEW_APPENDS, @"");
+}
+
+#[test]
+fn repeated_fixed_size_array_items_diagnostics() {
+    test_lint_diagnostics!(REPEATED_FIXED_SIZE_ARRAY_ITEMS, @r"
+    Plugin diagnostic: Array is built from the same repeated value. Consider using the repeat syntax `[value; N]` instead.
+     --> lib.cairo:3:15
+        let arr = [1, 1, 1, 1];
+                  ^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn repeated_fixed_size_array_items_fixer() {
+    test_lint_fixer!(REPEATED_FIXED_SIZE_ARRAY_ITEMS, @r"
+    fn main() -> [u32; 4] {
+        let arr = [1; 4];
+        arr
+    }
+    ");
+}
+
+#[test]
+fn repeated_fixed_size_array_items_allowed_diagnostics() {
+    test_lint_diagnostics!(REPEATED_FIXED_SIZE_ARRAY_ITEMS_ALLOWED, @"");
+}
+
+#[test]
+fn repeated_fixed_size_array_items_allowed_fixer() {
+    test_lint_fixer!(REPEATED_FIXED_SIZE_ARRAY_ITEMS_ALLOWED, @r"
+    fn main() -> [u32; 4] {
+        #[allow(manual_repeat)]
+        let arr = [1, 1, 1, 1];
+        arr
+    }
+    ");
+}
+
+#[test]
+fn fixed_size_array_of_side_effecting_calls_diagnostics() {
+    test_lint_diagnostics!(FIXED_SIZE_ARRAY_OF_SIDE_EFFECTING_CALLS, @"");
+}
+
+#[test]
+fn fixed_size_array_of_side_effecting_calls_fixer() {
+    test_lint_fixer!(FIXED_SIZE_ARRAY_OF_SIDE_EFFECTING_CALLS, @r"
+    fn compute() -> u32 {
+        42
+    }
+
+    fn main() -> [u32; 3] {
+        let arr = [compute(), compute(), compute()];
+        arr
+    }
+    ");
+}
@@ -116,6 +116,71 @@ fn main() {
 }
 "#;
 
+const MATCH_WITH_FUNCTION_CALL_FALLBACK: &str = r#"
+fn compute_default() -> u32 {
+    42
+}
+
+fn main() {
+    let a: Option<u32> = Option::Some(1);
+
+    let _b = match a {
+        Option::Some(v) => v,
+        Option::None => compute_default(),
+    };
+}
+"#;
+
+const MATCH_ON_RESULT_WITH_FUNCTION_CALL_FALLBACK: &str = r#"
+fn compute_default() -> u32 {
+    42
+}
+
+fn main() {
+    let a: Result<u32, felt252> = Result::Ok(1);
+
+    let _b = match a {
+        Result::Ok(v) => v,
+        Result::Err(_) => compute_default(),
+    };
+}
+"#;
+
+const IF_LET_WITH_METHOD_CALL_FALLBACK: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(1);
+    let values: Array<u32> = array![1, 2, 3];
+
+    let _b = if let Option::Some(v) = a {
+        v
+    } else {
+        values.len()
+    };
+}
+"#;
+
+const MATCH_WITH_OPERATOR_FALLBACK: &str = r#"
+fn main(x: u32) {
+    let a: Option<u32> = Option::Some(1);
+
+    let _b = match a {
+        Option::Some(v) => v,
+        Option::None => x + 1,
+    };
+}
+"#;
+
+const MATCH_WITH_PANIC_FALLBACK: &str = r#"
+fn main() {
+    let a: Option<u32> = Option::Some(1);
+
+    let _b = match a {
+        Option::Some(v) => v,
+        Option::None => core::panic_with_felt252('no value'),
+    };
+}
+"#;
+
 #[test]
 fn if_let_with_droppable_type_diagnostics() {
     test_lint_diagnostics!(IF_LET_WITH_DROPPABLE_TYPE, @r"
@@ -316,3 +381,110 @@ fn match_with_reversed_arms_result_fixer() {
     }
     ");
 }
+
+#[test]
+fn match_with_function_call_fallback_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITH_FUNCTION_CALL_FALLBACK, @r"
+    Plugin diagnostic: Manual `unwrap_or_else` detected. Consider using `unwrap_or_else()` instead.
+     --> lib.cairo:9:14-12:5
+          let _b = match a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_with_function_call_fallback_fixer() {
+    test_lint_fixer!(MATCH_WITH_FUNCTION_CALL_FALLBACK, @r"
+    fn compute_default() -> u32 {
+        42
+    }
+
+    fn main() {
+        let a: Option<u32> = Option::Some(1);
+
+        let _b = a.unwrap_or_else(|| compute_default());
+    }
+    ");
+}
+
+#[test]
+fn match_on_result_with_function_call_fallback_diagnostics() {
+    test_lint_diagnostics!(MATCH_ON_RESULT_WITH_FUNCTION_CALL_FALLBACK, @r"
+    Plugin diagnostic: Manual `unwrap_or_else` detected. Consider using `unwrap_or_else()` instead.
+     --> lib.cairo:9:14-12:5
+          let _b = match a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_on_result_with_function_call_fallback_fixer() {
+    test_lint_fixer!(MATCH_ON_RESULT_WITH_FUNCTION_CALL_FALLBACK, @r"
+    fn compute_default() -> u32 {
+        42
+    }
+
+    fn main() {
+        let a: Result<u32, felt252> = Result::Ok(1);
+
+        let _b = a.unwrap_or_else(|| compute_default());
+    }
+    ");
+}
+
+#[test]
+fn if_let_with_method_call_fallback_diagnostics() {
+    test_lint_diagnostics!(IF_LET_WITH_METHOD_CALL_FALLBACK, @r"
+    Plugin diagnostic: Manual `unwrap_or_else` detected. Consider using `unwrap_or_else()` instead.
+     --> lib.cairo:6:14-10:5
+          let _b = if let Option::Some(v) = a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn if_let_with_method_call_fallback_fixer() {
+    test_lint_fixer!(IF_LET_WITH_METHOD_CALL_FALLBACK, @r"
+    fn main() {
+        let a: Option<u32> = Option::Some(1);
+        let values: Array<u32> = array![1, 2, 3];
+
+        let _b = a.unwrap_or_else(|| values.len());
+    }
+    ");
+}
+
+#[test]
+fn match_with_operator_fallback_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITH_OPERATOR_FALLBACK, @r"
+    Plugin diagnostic: Manual `unwrap_or` detected. Consider using `unwrap_or()` instead.
+     --> lib.cairo:5:14-8:5
+          let _b = match a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_with_panic_fallback_diagnostics() {
+    test_lint_diagnostics!(MATCH_WITH_PANIC_FALLBACK, @r"
+    Plugin diagnostic: Manual match for expect detected. Consider using `expect()` instead
+     --> lib.cairo:5:14-8:5
+          let _b = match a {
+     ______________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
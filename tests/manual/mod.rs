@@ -1,14 +1,23 @@
 mod manual_assert;
+mod manual_checked_conversion;
 mod manual_err;
 mod manual_expect;
 mod manual_expect_err;
+mod manual_filter;
 mod manual_is_empty;
 mod manual_is_err;
 mod manual_is_none;
 mod manual_is_ok;
 mod manual_is_some;
+mod manual_is_some_and;
+mod manual_is_variant;
+mod manual_map_or;
 mod manual_ok;
 mod manual_ok_or;
+mod manual_ok_or_default;
+mod manual_partition;
+mod manual_repeat;
 mod manual_unwrap_or;
 mod manual_unwrap_or_default;
 mod manual_unwrap_or_else;
+mod needless_match;
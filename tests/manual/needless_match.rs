@@ -0,0 +1,234 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const OPTION_MATCH: &str = r#"
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::Some(x) => Option::Some(x),
+        Option::None => Option::None,
+    };
+}
+"#;
+
+const OPTION_MATCH_ALLOWED: &str = r#"
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    #[allow(needless_match)]
+    let _a = match opt {
+        Option::Some(x) => Option::Some(x),
+        Option::None => Option::None,
+    };
+}
+"#;
+
+const OPTION_MATCH_REVERSED_ARMS: &str = r#"
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::None => Option::None,
+        Option::Some(x) => Option::Some(x),
+    };
+}
+"#;
+
+const RESULT_MATCH: &str = r#"
+fn main() {
+    let res: Result<u32, felt252> = Result::Ok(1);
+    let _a = match res {
+        Result::Ok(x) => Result::Ok(x),
+        Result::Err(e) => Result::Err(e),
+    };
+}
+"#;
+
+const OPTION_MATCH_BLOCK_ARMS: &str = r#"
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::Some(x) => { Option::Some(x) },
+        Option::None => { Option::None },
+    };
+}
+"#;
+
+const OPTION_IF_LET: &str = r#"
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = if let Option::Some(x) = opt {
+        Option::Some(x)
+    } else {
+        Option::None
+    };
+}
+"#;
+
+const OPTION_MATCH_WITH_TRANSFORMATION: &str = r#"
+fn main() {
+    let opt: Option<u32> = Option::Some(1);
+    let _a = match opt {
+        Option::Some(x) => Option::Some(x + 1),
+        Option::None => Option::None,
+    };
+}
+"#;
+
+const RESULT_MATCH_WITH_DIFFERENT_ERROR: &str = r#"
+fn main() {
+    let res: Result<u32, felt252> = Result::Ok(1);
+    let _a: Result<u32, felt252> = match res {
+        Result::Ok(x) => Result::Ok(x),
+        Result::Err(_) => Result::Err('other'),
+    };
+}
+"#;
+
+const MATCH_INTO_DIFFERENT_ENUM: &str = r#"
+#[derive(Drop)]
+enum MyOption {
+    Some: u32,
+    None,
+}
+
+fn main() {
+    let opt = MyOption::Some(1);
+    let _a = match opt {
+        MyOption::Some(x) => Option::Some(x),
+        MyOption::None => Option::None,
+    };
+}
+"#;
+
+#[test]
+fn option_match_diagnostics() {
+    test_lint_diagnostics!(OPTION_MATCH, @r"
+    Plugin diagnostic: Needless match detected, the expression rebuilds the matched value. Consider using the matched value directly.
+     --> lib.cairo:4:14-9:5
+          let _a = match opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn option_match_fixer() {
+    test_lint_fixer!(OPTION_MATCH, @r"
+    fn main() {
+        let opt: Option<u32> = Option::Some(1);
+        let _a = opt;
+    }
+    ");
+}
+
+#[test]
+fn option_match_allowed_diagnostics() {
+    test_lint_diagnostics!(OPTION_MATCH_ALLOWED, @"");
+}
+
+#[test]
+fn option_match_reversed_arms_diagnostics() {
+    test_lint_diagnostics!(OPTION_MATCH_REVERSED_ARMS, @r"
+    Plugin diagnostic: Needless match detected, the expression rebuilds the matched value. Consider using the matched value directly.
+     --> lib.cairo:4:14-9:5
+          let _a = match opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn option_match_reversed_arms_fixer() {
+    test_lint_fixer!(OPTION_MATCH_REVERSED_ARMS, @r"
+    fn main() {
+        let opt: Option<u32> = Option::Some(1);
+        let _a = opt;
+    }
+    ");
+}
+
+#[test]
+fn result_match_diagnostics() {
+    test_lint_diagnostics!(RESULT_MATCH, @r"
+    Plugin diagnostic: Needless match detected, the expression rebuilds the matched value. Consider using the matched value directly.
+     --> lib.cairo:4:14-9:5
+          let _a = match res {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn result_match_fixer() {
+    test_lint_fixer!(RESULT_MATCH, @r"
+    fn main() {
+        let res: Result<u32, felt252> = Result::Ok(1);
+        let _a = res;
+    }
+    ");
+}
+
+#[test]
+fn option_match_block_arms_diagnostics() {
+    test_lint_diagnostics!(OPTION_MATCH_BLOCK_ARMS, @r"
+    Plugin diagnostic: Needless match detected, the expression rebuilds the matched value. Consider using the matched value directly.
+     --> lib.cairo:4:14-9:5
+          let _a = match opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn option_match_block_arms_fixer() {
+    test_lint_fixer!(OPTION_MATCH_BLOCK_ARMS, @r"
+    fn main() {
+        let opt: Option<u32> = Option::Some(1);
+        let _a = opt;
+    }
+    ");
+}
+
+#[test]
+fn option_if_let_diagnostics() {
+    test_lint_diagnostics!(OPTION_IF_LET, @r"
+    Plugin diagnostic: Needless match detected, the expression rebuilds the matched value. Consider using the matched value directly.
+     --> lib.cairo:4:14-10:5
+          let _a = if let Option::Some(x) = opt {
+     ______________^
+    | ...
+    |
+    |_____^
+    ");
+}
+
+#[test]
+fn option_if_let_fixer() {
+    test_lint_fixer!(OPTION_IF_LET, @r"
+    fn main() {
+        let opt: Option<u32> = Option::Some(1);
+        let _a = opt;
+    }
+    ");
+}
+
+#[test]
+fn option_match_with_transformation_diagnostics() {
+    test_lint_diagnostics!(OPTION_MATCH_WITH_TRANSFORMATION, @"");
+}
+
+#[test]
+fn result_match_with_different_error_diagnostics() {
+    test_lint_diagnostics!(RESULT_MATCH_WITH_DIFFERENT_ERROR, @"");
+}
+
+#[test]
+fn match_into_different_enum_diagnostics() {
+    test_lint_diagnostics!(MATCH_INTO_DIFFERENT_ENUM, @"");
+}
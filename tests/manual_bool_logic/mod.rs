@@ -0,0 +1,245 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const MANUAL_AND: &str = r#"
+fn main(a: bool, b: bool) -> bool {
+    if a {
+        b
+    } else {
+        false
+    }
+}
+"#;
+
+const MANUAL_OR: &str = r#"
+fn main(a: bool, b: bool) -> bool {
+    if a {
+        true
+    } else {
+        b
+    }
+}
+"#;
+
+const MANUAL_AND_WITH_OR_CONDITION: &str = r#"
+fn main(a: bool, b: bool, c: bool) -> bool {
+    if a || b {
+        c
+    } else {
+        false
+    }
+}
+"#;
+
+const MANUAL_OR_WITH_COMPARISONS: &str = r#"
+fn main(x: u32, y: u32) -> bool {
+    if x > 5 {
+        true
+    } else {
+        y == 3
+    }
+}
+"#;
+
+const MANUAL_AND_IN_UNARY_EXPRESSION: &str = r#"
+fn main(a: bool, b: bool) -> bool {
+    !if a {
+        b
+    } else {
+        false
+    }
+}
+"#;
+
+const MANUAL_AND_WITH_COMMENT: &str = r#"
+fn main(a: bool, b: bool) -> bool {
+    if a {
+        // Only when both hold.
+        b
+    } else {
+        false
+    }
+}
+"#;
+
+const BOTH_BRANCHES_LITERALS: &str = r#"
+fn main(a: bool) -> bool {
+    if a {
+        true
+    } else {
+        false
+    }
+}
+"#;
+
+const FALSE_IN_IF_BRANCH: &str = r#"
+fn main(a: bool, b: bool) -> bool {
+    if a {
+        false
+    } else {
+        b
+    }
+}
+"#;
+
+const MANUAL_AND_ALLOWED: &str = r#"
+fn main(a: bool, b: bool) -> bool {
+    #[allow(manual_bool_logic)]
+    if a {
+        b
+    } else {
+        false
+    }
+}
+"#;
+
+#[test]
+fn manual_and_diagnostics() {
+    test_lint_diagnostics!(MANUAL_AND, @r"
+    Plugin diagnostic: Manual implementation of a boolean operator. Consider using `&&` or `||` instead.
+     --> lib.cairo:3:5-7:5
+          if a {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_and_fixer() {
+    test_lint_fixer!(MANUAL_AND, @r"
+    fn main(a: bool, b: bool) -> bool {
+        a && b
+    }
+    ");
+}
+
+#[test]
+fn manual_or_diagnostics() {
+    test_lint_diagnostics!(MANUAL_OR, @r"
+    Plugin diagnostic: Manual implementation of a boolean operator. Consider using `&&` or `||` instead.
+     --> lib.cairo:3:5-7:5
+          if a {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_or_fixer() {
+    test_lint_fixer!(MANUAL_OR, @r"
+    fn main(a: bool, b: bool) -> bool {
+        a || b
+    }
+    ");
+}
+
+#[test]
+fn manual_and_with_or_condition_diagnostics() {
+    test_lint_diagnostics!(MANUAL_AND_WITH_OR_CONDITION, @r"
+    Plugin diagnostic: Manual implementation of a boolean operator. Consider using `&&` or `||` instead.
+     --> lib.cairo:3:5-7:5
+          if a || b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_and_with_or_condition_fixer() {
+    test_lint_fixer!(MANUAL_AND_WITH_OR_CONDITION, @r"
+    fn main(a: bool, b: bool, c: bool) -> bool {
+        (a || b) && c
+    }
+    ");
+}
+
+#[test]
+fn manual_or_with_comparisons_diagnostics() {
+    test_lint_diagnostics!(MANUAL_OR_WITH_COMPARISONS, @r"
+    Plugin diagnostic: Manual implementation of a boolean operator. Consider using `&&` or `||` instead.
+     --> lib.cairo:3:5-7:5
+          if x > 5 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_or_with_comparisons_fixer() {
+    test_lint_fixer!(MANUAL_OR_WITH_COMPARISONS, @r"
+    fn main(x: u32, y: u32) -> bool {
+        x > 5 || y == 3
+    }
+    ");
+}
+
+#[test]
+fn manual_and_in_unary_expression_diagnostics() {
+    test_lint_diagnostics!(MANUAL_AND_IN_UNARY_EXPRESSION, @r"
+    Plugin diagnostic: Manual implementation of a boolean operator. Consider using `&&` or `||` instead.
+     --> lib.cairo:3:6-7:5
+          !if a {
+     ______^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_and_in_unary_expression_fixer() {
+    test_lint_fixer!(MANUAL_AND_IN_UNARY_EXPRESSION, @r"
+    fn main(a: bool, b: bool) -> bool {
+        !(a && b)
+    }
+    ");
+}
+
+#[test]
+fn manual_and_with_comment_diagnostics() {
+    test_lint_diagnostics!(MANUAL_AND_WITH_COMMENT, @r"
+    Plugin diagnostic: Manual implementation of a boolean operator. Consider using `&&` or `||` instead.
+     --> lib.cairo:3:5-8:5
+          if a {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_and_with_comment_fixer() {
+    test_lint_fixer!(MANUAL_AND_WITH_COMMENT, @r"
+    fn main(a: bool, b: bool) -> bool {
+        if a {
+            // Only when both hold.
+            b
+        } else {
+            false
+        }
+    }
+    ");
+}
+
+#[test]
+fn both_branches_literals_diagnostics() {
+    test_lint_diagnostics!(BOTH_BRANCHES_LITERALS, @"");
+}
+
+#[test]
+fn false_in_if_branch_diagnostics() {
+    test_lint_diagnostics!(FALSE_IN_IF_BRANCH, @"");
+}
+
+#[test]
+fn manual_and_allowed_diagnostics() {
+    test_lint_diagnostics!(MANUAL_AND_ALLOWED, @"");
+}
@@ -0,0 +1,182 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const MANUAL_CLAMP: &str = r#"
+fn main(x: u32, lo: u32, hi: u32) -> u32 {
+    if x < lo {
+        lo
+    } else if x > hi {
+        hi
+    } else {
+        x
+    }
+}
+"#;
+
+const MANUAL_CLAMP_UPPER_BOUND_FIRST: &str = r#"
+fn main(x: u32, lo: u32, hi: u32) -> u32 {
+    if hi <= x {
+        hi
+    } else if lo >= x {
+        lo
+    } else {
+        x
+    }
+}
+"#;
+
+const MANUAL_CLAMP_LITERAL_BOUNDS: &str = r#"
+fn main(x: u32) -> u32 {
+    if x < 10 {
+        10
+    } else if x > 100 {
+        100
+    } else {
+        x
+    }
+}
+"#;
+
+const MANUAL_CLAMP_ALLOWED: &str = r#"
+#[allow(manual_clamp)]
+fn main(x: u32, lo: u32, hi: u32) -> u32 {
+    if x < lo {
+        lo
+    } else if x > hi {
+        hi
+    } else {
+        x
+    }
+}
+"#;
+
+const MISMATCHED_BOUND: &str = r#"
+fn main(x: u32, lo: u32, hi: u32) -> u32 {
+    if x < lo {
+        hi
+    } else if x > hi {
+        lo
+    } else {
+        x
+    }
+}
+"#;
+
+const SAME_DIRECTION_BOUNDS: &str = r#"
+fn main(x: u32, lo: u32, hi: u32) -> u32 {
+    if x < lo {
+        lo
+    } else if x < hi {
+        hi
+    } else {
+        x
+    }
+}
+"#;
+
+const DIFFERENT_VALUE_IN_ELSE: &str = r#"
+fn main(x: u32, y: u32, lo: u32, hi: u32) -> u32 {
+    if x < lo {
+        lo
+    } else if x > hi {
+        hi
+    } else {
+        y
+    }
+}
+"#;
+
+const BOUND_WITH_SIDE_EFFECTS: &str = r#"
+fn bound() -> u32 {
+    10
+}
+
+fn main(x: u32, hi: u32) -> u32 {
+    if x < bound() {
+        bound()
+    } else if x > hi {
+        hi
+    } else {
+        x
+    }
+}
+"#;
+
+#[test]
+fn manual_clamp_diagnostics() {
+    test_lint_diagnostics!(MANUAL_CLAMP, @r"
+    Plugin diagnostic: Manual implementation of `clamp` detected. Consider using a clamp helper instead.
+     --> lib.cairo:3:5-9:5
+          if x < lo {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+// The corelib doesn't define `clamp`, so the `if` is left unchanged.
+#[test]
+fn manual_clamp_fixer() {
+    test_lint_fixer!(MANUAL_CLAMP, @r"
+    fn main(x: u32, lo: u32, hi: u32) -> u32 {
+        if x < lo {
+            lo
+        } else if x > hi {
+            hi
+        } else {
+            x
+        }
+    }
+    ");
+}
+
+#[test]
+fn manual_clamp_upper_bound_first_diagnostics() {
+    test_lint_diagnostics!(MANUAL_CLAMP_UPPER_BOUND_FIRST, @r"
+    Plugin diagnostic: Manual implementation of `clamp` detected. Consider using a clamp helper instead.
+     --> lib.cairo:3:5-9:5
+          if hi <= x {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_clamp_literal_bounds_diagnostics() {
+    test_lint_diagnostics!(MANUAL_CLAMP_LITERAL_BOUNDS, @r"
+    Plugin diagnostic: Manual implementation of `clamp` detected. Consider using a clamp helper instead.
+     --> lib.cairo:3:5-9:5
+          if x < 10 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_clamp_allowed_diagnostics() {
+    test_lint_diagnostics!(MANUAL_CLAMP_ALLOWED, @"");
+}
+
+#[test]
+fn mismatched_bound_diagnostics() {
+    test_lint_diagnostics!(MISMATCHED_BOUND, @"");
+}
+
+#[test]
+fn same_direction_bounds_diagnostics() {
+    test_lint_diagnostics!(SAME_DIRECTION_BOUNDS, @"");
+}
+
+#[test]
+fn different_value_in_else_diagnostics() {
+    test_lint_diagnostics!(DIFFERENT_VALUE_IN_ELSE, @"");
+}
+
+#[test]
+fn bound_with_side_effects_diagnostics() {
+    test_lint_diagnostics!(BOUND_WITH_SIDE_EFFECTS, @"");
+}
@@ -0,0 +1,105 @@
+use crate::test_lint_diagnostics;
+
+const ADD_DIVISOR_MINUS_ONE: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    (a + b - 1) / b
+}
+"#;
+
+const SUB_ONE_DIVIDE_PLUS_ONE: &str = r#"
+fn main(a: u64, b: u64) -> u64 {
+    (a - 1) / b + 1
+}
+"#;
+
+const MEMBER_DIVISOR: &str = r#"
+#[derive(Drop)]
+struct Chunks {
+    len: u128,
+    size: u128,
+}
+
+fn main(chunks: Chunks) -> u128 {
+    let count = (chunks.len + chunks.size - 1) / chunks.size;
+    count * 2
+}
+"#;
+
+const ADD_DIVISOR_MINUS_ONE_ALLOWED: &str = r#"
+#[allow(manual_div_ceil)]
+fn main(a: u32, b: u32) -> u32 {
+    (a + b - 1) / b
+}
+"#;
+
+const DIFFERENT_DIVISOR: &str = r#"
+fn main(a: u32, b: u32, c: u32) -> u32 {
+    (a + b - 1) / c
+}
+"#;
+
+const SIGNED_INTEGERS: &str = r#"
+fn main(a: i32, b: i32) -> i32 {
+    (a + b - 1) / b
+}
+"#;
+
+const DIVISOR_WITH_SIDE_EFFECTS: &str = r#"
+fn divisor() -> u32 {
+    4
+}
+
+fn main(a: u32) -> u32 {
+    (a + divisor() - 1) / divisor()
+}
+"#;
+
+#[test]
+fn add_divisor_minus_one_diagnostics() {
+    test_lint_diagnostics!(ADD_DIVISOR_MINUS_ONE, @r"
+    Plugin diagnostic: Manual ceiling division detected. Consider using a dedicated ceil-div helper instead.
+     --> lib.cairo:3:5
+        (a + b - 1) / b
+        ^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn sub_one_divide_plus_one_diagnostics() {
+    test_lint_diagnostics!(SUB_ONE_DIVIDE_PLUS_ONE, @r"
+    Plugin diagnostic: Manual ceiling division detected. Consider using a dedicated ceil-div helper instead.
+     --> lib.cairo:3:5
+        (a - 1) / b + 1
+        ^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn member_divisor_diagnostics() {
+    test_lint_diagnostics!(MEMBER_DIVISOR, @r"
+    Plugin diagnostic: Manual ceiling division detected. Consider using a dedicated ceil-div helper instead.
+     --> lib.cairo:9:17
+        let count = (chunks.len + chunks.size - 1) / chunks.size;
+                    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn add_divisor_minus_one_allowed_diagnostics() {
+    test_lint_diagnostics!(ADD_DIVISOR_MINUS_ONE_ALLOWED, @"");
+}
+
+#[test]
+fn different_divisor_diagnostics() {
+    test_lint_diagnostics!(DIFFERENT_DIVISOR, @"");
+}
+
+#[test]
+fn signed_integers_diagnostics() {
+    test_lint_diagnostics!(SIGNED_INTEGERS, @"");
+}
+
+#[test]
+fn divisor_with_side_effects_diagnostics() {
+    test_lint_diagnostics!(DIVISOR_WITH_SIDE_EFFECTS, @"");
+}
@@ -0,0 +1,185 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const NESTED_MATCH: &str = r#"
+fn main(opt: Option<Option<u32>>) -> Option<u32> {
+    match opt {
+        Some(inner) => match inner {
+            Some(value) => Some(value),
+            _ => None,
+        },
+        None => None,
+    }
+}
+"#;
+
+const COLLAPSED_MATCH: &str = r#"
+fn main(opt: Option<Option<u32>>) -> Option<u32> {
+    match opt {
+        Some(Some(value)) => Some(value),
+        _ => None,
+    }
+}
+"#;
+
+const COLLAPSED_MATCH_WITH_ALL_ARMS: &str = r#"
+fn main(opt: Option<Option<u32>>) -> Option<u32> {
+    match opt {
+        Some(Some(value)) => Some(value),
+        Some(None) => None,
+        None => None,
+    }
+}
+"#;
+
+const MATCH_ON_FUNCTION_CALL: &str = r#"
+fn get() -> Option<Option<u32>> {
+    Some(Some(1))
+}
+
+fn main() -> u32 {
+    // Flatten the result.
+    let value = match get() {
+        Some(Some(value)) => Some(value),
+        _ => None,
+    };
+    value.unwrap_or(0)
+}
+"#;
+
+const COLLAPSED_MATCH_ALLOWED: &str = r#"
+#[allow(manual_flatten)]
+fn main(opt: Option<Option<u32>>) -> Option<u32> {
+    match opt {
+        Some(Some(value)) => Some(value),
+        _ => None,
+    }
+}
+"#;
+
+const TRANSFORMED_VALUE: &str = r#"
+fn main(opt: Option<Option<u32>>) -> Option<u32> {
+    match opt {
+        Some(Some(value)) => Some(value + 1),
+        _ => None,
+    }
+}
+"#;
+
+const DEFAULT_FOR_OUTER_NONE: &str = r#"
+fn main(opt: Option<Option<u32>>) -> Option<u32> {
+    match opt {
+        Some(Some(value)) => Some(value),
+        Some(None) => None,
+        None => Some(0),
+    }
+}
+"#;
+
+#[test]
+fn nested_match_diagnostics() {
+    test_lint_diagnostics!(NESTED_MATCH, @r"
+    Plugin diagnostic: Manual flattening of a nested `Option` detected. Consider using `.flatten()` instead.
+     --> lib.cairo:3:5-9:5
+          match opt {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn nested_match_fixer() {
+    test_lint_fixer!(NESTED_MATCH, @r"
+    fn main(opt: Option<Option<u32>>) -> Option<u32> {
+        opt.flatten()
+    }
+    ");
+}
+
+#[test]
+fn collapsed_match_diagnostics() {
+    test_lint_diagnostics!(COLLAPSED_MATCH, @r"
+    Plugin diagnostic: Manual flattening of a nested `Option` detected. Consider using `.flatten()` instead.
+     --> lib.cairo:3:5-6:5
+          match opt {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn collapsed_match_fixer() {
+    test_lint_fixer!(COLLAPSED_MATCH, @r"
+    fn main(opt: Option<Option<u32>>) -> Option<u32> {
+        opt.flatten()
+    }
+    ");
+}
+
+#[test]
+fn collapsed_match_with_all_arms_diagnostics() {
+    test_lint_diagnostics!(COLLAPSED_MATCH_WITH_ALL_ARMS, @r"
+    Plugin diagnostic: Manual flattening of a nested `Option` detected. Consider using `.flatten()` instead.
+     --> lib.cairo:3:5-7:5
+          match opt {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn collapsed_match_with_all_arms_fixer() {
+    test_lint_fixer!(COLLAPSED_MATCH_WITH_ALL_ARMS, @r"
+    fn main(opt: Option<Option<u32>>) -> Option<u32> {
+        opt.flatten()
+    }
+    ");
+}
+
+#[test]
+fn match_on_function_call_diagnostics() {
+    test_lint_diagnostics!(MATCH_ON_FUNCTION_CALL, @r"
+    Plugin diagnostic: Manual flattening of a nested `Option` detected. Consider using `.flatten()` instead.
+     --> lib.cairo:8:17-11:5
+          let value = match get() {
+     _________________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_on_function_call_fixer() {
+    test_lint_fixer!(MATCH_ON_FUNCTION_CALL, @r"
+    fn get() -> Option<Option<u32>> {
+        Some(Some(1))
+    }
+
+    fn main() -> u32 {
+        // Flatten the result.
+        let value = get().flatten();
+        value.unwrap_or(0)
+    }
+    ");
+}
+
+#[test]
+fn collapsed_match_allowed_diagnostics() {
+    test_lint_diagnostics!(COLLAPSED_MATCH_ALLOWED, @"");
+}
+
+#[test]
+fn transformed_value_diagnostics() {
+    test_lint_diagnostics!(TRANSFORMED_VALUE, @"");
+}
+
+#[test]
+fn default_for_outer_none_diagnostics() {
+    test_lint_diagnostics!(DEFAULT_FOR_OUTER_NONE, @"");
+}
@@ -0,0 +1,218 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const MANUAL_MIN_LESS_THAN: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+"#;
+
+const MANUAL_MIN_LESS_THAN_ALLOWED: &str = r#"
+#[allow(manual_min)]
+fn main(a: u32, b: u32) -> u32 {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+"#;
+
+const MANUAL_MIN_LESS_EQUAL: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    if a <= b {
+        a
+    } else {
+        b
+    }
+}
+"#;
+
+const MANUAL_MAX_GREATER_THAN: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+"#;
+
+const MANUAL_MAX_GREATER_THAN_ALLOWED: &str = r#"
+#[allow(manual_max)]
+fn main(a: u32, b: u32) -> u32 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+"#;
+
+const MANUAL_MAX_SWAPPED_ARMS: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    if a < b {
+        b
+    } else {
+        a
+    }
+}
+"#;
+
+const MANUAL_MIN_SWAPPED_ARMS: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    if a >= b {
+        b
+    } else {
+        a
+    }
+}
+"#;
+
+const COMPARISON_WITH_OTHER_ARMS: &str = r#"
+fn main(a: u32, b: u32) -> u32 {
+    if a < b {
+        a
+    } else {
+        a + b
+    }
+}
+"#;
+
+#[test]
+fn manual_min_less_than_diagnostics() {
+    test_lint_diagnostics!(MANUAL_MIN_LESS_THAN, @r"
+    Plugin diagnostic: Manual implementation of `min` detected. Consider using `core::cmp::min` instead.
+     --> lib.cairo:3:5-7:5
+          if a < b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_min_less_than_fixer() {
+    test_lint_fixer!(MANUAL_MIN_LESS_THAN, @r"
+    use core::cmp::min;
+
+    fn main(a: u32, b: u32) -> u32 {
+        min(a, b)
+    }
+    ");
+}
+
+#[test]
+fn manual_min_less_than_allowed_diagnostics() {
+    test_lint_diagnostics!(MANUAL_MIN_LESS_THAN_ALLOWED, @"");
+}
+
+#[test]
+fn manual_min_less_equal_diagnostics() {
+    test_lint_diagnostics!(MANUAL_MIN_LESS_EQUAL, @r"
+    Plugin diagnostic: Manual implementation of `min` detected. Consider using `core::cmp::min` instead.
+     --> lib.cairo:3:5-7:5
+          if a <= b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_min_less_equal_fixer() {
+    test_lint_fixer!(MANUAL_MIN_LESS_EQUAL, @r"
+    use core::cmp::min;
+
+    fn main(a: u32, b: u32) -> u32 {
+        min(a, b)
+    }
+    ");
+}
+
+#[test]
+fn manual_max_greater_than_diagnostics() {
+    test_lint_diagnostics!(MANUAL_MAX_GREATER_THAN, @r"
+    Plugin diagnostic: Manual implementation of `max` detected. Consider using `core::cmp::max` instead.
+     --> lib.cairo:3:5-7:5
+          if a > b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_max_greater_than_fixer() {
+    test_lint_fixer!(MANUAL_MAX_GREATER_THAN, @r"
+    use core::cmp::max;
+
+    fn main(a: u32, b: u32) -> u32 {
+        max(a, b)
+    }
+    ");
+}
+
+#[test]
+fn manual_max_greater_than_allowed_diagnostics() {
+    test_lint_diagnostics!(MANUAL_MAX_GREATER_THAN_ALLOWED, @"");
+}
+
+#[test]
+fn manual_max_swapped_arms_diagnostics() {
+    test_lint_diagnostics!(MANUAL_MAX_SWAPPED_ARMS, @r"
+    Plugin diagnostic: Manual implementation of `max` detected. Consider using `core::cmp::max` instead.
+     --> lib.cairo:3:5-7:5
+          if a < b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_max_swapped_arms_fixer() {
+    test_lint_fixer!(MANUAL_MAX_SWAPPED_ARMS, @r"
+    use core::cmp::max;
+
+    fn main(a: u32, b: u32) -> u32 {
+        max(a, b)
+    }
+    ");
+}
+
+#[test]
+fn manual_min_swapped_arms_diagnostics() {
+    test_lint_diagnostics!(MANUAL_MIN_SWAPPED_ARMS, @r"
+    Plugin diagnostic: Manual implementation of `min` detected. Consider using `core::cmp::min` instead.
+     --> lib.cairo:3:5-7:5
+          if a >= b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn manual_min_swapped_arms_fixer() {
+    test_lint_fixer!(MANUAL_MIN_SWAPPED_ARMS, @r"
+    use core::cmp::min;
+
+    fn main(a: u32, b: u32) -> u32 {
+        min(a, b)
+    }
+    ");
+}
+
+#[test]
+fn comparison_with_other_arms_diagnostics() {
+    test_lint_diagnostics!(COMPARISON_WITH_OTHER_ARMS, @"");
+}
@@ -0,0 +1,145 @@
+use cairo_lint::{LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const CUBE: &str = r#"
+fn main(x: u32) -> u32 {
+    x * x * x
+}
+"#;
+
+const CUBE_ALLOWED: &str = r#"
+#[allow(manual_pow)]
+fn main(x: u32) -> u32 {
+    x * x * x
+}
+"#;
+
+const MEMBER_WITH_PARENTHESES: &str = r#"
+#[derive(Copy, Drop)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn main(point: Point) -> u32 {
+    let _y = point.y;
+    (point.x * point.x) * point.x * point.x
+}
+"#;
+
+const SQUARE: &str = r#"
+fn main(x: u32) -> u32 {
+    x * x
+}
+"#;
+
+const DIFFERENT_OPERANDS: &str = r#"
+fn main(x: u32, y: u32) -> u32 {
+    x * x * y
+}
+"#;
+
+const FUNCTION_CALLS: &str = r#"
+fn value() -> u32 {
+    2
+}
+
+fn main() -> u32 {
+    value() * value() * value()
+}
+"#;
+
+#[test]
+fn cube_diagnostics() {
+    test_lint_diagnostics!(CUBE, @r"
+    Plugin diagnostic: Repeated multiplication of a value by itself detected. Consider using `pow` instead.
+     --> lib.cairo:3:5
+        x * x * x
+        ^^^^^^^^^
+    ");
+}
+
+#[test]
+fn cube_fixer() {
+    test_lint_fixer!(CUBE, @r"
+    use core::num::traits::Pow;
+
+    fn main(x: u32) -> u32 {
+        x.pow(3)
+    }
+    ");
+}
+
+#[test]
+fn cube_allowed_diagnostics() {
+    test_lint_diagnostics!(CUBE_ALLOWED, @"");
+}
+
+#[test]
+fn member_with_parentheses_diagnostics() {
+    test_lint_diagnostics!(MEMBER_WITH_PARENTHESES, @r"
+    Plugin diagnostic: Repeated multiplication of a value by itself detected. Consider using `pow` instead.
+     --> lib.cairo:10:5
+        (point.x * point.x) * point.x * point.x
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn member_with_parentheses_fixer() {
+    test_lint_fixer!(MEMBER_WITH_PARENTHESES, @r"
+    use core::num::traits::Pow;
+
+    #[derive(Copy, Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    fn main(point: Point) -> u32 {
+        let _y = point.y;
+        point.x.pow(4)
+    }
+    ");
+}
+
+#[test]
+fn square_diagnostics() {
+    test_lint_diagnostics!(SQUARE, @"");
+}
+
+#[test]
+fn different_operands_diagnostics() {
+    test_lint_diagnostics!(DIFFERENT_OPERANDS, @"");
+}
+
+#[test]
+fn function_calls_diagnostics() {
+    test_lint_diagnostics!(FUNCTION_CALLS, @"");
+}
+
+fn manual_pow_count(code: &str, manual_pow_threshold: usize) -> usize {
+    let params = LinterDiagnosticParams {
+        manual_pow_threshold,
+        ..Default::default()
+    };
+    lint_string(
+        code,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| diagnostics.len(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn lowered_threshold() {
+    assert_eq!(manual_pow_count(SQUARE, 2), 1);
+}
+
+#[test]
+fn raised_threshold() {
+    assert_eq!(manual_pow_count(CUBE, 4), 0);
+}
@@ -0,0 +1,124 @@
+use crate::test_lint_diagnostics;
+
+const SATURATING_ADD: &str = r#"
+use core::num::traits::Bounded;
+
+fn main(a: u8, b: u8) -> u8 {
+    if a > Bounded::MAX - b {
+        Bounded::MAX
+    } else {
+        a + b
+    }
+}
+"#;
+
+const SATURATING_SUB: &str = r#"
+use core::num::traits::Bounded;
+
+fn main(a: i8, b: i8) -> i8 {
+    if a < Bounded::MIN + b {
+        Bounded::MIN
+    } else {
+        a - b
+    }
+}
+"#;
+
+const SATURATING_ADD_WITH_LITERAL_BOUND: &str = r#"
+fn main(a: u8, b: u8) -> u8 {
+    if a <= 255 - b {
+        a + b
+    } else {
+        255
+    }
+}
+"#;
+
+const GUARD_AGAINST_NON_BOUND: &str = r#"
+fn main(a: u8, b: u8) -> u8 {
+    if a > 100 - b {
+        100
+    } else {
+        a + b
+    }
+}
+"#;
+
+const BRANCH_WITHOUT_ARITHMETIC: &str = r#"
+use core::num::traits::Bounded;
+
+fn main(a: u8, b: u8) -> u8 {
+    if a > Bounded::MAX - b {
+        Bounded::MAX
+    } else {
+        a
+    }
+}
+"#;
+
+const SATURATING_ADD_ALLOWED: &str = r#"
+use core::num::traits::Bounded;
+
+fn main(a: u8, b: u8) -> u8 {
+    #[allow(manual_saturating)]
+    if a > Bounded::MAX - b {
+        Bounded::MAX
+    } else {
+        a + b
+    }
+}
+"#;
+
+#[test]
+fn saturating_add_diagnostics() {
+    test_lint_diagnostics!(SATURATING_ADD, @r"
+    Plugin diagnostic: Manual saturating arithmetic. Consider using the `SaturatingAdd`, `SaturatingSub` or `SaturatingMul` traits instead.
+     --> lib.cairo:5:5-9:5
+          if a > Bounded::MAX - b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn saturating_sub_diagnostics() {
+    test_lint_diagnostics!(SATURATING_SUB, @r"
+    Plugin diagnostic: Manual saturating arithmetic. Consider using the `SaturatingAdd`, `SaturatingSub` or `SaturatingMul` traits instead.
+     --> lib.cairo:5:5-9:5
+          if a < Bounded::MIN + b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn saturating_add_with_literal_bound_diagnostics() {
+    test_lint_diagnostics!(SATURATING_ADD_WITH_LITERAL_BOUND, @r"
+    Plugin diagnostic: Manual saturating arithmetic. Consider using the `SaturatingAdd`, `SaturatingSub` or `SaturatingMul` traits instead.
+     --> lib.cairo:3:5-7:5
+          if a <= 255 - b {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn guard_against_non_bound_diagnostics() {
+    test_lint_diagnostics!(GUARD_AGAINST_NON_BOUND, @"");
+}
+
+#[test]
+fn branch_without_arithmetic_diagnostics() {
+    test_lint_diagnostics!(BRANCH_WITHOUT_ARITHMETIC, @"");
+}
+
+#[test]
+fn saturating_add_allowed_diagnostics() {
+    test_lint_diagnostics!(SATURATING_ADD_ALLOWED, @"");
+}
@@ -0,0 +1,221 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const VARIABLES_SWAP: &str = r#"
+fn main() -> (u32, u32) {
+    let mut a: u32 = 1;
+    let mut b: u32 = 2;
+    // Swap the values.
+    let t = a;
+    a = b;
+    b = t;
+    (a, b)
+}
+"#;
+
+const PARAMETERS_SWAP: &str = r#"
+fn main(mut a: u32, mut b: u32) -> (u32, u32) {
+    let t = a;
+    a = b;
+    b = t;
+    (a, b)
+}
+"#;
+
+const MEMBERS_SWAP: &str = r#"
+#[derive(Drop)]
+struct Pair {
+    x: u32,
+    y: u32,
+}
+
+fn main() -> Pair {
+    let mut p = Pair { x: 1, y: 2 };
+    let t = p.x;
+    p.x = p.y;
+    p.y = t;
+    p
+}
+"#;
+
+const SWAP_IN_NESTED_BLOCK: &str = r#"
+fn main(flip: bool) -> (u32, u32) {
+    let mut a: u32 = 1;
+    let mut b: u32 = 2;
+    if flip {
+        let t = a;
+        a = b;
+        b = t;
+    }
+    (a, b)
+}
+"#;
+
+const VARIABLES_SWAP_ALLOWED: &str = r#"
+#[allow(manual_swap)]
+fn main() -> (u32, u32) {
+    let mut a: u32 = 1;
+    let mut b: u32 = 2;
+    let t = a;
+    a = b;
+    b = t;
+    (a, b)
+}
+"#;
+
+const TEMPORARY_USED_LATER: &str = r#"
+fn main() -> (u32, u32, u32) {
+    let mut a: u32 = 1;
+    let mut b: u32 = 2;
+    let t = a;
+    a = b;
+    b = t;
+    (a, b, t)
+}
+"#;
+
+const DIFFERENT_PLACES: &str = r#"
+fn main() -> (u32, u32, u32) {
+    let mut a: u32 = 1;
+    let mut b: u32 = 2;
+    let mut c: u32 = 3;
+    let t = a;
+    a = b;
+    c = t;
+    (a, b, c)
+}
+"#;
+
+const DIFFERENT_MEMBERS: &str = r#"
+#[derive(Drop)]
+struct Pair {
+    x: u32,
+    y: u32,
+}
+
+fn main() -> Pair {
+    let mut p = Pair { x: 1, y: 2 };
+    let mut q = Pair { x: 3, y: 4 };
+    let t = p.x;
+    p.x = p.y;
+    q.y = t;
+    Pair { x: p.x + q.x, y: p.y + q.y }
+}
+"#;
+
+#[test]
+fn variables_swap_diagnostics() {
+    test_lint_diagnostics!(VARIABLES_SWAP, @r"
+    Plugin diagnostic: Manual swap detected. Consider swapping the values with a tuple instead.
+     --> lib.cairo:6:5
+        let t = a;
+        ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn variables_swap_fixer() {
+    test_lint_fixer!(VARIABLES_SWAP, @r"
+    fn main() -> (u32, u32) {
+        let mut a: u32 = 1;
+        let mut b: u32 = 2;
+        // Swap the values.
+        let (mut a, mut b) = (b, a);
+        (a, b)
+    }
+    ");
+}
+
+#[test]
+fn parameters_swap_diagnostics() {
+    test_lint_diagnostics!(PARAMETERS_SWAP, @r"
+    Plugin diagnostic: Manual swap detected. Consider swapping the values with a tuple instead.
+     --> lib.cairo:3:5
+        let t = a;
+        ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn parameters_swap_fixer() {
+    test_lint_fixer!(PARAMETERS_SWAP, @r"
+    fn main(mut a: u32, mut b: u32) -> (u32, u32) {
+        let (mut a, mut b) = (b, a);
+        (a, b)
+    }
+    ");
+}
+
+#[test]
+fn members_swap_diagnostics() {
+    test_lint_diagnostics!(MEMBERS_SWAP, @r"
+    Plugin diagnostic: Manual swap detected. Consider swapping the values with a tuple instead.
+     --> lib.cairo:10:5
+        let t = p.x;
+        ^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn members_swap_fixer() {
+    test_lint_fixer!(MEMBERS_SWAP, @r"
+    #[derive(Drop)]
+    struct Pair {
+        x: u32,
+        y: u32,
+    }
+
+    fn main() -> Pair {
+        let mut p = Pair { x: 1, y: 2 };
+        let t = p.x;
+        p.x = p.y;
+        p.y = t;
+        p
+    }
+    ");
+}
+
+#[test]
+fn swap_in_nested_block_diagnostics() {
+    test_lint_diagnostics!(SWAP_IN_NESTED_BLOCK, @r"
+    Plugin diagnostic: Manual swap detected. Consider swapping the values with a tuple instead.
+     --> lib.cairo:6:9
+            let t = a;
+            ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn swap_in_nested_block_fixer() {
+    test_lint_fixer!(SWAP_IN_NESTED_BLOCK, @r"
+    fn main(flip: bool) -> (u32, u32) {
+        let mut a: u32 = 1;
+        let mut b: u32 = 2;
+        if flip {
+            let t = a;
+            a = b;
+            b = t;
+        }
+        (a, b)
+    }
+    ");
+}
+
+#[test]
+fn variables_swap_allowed_diagnostics() {
+    test_lint_diagnostics!(VARIABLES_SWAP_ALLOWED, @"");
+}
+
+#[test]
+fn temporary_used_later_diagnostics() {
+    test_lint_diagnostics!(TEMPORARY_USED_LATER, @"");
+}
+
+#[test]
+fn different_places_diagnostics() {
+    test_lint_diagnostics!(DIFFERENT_PLACES, @"");
+}
+
+#[test]
+fn different_members_diagnostics() {
+    test_lint_diagnostics!(DIFFERENT_MEMBERS, @"");
+}
@@ -0,0 +1,122 @@
+use crate::test_lint_diagnostics;
+
+const MOVE_AND_RESET: &str = r#"
+fn main() -> Option<u32> {
+    let mut option = Option::Some(1_u32);
+    let value = option;
+    option = Option::None;
+    value
+}
+"#;
+
+const MATCH_AND_RESET: &str = r#"
+#[allow(needless_match)]
+fn main() -> Option<u32> {
+    let mut option = Option::Some(1_u32);
+    let value = match option {
+        Option::Some(x) => Option::Some(x),
+        Option::None => Option::None,
+    };
+    option = Option::None;
+    value
+}
+"#;
+
+const RESET_TO_SOME: &str = r#"
+fn main() -> Option<u32> {
+    let mut option = Option::Some(1_u32);
+    let value = option;
+    option = Option::Some(2);
+    value
+}
+"#;
+
+const STATEMENT_BETWEEN_MOVE_AND_RESET: &str = r#"
+fn main() -> Option<u32> {
+    let mut option = Option::Some(1_u32);
+    let value = option;
+    let _other = 2_u32;
+    option = Option::None;
+    value
+}
+"#;
+
+const MEMBER_RESET: &str = r#"
+#[derive(Drop)]
+struct Holder {
+    option: Option<u32>,
+}
+
+fn main() -> Option<u32> {
+    let mut holder = Holder { option: Option::Some(1) };
+    let value = holder.option;
+    holder.option = Option::None;
+    value
+}
+"#;
+
+const PARAMETER_RESET: &str = r#"
+fn main(mut option: Option<u32>) -> Option<u32> {
+    let value = option;
+    option = Option::None;
+    value
+}
+"#;
+
+const MOVE_AND_RESET_ALLOWED: &str = r#"
+fn main() -> Option<u32> {
+    let mut option = Option::Some(1_u32);
+    #[allow(manual_take)]
+    let value = option;
+    option = Option::None;
+    value
+}
+"#;
+
+#[test]
+fn move_and_reset_diagnostics() {
+    test_lint_diagnostics!(MOVE_AND_RESET, @r"
+    Plugin diagnostic: Manual `take` of an `Option` detected. Consider using `take()` instead.
+     --> lib.cairo:4:5
+        let value = option;
+        ^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn match_and_reset_diagnostics() {
+    test_lint_diagnostics!(MATCH_AND_RESET, @r"
+    Plugin diagnostic: Manual `take` of an `Option` detected. Consider using `take()` instead.
+     --> lib.cairo:5:5-8:6
+          let value = match option {
+     _____^
+    | ...
+    |     };
+    |______^
+    ");
+}
+
+#[test]
+fn reset_to_some_diagnostics() {
+    test_lint_diagnostics!(RESET_TO_SOME, @"");
+}
+
+#[test]
+fn statement_between_move_and_reset_diagnostics() {
+    test_lint_diagnostics!(STATEMENT_BETWEEN_MOVE_AND_RESET, @"");
+}
+
+#[test]
+fn member_reset_diagnostics() {
+    test_lint_diagnostics!(MEMBER_RESET, @"");
+}
+
+#[test]
+fn parameter_reset_diagnostics() {
+    test_lint_diagnostics!(PARAMETER_RESET, @"");
+}
+
+#[test]
+fn move_and_reset_allowed_diagnostics() {
+    test_lint_diagnostics!(MOVE_AND_RESET_ALLOWED, @"");
+}
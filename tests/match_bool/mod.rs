@@ -0,0 +1,201 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const MATCH_BOOL: &str = r#"
+fn main(flag: bool) -> u32 {
+    match flag {
+        true => 1,
+        false => 0,
+    }
+}
+"#;
+
+const MATCH_BOOL_FALSE_FIRST: &str = r#"
+fn main(flag: bool) -> u32 {
+    match flag {
+        false => 0,
+        true => 1,
+    }
+}
+"#;
+
+const MATCH_BOOL_WITH_BLOCK_ARMS: &str = r#"
+fn main(flag: bool) -> u32 {
+    let value = match flag {
+        false => {
+            let base = 10;
+            base * 2
+        },
+        true => 1,
+    };
+    value + 1
+}
+"#;
+
+const MATCH_BOOL_ON_CONDITION: &str = r#"
+fn main(x: u32) -> u32 {
+    match x > 10 {
+        true => x,
+        false => 10,
+    }
+}
+"#;
+
+const MATCH_BOOL_WITH_WILDCARD: &str = r#"
+fn main(flag: bool) -> u32 {
+    match flag {
+        true => 1,
+        _ => 0,
+    }
+}
+"#;
+
+const MATCH_BOOL_ALLOWED: &str = r#"
+#[allow(match_bool)]
+fn main(flag: bool) -> u32 {
+    match flag {
+        true => 1,
+        false => 0,
+    }
+}
+"#;
+
+#[test]
+fn match_bool_diagnostics() {
+    test_lint_diagnostics!(MATCH_BOOL, @r"
+    Plugin diagnostic: Matching on a `bool`. Consider using an `if`/`else` expression instead.
+     --> lib.cairo:3:5-6:5
+          match flag {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn match_bool_fixer() {
+    test_lint_fixer!(MATCH_BOOL, @r"
+    fn main(flag: bool) -> u32 {
+        if flag {
+            1
+        } else {
+            0
+        }
+    }
+    ");
+}
+
+#[test]
+fn match_bool_false_first_diagnostics() {
+    test_lint_diagnostics!(MATCH_BOOL_FALSE_FIRST, @r"
+    Plugin diagnostic: Matching on a `bool`. Consider using an `if`/`else` expression instead.
+     --> lib.cairo:3:5-6:5
+          match flag {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn match_bool_false_first_fixer() {
+    test_lint_fixer!(MATCH_BOOL_FALSE_FIRST, @r"
+    fn main(flag: bool) -> u32 {
+        if flag {
+            1
+        } else {
+            0
+        }
+    }
+    ");
+}
+
+#[test]
+fn match_bool_with_block_arms_diagnostics() {
+    test_lint_diagnostics!(MATCH_BOOL_WITH_BLOCK_ARMS, @r"
+    Plugin diagnostic: Matching on a `bool`. Consider using an `if`/`else` expression instead.
+     --> lib.cairo:3:17-9:5
+          let value = match flag {
+     _________________^
+    | ...
+    |     };
+    |_____^
+    ");
+}
+
+#[test]
+fn match_bool_with_block_arms_fixer() {
+    test_lint_fixer!(MATCH_BOOL_WITH_BLOCK_ARMS, @r"
+    fn main(flag: bool) -> u32 {
+        let value = if flag {
+            1
+        } else {
+            let base = 10;
+            base * 2
+        };
+        value + 1
+    }
+    ");
+}
+
+#[test]
+fn match_bool_on_condition_diagnostics() {
+    test_lint_diagnostics!(MATCH_BOOL_ON_CONDITION, @r"
+    Plugin diagnostic: Matching on a `bool`. Consider using an `if`/`else` expression instead.
+     --> lib.cairo:3:5-6:5
+          match x > 10 {
+     _____^
+    | ...
+    |     }
+    |_____^
+    ");
+}
+
+#[test]
+fn match_bool_on_condition_fixer() {
+    test_lint_fixer!(MATCH_BOOL_ON_CONDITION, @r"
+    fn main(x: u32) -> u32 {
+        if x > 10 {
+            x
+        } else {
+            10
+        }
+    }
+    ");
+}
+
+#[test]
+fn match_bool_with_wildcard_diagnostics() {
+    test_lint_diagnostics!(MATCH_BOOL_WITH_WILDCARD, @"");
+}
+
+#[test]
+fn match_bool_with_wildcard_fixer() {
+    test_lint_fixer!(MATCH_BOOL_WITH_WILDCARD, @r"
+    fn main(flag: bool) -> u32 {
+        match flag {
+            true => 1,
+            _ => 0,
+        }
+    }
+    ");
+}
+
+#[test]
+fn match_bool_allowed_diagnostics() {
+    test_lint_diagnostics!(MATCH_BOOL_ALLOWED, @"");
+}
+
+#[test]
+fn match_bool_allowed_fixer() {
+    test_lint_fixer!(MATCH_BOOL_ALLOWED, @r"
+    #[allow(match_bool)]
+    fn main(flag: bool) -> u32 {
+        match flag {
+            true => 1,
+            false => 0,
+        }
+    }
+    ");
+}
@@ -0,0 +1,198 @@
+use cairo_lang_defs::{db::DefsGroup, diagnostic_utils::StableLocation, ids::ModuleId};
+use cairo_lang_filesystem::ids::CrateInput;
+use cairo_lang_formatter::FormatterConfig;
+use cairo_lang_semantic::{SemanticDiagnostic, diagnostic::SemanticDiagnosticKind};
+use cairo_lang_utils::Intern;
+use cairo_lint::{
+    LinterAnalysisDatabase, LinterDiagnosticParams, LinterGroup, compute_fixed_content, get_fixes,
+};
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags, init_corelib,
+    setup::{setup_test_crate_ex, setup_test_crate_with_files},
+};
+
+// The fixes of `double_parens` and `redundant_block` keep overlapping on the same expression.
+const NESTED_OVERLAPPING_FIXES: &str = r#"fn foo(y: u32) -> u32 {
+    let x = { (({ (({ ((y)) })) })) };
+    x + 1
+}
+"#;
+
+const NESTED_OVERLAPPING_FIXES_FIXED: &str = r#"fn foo(y: u32) -> u32 {
+    let x = y;
+    x + 1
+}
+"#;
+
+const LIB_WITH_OVERLAPPING_FIXES: &str = r#"mod other;
+
+fn foo(y: u32) -> u32 {
+    let x = { (({ (({ ((y)) })) })) };
+    x + 1
+}
+"#;
+
+const LIB_WITH_OVERLAPPING_FIXES_FIXED: &str = r#"mod other;
+
+fn foo(y: u32) -> u32 {
+    let x = y;
+    x + 1
+}
+"#;
+
+const OTHER_WITH_OVERLAPPING_FIXES: &str = r#"fn bar(z: u32) -> u32 {
+    let w = { (({ ((z)) })) };
+    w * 2
+}
+"#;
+
+const OTHER_WITH_OVERLAPPING_FIXES_FIXED: &str = r#"fn bar(z: u32) -> u32 {
+    let w = z;
+    w * 2
+}
+"#;
+
+// The fix of `enum_variant_names` spans the whole code from the enum to the renamed path, which
+// contains the fix of `double_parens`.
+const ENUM_VARIANT_NAMES_WITH_OTHER_FIX: &str = r#"enum Cake {
+    BlackForestCake,
+    HummingbirdCake,
+}
+
+fn main() -> Cake {
+    ((Cake::BlackForestCake))
+}
+"#;
+
+const ENUM_VARIANT_NAMES_WITH_OTHER_FIX_FIXED: &str = r#"enum Cake {
+    BlackForest,
+    Hummingbird,
+}
+
+fn main() -> Cake {
+    Cake::BlackForest
+}
+"#;
+
+fn test_db() -> LinterAnalysisDatabase {
+    LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap()
+}
+
+fn linter_params(max_fix_merge_iterations: usize) -> LinterDiagnosticParams {
+    LinterDiagnosticParams {
+        only_generated_files: true,
+        tool_metadata: get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+        max_fix_merge_iterations,
+        ..Default::default()
+    }
+}
+
+fn fixed_content(code: &str, max_fix_merge_iterations: usize) -> String {
+    let mut db = test_db();
+    let test_crate = setup_test_crate_ex(&mut db, code);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    let (file_id, fixes) = get_fixes(&db, &linter_params(max_fix_merge_iterations), diags)
+        .into_iter()
+        .next()
+        .unwrap();
+    compute_fixed_content(file_id, &fixes, &db, FormatterConfig::default()).unwrap()
+}
+
+/// Returns the linter diagnostics of all the modules of the crate.
+fn crate_diagnostics<'db>(
+    db: &'db LinterAnalysisDatabase,
+    test_crate: &CrateInput,
+    linter_params: &LinterDiagnosticParams,
+) -> Vec<SemanticDiagnostic<'db>> {
+    let crate_id = test_crate.clone().into_crate_long_id(db).intern(db);
+    db.crate_modules(crate_id)
+        .iter()
+        .flat_map(|module_id| {
+            db.linter_diagnostics(linter_params.clone(), *module_id)
+                .iter()
+                .map(|diagnostic| {
+                    SemanticDiagnostic::new(
+                        StableLocation::new(diagnostic.stable_ptr),
+                        SemanticDiagnosticKind::PluginDiagnostic(diagnostic.clone()),
+                        *module_id,
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn overlapping_fixes_are_fully_merged() {
+    assert_eq!(
+        fixed_content(NESTED_OVERLAPPING_FIXES, 10),
+        NESTED_OVERLAPPING_FIXES_FIXED
+    );
+}
+
+#[test]
+fn enum_variant_names_fix_is_merged_with_other_fixes() {
+    assert_eq!(
+        fixed_content(ENUM_VARIANT_NAMES_WITH_OTHER_FIX, 10),
+        ENUM_VARIANT_NAMES_WITH_OTHER_FIX_FIXED
+    );
+}
+
+#[test]
+fn merging_stops_after_max_iterations() {
+    let partially_fixed = fixed_content(NESTED_OVERLAPPING_FIXES, 1);
+
+    // Only a part of the fixes is applied, the same way every time.
+    assert_ne!(partially_fixed, NESTED_OVERLAPPING_FIXES);
+    assert_ne!(partially_fixed, NESTED_OVERLAPPING_FIXES_FIXED);
+    assert_eq!(fixed_content(NESTED_OVERLAPPING_FIXES, 1), partially_fixed);
+}
+
+#[test]
+fn merging_without_iterations_skips_overlapping_fixes() {
+    let fixed = fixed_content(NESTED_OVERLAPPING_FIXES, 0);
+
+    assert_eq!(fixed_content(NESTED_OVERLAPPING_FIXES, 0), fixed);
+}
+
+#[test]
+fn overlapping_fixes_of_one_file_dont_leak_into_another() {
+    let mut db = test_db();
+    let test_crate = setup_test_crate_with_files(
+        &mut db,
+        &[
+            ("lib.cairo", LIB_WITH_OVERLAPPING_FIXES),
+            ("other.cairo", OTHER_WITH_OVERLAPPING_FIXES),
+        ],
+    );
+    init_corelib(&mut db);
+    let linter_params = linter_params(10);
+    let diags = crate_diagnostics(&db, &test_crate, &linter_params);
+
+    let crate_id = test_crate.into_crate_long_id(&db).intern(&db);
+    let lib_file = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let fixed_contents = get_fixes(&db, &linter_params, diags)
+        .into_iter()
+        .map(|(file_id, fixes)| {
+            let fixed =
+                compute_fixed_content(file_id, &fixes, &db, FormatterConfig::default()).unwrap();
+            (file_id == lib_file, fixed)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(fixed_contents.len(), 2);
+    for (is_lib_file, fixed) in fixed_contents {
+        let expected = if is_lib_file {
+            LIB_WITH_OVERLAPPING_FIXES_FIXED
+        } else {
+            OTHER_WITH_OVERLAPPING_FIXES_FIXED
+        };
+        assert_eq!(fixed, expected);
+    }
+}
@@ -0,0 +1,103 @@
+use std::fs;
+use std::path::Path;
+
+use cairo_lint::{LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const CLONE_ON_COPY_AND_DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    let a: u32 = 1;
+    let _b = a.clone();
+    ((0))
+}
+"#;
+
+const MANUAL_TAKE: &str = r#"
+fn main() -> Option<u32> {
+    let mut option = Option::Some(1_u32);
+    let value = option;
+    option = Option::None;
+    value
+}
+"#;
+
+const CLONE_ON_COPY_MESSAGE: &str = "using `clone` on type which implements `Copy` trait";
+const DOUBLE_PARENS_MESSAGE: &str = "unnecessary double parentheses found. Consider removing them.";
+const MANUAL_TAKE_MESSAGE: &str =
+    "Manual `take` of an `Option` detected. Consider using `take()` instead.";
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &target);
+        } else {
+            fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+fn lint_messages(source: &str, corelib_path: &Path) -> Vec<String> {
+    lint_string(
+        source,
+        &LinterDiagnosticParams::default(),
+        Some(corelib_path.to_path_buf()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.message)
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn missing_corelib_item_does_not_panic() {
+    let corelib_path = get_corelib_path();
+    let messages = lint_messages(CLONE_ON_COPY_AND_DOUBLE_PARENS, &corelib_path);
+    assert!(messages.contains(&CLONE_ON_COPY_MESSAGE.to_string()));
+    assert!(messages.contains(&DOUBLE_PARENS_MESSAGE.to_string()));
+
+    // A corelib without `core::clone::TCopyClone`.
+    let stripped_corelib = tempfile::tempdir().unwrap();
+    copy_dir(&corelib_path, stripped_corelib.path());
+    let clone_file = stripped_corelib.path().join("clone.cairo");
+    let clone_content = fs::read_to_string(&clone_file).unwrap();
+    assert!(clone_content.contains("impl TCopyClone<"));
+    fs::write(
+        &clone_file,
+        clone_content.replace("impl TCopyClone<", "impl TCopyCloneRenamed<"),
+    )
+    .unwrap();
+
+    // The lint depending on the missing item doesn't fire, but the other ones still run.
+    let messages = lint_messages(CLONE_ON_COPY_AND_DOUBLE_PARENS, stripped_corelib.path());
+    assert!(!messages.contains(&CLONE_ON_COPY_MESSAGE.to_string()));
+    assert!(messages.contains(&DOUBLE_PARENS_MESSAGE.to_string()));
+}
+
+#[test]
+fn manual_take_is_skipped_without_option_take() {
+    let corelib_path = get_corelib_path();
+    let messages = lint_messages(MANUAL_TAKE, &corelib_path);
+    assert!(messages.contains(&MANUAL_TAKE_MESSAGE.to_string()));
+
+    // A corelib without `core::option::OptionTrait::take`.
+    let stripped_corelib = tempfile::tempdir().unwrap();
+    copy_dir(&corelib_path, stripped_corelib.path());
+    let option_file = stripped_corelib.path().join("option.cairo");
+    let option_content = fs::read_to_string(&option_file).unwrap();
+    assert!(option_content.contains("fn take("));
+    fs::write(
+        &option_file,
+        option_content.replace("fn take(", "fn take_renamed("),
+    )
+    .unwrap();
+
+    let messages = lint_messages(MANUAL_TAKE, stripped_corelib.path());
+    assert!(!messages.contains(&MANUAL_TAKE_MESSAGE.to_string()));
+}
@@ -0,0 +1,173 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const MODULO_ONE: &str = r#"
+fn main() -> u32 {
+    let x: u32 = 5;
+    let y: u32 = x % 1;
+    y
+}
+"#;
+
+const MODULO_ONE_WITHOUT_TYPE_ANNOTATION: &str = r#"
+fn main() -> u8 {
+    let x: u8 = 5;
+    let y = x % 1;
+    y
+}
+"#;
+
+const MODULO_ONE_SIGNED: &str = r#"
+fn main() -> i32 {
+    let x: i32 = -5;
+    x % 1
+}
+"#;
+
+const MODULO_ONE_IN_EXPRESSION: &str = r#"
+fn main() -> u64 {
+    let x: u64 = 5;
+    x % 1 + 2
+}
+"#;
+
+const MODULO_ONE_ALLOWED: &str = r#"
+#[allow(modulo_one)]
+fn main() -> u32 {
+    let x: u32 = 5;
+    x % 1
+}
+"#;
+
+const MODULO_TWO: &str = r#"
+fn main() -> u32 {
+    let x: u32 = 5;
+    x % 2
+}
+"#;
+
+const USER_DEFINED_MODULO_ONE: &str = r#"
+#[derive(Drop)]
+struct Wrapper {
+    value: u32,
+}
+
+impl WrapperRem of Rem<Wrapper> {
+    fn rem(lhs: Wrapper, rhs: Wrapper) -> Wrapper {
+        lhs
+    }
+}
+
+fn main() -> Wrapper {
+    let x = Wrapper { value: 5 };
+    let one = Wrapper { value: 1 };
+    x % one
+}
+"#;
+
+#[test]
+fn modulo_one_diagnostics() {
+    test_lint_diagnostics!(MODULO_ONE, @r"
+    Plugin diagnostic: Any integer modulo 1 is 0. Consider replacing the expression with 0.
+     --> lib.cairo:4:18
+        let y: u32 = x % 1;
+                     ^^^^^
+    ");
+}
+
+#[test]
+fn modulo_one_fixer() {
+    test_lint_fixer!(MODULO_ONE, @r"
+    fn main() -> u32 {
+        let x: u32 = 5;
+        let y: u32 = 0;
+        y
+    }
+    ");
+}
+
+#[test]
+fn modulo_one_without_type_annotation_diagnostics() {
+    test_lint_diagnostics!(MODULO_ONE_WITHOUT_TYPE_ANNOTATION, @r"
+    Plugin diagnostic: Any integer modulo 1 is 0. Consider replacing the expression with 0.
+     --> lib.cairo:4:13
+        let y = x % 1;
+                ^^^^^
+    ");
+}
+
+#[test]
+fn modulo_one_without_type_annotation_fixer() {
+    test_lint_fixer!(MODULO_ONE_WITHOUT_TYPE_ANNOTATION, @r"
+    fn main() -> u8 {
+        let x: u8 = 5;
+        let y = 0_u8;
+        y
+    }
+    ");
+}
+
+#[test]
+fn modulo_one_signed_diagnostics() {
+    test_lint_diagnostics!(MODULO_ONE_SIGNED, @r"
+    Plugin diagnostic: Any integer modulo 1 is 0. Consider replacing the expression with 0.
+     --> lib.cairo:4:5
+        x % 1
+        ^^^^^
+    ");
+}
+
+#[test]
+fn modulo_one_signed_fixer() {
+    test_lint_fixer!(MODULO_ONE_SIGNED, @r"
+    fn main() -> i32 {
+        let x: i32 = -5;
+        0
+    }
+    ");
+}
+
+#[test]
+fn modulo_one_in_expression_diagnostics() {
+    test_lint_diagnostics!(MODULO_ONE_IN_EXPRESSION, @r"
+    Plugin diagnostic: Any integer modulo 1 is 0. Consider replacing the expression with 0.
+     --> lib.cairo:4:5
+        x % 1 + 2
+        ^^^^^
+    ");
+}
+
+#[test]
+fn modulo_one_in_expression_fixer() {
+    test_lint_fixer!(MODULO_ONE_IN_EXPRESSION, @r"
+    fn main() -> u64 {
+        let x: u64 = 5;
+        0 + 2
+    }
+    ");
+}
+
+#[test]
+fn modulo_one_allowed_diagnostics() {
+    test_lint_diagnostics!(MODULO_ONE_ALLOWED, @"");
+}
+
+#[test]
+fn modulo_one_allowed_fixer() {
+    test_lint_fixer!(MODULO_ONE_ALLOWED, @r"
+    #[allow(modulo_one)]
+    fn main() -> u32 {
+        let x: u32 = 5;
+        x % 1
+    }
+    ");
+}
+
+#[test]
+fn modulo_two_diagnostics() {
+    test_lint_diagnostics!(MODULO_TWO, @"");
+}
+
+#[test]
+fn user_defined_modulo_one_diagnostics() {
+    test_lint_diagnostics!(USER_DEFINED_MODULO_ONE, @"");
+}
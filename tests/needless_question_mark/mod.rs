@@ -0,0 +1,148 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const OPTION_TAIL: &str = r#"
+fn main(value: Option<u32>) -> Option<u32> {
+    Option::Some(value?)
+}
+"#;
+
+const OPTION_TAIL_ALLOWED: &str = r#"
+#[allow(needless_question_mark)]
+fn main(value: Option<u32>) -> Option<u32> {
+    Option::Some(value?)
+}
+"#;
+
+const RESULT_TAIL: &str = r#"
+fn foo() -> Result<u32, felt252> {
+    Result::Ok(1)
+}
+
+fn main() -> Result<u32, felt252> {
+    // Comment.
+    Result::Ok(foo()?)
+}
+"#;
+
+const EARLY_RETURN: &str = r#"
+fn main(value: Option<u32>, flag: bool) -> Option<u32> {
+    if flag {
+        return Option::Some(value?);
+    }
+    Option::None
+}
+"#;
+
+const BRANCH_TAIL: &str = r#"
+fn main(value: Option<u32>, flag: bool) -> Option<u32> {
+    if flag {
+        Option::Some(value?)
+    } else {
+        Option::None
+    }
+}
+"#;
+
+const CONVERTED_VALUE: &str = r#"
+fn main(value: Option<u32>) -> Option<u64> {
+    Option::Some(value?.into())
+}
+"#;
+
+#[test]
+fn option_tail_diagnostics() {
+    test_lint_diagnostics!(OPTION_TAIL, @r"
+    Plugin diagnostic: Needless `?` wrapped in the same variant it propagates. Consider returning the inner expression directly.
+     --> lib.cairo:3:5
+        Option::Some(value?)
+        ^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn option_tail_fixer() {
+    test_lint_fixer!(OPTION_TAIL, @r"
+    fn main(value: Option<u32>) -> Option<u32> {
+        value
+    }
+    ");
+}
+
+#[test]
+fn option_tail_allowed_diagnostics() {
+    test_lint_diagnostics!(OPTION_TAIL_ALLOWED, @"");
+}
+
+#[test]
+fn result_tail_diagnostics() {
+    test_lint_diagnostics!(RESULT_TAIL, @r"
+    Plugin diagnostic: Needless `?` wrapped in the same variant it propagates. Consider returning the inner expression directly.
+     --> lib.cairo:8:5
+        Result::Ok(foo()?)
+        ^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn result_tail_fixer() {
+    test_lint_fixer!(RESULT_TAIL, @r"
+    fn foo() -> Result<u32, felt252> {
+        Result::Ok(1)
+    }
+
+    fn main() -> Result<u32, felt252> {
+        // Comment.
+        foo()
+    }
+    ");
+}
+
+#[test]
+fn early_return_diagnostics() {
+    test_lint_diagnostics!(EARLY_RETURN, @r"
+    Plugin diagnostic: Needless `?` wrapped in the same variant it propagates. Consider returning the inner expression directly.
+     --> lib.cairo:4:16
+            return Option::Some(value?);
+                   ^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn early_return_fixer() {
+    test_lint_fixer!(EARLY_RETURN, @r"
+    fn main(value: Option<u32>, flag: bool) -> Option<u32> {
+        if flag {
+            return value;
+        }
+        Option::None
+    }
+    ");
+}
+
+#[test]
+fn branch_tail_diagnostics() {
+    test_lint_diagnostics!(BRANCH_TAIL, @r"
+    Plugin diagnostic: Needless `?` wrapped in the same variant it propagates. Consider returning the inner expression directly.
+     --> lib.cairo:4:9
+            Option::Some(value?)
+            ^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn branch_tail_fixer() {
+    test_lint_fixer!(BRANCH_TAIL, @r"
+    fn main(value: Option<u32>, flag: bool) -> Option<u32> {
+        if flag {
+            value
+        } else {
+            Option::None
+        }
+    }
+    ");
+}
+
+#[test]
+fn converted_value_diagnostics() {
+    test_lint_diagnostics!(CONVERTED_VALUE, @"");
+}
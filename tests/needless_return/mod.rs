@@ -0,0 +1,111 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const RETURN_VALUE: &str = r#"
+fn main(x: u32) -> u32 {
+    let y = x * 2;
+    return y + 1;
+}
+"#;
+
+const RETURN_UNIT: &str = r#"
+fn main(ref x: u32) {
+    x = 2;
+    // Nothing left to do.
+    return;
+}
+"#;
+
+const RETURN_IN_TRAIT_FUNCTION: &str = r#"
+trait Doubler {
+    fn double(x: u32) -> u32 {
+        return x * 2;
+    }
+}
+"#;
+
+const RETURN_VALUE_ALLOWED: &str = r#"
+#[allow(needless_return)]
+fn main(x: u32) -> u32 {
+    return x + 1;
+}
+"#;
+
+const EARLY_RETURN_IN_IF: &str = r#"
+fn main(x: u32) -> u32 {
+    if x == 0 {
+        return 1;
+    }
+    x
+}
+"#;
+
+#[test]
+fn return_value_diagnostics() {
+    test_lint_diagnostics!(RETURN_VALUE, enabling ["needless_return"], @r"
+    Plugin diagnostic: Unnecessary `return` at the end of the function body. Use a tail expression instead.
+     --> lib.cairo:4:5
+        return y + 1;
+        ^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn return_value_fixer() {
+    test_lint_fixer!(RETURN_VALUE, enabling ["needless_return"], @r"
+    fn main(x: u32) -> u32 {
+        let y = x * 2;
+        y + 1
+    }
+    ");
+}
+
+#[test]
+fn return_unit_diagnostics() {
+    test_lint_diagnostics!(RETURN_UNIT, enabling ["needless_return"], @r"
+    Plugin diagnostic: Unnecessary `return` at the end of the function body. Use a tail expression instead.
+     --> lib.cairo:5:5
+        return;
+        ^^^^^^^
+    ");
+}
+
+#[test]
+fn return_unit_fixer() {
+    test_lint_fixer!(RETURN_UNIT, enabling ["needless_return"], @r"
+    fn main(ref x: u32) {
+        x = 2;
+        // Nothing left to do.
+    }
+    ");
+}
+
+#[test]
+fn return_in_trait_function_diagnostics() {
+    test_lint_diagnostics!(RETURN_IN_TRAIT_FUNCTION, enabling ["needless_return"], @r"
+    Plugin diagnostic: Unnecessary `return` at the end of the function body. Use a tail expression instead.
+     --> lib.cairo:4:9
+            return x * 2;
+            ^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn return_in_trait_function_fixer() {
+    test_lint_fixer!(RETURN_IN_TRAIT_FUNCTION, enabling ["needless_return"], @r"
+    trait Doubler {
+        fn double(x: u32) -> u32 {
+            x * 2
+        }
+    }
+    ");
+}
+
+#[test]
+fn return_value_allowed_diagnostics() {
+    test_lint_diagnostics!(RETURN_VALUE_ALLOWED, enabling ["needless_return"], @"");
+}
+
+#[test]
+fn early_return_in_if_diagnostics() {
+    test_lint_diagnostics!(EARLY_RETURN_IN_IF, enabling ["needless_return"], @"");
+}
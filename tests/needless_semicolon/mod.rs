@@ -0,0 +1,219 @@
+use std::collections::BTreeSet;
+
+use cairo_lint::lints::needless_semicolon::fix_needless_semicolon;
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const UNIT_IF_WITH_SEMICOLON: &str = r#"
+fn main(x: u32) {
+    if x > 10 {
+        println!("big");
+    };
+    println!("done");
+}
+"#;
+
+const UNIT_MATCH_WITH_SEMICOLON: &str = r#"
+fn main(x: Option<u32>) {
+    match x {
+        Option::Some(v) => println!("{v}"),
+        Option::None => println!("none"),
+    };
+}
+"#;
+
+const LOOPS_WITH_SEMICOLONS: &str = r#"
+fn main() {
+    let mut i: u32 = 0;
+    while i < 10 {
+        i += 1;
+    };
+    loop {
+        break;
+    };
+    for _x in array![1_u32, 2, 3] {
+        i += 1;
+    };
+}
+"#;
+
+const BLOCK_WITH_SEMICOLON: &str = r#"
+fn main() {
+    {
+        println!("scoped");
+    };
+}
+"#;
+
+const NON_UNIT_IF_WITH_SEMICOLON: &str = r#"
+fn main(x: u32) {
+    if x > 10 {
+        1_u32
+    } else {
+        2_u32
+    };
+}
+"#;
+
+const IF_WITHOUT_SEMICOLON: &str = r#"
+fn main(x: u32) {
+    if x > 10 {
+        println!("big");
+    }
+    println!("done");
+}
+"#;
+
+const NEXT_STATEMENT_CONTINUES_EXPRESSION: &str = r#"
+fn main(x: u32) {
+    if x > 10 {
+        println!("big");
+    };
+    (x, x);
+}
+"#;
+
+const SEMICOLON_ALLOWED: &str = r#"
+#[allow(needless_semicolon)]
+fn main(x: u32) {
+    if x > 10 {
+        println!("big");
+    };
+}
+"#;
+
+fn needless_semicolon_params() -> LinterDiagnosticParams {
+    LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("needless_semicolon".to_string(), true)]),
+        enabled_lints: Some(BTreeSet::from(["needless_semicolon".to_string()])),
+        ..Default::default()
+    }
+}
+
+/// Returns the text of the statements reported with the given parameters.
+fn lint(source: &str, params: LinterDiagnosticParams) -> Vec<String> {
+    lint_string(
+        source,
+        &params,
+        Some(get_corelib_path()),
+        |db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    let node = diagnostic.stable_ptr.lookup(db);
+                    node.get_text_without_trivia(db).long(db).to_string()
+                })
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+/// Returns the source with the fixes of all the reported diagnostics applied.
+fn fix(source: &str) -> String {
+    lint_string(
+        source,
+        &needless_semicolon_params(),
+        Some(get_corelib_path()),
+        |db, diagnostics| {
+            let mut fixes = diagnostics
+                .into_iter()
+                .filter_map(|diagnostic| {
+                    fix_needless_semicolon(db, diagnostic.stable_ptr.lookup(db))
+                })
+                .map(|fix| (fix.node.span(db).to_str_range(), fix.suggestion))
+                .collect::<Vec<_>>();
+            fixes.sort_by_key(|(range, _suggestion)| std::cmp::Reverse(range.start));
+            let mut fixed = source.to_string();
+            for (range, suggestion) in fixes {
+                fixed.replace_range(range, &suggestion);
+            }
+            fixed
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn unit_if_with_semicolon_is_reported() {
+    let reported = lint(UNIT_IF_WITH_SEMICOLON, needless_semicolon_params());
+    assert_eq!(reported.len(), 1);
+    assert!(reported[0].starts_with("if x > 10"));
+    assert!(reported[0].ends_with("};"));
+}
+
+#[test]
+fn unit_if_with_semicolon_is_fixed() {
+    assert_eq!(
+        fix(UNIT_IF_WITH_SEMICOLON),
+        UNIT_IF_WITH_SEMICOLON.replace("    };\n", "    }\n")
+    );
+}
+
+#[test]
+fn unit_match_with_semicolon_is_fixed() {
+    assert_eq!(
+        lint(UNIT_MATCH_WITH_SEMICOLON, needless_semicolon_params()).len(),
+        1
+    );
+    assert_eq!(
+        fix(UNIT_MATCH_WITH_SEMICOLON),
+        UNIT_MATCH_WITH_SEMICOLON.replace("    };\n", "    }\n")
+    );
+}
+
+#[test]
+fn loops_with_semicolons_are_fixed() {
+    assert_eq!(
+        lint(LOOPS_WITH_SEMICOLONS, needless_semicolon_params()).len(),
+        3
+    );
+    assert_eq!(
+        fix(LOOPS_WITH_SEMICOLONS),
+        LOOPS_WITH_SEMICOLONS.replace("    };\n", "    }\n")
+    );
+}
+
+#[test]
+fn block_with_semicolon_is_fixed() {
+    assert_eq!(
+        lint(BLOCK_WITH_SEMICOLON, needless_semicolon_params()).len(),
+        1
+    );
+    assert_eq!(
+        fix(BLOCK_WITH_SEMICOLON),
+        BLOCK_WITH_SEMICOLON.replace("    };\n", "    }\n")
+    );
+}
+
+#[test]
+fn non_unit_if_with_semicolon_is_not_reported() {
+    assert!(lint(NON_UNIT_IF_WITH_SEMICOLON, needless_semicolon_params()).is_empty());
+}
+
+#[test]
+fn if_without_semicolon_is_not_reported() {
+    assert!(lint(IF_WITHOUT_SEMICOLON, needless_semicolon_params()).is_empty());
+}
+
+#[test]
+fn semicolon_before_statement_continuing_expression_is_not_reported() {
+    assert!(
+        lint(
+            NEXT_STATEMENT_CONTINUES_EXPRESSION,
+            needless_semicolon_params()
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn allowed_semicolon_is_not_reported() {
+    assert!(lint(SEMICOLON_ALLOWED, needless_semicolon_params()).is_empty());
+}
+
+#[test]
+fn lint_is_disabled_by_default() {
+    assert!(lint(UNIT_IF_WITH_SEMICOLON, LinterDiagnosticParams::default()).is_empty());
+}
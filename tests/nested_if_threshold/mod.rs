@@ -0,0 +1,72 @@
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const TWO_LEVELS: &str = r#"
+fn main(x: bool, y: bool) {
+    if x {
+        if y {
+            println!("Hello");
+        }
+    }
+}
+"#;
+
+const THREE_LEVELS: &str = r#"
+fn main(x: bool, y: bool, z: bool) {
+    if x {
+        if y {
+            if z {
+                println!("Hello");
+            }
+        }
+    }
+}
+"#;
+
+fn collapsible_if_messages(code: &str, nested_if_threshold: usize) -> Vec<String> {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("collapsible_if".to_string(), true)]),
+        nested_if_threshold,
+        ..Default::default()
+    };
+    lint_string(
+        code,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.message)
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn two_levels_fire_with_default_threshold() {
+    let default_threshold = LinterDiagnosticParams::default().nested_if_threshold;
+    assert_eq!(
+        collapsible_if_messages(TWO_LEVELS, default_threshold).len(),
+        1
+    );
+}
+
+#[test]
+fn two_levels_dont_fire_with_threshold_of_three() {
+    assert!(collapsible_if_messages(TWO_LEVELS, 3).is_empty());
+}
+
+#[test]
+fn three_levels_fire_with_threshold_of_three() {
+    let messages = collapsible_if_messages(THREE_LEVELS, 3);
+    // Only the outermost `if` has two collapsible levels nested in it.
+    assert_eq!(
+        messages,
+        vec![
+            "Each `if`-statement adds one level of nesting, which makes code look more complex than it really is."
+                .to_string()
+        ]
+    );
+}
@@ -1,3 +1,6 @@
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
 use crate::{test_lint_diagnostics, test_lint_fixer};
 
 const SINGLE_PANIC: &str = r#"
@@ -99,6 +102,29 @@ fn main() {
 }
 "#;
 
+const PANIC_IN_TEST_FUNCTION: &str = r#"
+fn main() {
+    panic!("panic");
+}
+
+#[test]
+fn test_main() {
+    panic!("panic in test");
+}
+"#;
+
+const PANIC_IN_TEST_MODULE: &str = r#"
+fn main() {
+}
+
+#[cfg(test)]
+mod tests {
+    fn failing_helper() {
+        panic!("panic in test module");
+    }
+}
+"#;
+
 #[test]
 fn single_panic_diagnostics() {
     test_lint_diagnostics!(SINGLE_PANIC, @r#"
@@ -327,3 +353,35 @@ fn panic_with_args_fixer() {
     }
     "#);
 }
+
+#[test]
+fn panic_in_test_function_diagnostics() {
+    test_lint_diagnostics!(PANIC_IN_TEST_FUNCTION, @r#"
+    Plugin diagnostic: Leaving `panic` in the code is discouraged.
+     --> lib.cairo:3:5
+        panic!("panic");
+        ^^^^^
+    "#);
+}
+
+#[test]
+fn panic_in_test_module_diagnostics() {
+    test_lint_diagnostics!(PANIC_IN_TEST_MODULE, @"");
+}
+
+#[test]
+fn panic_in_test_function_is_reported_if_configured() {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("panic".to_string(), true)]),
+        allow_panic_in_tests: false,
+        ..Default::default()
+    };
+    let panic_count = lint_string(
+        PANIC_IN_TEST_FUNCTION,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| diagnostics.len(),
+    )
+    .unwrap();
+    assert_eq!(panic_count, 2);
+}
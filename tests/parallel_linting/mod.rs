@@ -0,0 +1,71 @@
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lint::{LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const FUNCTIONS_COUNT: usize = 200;
+
+fn synthetic_function(index: usize) -> String {
+    format!(
+        r#"
+fn function_{index}() -> u32 {{
+    let x = (({index}));
+    x
+}}
+"#
+    )
+}
+
+/// Lints the source and returns the messages along with the start offsets of the diagnostics.
+fn lint_positions(source: &str) -> Vec<(String, TextOffset)> {
+    lint_string(
+        source,
+        &LinterDiagnosticParams::default(),
+        Some(get_corelib_path()),
+        |db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    let node: SyntaxNode = diagnostic.stable_ptr.lookup(db);
+                    (diagnostic.message, node.span_without_trivia(db).start)
+                })
+                .collect::<Vec<_>>()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn parallel_linting_matches_serial_diagnostics() {
+    // The diagnostics of a single function, which are computed without any parallelism.
+    let single_function_diagnostics = lint_positions(&synthetic_function(0));
+    assert_eq!(single_function_diagnostics.len(), 1);
+
+    let source = (0..FUNCTIONS_COUNT)
+        .map(synthetic_function)
+        .collect::<String>();
+    let diagnostics = lint_positions(&source);
+
+    assert_eq!(
+        diagnostics.len(),
+        FUNCTIONS_COUNT * single_function_diagnostics.len()
+    );
+    assert!(
+        diagnostics
+            .iter()
+            .all(|(message, _)| *message == single_function_diagnostics[0].0)
+    );
+}
+
+#[test]
+fn parallel_linting_output_is_deterministic() {
+    let source = (0..FUNCTIONS_COUNT)
+        .map(synthetic_function)
+        .collect::<String>();
+    let diagnostics = lint_positions(&source);
+
+    // The diagnostics are reported in the order of the items.
+    assert!(diagnostics.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    assert_eq!(diagnostics, lint_positions(&source));
+}
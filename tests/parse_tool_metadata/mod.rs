@@ -0,0 +1,135 @@
+use cairo_lang_diagnostics::Severity;
+use cairo_lint::{
+    CairoLintSeverities, CairoLintToolMetadata, LintSeverity, LinterDiagnosticParams, lint_string,
+    parse_tool_metadata,
+};
+use serde_json::json;
+
+use crate::helpers::get_corelib_path;
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+"#;
+
+#[test]
+fn bool_map_is_parsed_as_tool_metadata() {
+    let params = parse_tool_metadata(&json!({
+        "panic": true,
+        "bool_comparison": false,
+    }))
+    .unwrap();
+    assert_eq!(
+        params,
+        LinterDiagnosticParams {
+            tool_metadata: CairoLintToolMetadata::from([
+                ("panic".to_string(), true),
+                ("bool_comparison".to_string(), false),
+            ]),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn group_bool_is_parsed_as_tool_metadata() {
+    let params = parse_tool_metadata(&json!({ "style": false })).unwrap();
+    assert_eq!(
+        params.tool_metadata,
+        CairoLintToolMetadata::from([("style".to_string(), false)])
+    );
+}
+
+#[test]
+fn severity_strings_are_parsed() {
+    let params = parse_tool_metadata(&json!({
+        "panic": "deny",
+        "double_parens": "warn",
+        "performance": "allow",
+    }))
+    .unwrap();
+    assert_eq!(
+        params.severities,
+        CairoLintSeverities::from([
+            ("panic".to_string(), LintSeverity::Deny),
+            ("double_parens".to_string(), LintSeverity::Warn),
+            ("performance".to_string(), LintSeverity::Allow),
+        ])
+    );
+    assert!(params.tool_metadata.is_empty());
+}
+
+#[test]
+fn thresholds_are_parsed() {
+    let params = parse_tool_metadata(&json!({
+        "max_nesting_depth": 3,
+        "max_fix_merge_iterations": 5,
+        "nested_if_threshold": 4,
+        "too_many_arguments_threshold": 8,
+        "large_enum_variant_threshold": 32,
+        "manual_pow_threshold": 4,
+    }))
+    .unwrap();
+    assert_eq!(params.max_nesting_depth, 3);
+    assert_eq!(params.max_fix_merge_iterations, 5);
+    assert_eq!(params.nested_if_threshold, 4);
+    assert_eq!(params.too_many_arguments_threshold, 8);
+    assert_eq!(params.large_enum_variant_threshold, 32);
+    assert_eq!(params.manual_pow_threshold, 4);
+}
+
+#[test]
+fn options_are_parsed() {
+    let params = parse_tool_metadata(&json!({
+        "check_public_enum_variants": true,
+        "allow_panic_in_tests": false,
+    }))
+    .unwrap();
+    assert!(params.check_public_enum_variants);
+    assert!(!params.allow_panic_in_tests);
+    assert!(params.tool_metadata.is_empty());
+}
+
+#[test]
+fn invalid_severity_is_an_error() {
+    assert!(parse_tool_metadata(&json!({ "panic": "forbid" })).is_err());
+}
+
+#[test]
+fn unknown_threshold_is_an_error() {
+    assert!(parse_tool_metadata(&json!({ "max_depth": 3 })).is_err());
+}
+
+#[test]
+fn negative_threshold_is_an_error() {
+    assert!(parse_tool_metadata(&json!({ "max_nesting_depth": -1 })).is_err());
+}
+
+#[test]
+fn unsupported_value_is_an_error() {
+    assert!(parse_tool_metadata(&json!({ "panic": ["deny"] })).is_err());
+    assert!(parse_tool_metadata(&json!(true)).is_err());
+}
+
+#[test]
+fn lint_setting_takes_precedence_over_group_setting() {
+    let params = parse_tool_metadata(&json!({
+        "complexity": "deny",
+        "double_parens": true,
+    }))
+    .unwrap();
+    let severities = lint_string(
+        DOUBLE_PARENS,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.severity)
+                .collect::<Vec<_>>()
+        },
+    )
+    .unwrap();
+    assert_eq!(severities, vec![Severity::Warning]);
+}
@@ -0,0 +1,172 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const CONTAINS_GUARDED_APPEND: &str = r#"
+trait ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool;
+}
+
+#[allow(len_zero)]
+impl ArrayContainsImpl<T> of ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool {
+        self.len() == 0
+    }
+}
+
+fn main(values: Array<felt252>) -> Array<felt252> {
+    let mut unique = array![];
+    for value in values {
+        if !unique.contains(@value) {
+            unique.append(value);
+        }
+    }
+    unique
+}
+"#;
+
+const CONTAINS_GUARDED_APPEND_ALLOWED: &str = r#"
+trait ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool;
+}
+
+#[allow(len_zero)]
+impl ArrayContainsImpl<T> of ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool {
+        self.len() == 0
+    }
+}
+
+#[allow(manual_set_insert)]
+fn main(values: Array<felt252>) -> Array<felt252> {
+    let mut unique = array![];
+    for value in values {
+        if !unique.contains(@value) {
+            unique.append(value);
+        }
+    }
+    unique
+}
+"#;
+
+const UNGUARDED_APPEND: &str = r#"
+trait ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool;
+}
+
+#[allow(len_zero)]
+impl ArrayContainsImpl<T> of ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool {
+        self.len() == 0
+    }
+}
+
+fn main(values: Array<felt252>) -> Array<felt252> {
+    let mut unique = array![];
+    for value in values {
+        unique.append(value);
+    }
+    unique
+}
+"#;
+
+const GUARDED_APPEND_OF_OTHER_VALUE: &str = r#"
+trait ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool;
+}
+
+#[allow(len_zero)]
+impl ArrayContainsImpl<T> of ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool {
+        self.len() == 0
+    }
+}
+
+fn main(values: Array<felt252>) -> Array<felt252> {
+    let mut unique = array![];
+    for value in values {
+        if !unique.contains(@value) {
+            unique.append(value + 1);
+        }
+    }
+    unique
+}
+"#;
+
+const CONTAINS_GUARDED_APPEND_OUTSIDE_LOOP: &str = r#"
+trait ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool;
+}
+
+#[allow(len_zero)]
+impl ArrayContainsImpl<T> of ArrayContains<T> {
+    fn contains(self: @Array<T>, _value: @T) -> bool {
+        self.len() == 0
+    }
+}
+
+fn main(value: felt252) -> Array<felt252> {
+    let mut unique = array![];
+    if !unique.contains(@value) {
+        unique.append(value);
+    }
+    unique
+}
+"#;
+
+#[test]
+fn contains_guarded_append_diagnostics() {
+    test_lint_diagnostics!(CONTAINS_GUARDED_APPEND, @r"
+    Plugin diagnostic: Appending to an array only if it doesn't contain the value yet has O(n²) complexity in a loop. Consider using a set-like structure such as `Felt252Dict` instead.
+     --> lib.cairo:16:9-18:9
+              if !unique.contains(@value) {
+     _________^
+    |             unique.append(value);
+    |         }
+    |_________^
+    ");
+}
+
+#[test]
+fn contains_guarded_append_fixer() {
+    test_lint_fixer!(CONTAINS_GUARDED_APPEND, @r"
+    trait ArrayContains<T> {
+        fn contains(self: @Array<T>, _value: @T) -> bool;
+    }
+
+    #[allow(len_zero)]
+    impl ArrayContainsImpl<T> of ArrayContains<T> {
+        fn contains(self: @Array<T>, _value: @T) -> bool {
+            self.len() == 0
+        }
+    }
+
+    fn main(values: Array<felt252>) -> Array<felt252> {
+        let mut unique = array![];
+        for value in values {
+            if !unique.contains(@value) {
+                unique.append(value);
+            }
+        }
+        unique
+    }
+    ");
+}
+
+#[test]
+fn contains_guarded_append_allowed_diagnostics() {
+    test_lint_diagnostics!(CONTAINS_GUARDED_APPEND_ALLOWED, @"");
+}
+
+#[test]
+fn unguarded_append_diagnostics() {
+    test_lint_diagnostics!(UNGUARDED_APPEND, @"");
+}
+
+#[test]
+fn guarded_append_of_other_value_diagnostics() {
+    test_lint_diagnostics!(GUARDED_APPEND_OF_OTHER_VALUE, @"");
+}
+
+#[test]
+fn contains_guarded_append_outside_loop_diagnostics() {
+    test_lint_diagnostics!(CONTAINS_GUARDED_APPEND_OUTSIDE_LOOP, @"");
+}
@@ -1,2 +1,3 @@
 mod inefficient_unwrap_or;
 mod inefficient_while_comp;
+mod manual_set_insert;
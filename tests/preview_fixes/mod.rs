@@ -0,0 +1,58 @@
+use cairo_lang_defs::{db::DefsGroup, ids::ModuleId};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_formatter::FormatterConfig;
+use cairo_lang_utils::Intern;
+use cairo_lint::{LinterAnalysisDatabase, LinterDiagnosticParams, get_fixes, preview_fixes};
+
+use crate::helpers::{
+    get_cairo_lint_tool_metadata_with_all_lints_enabled, get_diags, init_corelib,
+    setup::setup_test_crate_ex,
+};
+
+const DOUBLE_PARENS: &str = "fn main() -> u32 {\n    ((0))\n}\n";
+
+fn test_db() -> LinterAnalysisDatabase {
+    LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn preview_shows_removed_parentheses() {
+    let mut db = test_db();
+    let test_crate = setup_test_crate_ex(&mut db, DOUBLE_PARENS);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+    let linter_params = LinterDiagnosticParams {
+        only_generated_files: true,
+        tool_metadata: get_cairo_lint_tool_metadata_with_all_lints_enabled(),
+        ..Default::default()
+    };
+    let fixes = get_fixes(&db, &linter_params, diags);
+    let (file_id, fixes) = fixes.into_iter().next().unwrap();
+
+    let diff = preview_fixes(&db, file_id, &fixes, FormatterConfig::default());
+
+    assert!(diff.contains("-    ((0))\n"), "{diff}");
+    assert_eq!(
+        diff,
+        "--- a/lib.cairo\n+++ b/lib.cairo\n@@ -1,3 +1,3 @@\n fn main() -> u32 {\n-    ((0))\n+    0\n }\n"
+    );
+    // The file itself is left untouched.
+    assert_eq!(db.file_content(file_id).unwrap().to_string(), DOUBLE_PARENS);
+}
+
+#[test]
+fn preview_without_fixes_is_empty() {
+    let mut db = test_db();
+    let test_crate = setup_test_crate_ex(&mut db, DOUBLE_PARENS);
+    let crate_id = test_crate.into_crate_long_id(&db).intern(&db);
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+
+    assert_eq!(
+        preview_fixes(&db, file_id, &[], FormatterConfig::default()),
+        ""
+    );
+}
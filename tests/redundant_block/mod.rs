@@ -0,0 +1,138 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const LET_WITH_REDUNDANT_BLOCK: &str = r#"
+fn main() -> u32 {
+    let y = 5;
+    let x = { y };
+    x
+}
+"#;
+
+const LET_WITH_REDUNDANT_BLOCK_ALLOWED: &str = r#"
+#[allow(redundant_block)]
+fn main() -> u32 {
+    let y = 5;
+    let x = { y };
+    x
+}
+"#;
+
+const FUNCTION_ARGUMENT_WITH_REDUNDANT_BLOCK: &str = r#"
+fn foo(a: u32) -> u32 {
+    a
+}
+
+fn main() -> u32 {
+    let y = 5;
+    foo({ y })
+}
+"#;
+
+const BLOCK_WITH_STATEMENTS: &str = r#"
+fn main() -> u32 {
+    let y = 5;
+    let x = {
+        let z = y + 1;
+        z
+    };
+    x
+}
+"#;
+
+const BLOCK_WITH_COMMENT: &str = r#"
+fn main() -> u32 {
+    let y = 5;
+    let x = {
+        // Important value.
+        y
+    };
+    x
+}
+"#;
+
+const MATCH_ARM_WITH_BLOCK: &str = r#"
+fn main() -> u32 {
+    let y: u32 = 5;
+    match y {
+        0 => { 1 },
+        _ => { y },
+    }
+}
+"#;
+
+#[test]
+fn let_with_redundant_block_diagnostics() {
+    test_lint_diagnostics!(LET_WITH_REDUNDANT_BLOCK, @r"
+    Plugin diagnostic: Redundant block containing a single expression. Consider using the expression directly.
+     --> lib.cairo:4:13
+        let x = { y };
+                ^^^^^
+    ");
+}
+
+#[test]
+fn let_with_redundant_block_fixer() {
+    test_lint_fixer!(LET_WITH_REDUNDANT_BLOCK, @r"
+    fn main() -> u32 {
+        let y = 5;
+        let x = y;
+        x
+    }
+    ");
+}
+
+#[test]
+fn let_with_redundant_block_allowed_diagnostics() {
+    test_lint_diagnostics!(LET_WITH_REDUNDANT_BLOCK_ALLOWED, @"");
+}
+
+#[test]
+fn let_with_redundant_block_allowed_fixer() {
+    test_lint_fixer!(LET_WITH_REDUNDANT_BLOCK_ALLOWED, @r"
+    #[allow(redundant_block)]
+    fn main() -> u32 {
+        let y = 5;
+        let x = { y };
+        x
+    }
+    ");
+}
+
+#[test]
+fn function_argument_with_redundant_block_diagnostics() {
+    test_lint_diagnostics!(FUNCTION_ARGUMENT_WITH_REDUNDANT_BLOCK, @r"
+    Plugin diagnostic: Redundant block containing a single expression. Consider using the expression directly.
+     --> lib.cairo:8:9
+        foo({ y })
+            ^^^^^
+    ");
+}
+
+#[test]
+fn function_argument_with_redundant_block_fixer() {
+    test_lint_fixer!(FUNCTION_ARGUMENT_WITH_REDUNDANT_BLOCK, @r"
+    fn foo(a: u32) -> u32 {
+        a
+    }
+
+    fn main() -> u32 {
+        let y = 5;
+        foo(y)
+    }
+    ");
+}
+
+#[test]
+fn block_with_statements_diagnostics() {
+    test_lint_diagnostics!(BLOCK_WITH_STATEMENTS, @"");
+}
+
+#[test]
+fn block_with_comment_diagnostics() {
+    test_lint_diagnostics!(BLOCK_WITH_COMMENT, @"");
+}
+
+#[test]
+fn match_arm_with_block_diagnostics() {
+    test_lint_diagnostics!(MATCH_ARM_WITH_BLOCK, @"");
+}
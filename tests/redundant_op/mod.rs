@@ -25,6 +25,17 @@ fn main() {
 }
 "#;
 
+const BITWISE_OR_WITH_ZERO: &str = r#"
+fn main(x: u32) {
+    let _y = x | 0;
+}
+"#;
+const BITWISE_XOR_WITH_ZERO: &str = r#"
+fn main(x: u32) {
+    let _y = 0 ^ x;
+}
+"#;
+
 #[test]
 fn addition_by_zero_diagnostics() {
     test_lint_diagnostics!(ADDITION_BY_ZERO, @r"
@@ -39,7 +50,7 @@ fn addition_by_zero_fixer() {
     test_lint_fixer!(ADDITION_BY_ZERO, @r"
     fn main() {
         let x = 42;
-        let _y = x + 0;
+        let _y = x;
     }
     ");
 }
@@ -57,7 +68,7 @@ fn subtraction_by_zero_fixer() {
     test_lint_fixer!(SUBTRACTION_BY_ZERO, @r"
     fn main() {
         let x = 42;
-        let _y = x - 0;
+        let _y = x;
     }
     ");
 }
@@ -75,7 +86,7 @@ fn multiplication_by_one_fixer() {
     test_lint_fixer!(MULTIPLICATION_BY_ONE, @r#"
     fn main() {
         let x = 42;
-        let _y = x * 1;
+        let _y = x;
     }
     "#);
 }
@@ -93,7 +104,41 @@ fn division_by_one_fixer() {
     test_lint_fixer!(DIVISION_BY_ONE, @r#"
     fn main() {
         let x = 42_u32;
-        let _y = x / 1;
+        let _y = x;
     }
     "#);
 }
+#[test]
+fn bitwise_or_with_zero_diagnostics() {
+    test_lint_diagnostics!(BITWISE_OR_WITH_ZERO, @r"
+    Plugin diagnostic: This operation doesn't change the value and can be simplified.
+     --> lib.cairo:3:14
+        let _y = x | 0;
+                 ^^^^^
+    ");
+}
+#[test]
+fn bitwise_or_with_zero_fixer() {
+    test_lint_fixer!(BITWISE_OR_WITH_ZERO, @r"
+    fn main(x: u32) {
+        let _y = x;
+    }
+    ");
+}
+#[test]
+fn bitwise_xor_with_zero_diagnostics() {
+    test_lint_diagnostics!(BITWISE_XOR_WITH_ZERO, @r"
+    Plugin diagnostic: This operation doesn't change the value and can be simplified.
+     --> lib.cairo:3:14
+        let _y = 0 ^ x;
+                 ^^^^^
+    ");
+}
+#[test]
+fn bitwise_xor_with_zero_fixer() {
+    test_lint_fixer!(BITWISE_XOR_WITH_ZERO, @r"
+    fn main(x: u32) {
+        let _y = x;
+    }
+    ");
+}
@@ -0,0 +1,141 @@
+use cairo_lint::diagnostics::sarif::to_sarif;
+use serde_json::Value;
+
+use crate::helpers::{get_diags, init_corelib, setup::setup_test_crate_ex};
+
+const DOUBLE_PARENS: &str = r#"
+fn main() -> u32 {
+    ((0))
+}
+
+fn foo() -> u32 {
+    ((1))
+}
+"#;
+
+const DOUBLE_PARENS_AFTER_COMMENT: &str = r#"
+fn main() -> u32 {
+    // The parentheses are preceded by a comment.
+    ((0))
+}
+"#;
+
+const REDUNDANT_ELSE: &str = r#"
+fn main(x: u32) -> u32 {
+    if x == 0 {
+        return 0;
+    } else {
+        x + 1
+    }
+}
+"#;
+
+/// Lints the code and returns its SARIF log, serialized and parsed back as JSON.
+fn sarif_log(code: &str) -> Value {
+    let mut db = ::cairo_lint::LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap();
+    let test_crate = setup_test_crate_ex(&mut db, code);
+    init_corelib(&mut db);
+    let diags = get_diags(test_crate, &db);
+
+    let serialized = serde_json::to_string(&to_sarif(&diags, &db)).unwrap();
+    serde_json::from_str(&serialized).unwrap()
+}
+
+/// Returns the only result of the given rule in the SARIF log.
+fn single_result<'a>(log: &'a Value, rule_id: &str) -> &'a Value {
+    let results = log["runs"][0]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|result| result["ruleId"] == rule_id)
+        .collect::<Vec<_>>();
+    let [result] = &results[..] else {
+        panic!(
+            "Expected a single `{rule_id}` result, got {}",
+            results.len()
+        );
+    };
+    result
+}
+
+#[test]
+fn sarif_round_trip() {
+    let log = sarif_log(DOUBLE_PARENS);
+
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0]["id"], "double_parens");
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    let result = &results[0];
+    assert_eq!(result["ruleId"], "double_parens");
+    assert_eq!(result["ruleIndex"], 0);
+    assert_eq!(result["level"], "warning");
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["region"],
+        serde_json::json!({ "startLine": 3, "startColumn": 5, "endLine": 3, "endColumn": 10 })
+    );
+
+    let replacement = &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+    assert_eq!(
+        replacement["insertedContent"]["text"]
+            .as_str()
+            .unwrap()
+            .trim(),
+        "0"
+    );
+}
+
+#[test]
+fn sarif_fix_of_node_with_leading_trivia() {
+    let log = sarif_log(DOUBLE_PARENS_AFTER_COMMENT);
+    let result = single_result(&log, "double_parens");
+
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["region"],
+        serde_json::json!({ "startLine": 4, "startColumn": 5, "endLine": 4, "endColumn": 10 })
+    );
+    let fixes = result["fixes"].as_array().unwrap();
+    assert_eq!(fixes.len(), 1);
+    let replacement = &fixes[0]["artifactChanges"][0]["replacements"][0];
+    // The replaced region includes the comment preceding the diagnosed code, which is kept.
+    assert_eq!(replacement["deletedRegion"]["startLine"], 3);
+    assert_eq!(replacement["deletedRegion"]["startColumn"], 1);
+    let inserted = replacement["insertedContent"]["text"].as_str().unwrap();
+    assert!(inserted.contains("// The parentheses are preceded by a comment."));
+    assert_eq!(inserted.trim().lines().last(), Some("0"));
+}
+
+#[test]
+fn sarif_fix_replacing_parent_node() {
+    let log = sarif_log(REDUNDANT_ELSE);
+    let result = single_result(&log, "redundant_else");
+
+    let fixes = result["fixes"].as_array().unwrap();
+    assert_eq!(fixes.len(), 1);
+    let replacements = fixes[0]["artifactChanges"][0]["replacements"]
+        .as_array()
+        .unwrap();
+    assert_eq!(replacements.len(), 1);
+    // The whole statement containing the diagnosed `if` is replaced, including its indentation.
+    let location = &result["locations"][0]["physicalLocation"]["region"];
+    let deleted_region = &replacements[0]["deletedRegion"];
+    assert_ne!(deleted_region, location);
+    assert_eq!(deleted_region["startLine"], location["startLine"]);
+    assert_eq!(deleted_region["startColumn"], 1);
+    assert_eq!(location["startColumn"], 5);
+    assert!(
+        !replacements[0]["insertedContent"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("else")
+    );
+}
@@ -0,0 +1,189 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const VARIABLE_SELF_ASSIGNMENT: &str = r#"
+fn main() -> u32 {
+    let mut x: u32 = 1;
+    x = x;
+    x
+}
+"#;
+
+const MEMBER_SELF_ASSIGNMENT: &str = r#"
+#[derive(Drop)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn main() -> Point {
+    let mut p = Point { x: 1, y: 2 };
+    // Keep the point as it is.
+    p.x = p.x;
+    p
+}
+"#;
+
+const NESTED_MEMBER_SELF_ASSIGNMENT: &str = r#"
+#[derive(Drop)]
+struct Inner {
+    c: u32,
+}
+
+#[derive(Drop)]
+struct Outer {
+    b: Inner,
+}
+
+fn main() -> Outer {
+    let mut a = Outer { b: Inner { c: 1 } };
+    a.b.c = a.b.c;
+    a
+}
+"#;
+
+const SELF_ASSIGNMENT_ALLOWED: &str = r#"
+#[allow(self_assignment)]
+fn main() -> u32 {
+    let mut x: u32 = 1;
+    x = x;
+    x
+}
+"#;
+
+const ASSIGNMENT_OF_OTHER_MEMBER: &str = r#"
+#[derive(Drop)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn main() -> Point {
+    let mut p = Point { x: 1, y: 2 };
+    p.x = p.y;
+    p
+}
+"#;
+
+const ASSIGNMENT_OF_METHOD_CALL: &str = r#"
+#[derive(Drop, Copy)]
+struct Counter {
+    value: u32,
+}
+
+#[generate_trait]
+impl CounterImpl of CounterTrait {
+    fn value(self: @Counter) -> u32 {
+        *self.value
+    }
+}
+
+fn main() -> Counter {
+    let mut counter = Counter { value: 1 };
+    counter.value = counter.value();
+    counter
+}
+"#;
+
+#[test]
+fn variable_self_assignment_diagnostics() {
+    test_lint_diagnostics!(VARIABLE_SELF_ASSIGNMENT, @r"
+    Plugin diagnostic: Assignment of a value to itself has no effect. Consider removing it.
+     --> lib.cairo:4:5
+        x = x;
+        ^^^^^
+    ");
+}
+
+#[test]
+fn variable_self_assignment_fixer() {
+    test_lint_fixer!(VARIABLE_SELF_ASSIGNMENT, @r"
+    fn main() -> u32 {
+        let mut x: u32 = 1;
+        x
+    }
+    ");
+}
+
+#[test]
+fn member_self_assignment_diagnostics() {
+    test_lint_diagnostics!(MEMBER_SELF_ASSIGNMENT, @r"
+    Plugin diagnostic: Assignment of a value to itself has no effect. Consider removing it.
+     --> lib.cairo:11:5
+        p.x = p.x;
+        ^^^^^^^^^
+    ");
+}
+
+#[test]
+fn member_self_assignment_fixer() {
+    test_lint_fixer!(MEMBER_SELF_ASSIGNMENT, @r"
+    #[derive(Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    fn main() -> Point {
+        let mut p = Point { x: 1, y: 2 };
+        // Keep the point as it is.
+        p
+    }
+    ");
+}
+
+#[test]
+fn nested_member_self_assignment_diagnostics() {
+    test_lint_diagnostics!(NESTED_MEMBER_SELF_ASSIGNMENT, @r"
+    Plugin diagnostic: Assignment of a value to itself has no effect. Consider removing it.
+     --> lib.cairo:14:5
+        a.b.c = a.b.c;
+        ^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn nested_member_self_assignment_fixer() {
+    test_lint_fixer!(NESTED_MEMBER_SELF_ASSIGNMENT, @r"
+    #[derive(Drop)]
+    struct Inner {
+        c: u32,
+    }
+
+    #[derive(Drop)]
+    struct Outer {
+        b: Inner,
+    }
+
+    fn main() -> Outer {
+        let mut a = Outer { b: Inner { c: 1 } };
+        a
+    }
+    ");
+}
+
+#[test]
+fn self_assignment_allowed_diagnostics() {
+    test_lint_diagnostics!(SELF_ASSIGNMENT_ALLOWED, @"");
+}
+
+#[test]
+fn self_assignment_allowed_fixer() {
+    test_lint_fixer!(SELF_ASSIGNMENT_ALLOWED, @r"
+    #[allow(self_assignment)]
+    fn main() -> u32 {
+        let mut x: u32 = 1;
+        x = x;
+        x
+    }
+    ");
+}
+
+#[test]
+fn assignment_of_other_member_diagnostics() {
+    test_lint_diagnostics!(ASSIGNMENT_OF_OTHER_MEMBER, @"");
+}
+
+#[test]
+fn assignment_of_method_call_diagnostics() {
+    test_lint_diagnostics!(ASSIGNMENT_OF_METHOD_CALL, @"");
+}
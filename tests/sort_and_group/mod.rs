@@ -0,0 +1,137 @@
+use cairo_lang_defs::{db::DefsGroup, diagnostic_utils::StableLocation, ids::ModuleId};
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::ids::{CrateInput, FileId};
+use cairo_lang_semantic::{SemanticDiagnostic, diagnostic::SemanticDiagnosticKind};
+use cairo_lang_utils::Intern;
+use cairo_lint::context::get_name_for_diagnostic_message;
+use cairo_lint::diagnostics::sort_and_group;
+use cairo_lint::{LinterAnalysisDatabase, LinterDiagnosticParams, LinterGroup};
+
+use crate::helpers::{init_corelib, setup::setup_test_crate_with_files};
+
+const LIB: &str = r#"
+mod other;
+
+fn main(x: u32) -> u32 {
+    x * 0 + 0
+}
+
+fn foo() -> u32 {
+    ((1))
+}
+"#;
+
+const OTHER: &str = r#"
+fn bar() -> u32 {
+    ((2))
+}
+
+fn baz(y: u32) -> u32 {
+    y + 0
+}
+"#;
+
+fn test_db() -> LinterAnalysisDatabase {
+    LinterAnalysisDatabase::builder()
+        .with_default_plugin_suite(::cairo_lang_semantic::inline_macros::get_default_plugin_suite())
+        .with_default_plugin_suite(::cairo_lang_test_plugin::test_plugin_suite())
+        .build()
+        .unwrap()
+}
+
+/// Returns the main files of the crate root and of the `other` submodule.
+fn crate_files<'db>(
+    db: &'db LinterAnalysisDatabase,
+    test_crate: &CrateInput,
+) -> (FileId<'db>, FileId<'db>) {
+    let crate_id = test_crate.clone().into_crate_long_id(db).intern(db);
+    let lib_file = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let other_file = db
+        .crate_modules(crate_id)
+        .iter()
+        .map(|module_id| db.module_main_file(*module_id).unwrap())
+        .find(|file_id| *file_id != lib_file)
+        .unwrap();
+    (lib_file, other_file)
+}
+
+/// Returns the linter diagnostics of all the modules of the crate.
+fn crate_diagnostics<'db>(
+    db: &'db LinterAnalysisDatabase,
+    test_crate: &CrateInput,
+) -> Vec<SemanticDiagnostic<'db>> {
+    let crate_id = test_crate.clone().into_crate_long_id(db).intern(db);
+    let linter_params = LinterDiagnosticParams {
+        only_generated_files: true,
+        ..Default::default()
+    };
+    db.crate_modules(crate_id)
+        .iter()
+        .flat_map(|module_id| {
+            db.linter_diagnostics(linter_params.clone(), *module_id)
+                .iter()
+                .map(|diagnostic| {
+                    SemanticDiagnostic::new(
+                        StableLocation::new(diagnostic.stable_ptr),
+                        SemanticDiagnosticKind::PluginDiagnostic(diagnostic.clone()),
+                        *module_id,
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Returns the linted code and the lint name of each diagnostic.
+fn linted_code_and_lint(
+    db: &LinterAnalysisDatabase,
+    diagnostics: &[SemanticDiagnostic],
+) -> Vec<(String, &'static str)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let code = diagnostic
+                .stable_location
+                .syntax_node(db)
+                .get_text_without_trivia(db)
+                .long(db)
+                .to_string();
+            let lint = get_name_for_diagnostic_message(&diagnostic.format(db)).unwrap();
+            (code, lint)
+        })
+        .collect()
+}
+
+#[test]
+fn diagnostics_are_grouped_by_file_and_sorted() {
+    let mut db = test_db();
+    let test_crate =
+        setup_test_crate_with_files(&mut db, &[("lib.cairo", LIB), ("other.cairo", OTHER)]);
+    init_corelib(&mut db);
+
+    let mut diagnostics = crate_diagnostics(&db, &test_crate);
+    assert_eq!(diagnostics.len(), 5);
+    // Shuffle the diagnostics so the ones of both files are interleaved.
+    diagnostics.reverse();
+    diagnostics.swap(1, 3);
+
+    let grouped = sort_and_group(diagnostics, &db);
+    assert_eq!(grouped.len(), 2);
+
+    let (lib_file, other_file) = crate_files(&db, &test_crate);
+    assert_eq!(
+        linted_code_and_lint(&db, &grouped[&lib_file]),
+        vec![
+            ("x * 0".to_string(), "erasing_op"),
+            ("x * 0 + 0".to_string(), "redundant_op"),
+            ("((1))".to_string(), "double_parens"),
+        ]
+    );
+    assert_eq!(
+        linted_code_and_lint(&db, &grouped[&other_file]),
+        vec![
+            ("((2))".to_string(), "double_parens"),
+            ("y + 0".to_string(), "redundant_op"),
+        ]
+    );
+}
@@ -0,0 +1,109 @@
+use crate::test_lint_diagnostics;
+
+const FIELDS_ASSIGNED_AFTER_DEFAULT: &str = r#"
+#[derive(Default, Drop)]
+struct Config {
+    size: u32,
+    verbose: bool,
+}
+
+fn main() -> Config {
+    let mut config: Config = Default::default();
+    config.size = 10;
+    config.verbose = true;
+    config
+}
+"#;
+
+const SINGLE_FIELD_ASSIGNED_AFTER_DEFAULT: &str = r#"
+#[derive(Default, Drop)]
+struct Config {
+    size: u32,
+    verbose: bool,
+}
+
+fn main(size: u32) -> Config {
+    let mut config: Config = Default::default();
+    config.size = size;
+    config
+}
+"#;
+
+const FIELDS_ASSIGNED_AFTER_DEFAULT_ALLOWED: &str = r#"
+#[derive(Default, Drop)]
+struct Config {
+    size: u32,
+    verbose: bool,
+}
+
+#[allow(field_reassign_with_default)]
+fn main() -> Config {
+    let mut config: Config = Default::default();
+    config.size = 10;
+    config
+}
+"#;
+
+const ASSIGNED_VALUE_READS_STRUCT: &str = r#"
+#[derive(Default, Drop)]
+struct Config {
+    size: u32,
+    verbose: bool,
+}
+
+fn main() -> Config {
+    let mut config: Config = Default::default();
+    config.verbose = config.size > 5;
+    config
+}
+"#;
+
+const STATEMENT_BEFORE_ASSIGNMENT: &str = r#"
+#[derive(Default, Drop)]
+struct Config {
+    size: u32,
+    verbose: bool,
+}
+
+fn main(size: u32) -> Config {
+    let mut config: Config = Default::default();
+    let doubled = size * 2;
+    config.size = doubled;
+    config
+}
+"#;
+
+#[test]
+fn fields_assigned_after_default_diagnostics() {
+    test_lint_diagnostics!(FIELDS_ASSIGNED_AFTER_DEFAULT, @r"
+    Plugin diagnostic: Fields of a struct created with `Default::default()` are assigned right away. Consider initializing the struct with these fields directly.
+     --> lib.cairo:9:5
+        let mut config: Config = Default::default();
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn single_field_assigned_after_default_diagnostics() {
+    test_lint_diagnostics!(SINGLE_FIELD_ASSIGNED_AFTER_DEFAULT, @r"
+    Plugin diagnostic: Fields of a struct created with `Default::default()` are assigned right away. Consider initializing the struct with these fields directly.
+     --> lib.cairo:9:5
+        let mut config: Config = Default::default();
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn fields_assigned_after_default_allowed_diagnostics() {
+    test_lint_diagnostics!(FIELDS_ASSIGNED_AFTER_DEFAULT_ALLOWED, @"");
+}
+
+#[test]
+fn assigned_value_reads_struct_diagnostics() {
+    test_lint_diagnostics!(ASSIGNED_VALUE_READS_STRUCT, @"");
+}
+
+#[test]
+fn statement_before_assignment_diagnostics() {
+    test_lint_diagnostics!(STATEMENT_BEFORE_ASSIGNMENT, @"");
+}
@@ -0,0 +1,101 @@
+use crate::test_lint_diagnostics;
+
+const COPY_PASTED_MEMBER: &str = r#"
+#[derive(Copy, Drop, PartialEq)]
+struct Point {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+fn main(a: Point, b: Point) -> bool {
+    a.x == b.x && a.y == b.x
+}
+"#;
+
+const COPY_PASTED_MEMBER_IN_LONGER_CHAIN: &str = r#"
+#[derive(Copy, Drop, PartialEq)]
+struct Point {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+fn main(a: Point, b: Point) -> bool {
+    a.x != b.x || a.y != b.y || a.z != b.y
+}
+"#;
+
+const COPY_PASTED_MEMBER_ALLOWED: &str = r#"
+#[derive(Copy, Drop, PartialEq)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[allow(suspicious_operation_groupings)]
+fn main(a: Point, b: Point) -> bool {
+    a.x == b.x && a.y == b.x
+}
+"#;
+
+const ASYMMETRIC_COMPARISON: &str = r#"
+#[derive(Copy, Drop, PartialEq)]
+struct Range {
+    id: u32,
+    start: u32,
+    end: u32,
+}
+
+fn main(a: Range, b: Range) -> bool {
+    a.id == b.id && a.end == b.start
+}
+"#;
+
+const DIFFERENT_VARIABLES: &str = r#"
+#[derive(Copy, Drop, PartialEq)]
+struct Point {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+fn main(a: Point, b: Point, c: Point) -> bool {
+    a.x == b.x && a.y == c.x
+}
+"#;
+
+#[test]
+fn copy_pasted_member_diagnostics() {
+    test_lint_diagnostics!(COPY_PASTED_MEMBER, @r"
+    Plugin diagnostic: Suspicious comparison of different members in a chain of comparisons. Check if the compared members are correct.
+     --> lib.cairo:10:19
+        a.x == b.x && a.y == b.x
+                      ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn copy_pasted_member_in_longer_chain_diagnostics() {
+    test_lint_diagnostics!(COPY_PASTED_MEMBER_IN_LONGER_CHAIN, @r"
+    Plugin diagnostic: Suspicious comparison of different members in a chain of comparisons. Check if the compared members are correct.
+     --> lib.cairo:10:33
+        a.x != b.x || a.y != b.y || a.z != b.y
+                                    ^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn copy_pasted_member_allowed_diagnostics() {
+    test_lint_diagnostics!(COPY_PASTED_MEMBER_ALLOWED, @"");
+}
+
+#[test]
+fn asymmetric_comparison_diagnostics() {
+    test_lint_diagnostics!(ASYMMETRIC_COMPARISON, @"");
+}
+
+#[test]
+fn different_variables_diagnostics() {
+    test_lint_diagnostics!(DIFFERENT_VARIABLES, @"");
+}
@@ -0,0 +1,112 @@
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+use crate::test_lint_diagnostics;
+
+const EIGHT_ARGUMENTS: &str = r#"
+fn draw(_a: u32, _b: u32, _c: u32, _d: u32, _e: u32, _f: u32, _g: u32, _h: u32) {}
+"#;
+
+const EIGHT_ARGUMENTS_ALLOWED: &str = r#"
+#[allow(too_many_arguments)]
+fn draw(_a: u32, _b: u32, _c: u32, _d: u32, _e: u32, _f: u32, _g: u32, _h: u32) {}
+"#;
+
+const SEVEN_ARGUMENTS: &str = r#"
+fn draw(_a: u32, _b: u32, _c: u32, _d: u32, _e: u32, _f: u32, _g: u32) {}
+"#;
+
+const TRAIT_AND_IMPL_FUNCTIONS: &str = r#"
+#[derive(Drop)]
+struct Canvas {}
+
+trait CanvasTrait {
+    fn draw(_a: u32, _b: u32, _c: u32, _d: u32, _e: u32, _f: u32, _g: u32, _h: u32);
+}
+
+impl CanvasImpl of CanvasTrait {
+    fn draw(_a: u32, _b: u32, _c: u32, _d: u32, _e: u32, _f: u32, _g: u32, _h: u32) {}
+}
+"#;
+
+const SELF_IS_NOT_COUNTED: &str = r#"
+#[derive(Drop)]
+struct Canvas {}
+
+trait CanvasTrait {
+    fn draw(ref self: Canvas, a: u32, b: u32, c: u32, d: u32, e: u32, f: u32, g: u32);
+}
+"#;
+
+const THREE_ARGUMENTS: &str = r#"
+fn draw(_a: u32, _b: u32, _c: u32) {}
+"#;
+
+#[test]
+fn eight_arguments_diagnostics() {
+    test_lint_diagnostics!(EIGHT_ARGUMENTS, @r"
+    Plugin diagnostic: This function has too many arguments. Consider grouping some of them in a struct.
+     --> lib.cairo:2:8
+    fn draw(_a: u32, _b: u32, _c: u32, _d: u32, _e: u32, _f: u32, _g: u32, _h: u32) {}
+           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn eight_arguments_allowed_diagnostics() {
+    test_lint_diagnostics!(EIGHT_ARGUMENTS_ALLOWED, @"");
+}
+
+#[test]
+fn seven_arguments_diagnostics() {
+    test_lint_diagnostics!(SEVEN_ARGUMENTS, @"");
+}
+
+#[test]
+fn trait_and_impl_functions_diagnostics() {
+    test_lint_diagnostics!(TRAIT_AND_IMPL_FUNCTIONS, @r"
+    Plugin diagnostic: This function has too many arguments. Consider grouping some of them in a struct.
+     --> lib.cairo:6:12
+        fn draw(_a: u32, _b: u32, _c: u32, _d: u32, _e: u32, _f: u32, _g: u32, _h: u32);
+               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    Plugin diagnostic: This function has too many arguments. Consider grouping some of them in a struct.
+     --> lib.cairo:10:12
+        fn draw(_a: u32, _b: u32, _c: u32, _d: u32, _e: u32, _f: u32, _g: u32, _h: u32) {}
+               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn self_is_not_counted_diagnostics() {
+    test_lint_diagnostics!(SELF_IS_NOT_COUNTED, @"");
+}
+
+fn too_many_arguments_count(code: &str, too_many_arguments_threshold: usize) -> usize {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("too_many_arguments".to_string(), true)]),
+        too_many_arguments_threshold,
+        ..Default::default()
+    };
+    lint_string(
+        code,
+        &params,
+        Some(get_corelib_path()),
+        |_db, diagnostics| diagnostics.len(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn lowered_threshold_is_exceeded() {
+    assert_eq!(too_many_arguments_count(THREE_ARGUMENTS, 2), 1);
+}
+
+#[test]
+fn lowered_threshold_is_reached() {
+    assert_eq!(too_many_arguments_count(THREE_ARGUMENTS, 3), 0);
+}
+
+#[test]
+fn raised_threshold() {
+    assert_eq!(too_many_arguments_count(EIGHT_ARGUMENTS, 8), 0);
+}
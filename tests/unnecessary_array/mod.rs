@@ -0,0 +1,102 @@
+use crate::test_lint_diagnostics;
+
+const ARRAY_TO_SPAN: &str = r#"
+fn main() -> Span<u32> {
+    array![1, 2, 3].span()
+}
+"#;
+
+const ARRAY_INTO_ITER: &str = r#"
+fn main() {
+    let mut iter = array![1, 2, 3].into_iter();
+    let _first = iter.next();
+}
+"#;
+
+const ARRAY_IN_FOR_LOOP: &str = r#"
+fn main() -> u32 {
+    let mut sum = 0;
+    for value in array![1, 2, 3] {
+        sum += value;
+    }
+    sum
+}
+"#;
+
+const ARRAY_BOUND_TO_VARIABLE: &str = r#"
+fn main() -> Span<u32> {
+    let mut arr = array![1, 2, 3];
+    arr.append(4);
+    arr.span()
+}
+"#;
+
+const EMPTY_ARRAY_TO_SPAN: &str = r#"
+fn main() -> Span<u32> {
+    array![].span()
+}
+"#;
+
+const ARRAY_RETURNED: &str = r#"
+fn main() -> Array<u32> {
+    array![1, 2, 3]
+}
+"#;
+
+const ARRAY_TO_SPAN_ALLOWED: &str = r#"
+fn main() -> Span<u32> {
+    #[allow(unnecessary_array)]
+    let span = array![1, 2, 3].span();
+    span
+}
+"#;
+
+#[test]
+fn array_to_span_diagnostics() {
+    test_lint_diagnostics!(ARRAY_TO_SPAN, @r"
+    Plugin diagnostic: Array created only to be iterated over or turned into a span. Consider using a fixed-size array instead.
+     --> lib.cairo:3:5
+        array![1, 2, 3].span()
+        ^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn array_into_iter_diagnostics() {
+    test_lint_diagnostics!(ARRAY_INTO_ITER, @r"
+    Plugin diagnostic: Array created only to be iterated over or turned into a span. Consider using a fixed-size array instead.
+     --> lib.cairo:3:20
+        let mut iter = array![1, 2, 3].into_iter();
+                       ^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn array_in_for_loop_diagnostics() {
+    test_lint_diagnostics!(ARRAY_IN_FOR_LOOP, @r"
+    Plugin diagnostic: Array created only to be iterated over or turned into a span. Consider using a fixed-size array instead.
+     --> lib.cairo:4:18
+        for value in array![1, 2, 3] {
+                     ^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn array_bound_to_variable_diagnostics() {
+    test_lint_diagnostics!(ARRAY_BOUND_TO_VARIABLE, @"");
+}
+
+#[test]
+fn empty_array_to_span_diagnostics() {
+    test_lint_diagnostics!(EMPTY_ARRAY_TO_SPAN, @"");
+}
+
+#[test]
+fn array_returned_diagnostics() {
+    test_lint_diagnostics!(ARRAY_RETURNED, @"");
+}
+
+#[test]
+fn array_to_span_allowed_diagnostics() {
+    test_lint_diagnostics!(ARRAY_TO_SPAN_ALLOWED, @"");
+}
@@ -0,0 +1,139 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const INTO_AND_BACK: &str = r#"
+#[derive(Copy, Drop)]
+struct Meters {
+    value: u32,
+}
+
+impl U32IntoMeters of Into<u32, Meters> {
+    fn into(self: u32) -> Meters {
+        Meters { value: self }
+    }
+}
+
+impl MetersIntoU32 of Into<Meters, u32> {
+    fn into(self: Meters) -> u32 {
+        self.value
+    }
+}
+
+fn main(x: u32) -> u32 {
+    let y: u32 = Into::<u32, Meters>::into(x).into();
+    y * 2
+}
+"#;
+
+const INTO_AND_TRY_INTO_BACK: &str = r#"
+fn main(x: u8) -> u8 {
+    Into::<u8, u64>::into(x).try_into().unwrap()
+}
+"#;
+
+const INTO_AND_BACK_ALLOWED: &str = r#"
+#[derive(Copy, Drop)]
+struct Meters {
+    value: u32,
+}
+
+impl U32IntoMeters of Into<u32, Meters> {
+    fn into(self: u32) -> Meters {
+        Meters { value: self }
+    }
+}
+
+impl MetersIntoU32 of Into<Meters, u32> {
+    fn into(self: Meters) -> u32 {
+        self.value
+    }
+}
+
+#[allow(unnecessary_cast_chain)]
+fn main(x: u32) -> u32 {
+    let y: u32 = Into::<u32, Meters>::into(x).into();
+    y * 2
+}
+"#;
+
+const CHAIN_TO_ANOTHER_TYPE: &str = r#"
+fn main(x: u8) -> felt252 {
+    Into::<u8, u64>::into(x).into()
+}
+"#;
+
+const SINGLE_CONVERSION: &str = r#"
+fn main(x: u8) -> u64 {
+    x.into()
+}
+"#;
+
+#[test]
+fn into_and_back_diagnostics() {
+    test_lint_diagnostics!(INTO_AND_BACK, @r"
+    Plugin diagnostic: Unnecessary conversion chain: the value is converted back to its original type.
+     --> lib.cairo:20:18
+        let y: u32 = Into::<u32, Meters>::into(x).into();
+                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn into_and_back_fixer() {
+    test_lint_fixer!(INTO_AND_BACK, @r"
+    #[derive(Copy, Drop)]
+    struct Meters {
+        value: u32,
+    }
+
+    impl U32IntoMeters of Into<u32, Meters> {
+        fn into(self: u32) -> Meters {
+            Meters { value: self }
+        }
+    }
+
+    impl MetersIntoU32 of Into<Meters, u32> {
+        fn into(self: Meters) -> u32 {
+            self.value
+        }
+    }
+
+    fn main(x: u32) -> u32 {
+        let y: u32 = x;
+        y * 2
+    }
+    ");
+}
+
+#[test]
+fn into_and_try_into_back_diagnostics() {
+    test_lint_diagnostics!(INTO_AND_TRY_INTO_BACK, @r"
+    Plugin diagnostic: Unnecessary conversion chain: the value is converted back to its original type.
+     --> lib.cairo:3:5
+        Into::<u8, u64>::into(x).try_into().unwrap()
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn into_and_try_into_back_fixer() {
+    test_lint_fixer!(INTO_AND_TRY_INTO_BACK, @r"
+    fn main(x: u8) -> u8 {
+        Into::<u8, u64>::into(x).try_into().unwrap()
+    }
+    ");
+}
+
+#[test]
+fn into_and_back_allowed_diagnostics() {
+    test_lint_diagnostics!(INTO_AND_BACK_ALLOWED, @"");
+}
+
+#[test]
+fn chain_to_another_type_diagnostics() {
+    test_lint_diagnostics!(CHAIN_TO_ANOTHER_TYPE, @"");
+}
+
+#[test]
+fn single_conversion_diagnostics() {
+    test_lint_diagnostics!(SINGLE_CONVERSION, @"");
+}
@@ -0,0 +1,171 @@
+use std::collections::BTreeSet;
+
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const UNUSED_ALLOW_MESSAGE: &str =
+    "Unnecessary `allow` attribute, the allowed lint is not reported in the covered code.";
+
+const NECESSARY_ALLOW: &str = r#"
+#[allow(duplicate_underscore_args)]
+fn foo(a: u32, _a: u32) {}
+"#;
+
+const SUPERFLUOUS_ALLOW: &str = r#"
+#[allow(duplicate_underscore_args)]
+fn foo(a: u32, b: u32) {}
+"#;
+
+const SUPERFLUOUS_GROUP_ALLOW: &str = r#"
+#[allow(cairo_lint::performance)]
+fn foo(a: u32, _a: u32) {}
+"#;
+
+const NECESSARY_GROUP_ALLOW: &str = r#"
+#[allow(cairo_lint::correctness)]
+fn foo(a: u32, _a: u32) {}
+"#;
+
+const SUPERFLUOUS_ALLOW_IN_FUNCTION: &str = r#"
+fn main() {
+    let x = true;
+    #[allow(bool_comparison)]
+    let _y = x;
+}
+"#;
+
+const SUPERFLUOUS_ALLOW_ALLOWED: &str = r#"
+#[allow(unused_allow)]
+#[allow(duplicate_underscore_args)]
+fn foo(a: u32, b: u32) {}
+"#;
+
+const ALLOWS_OF_SELECTED_AND_NOT_SELECTED_LINTS: &str = r#"
+#[allow(duplicate_underscore_args)]
+fn foo(a: u32, _a: u32) {}
+
+fn main() {
+    let x = true;
+    #[allow(bool_comparison)]
+    let _y = x;
+}
+"#;
+
+fn lint(source: &str, tool_metadata: CairoLintToolMetadata) -> Vec<(String, String)> {
+    lint_with_params(
+        source,
+        &LinterDiagnosticParams {
+            tool_metadata,
+            ..Default::default()
+        },
+    )
+}
+
+/// Returns the messages of the reported diagnostics, along with the text of their nodes.
+fn lint_with_params(source: &str, params: &LinterDiagnosticParams) -> Vec<(String, String)> {
+    lint_string(
+        source,
+        params,
+        Some(get_corelib_path()),
+        |db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    let node = diagnostic.stable_ptr.lookup(db);
+                    (
+                        diagnostic.message,
+                        node.get_text_without_trivia(db).long(db).to_string(),
+                    )
+                })
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+fn lint_with_unused_allow_enabled(source: &str) -> Vec<(String, String)> {
+    lint(
+        source,
+        CairoLintToolMetadata::from([("unused_allow".to_string(), true)]),
+    )
+}
+
+#[test]
+fn necessary_allow_is_not_reported() {
+    assert_eq!(lint_with_unused_allow_enabled(NECESSARY_ALLOW), vec![]);
+}
+
+#[test]
+fn superfluous_allow_is_reported() {
+    assert_eq!(
+        lint_with_unused_allow_enabled(SUPERFLUOUS_ALLOW),
+        vec![(
+            UNUSED_ALLOW_MESSAGE.to_string(),
+            "#[allow(duplicate_underscore_args)]".to_string()
+        )]
+    );
+}
+
+#[test]
+fn superfluous_group_allow_is_reported() {
+    let diagnostics = lint_with_unused_allow_enabled(SUPERFLUOUS_GROUP_ALLOW);
+    assert!(diagnostics.contains(&(
+        UNUSED_ALLOW_MESSAGE.to_string(),
+        "#[allow(cairo_lint::performance)]".to_string()
+    )));
+}
+
+#[test]
+fn necessary_group_allow_is_not_reported() {
+    assert_eq!(
+        lint_with_unused_allow_enabled(NECESSARY_GROUP_ALLOW),
+        vec![]
+    );
+}
+
+#[test]
+fn superfluous_allow_in_function_is_reported() {
+    assert_eq!(
+        lint_with_unused_allow_enabled(SUPERFLUOUS_ALLOW_IN_FUNCTION),
+        vec![(
+            UNUSED_ALLOW_MESSAGE.to_string(),
+            "#[allow(bool_comparison)]".to_string()
+        )]
+    );
+}
+
+#[test]
+fn superfluous_allow_can_be_allowed() {
+    assert_eq!(
+        lint_with_unused_allow_enabled(SUPERFLUOUS_ALLOW_ALLOWED),
+        vec![]
+    );
+}
+
+#[test]
+fn unused_allow_is_disabled_by_default() {
+    assert_eq!(
+        lint(SUPERFLUOUS_ALLOW, CairoLintToolMetadata::default()),
+        vec![]
+    );
+}
+
+#[test]
+fn allows_of_not_selected_lints_are_not_reported() {
+    let params = LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("unused_allow".to_string(), true)]),
+        enabled_lints: Some(BTreeSet::from([
+            "unused_allow".to_string(),
+            "bool_comparison".to_string(),
+        ])),
+        ..Default::default()
+    };
+    assert_eq!(
+        lint_with_params(ALLOWS_OF_SELECTED_AND_NOT_SELECTED_LINTS, &params),
+        vec![(
+            UNUSED_ALLOW_MESSAGE.to_string(),
+            "#[allow(bool_comparison)]".to_string()
+        )]
+    );
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeSet;
+
+use cairo_lint::{CairoLintToolMetadata, LinterDiagnosticParams, lint_string};
+
+use crate::helpers::get_corelib_path;
+
+const UNUSED_ENUM_VARIANT_MESSAGE: &str =
+    "Enum variant is never constructed. Consider removing it.";
+
+const VARIANT_ONLY_MATCHED: &str = r#"
+#[derive(Drop)]
+enum Shape {
+    Circle: u32,
+    Square: u32,
+}
+
+fn area(shape: Shape) -> u32 {
+    match shape {
+        Shape::Circle(radius) => 3 * radius * radius,
+        Shape::Square(side) => side * side,
+    }
+}
+
+fn main() -> u32 {
+    area(Shape::Square(2))
+}
+"#;
+
+const VARIANT_NEVER_REFERENCED: &str = r#"
+#[derive(Drop)]
+enum Direction {
+    Left,
+    Right,
+}
+
+fn main() -> Direction {
+    Direction::Left
+}
+"#;
+
+const ALL_VARIANTS_CONSTRUCTED: &str = r#"
+#[derive(Drop)]
+enum Direction {
+    Left,
+    Right,
+}
+
+fn main(left: bool) -> Direction {
+    if left {
+        Direction::Left
+    } else {
+        Direction::Right
+    }
+}
+"#;
+
+const VARIANT_CONSTRUCTED_IN_ANOTHER_MODULE: &str = r#"
+#[derive(Drop)]
+enum Direction {
+    Left,
+    Right,
+}
+
+fn main() -> Direction {
+    Direction::Left
+}
+
+mod other {
+    use super::Direction;
+
+    fn right() -> Direction {
+        Direction::Right
+    }
+}
+"#;
+
+const PUBLIC_ENUM: &str = r#"
+#[derive(Drop)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+fn main() -> Direction {
+    Direction::Left
+}
+"#;
+
+const CRATE_VISIBLE_ENUM: &str = r#"
+#[derive(Drop)]
+pub(crate) enum Direction {
+    Left,
+    Right,
+}
+
+fn main() -> Direction {
+    Direction::Left
+}
+"#;
+
+const UNUSED_ENUM_VARIANT_ALLOWED: &str = r#"
+#[derive(Drop)]
+#[allow(unused_enum_variant)]
+enum Direction {
+    Left,
+    Right,
+}
+
+fn main() -> Direction {
+    Direction::Left
+}
+"#;
+
+/// Returns the messages of the reported diagnostics, along with the text of their nodes.
+fn lint(source: &str, params: LinterDiagnosticParams) -> Vec<(String, String)> {
+    lint_string(
+        source,
+        &params,
+        Some(get_corelib_path()),
+        |db, diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    let node = diagnostic.stable_ptr.lookup(db);
+                    (
+                        diagnostic.message,
+                        node.get_text_without_trivia(db).long(db).to_string(),
+                    )
+                })
+                .collect()
+        },
+    )
+    .unwrap()
+}
+
+fn unused_enum_variant_params() -> LinterDiagnosticParams {
+    LinterDiagnosticParams {
+        tool_metadata: CairoLintToolMetadata::from([("unused_enum_variant".to_string(), true)]),
+        enabled_lints: Some(BTreeSet::from(["unused_enum_variant".to_string()])),
+        ..Default::default()
+    }
+}
+
+fn unused_variant(variant: &str) -> (String, String) {
+    (UNUSED_ENUM_VARIANT_MESSAGE.to_string(), variant.to_string())
+}
+
+#[test]
+fn variant_only_matched_is_reported() {
+    assert_eq!(
+        lint(VARIANT_ONLY_MATCHED, unused_enum_variant_params()),
+        vec![unused_variant("Circle: u32")]
+    );
+}
+
+#[test]
+fn variant_never_referenced_is_reported() {
+    assert_eq!(
+        lint(VARIANT_NEVER_REFERENCED, unused_enum_variant_params()),
+        vec![unused_variant("Right")]
+    );
+}
+
+#[test]
+fn all_variants_constructed_are_not_reported() {
+    assert_eq!(
+        lint(ALL_VARIANTS_CONSTRUCTED, unused_enum_variant_params()),
+        vec![]
+    );
+}
+
+#[test]
+fn variant_constructed_in_another_module_is_not_reported() {
+    assert_eq!(
+        lint(
+            VARIANT_CONSTRUCTED_IN_ANOTHER_MODULE,
+            unused_enum_variant_params()
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn public_enum_is_not_checked_by_default() {
+    assert_eq!(lint(PUBLIC_ENUM, unused_enum_variant_params()), vec![]);
+}
+
+#[test]
+fn public_enum_is_checked_if_configured() {
+    let params = LinterDiagnosticParams {
+        check_public_enum_variants: true,
+        ..unused_enum_variant_params()
+    };
+    assert_eq!(lint(PUBLIC_ENUM, params), vec![unused_variant("Right")]);
+}
+
+#[test]
+fn crate_visible_enum_is_checked() {
+    assert_eq!(
+        lint(CRATE_VISIBLE_ENUM, unused_enum_variant_params()),
+        vec![unused_variant("Right")]
+    );
+}
+
+#[test]
+fn unused_enum_variant_can_be_allowed() {
+    assert_eq!(
+        lint(UNUSED_ENUM_VARIANT_ALLOWED, unused_enum_variant_params()),
+        vec![]
+    );
+}
+
+#[test]
+fn unused_enum_variant_is_disabled_by_default() {
+    let params = LinterDiagnosticParams {
+        enabled_lints: Some(BTreeSet::from(["unused_enum_variant".to_string()])),
+        ..Default::default()
+    };
+    assert_eq!(lint(VARIANT_NEVER_REFERENCED, params), vec![]);
+}
@@ -0,0 +1,124 @@
+use crate::test_lint_diagnostics;
+
+const WILDCARD_IMPORT: &str = r#"
+mod a {
+    pub fn foo() -> u32 {
+        1
+    }
+}
+
+use a::*;
+
+fn main() -> u32 {
+    foo()
+}
+"#;
+
+const WILDCARD_IMPORT_IN_MULTI_IMPORT: &str = r#"
+mod a {
+    pub mod b {
+        pub fn foo() -> u32 {
+            1
+        }
+    }
+
+    pub fn bar() -> u32 {
+        2
+    }
+}
+
+use a::{b::*, bar};
+
+fn main() -> u32 {
+    foo() + bar()
+}
+"#;
+
+const WILDCARD_IMPORT_IN_SUBMODULE: &str = r#"
+mod a {
+    pub fn foo() -> u32 {
+        1
+    }
+}
+
+mod b {
+    use super::a::*;
+
+    pub fn bar() -> u32 {
+        foo()
+    }
+}
+
+fn main() -> u32 {
+    b::bar()
+}
+"#;
+
+const EXPLICIT_IMPORT: &str = r#"
+mod a {
+    pub fn foo() -> u32 {
+        1
+    }
+}
+
+use a::foo;
+
+fn main() -> u32 {
+    foo()
+}
+"#;
+
+const WILDCARD_IMPORT_ALLOWED: &str = r#"
+mod a {
+    pub fn foo() -> u32 {
+        1
+    }
+}
+
+#[allow(wildcard_import)]
+use a::*;
+
+fn main() -> u32 {
+    foo()
+}
+"#;
+
+#[test]
+fn wildcard_import_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_IMPORT, @r"
+    Plugin diagnostic: Wildcard import obscures which names are in scope. Consider importing the used items explicitly.
+     --> lib.cairo:8:5
+    use a::*;
+        ^^^^
+    ");
+}
+
+#[test]
+fn wildcard_import_in_multi_import_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_IMPORT_IN_MULTI_IMPORT, @r"
+    Plugin diagnostic: Wildcard import obscures which names are in scope. Consider importing the used items explicitly.
+     --> lib.cairo:14:9
+    use a::{b::*, bar};
+            ^^^^
+    ");
+}
+
+#[test]
+fn wildcard_import_in_submodule_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_IMPORT_IN_SUBMODULE, @r"
+    Plugin diagnostic: Wildcard import obscures which names are in scope. Consider importing the used items explicitly.
+     --> lib.cairo:9:9
+        use super::a::*;
+            ^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn explicit_import_diagnostics() {
+    test_lint_diagnostics!(EXPLICIT_IMPORT, @"");
+}
+
+#[test]
+fn wildcard_import_allowed_diagnostics() {
+    test_lint_diagnostics!(WILDCARD_IMPORT_ALLOWED, @"");
+}
@@ -0,0 +1,100 @@
+use crate::{test_lint_diagnostics, test_lint_fixer};
+
+const ZERO_MODULO_VARIABLE: &str = r#"
+fn main(x: u8) -> u8 {
+    0_u8 % x
+}
+"#;
+
+const ZERO_MODULO_VARIABLE_ALLOWED: &str = r#"
+fn main() {
+    let x = 1_u32;
+    #[allow(zero_divided_by)]
+    let _y = 0 % x;
+}
+"#;
+
+const ZERO_MODULO_FUNCTION_CALL: &str = r#"
+fn divisor() -> u32 {
+    2
+}
+
+fn main() -> u32 {
+    0 % divisor()
+}
+"#;
+
+const ZERO_DIVIDED_BY_VARIABLE: &str = r#"
+fn main() {
+    let x = 1_u32;
+    let _y = 0 / x;
+}
+"#;
+
+#[test]
+fn zero_modulo_variable_diagnostics() {
+    test_lint_diagnostics!(ZERO_MODULO_VARIABLE, @r"
+    Plugin diagnostic: The remainder of zero divided by a value is always zero. Consider replacing the entire expression with 0.
+     --> lib.cairo:3:5
+        0_u8 % x
+        ^^^^^^^^
+    ");
+}
+
+#[test]
+fn zero_modulo_variable_fixer() {
+    test_lint_fixer!(ZERO_MODULO_VARIABLE, @r"
+    fn main(x: u8) -> u8 {
+        0_u8
+    }
+    ");
+}
+
+#[test]
+fn zero_modulo_variable_allowed_diagnostics() {
+    test_lint_diagnostics!(ZERO_MODULO_VARIABLE_ALLOWED, @"");
+}
+
+#[test]
+fn zero_modulo_variable_allowed_fixer() {
+    test_lint_fixer!(ZERO_MODULO_VARIABLE_ALLOWED, @r"
+    fn main() {
+        let x = 1_u32;
+        #[allow(zero_divided_by)]
+        let _y = 0 % x;
+    }
+    ");
+}
+
+#[test]
+fn zero_modulo_function_call_diagnostics() {
+    test_lint_diagnostics!(ZERO_MODULO_FUNCTION_CALL, @r"
+    Plugin diagnostic: The remainder of zero divided by a value is always zero. Consider replacing the entire expression with 0.
+     --> lib.cairo:7:5
+        0 % divisor()
+        ^^^^^^^^^^^^^
+    ");
+}
+
+#[test]
+fn zero_modulo_function_call_fixer() {
+    test_lint_fixer!(ZERO_MODULO_FUNCTION_CALL, @r"
+    fn divisor() -> u32 {
+        2
+    }
+
+    fn main() -> u32 {
+        0 % divisor()
+    }
+    ");
+}
+
+#[test]
+fn zero_divided_by_variable_diagnostics() {
+    test_lint_diagnostics!(ZERO_DIVIDED_BY_VARIABLE, @r"
+    Plugin diagnostic: This operation results in the value being erased (e.g., multiplication by 0). Consider replacing the entire expression with 0.
+     --> lib.cairo:4:14
+        let _y = 0 / x;
+                 ^^^^^
+    ");
+}